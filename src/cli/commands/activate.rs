@@ -4,12 +4,15 @@ use crate::cli::ShellType;
 use crate::core::VirtualenvService;
 use crate::error::Result;
 use crate::paths;
-use crate::shell::{detect_shell, print_activate_script};
+use crate::shell::{PathPosition, detect_shell, print_activate_script};
 use crate::validate;
 
 /// Execute the activate command
 /// Outputs shell script to be eval'd
-pub fn execute(name: &str, shell: Option<ShellType>) -> Result<()> {
+///
+/// `append_path` puts the env's `bin` at the end of `PATH`; without it,
+/// `activate.append_path` in config.json decides (default: prepend).
+pub fn execute(name: &str, shell: Option<ShellType>, append_path: bool) -> Result<()> {
     // Security: Validate input before any processing
     // This is defense-in-depth against command injection via malicious .scuv-version files
     validate::validate_env_name(name)?;
@@ -24,7 +27,13 @@ pub fn execute(name: &str, shell: Option<ShellType>) -> Result<()> {
     let shell_type = shell.unwrap_or_else(detect_shell);
 
     // Output activation script for eval
-    print_activate_script(shell_type, &venv_path, &bin_path, name);
+    print_activate_script(
+        shell_type,
+        &venv_path,
+        &bin_path,
+        name,
+        PathPosition::resolve(append_path),
+    );
 
     // Record activation timestamp. Universal touch site: `use`, auto-activation
     // (cd hook), and explicit `scuv activate` all flow through here via the
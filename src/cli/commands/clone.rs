@@ -32,7 +32,7 @@ pub fn execute(
         });
     }
 
-    let service = VirtualenvService::auto()?;
+    let service = VirtualenvService::for_output(output)?;
 
     if !service.exists(src)? {
         return Err(ScoopError::VirtualenvNotFound {
@@ -101,6 +101,7 @@ pub fn execute(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::ColorChoice;
     use crate::test_utils::with_temp_scoop_home;
     use serial_test::serial;
 
@@ -109,7 +110,7 @@ mod tests {
     fn execute_rejects_invalid_dst_name() {
         with_temp_scoop_home(|temp_dir| {
             std::fs::create_dir_all(temp_dir.path().join("virtualenvs")).unwrap();
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             // "list" is reserved.
             let err = execute(&output, "src", "list", false, false).unwrap_err();
             assert!(matches!(err, ScoopError::InvalidEnvName { .. }));
@@ -121,7 +122,7 @@ mod tests {
     fn execute_rejects_self_clone() {
         with_temp_scoop_home(|temp_dir| {
             std::fs::create_dir_all(temp_dir.path().join("virtualenvs")).unwrap();
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             let err = execute(&output, "same", "same", false, false).unwrap_err();
             assert!(matches!(err, ScoopError::InvalidArgument { .. }));
         });
@@ -132,7 +133,7 @@ mod tests {
     fn execute_returns_not_found_when_src_missing() {
         with_temp_scoop_home(|temp_dir| {
             std::fs::create_dir_all(temp_dir.path().join("virtualenvs")).unwrap();
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             let err = execute(&output, "ghost", "newcopy", false, false).unwrap_err();
             assert!(matches!(err, ScoopError::VirtualenvNotFound { .. }));
         });
@@ -148,11 +149,14 @@ mod tests {
             let meta = crate::core::Metadata {
                 name: "source".to_string(),
                 python_version: "3.12.0".to_string(),
+                python_request: None,
                 created_at: chrono::Utc::now(),
                 created_by: "test".to_string(),
                 uv_version: None,
                 python_path: None,
                 last_used: None,
+                tags: Vec::new(),
+                deps_upgraded: false,
             };
             std::fs::write(
                 src.join(".scoop-metadata.json"),
@@ -162,7 +166,7 @@ mod tests {
 
             std::fs::create_dir_all(temp_dir.path().join("virtualenvs").join("dupe")).unwrap();
 
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             let err = execute(&output, "source", "dupe", false, false /* no force */).unwrap_err();
             assert!(matches!(err, ScoopError::VirtualenvExists { .. }));
         });
@@ -181,7 +185,7 @@ mod tests {
                     .join("bin"),
             )
             .unwrap();
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             let err = execute(&output, "nometa", "newone", false, false).unwrap_err();
             assert!(matches!(err, ScoopError::CorruptedEnvironment { .. }));
         });
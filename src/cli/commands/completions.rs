@@ -1,27 +1,74 @@
 //! Completions command
+//!
+//! Prints the completion script to stdout, or with `--out-dir` writes it
+//! under the shell's conventional file name so packagers can emit every
+//! shell's file in one loop.
+
+use std::path::{Path, PathBuf};
 
 use clap::CommandFactory;
-use clap_complete::{Shell, generate};
+use clap_complete::{Generator, Shell, generate};
+use rust_i18n::t;
 
 use crate::cli::{Cli, ShellType};
-use crate::error::Result;
+use crate::error::{Result, ScoopError};
+use crate::output::Output;
 
 /// Execute the completions command
-pub fn execute(shell: ShellType) -> Result<()> {
-    let mut cmd = Cli::command();
-
+pub fn execute(output: &Output, shell: ShellType, out_dir: Option<&Path>) -> Result<()> {
     let shell = match shell {
         ShellType::Bash => Shell::Bash,
         ShellType::Zsh => Shell::Zsh,
         ShellType::Fish => Shell::Fish,
         ShellType::Powershell => Shell::PowerShell,
+        // cmd.exe has no programmable completion.
+        ShellType::Cmd => {
+            return Err(ScoopError::UnsupportedShell {
+                shell: "cmd".to_string(),
+            });
+        }
     };
 
-    generate(shell, &mut cmd, "scuv", &mut std::io::stdout());
+    match out_dir {
+        None => {
+            generate(shell, &mut Cli::command(), "scuv", &mut std::io::stdout());
+        }
+        Some(dir) => {
+            let path = write_to_dir(shell, dir)?;
+            output.success(&t!(
+                "completions.written",
+                shell = shell.to_string(),
+                path = path.display()
+            ));
+        }
+    }
 
     Ok(())
 }
 
+/// Write `shell`'s completion script into `dir` (created if needed) as
+/// `scuv.bash`, `_scuv`, `scuv.fish` or `_scuv.ps1`, returning the path.
+///
+/// Like `scuv man <dir>`, refuses to write through a symlinked file, since
+/// packaging scripts often run this as root.
+fn write_to_dir(shell: Shell, dir: &Path) -> Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    let target = dir.join(shell.file_name("scuv"));
+
+    if let Ok(meta) = std::fs::symlink_metadata(&target) {
+        if meta.file_type().is_symlink() {
+            return Err(ScoopError::InvalidArgument {
+                message: t!("completions.refuse_symlink", path = target.display()).to_string(),
+            });
+        }
+    }
+
+    let mut buf: Vec<u8> = Vec::new();
+    generate(shell, &mut Cli::command(), "scuv", &mut buf);
+    std::fs::write(&target, buf)?;
+    Ok(target)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,6 +132,36 @@ mod tests {
         assert_every_subcommand_present(Shell::PowerShell, &body);
     }
 
+    #[test]
+    fn write_to_dir_creates_conventionally_named_files() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let dir = tmp.path().join("share/completions");
+
+        for (shell, file) in [
+            (Shell::Bash, "scuv.bash"),
+            (Shell::Zsh, "_scuv"),
+            (Shell::Fish, "scuv.fish"),
+            (Shell::PowerShell, "_scuv.ps1"),
+        ] {
+            let path = write_to_dir(shell, &dir).unwrap();
+            assert_eq!(path, dir.join(file));
+            assert_eq!(std::fs::read_to_string(&path).unwrap(), generate_for(shell));
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn write_to_dir_refuses_symlinked_target() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let victim = tmp.path().join("victim");
+        std::fs::write(&victim, "keep").unwrap();
+        std::os::unix::fs::symlink(&victim, tmp.path().join("scuv.fish")).unwrap();
+
+        let err = write_to_dir(Shell::Fish, tmp.path()).unwrap_err();
+        assert!(matches!(err, ScoopError::InvalidArgument { .. }));
+        assert_eq!(std::fs::read_to_string(&victim).unwrap(), "keep");
+    }
+
     // Note: a "hidden subcommands don't appear in completions" probe
     // was considered and rejected. clap-complete embeds every
     // subcommand name (including hidden ones) in helper-function
@@ -1,31 +1,160 @@
 //! Create command
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use rust_i18n::t;
 
-use crate::core::VirtualenvService;
-use crate::error::Result;
-use crate::output::{CreateData, Output};
+use crate::config::{Config, TemplateConfig};
+use crate::core::{VersionService, VirtualenvService, cancel, hooks};
+use crate::error::{Result, ScoopError};
+use crate::output::{CreateData, HookData, Output};
 use crate::paths;
+use crate::uv::UvClient;
 use crate::validate;
 
+/// Options collected from the CLI parse, forwarded into `execute`.
+#[derive(Debug, Clone)]
+pub struct CreateOpts {
+    /// Ignored with `name_from_cwd`, which derives it from the current
+    /// directory.
+    pub name: String,
+    /// Requested Python; `None` falls back to the template's, then
+    /// [`default_python`].
+    pub python: Option<String>,
+    pub python_path: Option<PathBuf>,
+    pub force: bool,
+    pub clear: bool,
+    pub install_python: bool,
+    pub link: Option<PathBuf>,
+    pub dry_run: bool,
+    pub no_activate_hint: bool,
+    pub no_hooks: bool,
+    pub quiet_uv: bool,
+    pub build_constraints: Option<PathBuf>,
+    /// Template from config.json (`--template`)
+    pub template: Option<String>,
+    /// Seed pip into the env (`uv venv --seed`)
+    pub seed: bool,
+    /// Upgrade the seeded packages after creating; only valid with `seed`
+    pub upgrade_deps: bool,
+    /// Silence pip's upgrade notice while seeding; only valid with `seed`
+    pub no_pip_upgrade_warning: bool,
+    /// Name the env after the current directory and use it there locally
+    /// (`--name-from-cwd`)
+    pub name_from_cwd: bool,
+}
+
+/// Python used when `scuv create` is given no version:
+/// `create.default_python` from config.json (set by `scuv install
+/// --default`), else `3`.
+pub fn default_python() -> String {
+    Config::load()
+        .ok()
+        .and_then(|c| c.create.default_python)
+        .filter(|v| !v.trim().is_empty())
+        .unwrap_or_else(|| "3".to_string())
+}
+
+/// The Python to create with: the explicit request, else the template's,
+/// else [`default_python`].
+fn resolve_python(requested: Option<&str>, template: Option<&TemplateConfig>) -> String {
+    requested
+        .map(str::to_string)
+        .or_else(|| template.and_then(|t| t.python.clone()))
+        .unwrap_or_else(default_python)
+}
+
 /// Execute the create command
-pub fn execute(
-    output: &Output,
-    name: &str,
-    python: &str,
-    python_path: Option<&Path>,
-    force: bool,
-    install_python: bool,
-) -> Result<()> {
-    let service = VirtualenvService::auto()?;
+pub fn execute(output: &Output, opts: &CreateOpts) -> Result<()> {
+    let from_cwd = if opts.name_from_cwd {
+        let name = name_from_dir(&std::env::current_dir()?, opts.force || opts.clear)?;
+        Some(CreateOpts {
+            name,
+            ..opts.clone()
+        })
+    } else {
+        None
+    };
+    let opts = from_cwd.as_ref().unwrap_or(opts);
+    let name = opts.name.as_str();
+    let template = opts
+        .template
+        .as_deref()
+        .map(super::template::load)
+        .transpose()?;
+    let python = resolve_python(opts.python.as_deref(), template.as_ref());
+    let python = python.as_str();
+    let packages = template.map(|t| t.packages).unwrap_or_default();
+    let python_path = opts.python_path.as_deref();
+    let force = opts.force;
+    let install_python = opts.install_python;
+    let link = opts.link.as_deref();
+
+    if opts.upgrade_deps && !opts.seed {
+        return Err(ScoopError::InvalidArgument {
+            message: t!("create.upgrade_deps_requires_seed").to_string(),
+        });
+    }
+    if opts.no_pip_upgrade_warning && !opts.seed {
+        return Err(ScoopError::InvalidArgument {
+            message: t!("create.no_pip_upgrade_warning_requires_seed").to_string(),
+        });
+    }
+    // Reject a bad --link target before creating anything, so a typo in the
+    // link path doesn't leave a half-finished env behind.
+    if let Some(link) = link {
+        validate_link_path(link)?;
+    }
+    // Likewise a malformed version request, rather than letting uv guess.
+    if python_path.is_none() {
+        validate::validate_python_spec(python)?;
+    }
+    let constraints = opts
+        .build_constraints
+        .as_deref()
+        .map(resolve_build_constraints)
+        .transpose()?;
+
+    if opts.dry_run {
+        return emit_plan(output, opts, python, &packages);
+    }
+
+    // Ctrl-C from here stops uv and removes the half-built environment.
+    cancel::install_handler();
 
-    // Check if exists and handle force
+    let uv = UvClient::for_output(output)?.with_constraint(constraints.clone());
+    let uv = if opts.quiet_uv {
+        uv.with_verbosity(true, 0).with_stderr_echo(None)
+    } else {
+        uv
+    };
+    let uv = if opts.no_pip_upgrade_warning {
+        uv.without_pip_version_check()
+    } else {
+        uv
+    };
+    validate::validate_env_name(name)?;
+    reject_case_collision(name)?;
+    // Rebuilding in place relies on uv leaving the directory alone, which
+    // older releases don't; refuse before anything is touched.
+    if opts.clear && paths::virtualenv_path(name)?.exists() {
+        uv.ensure_compatible(crate::uv::version::VENV_CLEAR_VERSION)?;
+    }
+    let service = VirtualenvService::new(uv)
+        .with_clear_existing(opts.clear)
+        .with_seed(opts.seed)
+        .with_upgrade_deps(opts.upgrade_deps);
+
+    // Check if exists and handle force. With --clear an existing env is
+    // rebuilt in place rather than created by this run.
+    let mut rebuilt_in_place = false;
     if service.exists(name)? {
         if force {
             output.info(&t!("create.removing_existing", name = name));
             service.delete(name)?;
+        } else if opts.clear {
+            output.info(&t!("create.clearing_existing", name = name));
+            rebuilt_in_place = true;
         } else {
             return Err(crate::error::ScoopError::VirtualenvExists {
                 name: name.to_string(),
@@ -54,6 +183,26 @@ pub fn execute(
         ));
 
         let env_path = service.create_with_python_path(name, &detected_version, &canonical)?;
+        install_template_packages(
+            output,
+            &service,
+            opts,
+            &env_path,
+            &packages,
+            rebuilt_in_place,
+        )?;
+        let linked = match link {
+            Some(link) => create_link(link, &env_path, output)?,
+            None => None,
+        };
+        let hook = run_post_create_hook(
+            output,
+            opts,
+            &detected_version,
+            constraints.as_deref(),
+            !packages.is_empty(),
+        );
+        let version_file = use_locally(output, opts)?;
 
         // JSON output
         if output.is_json() {
@@ -64,16 +213,28 @@ pub fn execute(
                     python: detected_version,
                     path: env_path.display().to_string(),
                     python_path: Some(canonical.display().to_string()),
+                    link: linked.map(|l| l.display().to_string()),
+                    dry_run: false,
+                    activate_command: activate_command(name),
+                    hook,
+                    template: opts.template.clone(),
+                    packages,
+                    version_file: version_file.map(|f| f.display().to_string()),
                 },
             );
             return Ok(());
         }
 
-        output.success(&t!("create.success", name = name));
-        output.info(&t!("create.path", path = paths::abbreviate_home(&env_path)));
-        output.info(&t!("create.activate_hint", name = name));
+        print_created(output, opts, name, &env_path);
     } else {
-        // Standard version-based mode
+        // Standard version-based mode. uv has no notion of `latest` /
+        // `stable`, so those aliases resolve to a concrete version first.
+        let python = if validate::is_version_alias(python) {
+            service.resolve_python_alias(python)?
+        } else {
+            python.to_string()
+        };
+        let python = python.as_str();
 
         // Lazy install: opt-in. If the requested version isn't installed yet,
         // ask uv to fetch it before handing off to venv creation. Without the
@@ -87,6 +248,19 @@ pub fn execute(
         output.info(&t!("create.creating", name = name, python = python));
 
         let path = service.create(name, python)?;
+        install_template_packages(output, &service, opts, &path, &packages, rebuilt_in_place)?;
+        let linked = match link {
+            Some(link) => create_link(link, &path, output)?,
+            None => None,
+        };
+        let hook = run_post_create_hook(
+            output,
+            opts,
+            python,
+            constraints.as_deref(),
+            !packages.is_empty(),
+        );
+        let version_file = use_locally(output, opts)?;
 
         // JSON output
         if output.is_json() {
@@ -97,15 +271,685 @@ pub fn execute(
                     python: python.to_string(),
                     path: path.display().to_string(),
                     python_path: None,
+                    link: linked.map(|l| l.display().to_string()),
+                    dry_run: false,
+                    activate_command: activate_command(name),
+                    hook,
+                    template: opts.template.clone(),
+                    packages,
+                    version_file: version_file.map(|f| f.display().to_string()),
                 },
             );
             return Ok(());
         }
 
-        output.success(&t!("create.success", name = name));
-        output.info(&t!("create.path", path = paths::abbreviate_home(&path)));
+        print_created(output, opts, name, &path);
+    }
+
+    Ok(())
+}
+
+/// The env name `--name-from-cwd` derives from `dir`: its last component,
+/// sanitized. Unless the env may be `overwrite`n (`--force`, `--clear`), an
+/// existing env of that name is an error that suggests a free `-N` suffix.
+fn name_from_dir(dir: &Path, overwrite: bool) -> Result<String> {
+    let raw = dir
+        .file_name()
+        .map(|n| n.to_string_lossy())
+        .unwrap_or_default();
+    let name = validate::sanitize_env_name(&raw);
+    validate::validate_env_name(&name)?;
+
+    let venvs = paths::virtualenvs_dir()?;
+    if overwrite || !venvs.join(&name).exists() {
+        return Ok(name);
+    }
+    let suggested = (2..)
+        .map(|n| {
+            let suffix = format!("-{n}");
+            let keep = name.len().min(validate::MAX_ENV_NAME_LENGTH - suffix.len());
+            format!("{}{suffix}", &name[..keep])
+        })
+        .find(|candidate| !venvs.join(candidate).exists())
+        .expect("some suffix is free");
+    Err(ScoopError::InvalidArgument {
+        message: t!(
+            "create.name_from_cwd_taken",
+            name = &name,
+            suggested = suggested
+        )
+        .to_string(),
+    })
+}
+
+/// With `--name-from-cwd`, set the new env as the current directory's local
+/// env, as `scuv use <name>` does. Returns the version file written.
+fn use_locally(output: &Output, opts: &CreateOpts) -> Result<Option<PathBuf>> {
+    if !opts.name_from_cwd {
+        return Ok(None);
+    }
+    let cwd = std::env::current_dir()?;
+    VersionService::set_local(&cwd, &opts.name)?;
+    output.info(&t!("use.set_local", name = &opts.name));
+    Ok(Some(paths::local_version_file(&cwd)))
+}
+
+/// Install the `--template`'s packages into the freshly created env.
+///
+/// A failed install removes an env this run created: it isn't what the
+/// template describes, and leaving it would make the retry fail with
+/// "already exists". An env `--clear` `rebuilt_in_place` was the user's
+/// before this run, and links may point into it, so it is kept.
+fn install_template_packages(
+    output: &Output,
+    service: &VirtualenvService,
+    opts: &CreateOpts,
+    env_path: &Path,
+    packages: &[String],
+    rebuilt_in_place: bool,
+) -> Result<()> {
+    let Some(template) = opts.template.as_deref() else {
+        return Ok(());
+    };
+    if packages.is_empty() {
+        return Ok(());
+    }
+    output.info(&t!(
+        "create.installing_template",
+        count = packages.len(),
+        template = template
+    ));
+    if let Err(e) = service.pip_install(env_path, packages) {
+        if !rebuilt_in_place {
+            let _ = std::fs::remove_dir_all(env_path);
+        }
+        return Err(e);
+    }
+    Ok(())
+}
+
+/// Run `create.post_create_hook` (unless `--no-hooks`) for the new env.
+///
+/// A failing hook is only a warning: the env itself was created fine, so
+/// `create` still succeeds. Hook output is captured, and shown only when
+/// it fails, so it can't corrupt `--json` output.
+///
+/// `--build-constraints` reaches the hook's installs as `UV_CONSTRAINT` and
+/// `PIP_CONSTRAINT`. When neither the hook nor the template
+/// (`template_installed`) installs anything, the file is unused and we say so.
+fn run_post_create_hook(
+    output: &Output,
+    opts: &CreateOpts,
+    python: &str,
+    constraints: Option<&Path>,
+    template_installed: bool,
+) -> Option<HookData> {
+    let template = if opts.no_hooks {
+        None
+    } else {
+        hooks::post_create_hook()
+    };
+    let Some(template) = template else {
+        if let Some(constraints) = constraints.filter(|_| !template_installed) {
+            output.warn(&t!(
+                "create.build_constraints_ignored",
+                path = constraints.display()
+            ));
+        }
+        return None;
+    };
+    let command = hooks::render(&template, &opts.name, python);
+    output.info(&t!("create.hook_running", command = &command));
+
+    let env: Vec<(&str, &Path)> = constraints
+        .map(|path| vec![("UV_CONSTRAINT", path), ("PIP_CONSTRAINT", path)])
+        .unwrap_or_default();
+    let data = match hooks::run_with_env(&command, &env) {
+        Ok(outcome) => HookData {
+            success: outcome.success(),
+            exit_code: outcome.exit_code,
+            command: outcome.command,
+            stdout: outcome.stdout,
+            stderr: outcome.stderr,
+        },
+        Err(e) => HookData {
+            success: false,
+            exit_code: None,
+            command,
+            stdout: String::new(),
+            stderr: e.to_string(),
+        },
+    };
+
+    if !data.success {
+        let status = data
+            .exit_code
+            .map_or_else(|| "-".to_string(), |code| code.to_string());
+        output.warn(&t!("create.hook_failed", status = status));
+        for line in data.stdout.lines().chain(data.stderr.lines()) {
+            output.warn(&format!("  {line}"));
+        }
+    }
+    Some(data)
+}
+
+/// The command that activates `name`, as shown by the human hint.
+fn activate_command(name: &str) -> String {
+    format!("scuv use {name}")
+}
+
+/// Human-readable success report. With `--seed` it names what was seeded;
+/// `--no-activate-hint` drops the last line.
+fn print_created(output: &Output, opts: &CreateOpts, name: &str, path: &Path) {
+    output.success(&t!("create.success", name = name));
+    output.info(&t!("create.path", path = paths::abbreviate_home(path)));
+    if opts.seed {
+        let seeded = crate::uv::seeded_packages(path);
+        if !seeded.is_empty() {
+            output.info(&t!("create.seeded", packages = seeded.join(", ")));
+        }
+    }
+    if !opts.no_activate_hint {
         output.info(&t!("create.activate_hint", name = name));
     }
+}
+
+/// Refuse a name that the filesystem folds onto a differently-cased env.
+///
+/// On a case-insensitive filesystem `myenv` resolves to an existing `MyEnv`
+/// directory, so the exists check would report the wrong env and `--force`
+/// would delete it. On case-sensitive filesystems the two are distinct
+/// directories and creation proceeds (`doctor` warns about the pair).
+pub(super) fn reject_case_collision(name: &str) -> Result<()> {
+    let venvs = paths::virtualenvs_dir()?;
+    match crate::core::case_variant_of(&venvs, name) {
+        Some(existing) if venvs.join(name).exists() => Err(ScoopError::InvalidEnvName {
+            name: name.to_string(),
+            reason: format!(
+                "differs only in case from existing environment '{existing}' (case-insensitive filesystem)"
+            ),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Report what `create` would do without calling uv or touching disk.
+///
+/// Runs the same name / existence / `--python-path` checks as a real create
+/// so a plan that prints is a plan that would succeed up to the uv call.
+fn emit_plan(output: &Output, opts: &CreateOpts, python: &str, packages: &[String]) -> Result<()> {
+    validate::validate_env_name(&opts.name)?;
+    reject_case_collision(&opts.name)?;
+    let path = paths::virtualenv_path(&opts.name)?;
+    let exists = path.exists();
+    if exists && !opts.force && !opts.clear {
+        return Err(ScoopError::VirtualenvExists {
+            name: opts.name.clone(),
+        });
+    }
+
+    let (python, python_path) = match opts.python_path.as_deref() {
+        Some(pp) => {
+            validate::validate_python_path(pp)?;
+            let canonical =
+                std::fs::canonicalize(pp).map_err(|_| ScoopError::InvalidPythonPath {
+                    path: pp.to_path_buf(),
+                    reason: "could not resolve path".to_string(),
+                })?;
+            let version = validate::detect_python_version(&canonical)
+                .unwrap_or_else(|| "unknown".to_string());
+            (version, Some(canonical))
+        }
+        None => (python.to_string(), None),
+    };
+
+    if output.is_json() {
+        output.json_success(
+            "create",
+            CreateData {
+                name: opts.name.clone(),
+                python,
+                path: path.display().to_string(),
+                python_path: python_path.map(|p| p.display().to_string()),
+                link: opts.link.as_ref().map(|l| l.display().to_string()),
+                dry_run: true,
+                activate_command: activate_command(&opts.name),
+                hook: None,
+                template: opts.template.clone(),
+                packages: packages.to_vec(),
+                version_file: None,
+            },
+        );
+        return Ok(());
+    }
+
+    output.info(&t!("create.dry_run_header"));
+    println!("{}", t!("create.plan_name", name = &opts.name));
+    match &python_path {
+        Some(pp) => println!(
+            "{}",
+            t!(
+                "create.plan_python_path",
+                python = python,
+                path = pp.display()
+            )
+        ),
+        None => println!("{}", t!("create.plan_python", python = python)),
+    }
+    println!(
+        "{}",
+        t!("create.plan_path", path = paths::abbreviate_home(&path))
+    );
+    if let Some(link) = &opts.link {
+        println!("{}", t!("create.plan_link", link = link.display()));
+    }
+    if opts.seed {
+        let key = match (opts.upgrade_deps, opts.no_pip_upgrade_warning) {
+            (true, true) => "create.plan_seed_upgraded_quiet",
+            (true, false) => "create.plan_seed_upgraded",
+            (false, true) => "create.plan_seed_quiet",
+            (false, false) => "create.plan_seed",
+        };
+        println!("{}", t!(key));
+    }
+    if opts.name_from_cwd {
+        println!("{}", t!("create.plan_local", file = paths::VERSION_FILE));
+    }
+    if let Some(template) = &opts.template {
+        println!("{}", t!("create.plan_template", template = template));
+        for package in packages {
+            println!("    {package}");
+        }
+    }
+    let action = if exists && opts.clear {
+        "create.plan_action_clear"
+    } else if exists {
+        "create.plan_action_force"
+    } else if opts.install_python {
+        "create.plan_action_install"
+    } else {
+        "create.plan_action_create"
+    };
+    println!("{}", t!(action));
+    Ok(())
+}
 
+/// Validate a `--link <PATH>` target before the env is created.
+///
+/// The parent directory must already exist (we don't create directory
+/// trees on the user's behalf), and an existing entry at `link` may only be
+/// replaced if it is itself a symlink — a real file or directory is never
+/// clobbered.
+fn validate_link_path(link: &Path) -> Result<()> {
+    let parent = match link.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p,
+        _ => Path::new("."),
+    };
+    if !parent.is_dir() {
+        return Err(ScoopError::InvalidArgument {
+            message: t!("create.link_parent_missing", path = parent.display()).to_string(),
+        });
+    }
+    // symlink_metadata so a dangling symlink still counts as "exists".
+    if let Ok(meta) = std::fs::symlink_metadata(link) {
+        if !meta.file_type().is_symlink() {
+            return Err(ScoopError::InvalidArgument {
+                message: t!("create.link_not_symlink", path = link.display()).to_string(),
+            });
+        }
+    }
     Ok(())
 }
+
+/// Check `--build-constraints` names a file and make it absolute, so a hook
+/// that changes directory still finds it.
+fn resolve_build_constraints(path: &Path) -> Result<PathBuf> {
+    if !path.is_file() {
+        return Err(ScoopError::InvalidArgument {
+            message: t!("create.build_constraints_missing", path = path.display()).to_string(),
+        });
+    }
+    Ok(std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf()))
+}
+
+/// Create (or replace) the `--link` symlink pointing at `target`.
+///
+/// An existing symlink is swapped atomically, so an interrupted run never
+/// leaves the link missing. Returns the link path on success. On Windows, directory symlinks need
+/// Developer Mode or elevation; when that fails we warn and return `None`
+/// rather than failing a create that otherwise succeeded.
+fn create_link(link: &Path, target: &Path, output: &Output) -> Result<Option<PathBuf>> {
+    #[cfg(unix)]
+    super::use_env::symlink::swap_symlink(link, target)?;
+
+    #[cfg(windows)]
+    {
+        if link.is_symlink() {
+            std::fs::remove_file(link)?;
+        }
+        if let Err(e) = std::os::windows::fs::symlink_dir(target, link) {
+            output.warn(&t!(
+                "create.link_unsupported",
+                path = link.display(),
+                error = e.to_string()
+            ));
+            return Ok(None);
+        }
+    }
+
+    output.info(&t!(
+        "create.linked",
+        link = link.display(),
+        path = paths::abbreviate_home(target)
+    ));
+    Ok(Some(link.to_path_buf()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::ColorChoice;
+    use crate::test_utils::with_temp_scoop_home;
+    use serial_test::serial;
+
+    fn dry_run_opts(name: &str) -> CreateOpts {
+        CreateOpts {
+            name: name.to_string(),
+            python: Some("3.12".to_string()),
+            python_path: None,
+            force: false,
+            clear: false,
+            install_python: false,
+            link: None,
+            dry_run: true,
+            no_activate_hint: false,
+            no_hooks: false,
+            quiet_uv: false,
+            build_constraints: None,
+            template: None,
+            seed: false,
+            upgrade_deps: false,
+            no_pip_upgrade_warning: false,
+            name_from_cwd: false,
+        }
+    }
+
+    /// A failed template install removes an env this run created, but keeps
+    /// one `--clear` rebuilt in place.
+    #[test]
+    #[cfg(unix)]
+    #[serial]
+    fn failed_template_install_keeps_env_rebuilt_in_place() {
+        use std::os::unix::fs::PermissionsExt;
+
+        with_temp_scoop_home(|home| {
+            let uv = home.path().join("uv");
+            std::fs::write(&uv, "#!/bin/sh\necho 'no such package' >&2\nexit 1\n").unwrap();
+            std::fs::set_permissions(&uv, std::fs::Permissions::from_mode(0o755)).unwrap();
+            let service = VirtualenvService::new(UvClient::with_path(uv));
+            let output = Output::new(0, true, ColorChoice::Never, false);
+            let opts = CreateOpts {
+                template: Some("web".to_string()),
+                ..dry_run_opts("web")
+            };
+            let packages = ["flask".to_string()];
+
+            for rebuilt_in_place in [true, false] {
+                crate::test_utils::create_mock_venv(home, "web", Some("3.12"));
+                let env = crate::paths::virtualenv_path("web").unwrap();
+                let result = install_template_packages(
+                    &output,
+                    &service,
+                    &opts,
+                    &env,
+                    &packages,
+                    rebuilt_in_place,
+                );
+                assert!(result.is_err());
+                assert_eq!(env.exists(), rebuilt_in_place);
+            }
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn case_collision_rejected_only_when_filesystem_folds_case() {
+        with_temp_scoop_home(|home| {
+            let venvs = home.path().join("virtualenvs");
+            std::fs::create_dir_all(venvs.join("MyEnv")).unwrap();
+            let folds_case = venvs.join("myenv").exists();
+
+            let result = reject_case_collision("myenv");
+            if folds_case {
+                assert!(
+                    matches!(result, Err(ScoopError::InvalidEnvName { ref reason, .. }) if reason.contains("MyEnv")),
+                    "got {result:?}"
+                );
+            } else {
+                assert!(
+                    result.is_ok(),
+                    "case-sensitive fs must allow it: {result:?}"
+                );
+            }
+            assert!(reject_case_collision("MyEnv").is_ok());
+            assert!(reject_case_collision("other").is_ok());
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn dry_run_creates_nothing() {
+        with_temp_scoop_home(|home| {
+            let output = Output::new(0, true, ColorChoice::Never, true);
+            execute(&output, &dry_run_opts("planned")).unwrap();
+            assert!(!home.path().join("virtualenvs").join("planned").exists());
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn dry_run_reports_existing_env_without_force() {
+        with_temp_scoop_home(|home| {
+            std::fs::create_dir_all(home.path().join("virtualenvs").join("taken")).unwrap();
+            let output = Output::new(0, true, ColorChoice::Never, true);
+            let err = execute(&output, &dry_run_opts("taken")).unwrap_err();
+            assert!(matches!(err, ScoopError::VirtualenvExists { .. }));
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn dry_run_accepts_existing_env_with_clear() {
+        with_temp_scoop_home(|home| {
+            let env = home.path().join("virtualenvs").join("taken");
+            std::fs::create_dir_all(&env).unwrap();
+            std::fs::write(env.join("marker"), "").unwrap();
+            let output = Output::new(0, true, ColorChoice::Never, true);
+            let opts = CreateOpts {
+                clear: true,
+                ..dry_run_opts("taken")
+            };
+            execute(&output, &opts).unwrap();
+            // Dry run: the existing directory is left alone.
+            assert!(env.join("marker").exists());
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn resolve_python_prefers_request_then_template_then_default() {
+        with_temp_scoop_home(|_| {
+            let template = TemplateConfig {
+                python: Some("3.11".to_string()),
+                packages: Vec::new(),
+            };
+            assert_eq!(resolve_python(Some("3.13"), Some(&template)), "3.13");
+            assert_eq!(resolve_python(None, Some(&template)), "3.11");
+            assert_eq!(resolve_python(None, Some(&TemplateConfig::default())), "3");
+            assert_eq!(resolve_python(None, None), "3");
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn dry_run_with_template_plans_without_creating() {
+        with_temp_scoop_home(|home| {
+            let mut config = Config::default();
+            config.templates.insert(
+                "data-science".to_string(),
+                TemplateConfig {
+                    python: Some("3.12".to_string()),
+                    packages: vec!["numpy".to_string()],
+                },
+            );
+            config.save().unwrap();
+
+            let output = Output::new(0, true, ColorChoice::Never, true);
+            let opts = CreateOpts {
+                python: None,
+                template: Some("data-science".to_string()),
+                ..dry_run_opts("planned")
+            };
+            execute(&output, &opts).unwrap();
+            assert!(!home.path().join("virtualenvs").join("planned").exists());
+
+            let missing = CreateOpts {
+                template: Some("web".to_string()),
+                ..dry_run_opts("planned")
+            };
+            assert!(matches!(
+                execute(&output, &missing),
+                Err(ScoopError::InvalidArgument { .. })
+            ));
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn upgrade_deps_without_seed_is_invalid() {
+        with_temp_scoop_home(|_| {
+            let output = Output::new(0, true, ColorChoice::Never, true);
+            let opts = CreateOpts {
+                upgrade_deps: true,
+                ..dry_run_opts("planned")
+            };
+            assert!(matches!(
+                execute(&output, &opts),
+                Err(ScoopError::InvalidArgument { .. })
+            ));
+            let seeded = CreateOpts { seed: true, ..opts };
+            execute(&output, &seeded).unwrap();
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn no_pip_upgrade_warning_without_seed_is_invalid() {
+        with_temp_scoop_home(|_| {
+            let output = Output::new(0, true, ColorChoice::Never, true);
+            let opts = CreateOpts {
+                no_pip_upgrade_warning: true,
+                ..dry_run_opts("planned")
+            };
+            assert!(matches!(
+                execute(&output, &opts),
+                Err(ScoopError::InvalidArgument { .. })
+            ));
+            let seeded = CreateOpts { seed: true, ..opts };
+            execute(&output, &seeded).unwrap();
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn name_from_dir_sanitizes_and_suggests_free_suffix() {
+        with_temp_scoop_home(|home| {
+            let project = home.path().join("My Project.v2");
+            assert_eq!(name_from_dir(&project, false).unwrap(), "my-project-v2");
+
+            let venvs = home.path().join("virtualenvs");
+            std::fs::create_dir_all(venvs.join("my-project-v2")).unwrap();
+            std::fs::create_dir_all(venvs.join("my-project-v2-2")).unwrap();
+            let err = name_from_dir(&project, false).unwrap_err();
+            assert!(matches!(err, ScoopError::InvalidArgument { .. }));
+            assert!(err.to_string().contains("my-project-v2-3"), "{err}");
+            // --force / --clear replace the existing env instead.
+            assert_eq!(name_from_dir(&project, true).unwrap(), "my-project-v2");
+
+            assert!(matches!(
+                name_from_dir(&home.path().join("list"), false),
+                Err(ScoopError::InvalidEnvName { .. })
+            ));
+            assert!(name_from_dir(Path::new("/"), false).is_err());
+        });
+    }
+
+    #[test]
+    fn activate_command_matches_hint() {
+        assert_eq!(activate_command("myenv"), "scuv use myenv");
+        assert!(
+            t!("create.activate_hint", locale = "en", name = "myenv")
+                .ends_with(&activate_command("myenv"))
+        );
+    }
+
+    #[test]
+    fn validate_link_path_rejects_missing_parent() {
+        let tmp = tempfile::tempdir().unwrap();
+        let err = validate_link_path(&tmp.path().join("nope").join("myenv")).unwrap_err();
+        assert!(matches!(err, ScoopError::InvalidArgument { .. }));
+    }
+
+    #[test]
+    fn validate_link_path_rejects_existing_directory() {
+        let tmp = tempfile::tempdir().unwrap();
+        let real = tmp.path().join("myenv");
+        std::fs::create_dir(&real).unwrap();
+        let err = validate_link_path(&real).unwrap_err();
+        assert!(matches!(err, ScoopError::InvalidArgument { .. }));
+    }
+
+    #[test]
+    fn resolve_build_constraints_requires_a_file() {
+        let tmp = tempfile::tempdir().unwrap();
+        let err = resolve_build_constraints(&tmp.path().join("missing.txt")).unwrap_err();
+        assert!(matches!(err, ScoopError::InvalidArgument { .. }));
+        let err = resolve_build_constraints(tmp.path()).unwrap_err();
+        assert!(matches!(err, ScoopError::InvalidArgument { .. }));
+
+        let file = tmp.path().join("constraints.txt");
+        std::fs::write(&file, "setuptools<70\n").unwrap();
+        let resolved = resolve_build_constraints(&file).unwrap();
+        assert!(resolved.is_absolute());
+        assert!(resolved.ends_with("constraints.txt"));
+    }
+
+    #[test]
+    fn validate_link_path_accepts_fresh_path() {
+        let tmp = tempfile::tempdir().unwrap();
+        assert!(validate_link_path(&tmp.path().join("myenv")).is_ok());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn create_link_replaces_existing_symlink() {
+        let tmp = tempfile::tempdir().unwrap();
+        let old_target = tmp.path().join("old");
+        let new_target = tmp.path().join("new");
+        std::fs::create_dir(&old_target).unwrap();
+        std::fs::create_dir(&new_target).unwrap();
+        let link = tmp.path().join("link");
+        std::os::unix::fs::symlink(&old_target, &link).unwrap();
+
+        assert!(validate_link_path(&link).is_ok());
+        let output = Output::new(0, true, ColorChoice::Never, false);
+        let linked = create_link(&link, &new_target, &output).unwrap();
+        assert_eq!(linked.as_deref(), Some(link.as_path()));
+        assert_eq!(std::fs::read_link(&link).unwrap(), new_target);
+        assert_eq!(
+            std::fs::read_dir(tmp.path()).unwrap().count(),
+            3,
+            "temporary link must not be left behind"
+        );
+    }
+}
@@ -1,11 +1,21 @@
 //! Deactivate command
 
 use crate::cli::ShellType;
+use crate::core::VirtualenvService;
 use crate::error::Result;
-use crate::shell::{detect_shell, print_deactivate_script};
+use crate::paths;
+use crate::shell::{
+    PathPosition, detect_shell, print_activate_script, print_deactivate_script,
+    print_export_scoop_version, stack,
+};
 
 /// Execute the deactivate command
 /// Outputs shell script to be eval'd
+///
+/// When `scuv shell --stack` left entries in `_SCUV_STACK`, the most recent
+/// one is re-activated after the full restore, so nested switches unwind one
+/// level at a time. A stacked env that has since been removed is dropped and
+/// the shell simply stays deactivated.
 pub fn execute(shell: Option<ShellType>) -> Result<()> {
     // Detect shell or use specified
     let shell_type = shell.unwrap_or_else(detect_shell);
@@ -13,5 +23,35 @@ pub fn execute(shell: Option<ShellType>) -> Result<()> {
     // Output deactivation script for eval
     print_deactivate_script(shell_type);
 
+    let current = std::env::var(stack::STACK_ENV).ok();
+    if let Some((previous, rest)) = stack::pop(current.as_deref()) {
+        restore(shell_type, &previous);
+        stack::print_set(shell_type, rest.as_deref());
+    }
+
     Ok(())
 }
+
+/// Re-activate a stacked env, keeping the `scuv shell` pin in step.
+fn restore(shell_type: ShellType, name: &str) {
+    let Ok(service) = VirtualenvService::auto() else {
+        return;
+    };
+    let (Ok(venv_path), Ok(bin_path)) = (service.get_path(name), paths::virtualenv_bin(name))
+    else {
+        return;
+    };
+
+    print_activate_script(
+        shell_type,
+        &venv_path,
+        &bin_path,
+        name,
+        PathPosition::resolve(false),
+    );
+    // `scuv shell --stack` pinned the env being left; without moving the
+    // pin back, the prompt hook would re-activate it right away.
+    if std::env::var_os("SCUV_VERSION").is_some() {
+        print_export_scoop_version(shell_type, name);
+    }
+}
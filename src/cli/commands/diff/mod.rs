@@ -25,6 +25,11 @@ mod tests;
 
 pub use types::{DiffMode, DiffOpts};
 
+// The package comparison behind `scuv info --compare`.
+pub(crate) use compute::compute_package_diff;
+pub(crate) use enumerator::{PackageEnumerator, UvPipEnumerator};
+pub(crate) use types::{PackageChanged, PackageEntry};
+
 use rust_i18n::t;
 use serde::Serialize;
 
@@ -33,8 +38,6 @@ use crate::error::{Result, ScoopError};
 use crate::output::Output;
 use crate::uv::UvClient;
 
-use compute::compute_package_diff;
-use enumerator::{PackageEnumerator, UvPipEnumerator};
 use types::{DiffData, DiffSummary, MetadataDiff, PackageDiff, ScalarDiff};
 
 /// Orchestrate the `scuv diff` command.
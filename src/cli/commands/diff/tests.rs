@@ -9,6 +9,7 @@ use std::path::Path;
 
 use chrono::{TimeZone, Utc};
 
+use crate::cli::ColorChoice;
 use crate::core::Metadata;
 use crate::error::{Result, ScoopError};
 use crate::output::Output;
@@ -69,9 +70,12 @@ fn make_env_with_metadata(name: &str, python_version: &str) -> std::path::PathBu
     let meta = Metadata {
         name: name.to_string(),
         python_version: python_version.to_string(),
+        python_request: None,
         created_at: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
         created_by: "scoop-test".to_string(),
         last_used: None,
+        tags: Vec::new(),
+        deps_upgraded: false,
         uv_version: Some("0.5.14".to_string()),
         python_path: None,
     };
@@ -96,7 +100,7 @@ fn execute_identical_envs_returns_ok_non_strict() {
         make_env_with_metadata("b", "3.12.0");
         let svc = VirtualenvService::auto().unwrap();
         let enumerator = MockEnumerator::shared(vec![pkg("requests", "2.31.0")]);
-        let out = Output::new(0, true, true, false);
+        let out = Output::new(0, true, ColorChoice::Never, false);
         let result = execute_with(
             &out,
             &opts_for("a", "b", false, DiffMode::All),
@@ -114,7 +118,7 @@ fn execute_identical_envs_returns_ok_strict() {
         make_env_with_metadata("b", "3.12.0");
         let svc = VirtualenvService::auto().unwrap();
         let enumerator = MockEnumerator::shared(vec![pkg("requests", "2.31.0")]);
-        let out = Output::new(0, true, true, false);
+        let out = Output::new(0, true, ColorChoice::Never, false);
         let result = execute_with(
             &out,
             &opts_for("a", "b", true, DiffMode::All),
@@ -144,7 +148,7 @@ fn execute_mismatch_non_strict_returns_ok() {
                 (path_b.clone(), vec![pkg("requests", "2.32.0")]),
             ],
         );
-        let out = Output::new(0, true, true, false);
+        let out = Output::new(0, true, ColorChoice::Never, false);
         let result = execute_with(
             &out,
             &opts_for("a", "b", false, DiffMode::All),
@@ -168,7 +172,7 @@ fn execute_mismatch_strict_returns_diff_mismatch() {
                 (path_b, vec![pkg("requests", "2.32.0")]),
             ],
         );
-        let out = Output::new(0, true, true, false);
+        let out = Output::new(0, true, ColorChoice::Never, false);
         let result = execute_with(
             &out,
             &opts_for("a", "b", true, DiffMode::All),
@@ -188,7 +192,7 @@ fn execute_env_a_missing_returns_not_found() {
         make_env_with_metadata("b", "3.12.0");
         let svc = VirtualenvService::auto().unwrap();
         let enumerator = MockEnumerator::shared(vec![]);
-        let out = Output::new(0, true, true, false);
+        let out = Output::new(0, true, ColorChoice::Never, false);
         let result = execute_with(
             &out,
             &opts_for("a", "b", false, DiffMode::All),
@@ -208,7 +212,7 @@ fn execute_env_b_missing_returns_not_found() {
         make_env_with_metadata("a", "3.12.0");
         let svc = VirtualenvService::auto().unwrap();
         let enumerator = MockEnumerator::shared(vec![]);
-        let out = Output::new(0, true, true, false);
+        let out = Output::new(0, true, ColorChoice::Never, false);
         let result = execute_with(
             &out,
             &opts_for("a", "b", false, DiffMode::All),
@@ -237,7 +241,7 @@ fn execute_packages_only_skips_metadata_section() {
                 (path_b, vec![pkg("requests", "2.31.0")]), // identical packages
             ],
         );
-        let out = Output::new(0, true, true, false);
+        let out = Output::new(0, true, ColorChoice::Never, false);
         // PackagesOnly suppresses the *metadata* section only; the
         // top-level `python` ScalarDiff is always computed (and is the
         // single source of truth for python_changed). Packages match
@@ -269,7 +273,7 @@ fn execute_metadata_only_skips_package_enumeration() {
         make_env_with_metadata("a", "3.12.0");
         make_env_with_metadata("b", "3.12.0");
         let svc = VirtualenvService::auto().unwrap();
-        let out = Output::new(0, true, true, false);
+        let out = Output::new(0, true, ColorChoice::Never, false);
         let result = execute_with(
             &out,
             &opts_for("a", "b", false, DiffMode::MetadataOnly),
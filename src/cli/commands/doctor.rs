@@ -1,85 +1,164 @@
 //! Doctor command
 
-use crate::core::doctor::{CheckResult, Doctor};
-use crate::error::Result;
-use crate::output::Output;
-
-/// Calculates exit code based on check results.
-///
-/// - 0: All checks passed
-/// - 1: Warnings found (no errors)
-/// - 2: Errors found (takes priority over warnings)
-#[inline]
-fn calculate_exit_code(results: &[CheckResult]) -> i32 {
-    if results.iter().any(|r| r.is_error()) {
-        2
-    } else if results.iter().any(|r| r.is_warning()) {
-        1
-    } else {
-        0
-    }
-}
+use std::path::{Path, PathBuf};
 
-/// Exit process with appropriate code based on check results.
-///
-/// - Exit 2: If any errors found
-/// - Exit 1: If any warnings found (no errors)
-/// - No exit: If all checks passed
-fn exit_with_result_status(results: &[CheckResult]) {
-    let code = calculate_exit_code(results);
-    if code != 0 {
-        std::process::exit(code);
-    }
-}
+use crate::core::doctor::{CheckResult, Doctor, DoctorSummary};
+use crate::error::Result;
+use crate::output::{Output, write_doctor_json, write_doctor_roots_json};
+use crate::paths;
 
 /// Execute the doctor command.
 ///
-/// Runs all health checks and reports any issues found.
+/// Runs all health checks and reports any issues found. With
+/// `summary_only`, per-check lines are suppressed and only the final tally
+/// is printed (JSON output is unchanged — its `summary` already carries the
+/// counts). With `with_sizes` (or `--verbose`), the slower checks that walk
+/// every environment's files also run; `--verbose` also adds the checks
+/// that inspect this shell's activation state. With `deep`, every
+/// environment's interpreter is started once, with a timeout, to find the
+/// ones that no longer launch. With `no_input`, fixes that would
+/// ask for confirmation (appending `scuv init` to an rc file) apply without
+/// prompting. With `report`, the JSON document goes to that file instead of
+/// stdout; the human report still prints unless `--json` is also set.
+///
+/// With more than one of `roots` (repeated `--root`), every check runs once
+/// per home with [`paths::scoop_home`] pointed at it. The human report gets
+/// a section per home; the JSON document nests each home's `summary` and
+/// `checks` under `roots`, with the overall tally as its `summary`.
 ///
-/// # Exit codes
+/// Returns the [`DoctorSummary`] rather than exiting, so `main.rs` (or an
+/// embedding tool) decides the process exit code via
+/// [`DoctorSummary::exit_code`]:
 ///
 /// - 0: All checks passed
 /// - 1: Some warnings found
 /// - 2: Some errors found
-pub fn execute(output: &Output, fix: bool) -> Result<()> {
-    let doctor = Doctor::new();
+pub fn execute(
+    output: &Output,
+    opts: &DoctorOpts,
+    report: Option<&Path>,
+    roots: &[PathBuf],
+) -> Result<DoctorSummary> {
+    if roots.len() > 1 {
+        return execute_roots(output, opts, report, roots);
+    }
 
-    if fix {
-        // Run with auto-fix
+    if !opts.summary_only {
         output.doctor_header();
-        let results = doctor.run_and_fix(output);
+    }
 
-        // Print summary or JSON
-        if output.is_json() {
-            output.doctor_json(&results);
-        } else {
-            output.doctor_summary(&results);
+    let results = run_checks(output, opts);
+
+    // Print summary or JSON
+    if let Some(path) = report {
+        write_doctor_json(path, &results)?;
+    } else if output.is_json() {
+        output.doctor_json(&results);
+    }
+    if !output.is_json() {
+        output.doctor_summary(&results);
+        if let Some(path) = report {
+            output.info(&format!("Report written to {}", path.display()));
         }
+    }
 
-        exit_with_result_status(&results);
-    } else {
-        // Normal run
-        let results = doctor.run_all();
+    Ok(DoctorSummary::from_results(&results))
+}
 
-        // Print header
-        output.doctor_header();
+/// The flags that shape a single pass over the checks.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DoctorOpts {
+    /// Repair what can be repaired (`--fix`)
+    pub fix: bool,
+    /// Print only the tally (`--summary-only`)
+    pub summary_only: bool,
+    /// Add the disk usage checks (`--with-sizes`)
+    pub with_sizes: bool,
+    /// Add the interpreter launch checks (`--deep`)
+    pub deep: bool,
+    /// Apply fixes without prompting (`--no-input`)
+    pub no_input: bool,
+}
 
-        // Print each result
-        for result in &results {
-            output.doctor_check(result);
-        }
+/// Run every check against each of `roots` in turn, restoring the
+/// previous `--root` override afterwards.
+fn execute_roots(
+    output: &Output,
+    opts: &DoctorOpts,
+    report: Option<&Path>,
+    roots: &[PathBuf],
+) -> Result<DoctorSummary> {
+    if !opts.summary_only {
+        output.doctor_header();
+    }
 
-        // Print summary or JSON
-        if output.is_json() {
-            output.doctor_json(&results);
-        } else {
+    let previous = paths::root_override();
+    let mut per_root = Vec::with_capacity(roots.len());
+    let mut total = DoctorSummary::default();
+    for root in roots {
+        paths::set_root_override(Some(root.clone()));
+        // As made absolute by the override.
+        let root = paths::root_override().unwrap_or_else(|| root.clone());
+        output.doctor_root_header(&root);
+        let results = run_checks(output, opts);
+        if !output.is_json() {
             output.doctor_summary(&results);
         }
+        total += DoctorSummary::from_results(&results);
+        per_root.push((root, results));
+    }
+    paths::set_root_override(previous);
 
-        exit_with_result_status(&results);
+    if let Some(path) = report {
+        write_doctor_roots_json(path, &per_root)?;
+    } else if output.is_json() {
+        output.doctor_roots_json(&per_root);
+    }
+    if !output.is_json() {
+        if let Some(path) = report {
+            output.info(&format!("Report written to {}", path.display()));
+        }
     }
 
-    Ok(())
+    Ok(total)
+}
+
+/// Run (and with `fix`, repair) every check against the current home,
+/// printing each result unless `summary_only`.
+fn run_checks(output: &Output, opts: &DoctorOpts) -> Vec<CheckResult> {
+    let DoctorOpts {
+        fix,
+        summary_only,
+        with_sizes,
+        deep,
+        no_input,
+    } = *opts;
+    let doctor = if no_input {
+        Doctor::without_prompts()
+    } else {
+        Doctor::new()
+    };
+    let doctor = doctor
+        .with_sizes(with_sizes || output.verbosity() > 0)
+        .with_deep_checks(deep)
+        .with_verbose_checks(output.verbosity() > 0);
+
+    if fix {
+        // Run with auto-fix; results are printed as they are produced
+        doctor.run_and_fix_with(output, |result| {
+            if !summary_only {
+                output.doctor_check(result);
+            }
+        })
+    } else {
+        let results = doctor.run_all();
+        if !summary_only {
+            for result in &results {
+                output.doctor_check(result);
+            }
+        }
+        results
+    }
 }
 
 // ============================================================================
@@ -89,7 +168,85 @@ pub fn execute(output: &Output, fix: bool) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::doctor::CheckStatus;
+    use crate::core::doctor::{CheckResult, CheckStatus};
+
+    #[test]
+    #[serial_test::serial]
+    fn report_file_holds_json_with_matching_summary() {
+        crate::test_utils::with_temp_scoop_home(|temp| {
+            let output = Output::new(0, false, crate::cli::ColorChoice::Never, true);
+            let report = temp.path().join("artifacts/ci/doctor.json");
+
+            let summary = execute(&output, &DoctorOpts::default(), Some(&report), &[]).unwrap();
+
+            let value: serde_json::Value =
+                serde_json::from_str(&std::fs::read_to_string(&report).unwrap()).unwrap();
+            let checks = value["checks"].as_array().unwrap();
+            assert_eq!(value["summary"]["total"], checks.len());
+            assert_eq!(value["summary"]["total"], summary.total);
+            assert_eq!(value["summary"]["errors"], summary.errors);
+            assert_eq!(value["summary"]["warnings"], summary.warnings);
+            assert!(checks.iter().any(|c| c["id"] == "home"));
+        });
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn repeated_root_reports_each_home_separately() {
+        crate::test_utils::with_temp_scoop_home(|temp| {
+            let output = Output::new(0, false, crate::cli::ColorChoice::Never, true);
+            let roots = [temp.path().join("store-a"), temp.path().join("store-b")];
+            for root in &roots {
+                std::fs::create_dir_all(root.join("virtualenvs")).unwrap();
+            }
+            // Only store-b has an env whose base Python is gone.
+            let orphan = roots[1].join("virtualenvs/orphan");
+            std::fs::create_dir_all(&orphan).unwrap();
+            std::fs::write(
+                orphan.join("pyvenv.cfg"),
+                "home = /nonexistent/python/bin\nversion = 3.11.9\n",
+            )
+            .unwrap();
+            let report = temp.path().join("doctor.json");
+
+            let summary = execute(&output, &DoctorOpts::default(), Some(&report), &roots).unwrap();
+
+            let value: serde_json::Value =
+                serde_json::from_str(&std::fs::read_to_string(&report).unwrap()).unwrap();
+            let entries = value["roots"].as_array().unwrap();
+            assert_eq!(entries.len(), 2);
+            let warns_orphan = |entry: &serde_json::Value| {
+                entry["checks"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .any(|c| c["id"] == "pyvenv_home" && c["status"] == "warning")
+            };
+            for (entry, root) in entries.iter().zip(&roots) {
+                assert_eq!(entry["root"], root.to_str().unwrap());
+                assert_eq!(
+                    entry["summary"]["total"],
+                    entry["checks"].as_array().unwrap().len()
+                );
+            }
+            assert!(!warns_orphan(&entries[0]));
+            assert!(warns_orphan(&entries[1]));
+
+            let totals: u64 = entries
+                .iter()
+                .map(|e| e["summary"]["total"].as_u64().unwrap())
+                .sum();
+            assert_eq!(value["summary"]["total"], totals);
+            assert_eq!(value["summary"]["total"], summary.total);
+            assert!(summary.warnings >= 1);
+            // The override is put back once every home has been checked.
+            assert_eq!(paths::root_override(), None);
+        });
+    }
+
+    fn calculate_exit_code(results: &[CheckResult]) -> i32 {
+        DoctorSummary::from_results(results).exit_code()
+    }
 
     /// Creates a test CheckResult with given status.
     fn make_result(status: CheckStatus) -> CheckResult {
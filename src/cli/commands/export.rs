@@ -53,6 +53,7 @@ pub fn execute(output: &Output, name: &str, dest: Option<&Path>) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::ColorChoice;
     use crate::test_utils::with_temp_scoop_home;
     use serial_test::serial;
 
@@ -61,7 +62,7 @@ mod tests {
     fn execute_returns_not_found_for_missing_env() {
         with_temp_scoop_home(|temp_dir| {
             std::fs::create_dir_all(temp_dir.path().join("virtualenvs")).unwrap();
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             let err = execute(&output, "ghost", None).unwrap_err();
             assert!(matches!(err, ScoopError::VirtualenvNotFound { .. }));
         });
@@ -80,17 +81,20 @@ mod tests {
             let meta = crate::core::Metadata {
                 name: "snap".to_string(),
                 python_version: "3.12.0".to_string(),
+                python_request: None,
                 created_at: chrono::Utc::now(),
                 created_by: "test".to_string(),
                 uv_version: None,
                 python_path: None,
                 last_used: None,
+                tags: Vec::new(),
+                deps_upgraded: false,
             };
             let meta_json = serde_json::to_string(&meta).unwrap();
             std::fs::write(env_dir.join(".scoop-metadata.json"), meta_json).unwrap();
 
             let out_file = temp_dir.path().join("snap.json");
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             execute(&output, "snap", Some(&out_file)).expect("export should succeed");
 
             let contents = std::fs::read_to_string(&out_file).unwrap();
@@ -0,0 +1,129 @@
+//! Handlers for `scuv hook prompt` and `scuv hook cd`.
+//!
+//! Building blocks for custom shell setups: `prompt` prints a segment for the
+//! active env, `cd` prints the resolve-and-activate hook `scuv init` wires up.
+
+use crate::cli::ShellType;
+use crate::error::{Result, ScoopError};
+use crate::output::{HookCdData, HookPromptData, Output};
+use crate::shell::{cd_hook_script, detect_shell};
+
+/// Execute `hook prompt`: print `<prefix><env><suffix>`, or nothing when no
+/// env is active.
+pub fn execute_prompt(output: &Output, prefix: &str, suffix: &str) -> Result<()> {
+    let active = std::env::var("SCUV_ACTIVE")
+        .ok()
+        .filter(|name| !name.is_empty());
+    let segment = prompt_segment(active.as_deref(), prefix, suffix);
+
+    if output.is_json() {
+        output.json_success("hook prompt", HookPromptData { active, segment });
+        return Ok(());
+    }
+
+    // Prompts embed this via `$(...)`; an empty line would still be a
+    // stray newline in some prompt frameworks.
+    if !segment.is_empty() {
+        println!("{segment}");
+    }
+    Ok(())
+}
+
+/// Execute `hook cd`: print the directory-change hook for `shell`.
+pub fn execute_cd(output: &Output, shell: Option<ShellType>) -> Result<()> {
+    let shell_type = shell.unwrap_or_else(detect_shell);
+    let script = cd_hook_script(shell_type).ok_or_else(|| ScoopError::UnsupportedShell {
+        shell: shell_name(shell_type).to_string(),
+    })?;
+
+    if output.is_json() {
+        output.json_success(
+            "hook cd",
+            HookCdData {
+                shell: shell_name(shell_type).to_string(),
+                script: script.to_string(),
+            },
+        );
+        return Ok(());
+    }
+
+    print!("{script}");
+    Ok(())
+}
+
+fn prompt_segment(active: Option<&str>, prefix: &str, suffix: &str) -> String {
+    active
+        .map(|name| format!("{prefix}{name}{suffix}"))
+        .unwrap_or_default()
+}
+
+fn shell_name(shell: ShellType) -> &'static str {
+    match shell {
+        ShellType::Bash => "bash",
+        ShellType::Zsh => "zsh",
+        ShellType::Fish => "fish",
+        ShellType::Powershell => "powershell",
+        ShellType::Cmd => "cmd",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prompt_segment_wraps_active_env() {
+        assert_eq!(prompt_segment(Some("myenv"), "(", ")"), "(myenv)");
+        assert_eq!(prompt_segment(Some("myenv"), "[py:", "] "), "[py:myenv] ");
+        assert_eq!(prompt_segment(Some("myenv"), "", ""), "myenv");
+    }
+
+    #[test]
+    fn prompt_segment_is_empty_without_active_env() {
+        assert_eq!(prompt_segment(None, "(", ")"), "");
+    }
+
+    #[test]
+    fn cd_hook_is_unsupported_for_cmd() {
+        assert!(cd_hook_script(ShellType::Cmd).is_none());
+        let output = Output::new(0, false, crate::cli::ColorChoice::Never, false);
+        assert!(matches!(
+            execute_cd(&output, Some(ShellType::Cmd)),
+            Err(ScoopError::UnsupportedShell { shell }) if shell == "cmd"
+        ));
+    }
+
+    #[test]
+    fn cd_hook_defines_hook_function_per_shell() {
+        for (shell, header) in [
+            (ShellType::Bash, "_scuv_hook() {"),
+            (ShellType::Zsh, "_scuv_hook() {"),
+            (ShellType::Fish, "function _scuv_hook"),
+            (ShellType::Powershell, "function _scuv_hook {"),
+        ] {
+            let script = cd_hook_script(shell).unwrap();
+            assert!(script.contains(header), "{}: {script}", shell_name(shell));
+            assert!(script.contains("resolve"), "{}", shell_name(shell));
+            assert!(script.contains("SCUV_VERSION"), "{}", shell_name(shell));
+        }
+    }
+
+    /// The emitted bash hook must parse on its own, outside `scuv init`.
+    #[test]
+    #[cfg(unix)]
+    fn bash_cd_hook_is_valid_syntax() {
+        let Ok(out) = std::process::Command::new("bash")
+            .arg("-n")
+            .arg("-c")
+            .arg(cd_hook_script(ShellType::Bash).unwrap())
+            .output()
+        else {
+            return; // bash not installed
+        };
+        assert!(
+            out.status.success(),
+            "{}",
+            String::from_utf8_lossy(&out.stderr)
+        );
+    }
+}
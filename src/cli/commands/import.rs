@@ -99,6 +99,7 @@ fn load_source(source: &str) -> Result<(String, PathBuf)> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::ColorChoice;
     use crate::test_utils::with_temp_scoop_home;
     use serial_test::serial;
 
@@ -113,7 +114,7 @@ mod tests {
     fn execute_rejects_invalid_json() {
         with_temp_scoop_home(|temp_dir| {
             let file = write_export_file(temp_dir.path(), "not json");
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             let err = execute(&output, file.to_str().unwrap(), None, false).unwrap_err();
             assert!(matches!(err, ScoopError::InvalidExportFile { .. }));
         });
@@ -129,7 +130,7 @@ mod tests {
                 "packages": []
             }"#;
             let file = write_export_file(temp_dir.path(), payload);
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             let err = execute(&output, file.to_str().unwrap(), None, false).unwrap_err();
             assert!(matches!(err, ScoopError::UnsupportedExportVersion { .. }));
         });
@@ -146,7 +147,7 @@ mod tests {
                 "packages": []
             }"#;
             let file = write_export_file(temp_dir.path(), payload);
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             let err = execute(
                 &output,
                 file.to_str().unwrap(),
@@ -173,7 +174,7 @@ mod tests {
                 "packages": []
             }"#;
             let file = write_export_file(temp_dir.path(), payload);
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             let err = execute(
                 &output,
                 file.to_str().unwrap(),
@@ -1,17 +1,82 @@
 //! Handler for the `scuv info` command
 
 use chrono::Utc;
+use rust_i18n::t;
+use serde::Serialize;
 
-use crate::core::{VirtualenvService, get_active_env, list_installed_packages};
+use super::diff::{
+    PackageChanged, PackageEntry, PackageEnumerator, UvPipEnumerator, compute_package_diff,
+};
+use crate::core::{
+    VersionService, VirtualenvService, get_active_env, list_installed_packages,
+    parse_pyvenv_version,
+};
 use crate::error::{Result, ScoopError};
 use crate::output::{EnvInfoData, Output, PackagesInfo, format_last_used_value, format_size};
 use crate::paths::{abbreviate_home, calculate_dir_size};
+use crate::uv::UvClient;
 
 const DEFAULT_PACKAGE_LIMIT: usize = 5;
 
+/// Whether `name` is the active env (`SCUV_ACTIVE`) and whether it is the
+/// global default (the global version file).
+fn env_status(name: &str) -> (bool, bool) {
+    (
+        get_active_env().as_deref() == Some(name),
+        VersionService::get_global().as_deref() == Some(name),
+    )
+}
+
+/// `info --compare` result: how the packages of `env_a` and `env_b` differ.
+#[derive(Debug, Serialize, PartialEq, Eq)]
+struct CompareData {
+    env_a: String,
+    env_b: String,
+    only_in_a: Vec<PackageEntry>,
+    only_in_b: Vec<PackageEntry>,
+    version_mismatches: Vec<PackageChanged>,
+}
+
+impl CompareData {
+    /// Sort the two package lists into the three categories.
+    fn new(env_a: &str, env_b: &str, a: &[PackageEntry], b: &[PackageEntry]) -> Self {
+        let diff = compute_package_diff(a, b);
+        Self {
+            env_a: env_a.to_string(),
+            env_b: env_b.to_string(),
+            only_in_a: diff.removed,
+            only_in_b: diff.added,
+            version_mismatches: diff.changed,
+        }
+    }
+}
+
 /// Execute the info command
-pub fn execute(output: &Output, name: &str, all_packages: bool, no_size: bool) -> Result<()> {
-    let service = VirtualenvService::auto()?;
+///
+/// With `no_uv`, only filesystem-derived details are reported: the package
+/// listing (which runs the environment's tooling) is skipped, so `info`
+/// stays fast and works offline. With `compare`, the packages of `name` and
+/// that other env are compared instead (`scuv info A --compare B`).
+pub fn execute(
+    output: &Output,
+    name: &str,
+    all_packages: bool,
+    no_size: bool,
+    no_uv: bool,
+    compare: Option<&str>,
+) -> Result<()> {
+    if let Some(other) = compare {
+        let uv = UvClient::new()?;
+        let service = VirtualenvService::auto()?;
+        return execute_compare(output, &service, &UvPipEnumerator { uv: &uv }, name, other);
+    }
+
+    // `--no-uv` must work even where uv isn't installed.
+    let service = if no_uv {
+        VirtualenvService::filesystem_only()
+    } else {
+        VirtualenvService::auto()?
+    };
 
     if !service.exists(name)? {
         return Err(ScoopError::VirtualenvNotFound {
@@ -21,7 +86,7 @@ pub fn execute(output: &Output, name: &str, all_packages: bool, no_size: bool) -
 
     let path = service.get_path(name)?;
     let metadata = service.read_metadata(&path);
-    let is_active = get_active_env().as_deref() == Some(name);
+    let (is_active, is_global_default) = env_status(name);
 
     // Calculate size (unless --no-size)
     let (size_bytes, size_display) = if no_size {
@@ -33,28 +98,40 @@ pub fn execute(output: &Output, name: &str, all_packages: bool, no_size: bool) -
             .unwrap_or((None, None))
     };
 
-    // Get packages with truncation
-    let packages = list_installed_packages(&path);
-    let limit = if all_packages {
-        usize::MAX
+    // Get packages with truncation (unless --no-uv)
+    let packages_info = if no_uv {
+        None
     } else {
-        DEFAULT_PACKAGE_LIMIT
+        let packages = list_installed_packages(&path);
+        let limit = if all_packages {
+            usize::MAX
+        } else {
+            DEFAULT_PACKAGE_LIMIT
+        };
+        Some(PackagesInfo::new(&packages, limit))
     };
-    let packages_info = PackagesInfo::new(&packages, limit);
+    let python = metadata
+        .as_ref()
+        .map(|m| m.python_version.clone())
+        .or_else(|| parse_pyvenv_version(&path));
 
     let last_used_ts = metadata.as_ref().and_then(|m| m.last_used);
+    let tags = metadata.as_ref().map(|m| m.tags.as_slice()).unwrap_or(&[]);
 
     // JSON output
     if output.is_json() {
         let data = EnvInfoData {
             name: name.to_string(),
-            python: metadata.as_ref().map(|m| m.python_version.clone()),
+            python,
             path: path.display().to_string(),
             active: is_active,
+            global_default: is_global_default,
             created_at: metadata.as_ref().map(|m| m.created_at.to_rfc3339()),
             last_used: last_used_ts.map(|t| t.to_rfc3339()),
+            tags: tags.to_vec(),
             size_bytes,
             size_display,
+            packages_skipped: packages_info.is_none(),
             packages: packages_info,
         };
         output.json_success("info", data);
@@ -63,30 +140,42 @@ pub fn execute(output: &Output, name: &str, all_packages: bool, no_size: bool) -
 
     // Human-readable output
     let w = 12; // label width
-    let python = metadata
-        .as_ref()
-        .map(|m| m.python_version.as_str())
-        .unwrap_or("-");
+    let python = python.as_deref().unwrap_or("-");
     let created = metadata
         .as_ref()
         .map(|m| m.created_at.format("%Y-%m-%d %H:%M:%S").to_string())
         .unwrap_or_else(|| "-".to_string());
 
     println!("{:w$}{}", "Name:", name);
-    println!("{:w$}{}", "Python:", python);
+    match metadata.as_ref().and_then(|m| m.python_request.as_deref()) {
+        Some(request) => println!("{:w$}{} (requested {})", "Python:", python, request),
+        None => println!("{:w$}{}", "Python:", python),
+    }
     println!("{:w$}{}", "Path:", abbreviate_home(&path));
     println!("{:w$}{}", "Active:", if is_active { "yes" } else { "no" });
+    println!(
+        "{:w$}{}",
+        "Global:",
+        if is_global_default { "yes" } else { "no" }
+    );
     println!("{:w$}{}", "Created:", created);
     // Shared three-state contract — see [`format_last_used_value`] for
     // the "hide vs never vs N units ago" rules.
     if let Some(label) = format_last_used_value(metadata.is_some(), last_used_ts, Utc::now()) {
         println!("{:w$}{}", "Last used:", label);
     }
+    if !tags.is_empty() {
+        println!("{:w$}{}", "Tags:", tags.join(", "));
+    }
 
     if let Some(size) = size_display {
         println!("{:w$}{}", "Size:", size);
     }
 
+    let Some(packages_info) = packages_info else {
+        println!("{:w$}skipped (--no-uv)", "Packages:");
+        return Ok(());
+    };
     println!("{:w$}{}", "Packages:", packages_info.total);
     let indent = " ".repeat(w);
     for pkg in &packages_info.items {
@@ -99,6 +188,76 @@ pub fn execute(output: &Output, name: &str, all_packages: bool, no_size: bool) -
     Ok(())
 }
 
+/// `info --compare`: list both envs' packages through `enumerator` (the
+/// same `uv pip list` as `scuv diff`) and print what differs.
+fn execute_compare<E: PackageEnumerator>(
+    output: &Output,
+    service: &VirtualenvService,
+    enumerator: &E,
+    name: &str,
+    other: &str,
+) -> Result<()> {
+    let mut paths = Vec::with_capacity(2);
+    for env in [name, other] {
+        if !service.exists(env)? {
+            return Err(ScoopError::VirtualenvNotFound {
+                name: env.to_string(),
+            });
+        }
+        paths.push(service.get_path(env)?);
+    }
+    let data = CompareData::new(
+        name,
+        other,
+        &enumerator.list(&paths[0])?,
+        &enumerator.list(&paths[1])?,
+    );
+
+    if output.is_json() {
+        output.json_success("info", data);
+        return Ok(());
+    }
+
+    if data.only_in_a.is_empty() && data.only_in_b.is_empty() && data.version_mismatches.is_empty()
+    {
+        println!("{}", t!("info.compare_same", a = name, b = other));
+        return Ok(());
+    }
+    for (env, packages) in [(name, &data.only_in_a), (other, &data.only_in_b)] {
+        if !packages.is_empty() {
+            println!(
+                "{}",
+                t!("info.compare_only_in", name = env, count = packages.len())
+            );
+            for package in packages {
+                println!("  {}=={}", package.display_name, package.version);
+            }
+        }
+    }
+    if !data.version_mismatches.is_empty() {
+        println!(
+            "{}",
+            t!(
+                "info.compare_mismatches",
+                count = data.version_mismatches.len()
+            )
+        );
+        let width = data
+            .version_mismatches
+            .iter()
+            .map(|c| c.name.len())
+            .max()
+            .unwrap_or(0);
+        for changed in &data.version_mismatches {
+            println!(
+                "  {:width$}  {} ({name})  {} ({other})",
+                changed.name, changed.version_a, changed.version_b
+            );
+        }
+    }
+    Ok(())
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -106,7 +265,8 @@ pub fn execute(output: &Output, name: &str, all_packages: bool, no_size: bool) -
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::with_temp_scoop_home;
+    use crate::cli::ColorChoice;
+    use crate::test_utils::{env_guard, with_temp_scoop_home};
     use serial_test::serial;
 
     // =========================================================================
@@ -120,8 +280,8 @@ mod tests {
             // Create virtualenvs directory (required by VirtualenvService)
             std::fs::create_dir_all(temp_dir.path().join("virtualenvs")).unwrap();
 
-            let output = Output::new(0, false, false, false);
-            let result = execute(&output, "nonexistent", false, false);
+            let output = Output::new(0, false, ColorChoice::Always, false);
+            let result = execute(&output, "nonexistent", false, false, false, None);
 
             assert!(result.is_err());
             let err = result.unwrap_err();
@@ -135,9 +295,9 @@ mod tests {
         with_temp_scoop_home(|temp_dir| {
             std::fs::create_dir_all(temp_dir.path().join("virtualenvs")).unwrap();
 
-            let output = Output::new(0, false, false, false);
+            let output = Output::new(0, false, ColorChoice::Always, false);
             // all_packages flag should not cause panic even with nonexistent env
-            let result = execute(&output, "nonexistent", true, false);
+            let result = execute(&output, "nonexistent", true, false, false, None);
 
             assert!(result.is_err());
         });
@@ -149,14 +309,153 @@ mod tests {
         with_temp_scoop_home(|temp_dir| {
             std::fs::create_dir_all(temp_dir.path().join("virtualenvs")).unwrap();
 
-            let output = Output::new(0, false, false, false);
+            let output = Output::new(0, false, ColorChoice::Always, false);
             // no_size flag should not cause panic
-            let result = execute(&output, "nonexistent", false, true);
+            let result = execute(&output, "nonexistent", false, true, false, None);
 
             assert!(result.is_err());
         });
     }
 
+    /// `--no-uv` must never run the env's package tooling (nor need uv on
+    /// PATH). The fake `pip` leaves a marker file behind if it is invoked.
+    #[cfg(unix)]
+    #[test]
+    #[serial]
+    fn execute_no_uv_skips_package_listing() {
+        use std::os::unix::fs::PermissionsExt;
+
+        with_temp_scoop_home(|temp_dir| {
+            let env = temp_dir.path().join("virtualenvs").join("offline");
+            std::fs::create_dir_all(env.join("bin")).unwrap();
+            std::fs::write(env.join("pyvenv.cfg"), "version = 3.12.1\n").unwrap();
+            let marker = temp_dir.path().join("pip-ran");
+            let pip = env.join("bin").join("pip");
+            std::fs::write(
+                &pip,
+                format!("#!/bin/sh\ntouch '{}'\necho '[]'\n", marker.display()),
+            )
+            .unwrap();
+            std::fs::set_permissions(&pip, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+            let output = Output::new(0, true, ColorChoice::Never, false);
+            execute(&output, "offline", false, true, true, None).unwrap();
+            assert!(!marker.exists(), "--no-uv must not list packages");
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn env_status_reports_active_and_global_default() {
+        let home = tempfile::tempdir().unwrap();
+        let home = home.path().to_str().unwrap();
+        // One guard at a time: each holds the env lock until dropped.
+        let with_active = |active: Option<&str>| {
+            env_guard(&[
+                (crate::paths::SCUV_HOME_ENV, Some(home)),
+                (crate::core::SCUV_ACTIVE_ENV, active),
+            ])
+        };
+
+        {
+            let _env = with_active(None);
+            assert_eq!(env_status("web"), (false, false));
+
+            VersionService::set_global("web").unwrap();
+            assert_eq!(env_status("web"), (false, true));
+            assert_eq!(env_status("api"), (false, false));
+        }
+        {
+            let _env = with_active(Some("api"));
+            assert_eq!(env_status("api"), (true, false));
+            assert_eq!(env_status("web"), (false, true));
+        }
+        {
+            let _env = with_active(Some("web"));
+            assert_eq!(env_status("web"), (true, true));
+        }
+    }
+
+    // =========================================================================
+    // --compare Tests
+    // =========================================================================
+
+    fn pkg(name: &str, version: &str) -> PackageEntry {
+        PackageEntry {
+            name: name.to_ascii_lowercase(),
+            version: version.to_string(),
+            display_name: name.to_string(),
+        }
+    }
+
+    /// Canned package lists per env directory name.
+    struct MockEnumerator(Vec<(&'static str, Vec<PackageEntry>)>);
+
+    impl PackageEnumerator for MockEnumerator {
+        fn list(&self, venv_path: &std::path::Path) -> Result<Vec<PackageEntry>> {
+            let name = venv_path.file_name().unwrap().to_str().unwrap();
+            Ok(self
+                .0
+                .iter()
+                .find(|(env, _)| *env == name)
+                .map(|(_, packages)| packages.clone())
+                .unwrap_or_default())
+        }
+    }
+
+    #[test]
+    fn compare_data_sorts_packages_into_three_categories() {
+        let a = [
+            pkg("Flask", "3.0.0"),
+            pkg("requests", "2.31.0"),
+            pkg("rich", "13.7.0"),
+        ];
+        let b = [
+            pkg("Django", "5.0"),
+            pkg("requests", "2.32.3"),
+            pkg("rich", "13.7.0"),
+        ];
+        let data = CompareData::new("web", "api", &a, &b);
+        assert_eq!(data.only_in_a, [pkg("Flask", "3.0.0")]);
+        assert_eq!(data.only_in_b, [pkg("Django", "5.0")]);
+        assert_eq!(
+            data.version_mismatches,
+            [PackageChanged {
+                name: "requests".to_string(),
+                version_a: "2.31.0".to_string(),
+                version_b: "2.32.3".to_string(),
+            }]
+        );
+
+        let same = CompareData::new("web", "web2", &a, &a);
+        assert!(same.only_in_a.is_empty() && same.only_in_b.is_empty());
+        assert!(same.version_mismatches.is_empty());
+    }
+
+    #[test]
+    #[serial]
+    fn compare_lists_both_envs_and_rejects_missing_other() {
+        with_temp_scoop_home(|temp_dir| {
+            crate::test_utils::create_mock_venv(temp_dir, "web", Some("3.12"));
+            crate::test_utils::create_mock_venv(temp_dir, "api", Some("3.12"));
+            let service = VirtualenvService::filesystem_only();
+            let enumerator = MockEnumerator(vec![
+                ("web", vec![pkg("flask", "3.0.0")]),
+                ("api", vec![pkg("django", "5.0")]),
+            ]);
+
+            for json in [false, true] {
+                let output = Output::new(0, true, ColorChoice::Never, json);
+                execute_compare(&output, &service, &enumerator, "web", "api").unwrap();
+            }
+            let output = Output::new(0, true, ColorChoice::Never, true);
+            assert!(matches!(
+                execute_compare(&output, &service, &enumerator, "web", "missing"),
+                Err(ScoopError::VirtualenvNotFound { name }) if name == "missing"
+            ));
+        });
+    }
+
     // =========================================================================
     // Package Limit Logic Tests
     // =========================================================================
@@ -1,18 +1,62 @@
 //! Init command
 
 use crate::cli::ShellType;
+use crate::config::Config;
 use crate::error::Result;
 use crate::shell;
 
 /// Execute the init command
+///
+/// With `activate.auto_deactivate` set in config.json, the script also
+/// installs the auto-deactivate hook (bash, zsh and fish only).
 pub fn execute(shell: ShellType) -> Result<()> {
-    let script = match shell {
+    let auto_deactivate =
+        Config::load().ok().and_then(|c| c.activate.auto_deactivate) == Some(true);
+    print!("{}", script(shell, auto_deactivate));
+    Ok(())
+}
+
+/// The init script for `shell`, with the auto-deactivate hook appended when
+/// `auto_deactivate` is set and the shell supports it.
+fn script(shell: ShellType, auto_deactivate: bool) -> String {
+    let init = match shell {
         ShellType::Bash => shell::bash::init_script(),
         ShellType::Zsh => shell::zsh::init_script(),
         ShellType::Fish => shell::fish::init_script(),
         ShellType::Powershell => shell::powershell::init_script(),
+        ShellType::Cmd => shell::cmd::init_script(),
     };
+    let hook = shell::auto_deactivate_script(shell).filter(|_| auto_deactivate);
+    format!("{init}{}", hook.unwrap_or_default())
+}
 
-    print!("{script}");
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn script_adds_deactivation_hook_only_when_enabled() {
+        for shell in [ShellType::Bash, ShellType::Zsh, ShellType::Fish] {
+            let plain = script(shell, false);
+            assert!(!plain.contains("_scuv_leave_hook"), "{shell:?}");
+
+            let enabled = script(shell, true);
+            assert!(enabled.starts_with(&plain), "{shell:?}");
+            let hook = &enabled[plain.len()..];
+            assert!(hook.contains("_scuv_leave_hook"), "{shell:?}");
+            assert!(hook.contains("command scuv deactivate"), "{shell:?}");
+        }
+        assert!(script(ShellType::Bash, true).contains("PROMPT_COMMAND=\"_scuv_leave_hook"));
+        assert!(script(ShellType::Zsh, true).contains("add-zsh-hook chpwd _scuv_leave_hook"));
+        assert!(
+            script(ShellType::Fish, true).contains("function _scuv_leave_hook --on-variable PWD")
+        );
+    }
+
+    #[test]
+    fn script_ignores_flag_for_unsupported_shells() {
+        for shell in [ShellType::Powershell, ShellType::Cmd] {
+            assert_eq!(script(shell, true), script(shell, false), "{shell:?}");
+        }
+    }
 }
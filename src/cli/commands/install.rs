@@ -1,39 +1,282 @@
 //! Install command
 
+use std::io::IsTerminal;
+use std::time::Instant;
+
+use dialoguer::Confirm;
 use rust_i18n::t;
 
+use super::uninstall::envs_using_python;
+use crate::config::Config;
+use crate::core::{VersionService, VirtualenvService, cancel};
 use crate::error::{Result, ScoopError};
-use crate::output::{InstallData, Output};
-use crate::uv::UvClient;
+use crate::output::{InstallData, InstallVerification, Output, Spinner};
+use crate::uv::{PythonInfo, UvClient};
+use crate::validate::{PythonVersion, detect_python_version, is_version_alias};
+
+/// Options collected from the CLI parse, forwarded into `execute`.
+#[derive(Debug, Clone, Default)]
+pub struct InstallOpts {
+    pub version: Option<String>,
+    pub latest: bool,
+    pub stable: bool,
+    pub reinstall: bool,
+    pub force: bool,
+    /// Post-install interpreter check on or off; `None` falls back to
+    /// `install.verify` in config.json (off by default).
+    pub verify: Option<bool>,
+    /// Also make the installed version the default for `scuv create`.
+    pub set_default: bool,
+}
 
 /// Execute the install command
-pub fn execute(output: &Output, version: Option<&str>, latest: bool, stable: bool) -> Result<()> {
+pub fn execute(output: &Output, opts: &InstallOpts) -> Result<()> {
+    let started = Instant::now();
+
     // Validate conflicting options
-    let target = determine_target(version, latest, stable)?;
+    let target = determine_target(opts.version.as_deref(), opts.latest, opts.stable)?;
 
-    let uv = UvClient::new()?;
+    let uv = UvClient::for_output(output)?;
+    let target = if is_version_alias(&target) {
+        VersionService::resolve_alias(&uv, &target)?
+    } else {
+        target
+    };
 
-    output.info(&t!("install.installing", version = &target));
+    let removed = if opts.reinstall {
+        reinstall_existing(output, &uv, &target, opts.force)?
+    } else {
+        None
+    };
+    let reinstalled = removed.is_some();
+    // Reinstall the exact patch that was there, not whatever `target`
+    // resolves to today — envs point at that interpreter's path.
+    let installed_version = removed.unwrap_or(target);
+
+    // Any prompt is behind us; Ctrl-C now stops uv instead of scuv.
+    cancel::install_handler();
 
-    uv.install_python(&target)?;
+    let spinner = Spinner::for_output(
+        output,
+        &t!("install.installing", version = &installed_version),
+    );
+    let downloading = t!("install.downloading", version = &installed_version);
+    let result = uv.install_python_with_progress(&installed_version, |line| {
+        if is_download_progress(line) {
+            spinner.set_message(&downloading);
+        }
+    });
+    spinner.finish_and_clear();
+    // What uv actually put on disk: `3.12` asked, `3.12.7` installed.
+    let resolved = result?;
+
+    let verify = opts
+        .verify
+        .unwrap_or_else(|| Config::load().ok().and_then(|c| c.install.verify) == Some(true));
+    let verification = if verify {
+        Some(verify_install(&uv, &resolved)?)
+    } else {
+        None
+    };
+
+    // Only once the interpreter is known to work, so a failed install
+    // never leaves `create` pointing at it.
+    let default_python = if opts.set_default {
+        let mut config = Config::load()?;
+        config.create.default_python = Some(installed_version.clone());
+        config.save()?;
+        Some(installed_version.clone())
+    } else {
+        None
+    };
 
     // JSON output
     if output.is_json() {
         output.json_success(
             "install",
             InstallData {
-                version: target,
-                path: None, // uv doesn't return path on install
+                version: resolved,
+                requested: installed_version,
+                // uv doesn't return a path on install; --verify looks it up
+                path: verification.as_ref().map(|v| v.path.clone()),
+                reinstalled,
+                elapsed_ms: u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+                verification,
+                default_python,
             },
         );
         return Ok(());
     }
 
-    output.success(&t!("install.success", version = &target));
+    if let Some(verification) = &verification {
+        output.info(&t!(
+            "install.verified",
+            path = &verification.path,
+            version = &verification.reported_version
+        ));
+        if !verification.managed {
+            output.warn(&t!("install.verify_unmanaged", path = &verification.path));
+        }
+    }
+
+    if reinstalled {
+        output.success(&t!("install.reinstall_success", version = &resolved));
+    } else {
+        output.success(&t!("install.success", version = &resolved));
+    }
+    if resolved != installed_version {
+        output.info(&t!(
+            "install.resolved",
+            requested = &installed_version,
+            version = &resolved
+        ));
+    }
+    if let Some(default) = &default_python {
+        output.info(&t!("install.default_set", version = default));
+    }
 
     Ok(())
 }
 
+/// Uninstall the installed Python matching `target` ahead of a fresh install.
+///
+/// Returns the exact version that was removed, or `None` if nothing matched
+/// (the caller then installs `target` as usual). Environments using that
+/// version are listed first and must be confirmed past unless `force`.
+fn reinstall_existing(
+    output: &Output,
+    uv: &UvClient,
+    target: &str,
+    force: bool,
+) -> Result<Option<String>> {
+    let Some(existing) = uv.find_python(target)? else {
+        output.info(&t!("install.reinstall_not_installed", version = target));
+        return Ok(None);
+    };
+    let version = existing.version;
+
+    let service = VirtualenvService::auto()?;
+    let dependents = envs_using_python(&service, &minor_prefix(&version))?;
+    if !dependents.is_empty() && !force {
+        output.warn(&t!(
+            "install.reinstall_in_use",
+            version = &version,
+            count = dependents.len()
+        ));
+        for name in &dependents {
+            output.warn(&t!("install.reinstall_env", name = name));
+        }
+
+        // JSON and non-interactive callers can't answer a prompt; they
+        // must opt in with --force.
+        if output.is_json() || !std::io::stdin().is_terminal() {
+            return Err(ScoopError::ReinstallAborted { version });
+        }
+
+        let confirmed = Confirm::new()
+            .with_prompt(t!("install.reinstall_confirm", version = &version).to_string())
+            .default(false)
+            .interact()
+            .unwrap_or(false);
+        if !confirmed {
+            return Err(ScoopError::ReinstallAborted { version });
+        }
+    }
+
+    output.info(&t!("install.reinstalling", version = &version));
+    uv.uninstall_python(&version)?;
+    Ok(Some(version))
+}
+
+/// Check the interpreter uv just installed for `version` actually runs.
+///
+/// Looks the install up in `uv python list`, runs it with `--version` and
+/// compares the answer to what was requested. Any failure is reported as
+/// [`ScoopError::PythonInstallFailed`]; an interpreter uv doesn't manage is
+/// allowed but flagged in the result.
+fn verify_install(uv: &UvClient, version: &str) -> Result<InstallVerification> {
+    let fail = |reason: String| ScoopError::PythonInstallFailed {
+        version: version.to_string(),
+        message: format!("verification failed: {reason}"),
+    };
+
+    let info = pick_installed(uv.list_installed_pythons()?, version)
+        .ok_or_else(|| fail("uv doesn't list it as installed".to_string()))?;
+    let path = info
+        .path
+        .ok_or_else(|| fail("uv reports no interpreter path".to_string()))?;
+    let reported = check_reported_version(version, detect_python_version(&path))
+        .map_err(|reason| fail(format!("{}: {reason}", path.display())))?;
+
+    Ok(InstallVerification {
+        path: path.display().to_string(),
+        reported_version: reported,
+        managed: info.managed,
+    })
+}
+
+/// The installed Python matching `version`, preferring a uv-managed one.
+///
+/// A system Python of the same version may also be on the list; it's not
+/// the one uv just downloaded, so it only counts when nothing managed matches.
+fn pick_installed(installed: Vec<PythonInfo>, version: &str) -> Option<PythonInfo> {
+    let pattern = PythonVersion::parse(version)?;
+    let mut matching = installed.into_iter().filter(|info| {
+        PythonVersion::parse(&info.version).is_some_and(|ver| pattern.matches(&ver))
+    });
+    let first = matching.next()?;
+    if first.managed {
+        return Some(first);
+    }
+    Some(matching.find(|info| info.managed).unwrap_or(first))
+}
+
+/// Judge what `python --version` printed (`None` if it didn't run or said
+/// nothing recognisable) against the requested `version`.
+fn check_reported_version(
+    version: &str,
+    reported: Option<String>,
+) -> std::result::Result<String, String> {
+    let reported = reported.ok_or_else(|| "interpreter failed to run `--version`".to_string())?;
+    let matches = match (
+        PythonVersion::parse(version),
+        PythonVersion::parse(&reported),
+    ) {
+        (Some(want), Some(have)) => want.matches(&have),
+        _ => false,
+    };
+    if matches {
+        Ok(reported)
+    } else {
+        Err(format!("reports Python {reported}, expected {version}"))
+    }
+}
+
+/// Whether a uv stderr line reports a download in progress.
+///
+/// uv prints `Downloading cpython-… (…MiB)` (and redraws its progress bar)
+/// while fetching; everything else — `Searching for…`, `Installed …` — is
+/// left to the spinner's default message.
+fn is_download_progress(line: &str) -> bool {
+    line.trim_start().starts_with("Downloading")
+}
+
+/// `MAJOR.MINOR` of an installed version, used to find dependent envs.
+///
+/// Env metadata usually records what the user asked for (`3.12`), not the
+/// resolved patch, so matching on the exact installed patch would miss them.
+/// Over-matching a sibling patch only costs an extra confirmation.
+fn minor_prefix(version: &str) -> String {
+    match PythonVersion::parse(version) {
+        Some(PythonVersion {
+            major,
+            minor: Some(minor),
+            ..
+        }) => format!("{major}.{minor}"),
+        _ => version.to_string(),
+    }
+}
+
 /// Determine the Python version to install based on options
 fn determine_target(version: Option<&str>, latest: bool, stable: bool) -> Result<String> {
     // Check for conflicting options
@@ -57,15 +300,12 @@ fn determine_target(version: Option<&str>, latest: bool, stable: bool) -> Result
         return Ok(ver.to_string());
     }
 
-    // No version specified
+    // No version specified: an alias, resolved against uv's version list
+    // by `VersionService::resolve_alias`. Default to latest.
     if stable {
-        // Use oldest fully-supported Python (currently 3.10)
-        // This is the oldest version with active security support
-        Ok("3.10".to_string())
+        Ok("stable".to_string())
     } else {
-        // Default to latest (--latest flag or no flag)
-        // uv uses "3" to mean "latest Python 3.x"
-        Ok("3".to_string())
+        Ok("latest".to_string())
     }
 }
 
@@ -83,17 +323,17 @@ mod tests {
 
     #[test]
     fn test_determine_target_latest() {
-        assert_eq!(determine_target(None, true, false).unwrap(), "3");
+        assert_eq!(determine_target(None, true, false).unwrap(), "latest");
     }
 
     #[test]
     fn test_determine_target_stable() {
-        assert_eq!(determine_target(None, false, true).unwrap(), "3.10");
+        assert_eq!(determine_target(None, false, true).unwrap(), "stable");
     }
 
     #[test]
     fn test_determine_target_default() {
-        assert_eq!(determine_target(None, false, false).unwrap(), "3");
+        assert_eq!(determine_target(None, false, false).unwrap(), "latest");
     }
 
     #[test]
@@ -106,8 +346,72 @@ mod tests {
         assert!(determine_target(Some("3.12"), false, true).is_err());
     }
 
+    #[test]
+    fn test_minor_prefix_drops_patch_and_suffix() {
+        assert_eq!(minor_prefix("3.12.1"), "3.12");
+        assert_eq!(minor_prefix("3.13.0rc1"), "3.13");
+        assert_eq!(minor_prefix("3.12"), "3.12");
+        assert_eq!(minor_prefix("3"), "3");
+        assert_eq!(minor_prefix("pypy"), "pypy");
+    }
+
+    #[test]
+    fn test_is_download_progress() {
+        assert!(is_download_progress(
+            "Downloading cpython-3.12.1-linux-x86_64-gnu (download) (30.5MiB)"
+        ));
+        assert!(is_download_progress("  Downloading cpython-3.13.0"));
+        assert!(!is_download_progress(
+            "Searching for Python versions matching: 3.12"
+        ));
+        assert!(!is_download_progress("Installed Python 3.12.1 in 2.31s"));
+    }
+
     #[test]
     fn test_determine_target_conflict_latest_version() {
         assert!(determine_target(Some("3.12"), true, false).is_err());
     }
+
+    fn python(version: &str, path: &str, managed: bool) -> PythonInfo {
+        PythonInfo {
+            version: version.to_string(),
+            path: Some(path.into()),
+            installed: true,
+            implementation: "cpython".to_string(),
+            managed,
+        }
+    }
+
+    #[test]
+    fn test_pick_installed_prefers_managed() {
+        let installed = vec![
+            python("3.13.1", "/uv/3.13", true),
+            python("3.12.8", "/usr/bin/python3.12", false),
+            python("3.12.7", "/uv/3.12", true),
+        ];
+        let picked = pick_installed(installed.clone(), "3.12").unwrap();
+        assert_eq!(picked.version, "3.12.7");
+        assert!(picked.managed);
+
+        let system_only = vec![python("3.11.2", "/usr/bin/python3.11", false)];
+        assert!(!pick_installed(system_only, "3.11").unwrap().managed);
+        assert!(pick_installed(installed, "3.10").is_none());
+    }
+
+    #[test]
+    fn test_check_reported_version() {
+        assert_eq!(
+            check_reported_version("3.12", Some("3.12.7".into())).unwrap(),
+            "3.12.7"
+        );
+        assert!(check_reported_version("3.12.7", Some("3.12.7".into())).is_ok());
+
+        let err = check_reported_version("3.12", Some("3.11.9".into())).unwrap_err();
+        assert!(
+            err.contains("3.11.9") && err.contains("expected 3.12"),
+            "{err}"
+        );
+        let err = check_reported_version("3.12", None).unwrap_err();
+        assert!(err.contains("failed to run"), "{err}");
+    }
 }
@@ -145,6 +145,7 @@ fn list_languages(output: &Output) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::ColorChoice;
     use crate::test_utils::with_temp_scoop_home;
     use serial_test::serial;
 
@@ -153,7 +154,7 @@ mod tests {
     // =========================================================================
 
     fn create_test_output(json: bool) -> Output {
-        Output::new(0, false, true, json) // verbose=0, quiet=false, no_color=true, json
+        Output::new(0, false, ColorChoice::Never, json) // verbose=0, quiet=false, color never, json
     }
 
     // =========================================================================
@@ -5,31 +5,114 @@ use std::collections::BTreeSet;
 
 use chrono::{DateTime, Utc};
 use owo_colors::OwoColorize;
+use rayon::prelude::*;
 use rust_i18n::t;
 
-use crate::cli::ListSortMode;
-use crate::core::{VirtualenvInfo as CoreVirtualenvInfo, VirtualenvService, get_active_env};
+use crate::cli::{ListGroupBy, ListSortMode};
+use crate::core::{
+    VirtualenvInfo as CoreVirtualenvInfo, VirtualenvService, get_active_env, is_env_healthy,
+    non_utf8_env_dirs,
+};
 use crate::error::Result;
-use crate::output::{ListEnvsData, ListPythonsData, Output, PythonInfo, VirtualenvInfo};
-use crate::paths::abbreviate_home;
-use crate::uv::UvClient;
+use crate::output::{
+    EnvGroups, ListCountData, ListEnvsData, ListGroupedEnvsData, ListPythonsData, Output,
+    PythonInfo, VirtualenvInfo, format_size,
+};
+use crate::paths::{self, abbreviate_home, calculate_dir_size};
+use crate::uv::{PythonInfo as UvPythonInfo, UvClient};
 use crate::validate::PythonVersion;
 
+/// Options collected from the CLI parse, forwarded into `execute`.
+#[derive(Debug, Clone)]
+pub struct ListOpts {
+    pub pythons: bool,
+    pub managed_only: bool,
+    pub system_only: bool,
+    pub bare: bool,
+    pub python_version: Option<String>,
+    pub sort: ListSortMode,
+    pub group_by: ListGroupBy,
+    pub tag: Option<String>,
+    pub older_than: Option<u32>,
+    pub broken_only: bool,
+    pub count: bool,
+    pub sizes: bool,
+}
+
 /// Execute the list command
-pub fn execute(
-    output: &Output,
-    pythons: bool,
-    bare: bool,
-    python_version: Option<&str>,
-    sort: ListSortMode,
-) -> Result<()> {
-    if pythons {
-        list_pythons(output, bare)
+pub fn execute(output: &Output, opts: &ListOpts) -> Result<()> {
+    if opts.pythons {
+        list_pythons(output, opts)
     } else {
-        list_virtualenvs(output, bare, python_version, sort)
+        list_virtualenvs(output, opts)
     }
 }
 
+/// `health` value for list output: `"ok"` or `"broken"` (same criteria as
+/// `doctor`'s virtualenv check, see [`is_env_healthy`]).
+fn health_label(healthy: bool) -> &'static str {
+    if healthy { "ok" } else { "broken" }
+}
+
+/// JSON rows for `envs` plus the system Python, if shown.
+///
+/// `active` is the env in use (`SCUV_ACTIVE`, else the resolved version)
+/// and `global_default` the one named in the global version file; either
+/// may be `"system"`.
+fn json_entries(
+    envs: &[CoreVirtualenvInfo],
+    system_python: Option<&(String, String)>,
+    active: Option<&str>,
+    global_default: Option<&str>,
+) -> Vec<VirtualenvInfo> {
+    let mut entries: Vec<VirtualenvInfo> = envs
+        .iter()
+        .map(|env| VirtualenvInfo {
+            name: env.name.clone(),
+            python: env.python_version.clone(),
+            path: env.path.display().to_string(),
+            active: active == Some(env.name.as_str()),
+            is_global_default: global_default == Some(env.name.as_str()),
+            created_at: env.created_at.map(|t| t.to_rfc3339()),
+            last_used: env.last_used.map(|t| t.to_rfc3339()),
+            tags: env.tags.clone(),
+            health: health_label(is_env_healthy(&env.path)),
+            size_bytes: None,
+        })
+        .collect();
+
+    if let Some((version, path)) = system_python {
+        entries.push(VirtualenvInfo {
+            name: "system".to_string(),
+            python: Some(version.clone()),
+            path: path.clone(),
+            active: active == Some("system"),
+            is_global_default: global_default == Some("system"),
+            // System Python isn't a scuv-managed env, so there's
+            // no on-disk metadata to source these from.
+            created_at: None,
+            last_used: None,
+            tags: Vec::new(),
+            health: health_label(true),
+            size_bytes: None,
+        });
+    }
+    entries
+}
+
+/// Disk size of each env in `envs`, same order, walking the directories in
+/// parallel. `None` where a directory couldn't be read.
+fn env_sizes(envs: &[CoreVirtualenvInfo]) -> Vec<Option<u64>> {
+    envs.par_iter()
+        .map(|env| calculate_dir_size(&env.path).ok())
+        .collect()
+}
+
+/// Sum of the sizes that could be measured.
+fn total_size(sizes: &[Option<u64>]) -> u64 {
+    sizes.iter().flatten().sum()
+}
+
 /// Sort a list of envs in place according to the chosen mode.
 ///
 /// Pulled out as a free function so the ordering can be unit-tested
@@ -54,6 +137,138 @@ pub(crate) fn sort_envs(envs: &mut [CoreVirtualenvInfo], mode: ListSortMode) {
     }
 }
 
+/// Whether the env's Python matches a `--python` spec; partial specs like
+/// `3.12` match every patch release. Envs with no known version never match.
+fn matches_python(env: &CoreVirtualenvInfo, filter: &PythonVersion) -> bool {
+    env.python_version
+        .as_deref()
+        .and_then(PythonVersion::parse)
+        .is_some_and(|v| filter.matches(&v))
+}
+
+/// Whether the env was last used (or, never used, created) before `cutoff`.
+/// Envs without either timestamp have no known age and never match.
+fn is_older_than(env: &CoreVirtualenvInfo, cutoff: DateTime<Utc>) -> bool {
+    env.last_used
+        .or(env.created_at)
+        .is_some_and(|seen| seen < cutoff)
+}
+
+/// Exact, case-sensitive tag match — tags are stored verbatim, so
+/// `--tag Web` and `--tag web` are different filters.
+fn has_tag(env: &CoreVirtualenvInfo, tag: &str) -> bool {
+    env.tags.iter().any(|t| t == tag)
+}
+
+/// Keep only the envs that pass every filter in `opts`; filters compose.
+/// `version_filter` is the parsed `--python`, `now` anchors `--older-than`.
+fn filter_envs(
+    envs: &mut Vec<CoreVirtualenvInfo>,
+    version_filter: Option<&PythonVersion>,
+    opts: &ListOpts,
+    now: DateTime<Utc>,
+) {
+    if let Some(filter) = version_filter {
+        envs.retain(|env| matches_python(env, filter));
+    }
+
+    if let Some(tag) = opts.tag.as_deref() {
+        envs.retain(|env| has_tag(env, tag));
+    }
+
+    if let Some(days) = opts.older_than {
+        let cutoff = now - chrono::Duration::days(i64::from(days));
+        envs.retain(|env| is_older_than(env, cutoff));
+    }
+
+    if opts.broken_only {
+        envs.retain(|env| !is_env_healthy(&env.path));
+    }
+}
+
+/// Group for envs whose Python version is missing or unparseable.
+const UNKNOWN_PYTHON_GROUP: &str = "unknown";
+
+/// Group for envs without tags under `--group-by tag`.
+const UNTAGGED_GROUP: &str = "untagged";
+
+/// The groups an entry with this Python and these tags belongs to: its
+/// minor version (`3.12.1` -> `3.12`) for `python`, each of its tags for
+/// `tag`.
+fn group_keys(by: ListGroupBy, python: Option<&str>, tags: &[String]) -> Vec<String> {
+    match by {
+        ListGroupBy::None => vec![String::new()],
+        ListGroupBy::Python => {
+            let key = match python.and_then(PythonVersion::parse) {
+                Some(PythonVersion {
+                    major,
+                    minor: Some(minor),
+                    ..
+                }) => format!("{major}.{minor}"),
+                Some(version) => version.major.to_string(),
+                None => UNKNOWN_PYTHON_GROUP.to_string(),
+            };
+            vec![key]
+        }
+        ListGroupBy::Tag if tags.is_empty() => vec![UNTAGGED_GROUP.to_string()],
+        ListGroupBy::Tag => tags.to_vec(),
+    }
+}
+
+/// Group order: Python versions semantically (`3.9` before `3.11`), tags
+/// alphabetically, and the catch-all group last either way.
+fn compare_groups(by: ListGroupBy, a: &str, b: &str) -> Ordering {
+    match by {
+        ListGroupBy::Python => match (PythonVersion::parse(a), PythonVersion::parse(b)) {
+            (Some(av), Some(bv)) => av.cmp(&bv),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => a.cmp(b),
+        },
+        ListGroupBy::Tag | ListGroupBy::None => (a == UNTAGGED_GROUP)
+            .cmp(&(b == UNTAGGED_GROUP))
+            .then_with(|| a.cmp(b)),
+    }
+}
+
+/// Bucket `items` under the keys `keys_of` gives them, groups sorted by
+/// [`compare_groups`] and items keeping their order within a group. An
+/// item with several keys (several tags) lands in each of those groups.
+fn group_items<T: Clone>(
+    items: Vec<T>,
+    by: ListGroupBy,
+    keys_of: impl Fn(&T) -> Vec<String>,
+) -> Vec<(String, Vec<T>)> {
+    let mut groups: Vec<(String, Vec<T>)> = Vec::new();
+    for item in items {
+        for key in keys_of(&item) {
+            match groups.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, members)) => members.push(item.clone()),
+                None => groups.push((key, vec![item.clone()])),
+            }
+        }
+    }
+    groups.sort_by(|(a, _), (b, _)| compare_groups(by, a, b));
+    groups
+}
+
+/// One line of the human-readable list.
+#[derive(Clone, Copy)]
+enum Row<'a> {
+    Env(&'a CoreVirtualenvInfo),
+    /// System Python: `(version, path)`
+    System(&'a (String, String)),
+}
+
+impl Row<'_> {
+    fn group_keys(&self, by: ListGroupBy) -> Vec<String> {
+        match self {
+            Row::Env(env) => group_keys(by, env.python_version.as_deref(), &env.tags),
+            Row::System((version, _)) => group_keys(by, Some(version), &[]),
+        }
+    }
+}
+
 /// Newest-first ordering with `None` pushed to the end, then a name
 /// tie-break. Lifted into its own helper so the same rules apply to
 /// `--sort=created` and `--sort=last-used` without copy-paste.
@@ -72,15 +287,15 @@ fn compare_desc_none_last(
 }
 
 /// List virtual environments
-fn list_virtualenvs(
-    output: &Output,
-    bare: bool,
-    python_version: Option<&str>,
-    sort: ListSortMode,
-) -> Result<()> {
+fn list_virtualenvs(output: &Output, opts: &ListOpts) -> Result<()> {
     use crate::core::VersionService;
     use crate::validate::validate_python_version;
 
+    let bare = opts.bare;
+    let python_version = opts.python_version.as_deref();
+    let tag = opts.tag.as_deref();
+    let older_than = opts.older_than;
+
     // Validate and parse version filter
     let version_filter = if let Some(ver_str) = python_version {
         validate_python_version(ver_str)?;
@@ -91,68 +306,111 @@ fn list_virtualenvs(
 
     let service = VirtualenvService::auto()?;
     let mut envs = service.list()?;
-    let active_env = get_active_env();
 
-    // Apply python version filter
-    if let Some(ref filter) = version_filter {
-        envs.retain(|env| {
-            env.python_version
-                .as_ref()
-                .and_then(|v| PythonVersion::parse(v))
-                .is_some_and(|v| filter.matches(&v))
-        });
+    // `service.list()` can't represent non-UTF-8 dir names; tell the user
+    // about them instead of hiding them. Bare output feeds completion and
+    // a count feeds prompts, so both stay silent (warn() already no-ops
+    // for JSON/quiet).
+    if !bare && !opts.count {
+        for path in non_utf8_env_dirs(&paths::virtualenvs_dir()?) {
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            output.warn(&t!("list.non_utf8_env", name = name));
+        }
+    }
+
+    filter_envs(&mut envs, version_filter.as_ref(), opts, Utc::now());
+
+    // System Python isn't an environment, so it never adds to the count.
+    if opts.count {
+        if output.is_json() {
+            output.json_success("list", ListCountData { count: envs.len() });
+        } else {
+            println!("{}", envs.len());
+        }
+        return Ok(());
     }
 
     // Sort *after* filtering so the user sees the requested ordering
     // applied to the same set their filter produced.
-    sort_envs(&mut envs, sort);
-
-    // Check if "system" is the resolved version
-    let resolved = VersionService::resolve_current();
-    let system_active = resolved.as_deref() == Some("system");
+    sort_envs(&mut envs, opts.sort);
+
+    // The env in use here: the activated one, else what version files
+    // resolve to. The global default is reported separately since a local
+    // pin or `scuv use` can override it.
+    let active_env = get_active_env().or_else(VersionService::resolve_current);
+    let global_default = VersionService::get_global();
+
+    // Get system Python info, filtered if needed. System Python carries
+    // no tags or usage metadata, so a tag or age filter excludes it; it is
+    // never "broken" either.
+    let system_python = get_system_python_info()
+        .filter(|_| tag.is_none() && older_than.is_none() && !opts.broken_only)
+        .filter(|(version, _)| match version_filter {
+            Some(ref filter) => PythonVersion::parse(version).is_some_and(|v| filter.matches(&v)),
+            None => true,
+        });
 
-    // Get system Python info, filtered if needed
-    let system_python = get_system_python_info().filter(|(version, _)| match version_filter {
-        Some(ref filter) => PythonVersion::parse(version).is_some_and(|v| filter.matches(&v)),
-        None => true,
-    });
+    let sizes = opts.sizes.then(|| env_sizes(&envs));
+    let total_size_bytes = sizes.as_deref().map(total_size);
 
     // JSON output
     if output.is_json() {
-        let mut virtualenvs: Vec<VirtualenvInfo> = envs
-            .iter()
-            .map(|env| VirtualenvInfo {
-                name: env.name.clone(),
-                python: env.python_version.clone(),
-                path: env.path.display().to_string(),
-                active: active_env.as_ref() == Some(&env.name),
-                created_at: env.created_at.map(|t| t.to_rfc3339()),
-                last_used: env.last_used.map(|t| t.to_rfc3339()),
-            })
-            .collect();
-
-        // Add system Python to JSON output
-        if let Some((version, path)) = &system_python {
-            virtualenvs.push(VirtualenvInfo {
-                name: "system".to_string(),
-                python: Some(version.clone()),
-                path: path.clone(),
-                active: system_active,
-                // System Python isn't a scuv-managed env, so there's
-                // no on-disk metadata to source these from.
-                created_at: None,
-                last_used: None,
-            });
+        let mut virtualenvs = json_entries(
+            &envs,
+            system_python.as_ref(),
+            active_env.as_deref(),
+            global_default.as_deref(),
+        );
+        // Entries start with `envs` in order; the system Python (last) has
+        // no size of its own.
+        for (entry, size) in virtualenvs.iter_mut().zip(sizes.iter().flatten()) {
+            entry.size_bytes = *size;
         }
-
         let total = virtualenvs.len();
-        output.json_success("list", ListEnvsData { virtualenvs, total });
+        let group_by = match opts.group_by {
+            ListGroupBy::None => {
+                output.json_success(
+                    "list",
+                    ListEnvsData {
+                        virtualenvs,
+                        total,
+                        total_size_bytes,
+                    },
+                );
+                return Ok(());
+            }
+            ListGroupBy::Python => "python",
+            ListGroupBy::Tag => "tag",
+        };
+        let groups = group_items(virtualenvs, opts.group_by, |entry| {
+            group_keys(opts.group_by, entry.python.as_deref(), &entry.tags)
+        });
+        output.json_success(
+            "list",
+            ListGroupedEnvsData {
+                group_by,
+                groups: EnvGroups(groups),
+                total,
+                total_size_bytes,
+            },
+        );
         return Ok(());
     }
 
     if envs.is_empty() && system_python.is_none() {
         if !bare {
-            if let Some(ver_str) = python_version {
+            if opts.broken_only {
+                output.info(&t!("list.broken_no_envs"));
+            } else if let Some(tag) = tag {
+                output.info(&t!("list.tag_no_envs", tag = tag));
+                output.info(&t!("list.filtered_hint"));
+            } else if let Some(days) = older_than {
+                output.info(&t!("list.older_than_no_envs", days = days));
+                output.info(&t!("list.filtered_hint"));
+            } else if let Some(ver_str) = python_version {
                 output.info(&t!("list.filtered_no_envs", version = ver_str));
                 output.info(&t!("list.filtered_hint"));
             } else {
@@ -189,38 +447,81 @@ fn list_virtualenvs(
             max_ver_len = max_ver_len.max(version.len());
         }
 
-        // Output with marker, name, version, and path
-        for env in &envs {
-            let is_active = active_env.as_ref() == Some(&env.name);
+        let active = active_env.as_deref();
+        let mut rows: Vec<Row> = envs.iter().map(Row::Env).collect();
+        rows.extend(system_python.as_ref().map(Row::System));
+
+        if opts.group_by == ListGroupBy::None {
+            for row in &rows {
+                print_row(output, row, active, max_name_len, max_ver_len);
+            }
+        } else {
+            let groups = group_items(rows, opts.group_by, |row| row.group_keys(opts.group_by));
+            for (i, (key, rows)) in groups.iter().enumerate() {
+                if i > 0 {
+                    println!();
+                }
+                if output.use_color() {
+                    println!("{}", key.bold());
+                } else {
+                    println!("{key}");
+                }
+                for row in rows {
+                    print_row(output, row, active, max_name_len, max_ver_len);
+                }
+            }
+        }
+        if let Some(bytes) = total_size_bytes {
+            println!();
+            println!(
+                "{}",
+                t!(
+                    "list.total_size",
+                    size = format_size(bytes),
+                    count = envs.len()
+                )
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Print one env (or the system Python) with marker, name, version and
+/// path, padded to the given column widths.
+fn print_row(output: &Output, row: &Row, active: Option<&str>, name_w: usize, ver_w: usize) {
+    match row {
+        Row::Env(env) => {
+            let is_active = active == Some(env.name.as_str());
             let marker = if is_active { "*" } else { " " };
             let version = env.python_version.as_deref().unwrap_or("-");
             let path = abbreviate_home(&env.path);
+            let broken = if is_env_healthy(&env.path) {
+                String::new()
+            } else if output.use_color() {
+                format!(" {}", "(broken)".red())
+            } else {
+                " (broken)".to_string()
+            };
 
             if output.use_color() && is_active {
                 println!(
-                    "{} {:<name_w$}  {:<ver_w$}  {}",
+                    "{} {:<name_w$}  {:<ver_w$}  {}{}",
                     marker.green(),
                     env.name.green(),
                     version,
                     path,
-                    name_w = max_name_len,
-                    ver_w = max_ver_len
+                    broken,
                 );
             } else {
                 println!(
-                    "{} {:<name_w$}  {:<ver_w$}  {}",
-                    marker,
-                    env.name,
-                    version,
-                    path,
-                    name_w = max_name_len,
-                    ver_w = max_ver_len
+                    "{} {:<name_w$}  {:<ver_w$}  {}{}",
+                    marker, env.name, version, path, broken,
                 );
             }
         }
-
-        // Add system Python at the end
-        if let Some((version, path)) = system_python {
+        Row::System((version, path)) => {
+            let system_active = active == Some("system");
             let marker = if system_active { "*" } else { " " };
             let display_path = format!("{} (system)", path);
 
@@ -231,30 +532,31 @@ fn list_virtualenvs(
                     "system".green(),
                     version,
                     display_path.dimmed(),
-                    name_w = max_name_len,
-                    ver_w = max_ver_len
                 );
             } else {
                 println!(
                     "{} {:<name_w$}  {:<ver_w$}  {}",
-                    marker,
-                    "system",
-                    version,
-                    display_path,
-                    name_w = max_name_len,
-                    ver_w = max_ver_len
+                    marker, "system", version, display_path,
                 );
             }
         }
     }
+}
 
-    Ok(())
+/// Keep the interpreters `--managed-only` / `--system-only` ask for, by
+/// whether uv manages them.
+fn filter_pythons(pythons: Vec<UvPythonInfo>, opts: &ListOpts) -> Vec<UvPythonInfo> {
+    pythons
+        .into_iter()
+        .filter(|p| (!opts.managed_only || p.managed) && (!opts.system_only || !p.managed))
+        .collect()
 }
 
 /// List installed Python versions
-fn list_pythons(output: &Output, bare: bool) -> Result<()> {
+fn list_pythons(output: &Output, opts: &ListOpts) -> Result<()> {
+    let bare = opts.bare;
     let uv = UvClient::new()?;
-    let pythons = uv.list_installed_pythons()?;
+    let pythons = filter_pythons(uv.list_installed_pythons()?, opts);
 
     // JSON output
     if output.is_json() {
@@ -264,6 +566,7 @@ fn list_pythons(output: &Output, bare: bool) -> Result<()> {
                 version: p.version.clone(),
                 implementation: Some(p.implementation.clone()),
                 path: p.path.as_ref().map(|path| path.display().to_string()),
+                managed: p.managed,
             })
             .collect();
         let total = python_infos.len();
@@ -279,8 +582,15 @@ fn list_pythons(output: &Output, bare: bool) -> Result<()> {
 
     if pythons.is_empty() {
         if !bare {
-            output.info(&t!("list.no_pythons"));
-            output.info(&t!("list.no_pythons_hint"));
+            if opts.system_only {
+                output.info(&t!("list.no_system_pythons"));
+            } else if opts.managed_only {
+                output.info(&t!("list.no_managed_pythons"));
+                output.info(&t!("list.no_pythons_hint"));
+            } else {
+                output.info(&t!("list.no_pythons"));
+                output.info(&t!("list.no_pythons_hint"));
+            }
         }
         return Ok(());
     }
@@ -388,9 +698,58 @@ mod tests {
             python_version: None,
             created_at,
             last_used,
+            tags: Vec::new(),
         }
     }
 
+    #[test]
+    fn health_label_reflects_a_corrupted_env() {
+        let tmp = tempfile::tempdir().unwrap();
+        let env = tmp.path().join("half-deleted");
+        std::fs::create_dir_all(&env).unwrap();
+        std::fs::write(env.join("pyvenv.cfg"), "version = 3.12.1\n").unwrap();
+
+        // pyvenv.cfg alone (no Python executable) is broken.
+        assert_eq!(health_label(is_env_healthy(&env)), "broken");
+
+        let python = paths::virtualenv_python_exe(&env);
+        std::fs::create_dir_all(python.parent().unwrap()).unwrap();
+        std::fs::write(&python, "").unwrap();
+        assert_eq!(health_label(is_env_healthy(&env)), "ok");
+    }
+
+    #[test]
+    fn json_entries_separate_active_from_global_default() {
+        let envs = [env("web", None, None), env("api", None, None)];
+        let system = ("3.12.1".to_string(), "/usr/bin/python3".to_string());
+        let flags = |entries: Vec<VirtualenvInfo>| -> Vec<(String, bool, bool)> {
+            entries
+                .into_iter()
+                .map(|e| (e.name, e.active, e.is_global_default))
+                .collect()
+        };
+
+        // Activated 'web' while the global default is 'api'.
+        let rows = flags(json_entries(&envs, Some(&system), Some("web"), Some("api")));
+        assert_eq!(
+            rows,
+            [
+                ("web".to_string(), true, false),
+                ("api".to_string(), false, true),
+                ("system".to_string(), false, false),
+            ]
+        );
+
+        // Global default on system, nothing in use.
+        let rows = flags(json_entries(&envs, Some(&system), None, Some("system")));
+        assert_eq!(rows[2], ("system".to_string(), false, true));
+        assert!(rows.iter().all(|(_, active, _)| !active));
+
+        // The same env can be both.
+        let rows = flags(json_entries(&envs, None, Some("api"), Some("api")));
+        assert_eq!(rows[1], ("api".to_string(), true, true));
+    }
+
     #[test]
     fn sort_by_name_is_alphabetical() {
         let mut envs = vec![env("zeta", None, None), env("alpha", None, None)];
@@ -575,4 +934,287 @@ mod tests {
         });
         assert!(filtered2.is_none());
     }
+
+    #[test]
+    fn test_has_tag_is_exact_match() {
+        let mut tagged = env("tagged", None, None);
+        tagged.tags = vec!["api".to_string(), "web".to_string()];
+        let untagged = env("plain", None, None);
+
+        assert!(has_tag(&tagged, "web"));
+        assert!(!has_tag(&tagged, "Web"));
+        assert!(!has_tag(&tagged, "we"));
+        assert!(!has_tag(&untagged, "web"));
+    }
+
+    fn env_with_python(name: &str, version: Option<&str>) -> CoreVirtualenvInfo {
+        let mut e = env(name, None, None);
+        e.python_version = version.map(str::to_string);
+        e
+    }
+
+    #[test]
+    fn matches_python_accepts_partial_specs() {
+        let envs = [
+            env_with_python("web", Some("3.12.0")),
+            env_with_python("api", Some("3.12.7")),
+            env_with_python("old", Some("3.11.9")),
+            env_with_python("broken", None),
+        ];
+        let names = |spec: &str| -> Vec<&str> {
+            let filter = PythonVersion::parse(spec).unwrap();
+            envs.iter()
+                .filter(|e| matches_python(e, &filter))
+                .map(|e| e.name.as_str())
+                .collect()
+        };
+
+        assert_eq!(names("3.12"), vec!["web", "api"]);
+        assert_eq!(names("3.12.7"), vec!["api"]);
+        assert_eq!(names("3"), vec!["web", "api", "old"]);
+        assert!(names("3.10").is_empty());
+    }
+
+    #[test]
+    fn is_older_than_prefers_last_used_over_created() {
+        let cutoff = ts(2026, 1, 1);
+        // Created long ago but used recently: not stale.
+        assert!(!is_older_than(
+            &env("busy", Some(ts(2024, 1, 1)), Some(ts(2026, 6, 1))),
+            cutoff
+        ));
+        // Created and last used before the cutoff.
+        assert!(is_older_than(
+            &env("stale", Some(ts(2024, 1, 1)), Some(ts(2025, 1, 1))),
+            cutoff
+        ));
+        // Never used: falls back to created_at.
+        assert!(is_older_than(
+            &env("unused", Some(ts(2025, 6, 1)), None),
+            cutoff
+        ));
+        assert!(!is_older_than(
+            &env("fresh", Some(ts(2026, 2, 1)), None),
+            cutoff
+        ));
+        // No metadata: unknown age never matches.
+        assert!(!is_older_than(&env("legacy", None, None), cutoff));
+    }
+
+    #[test]
+    fn python_and_age_filters_compose() {
+        let cutoff = ts(2026, 1, 1);
+        let mut envs = vec![
+            env("old-312", Some(ts(2024, 1, 1)), None),
+            env("new-312", Some(ts(2026, 6, 1)), None),
+            env("old-311", Some(ts(2024, 1, 1)), None),
+        ];
+        envs[0].python_version = Some("3.12.1".into());
+        envs[1].python_version = Some("3.12.4".into());
+        envs[2].python_version = Some("3.11.2".into());
+
+        let filter = PythonVersion::parse("3.12").unwrap();
+        envs.retain(|e| matches_python(e, &filter));
+        envs.retain(|e| is_older_than(e, cutoff));
+        let names: Vec<_> = envs.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["old-312"]);
+    }
+
+    fn versioned(name: &str, version: Option<&str>, tags: &[&str]) -> CoreVirtualenvInfo {
+        let mut e = env(name, None, None);
+        e.python_version = version.map(str::to_string);
+        e.tags = tags.iter().map(|t| t.to_string()).collect();
+        e
+    }
+
+    fn count_opts(tag: Option<&str>) -> ListOpts {
+        ListOpts {
+            pythons: false,
+            managed_only: false,
+            system_only: false,
+            bare: false,
+            python_version: None,
+            sort: ListSortMode::Name,
+            group_by: ListGroupBy::None,
+            tag: tag.map(str::to_string),
+            older_than: None,
+            broken_only: false,
+            count: true,
+            sizes: false,
+        }
+    }
+
+    /// `uv python list` as a stand-in uv prints it: one managed install,
+    /// one Homebrew interpreter.
+    #[cfg(unix)]
+    fn mock_uv_pythons(dir: &std::path::Path) -> Vec<UvPythonInfo> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let json = r#"[{"key":"cpython-3.12.1-macos-aarch64-none","version":"3.12.1","path":"/Users/u/.local/share/uv/python/cpython-3.12.1-macos-aarch64-none/bin/python3.12","implementation":"cpython"},{"key":"cpython-3.13.0-macos-aarch64-none","version":"3.13.0","path":"/opt/homebrew/bin/python3.13","implementation":"cpython"}]"#;
+        let uv = dir.join("uv");
+        std::fs::write(&uv, format!("#!/bin/sh\necho '{json}'\n")).unwrap();
+        std::fs::set_permissions(&uv, std::fs::Permissions::from_mode(0o755)).unwrap();
+        UvClient::with_path(uv).list_installed_pythons().unwrap()
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn filter_pythons_keeps_managed_or_system_interpreters() {
+        let tmp = tempfile::tempdir().unwrap();
+        let pythons = mock_uv_pythons(tmp.path());
+        let versions = |managed_only, system_only| -> Vec<String> {
+            let opts = ListOpts {
+                pythons: true,
+                managed_only,
+                system_only,
+                ..count_opts(None)
+            };
+            filter_pythons(pythons.clone(), &opts)
+                .into_iter()
+                .map(|p| p.version)
+                .collect()
+        };
+
+        assert_eq!(versions(false, false), ["3.12.1", "3.13.0"]);
+        assert_eq!(versions(true, false), ["3.12.1"]);
+        assert_eq!(versions(false, true), ["3.13.0"]);
+    }
+
+    #[test]
+    fn count_respects_version_and_tag_filters() {
+        let all = vec![
+            versioned("api", Some("3.11.9"), &["web"]),
+            versioned("etl", Some("3.11.2"), &[]),
+            versioned("site", Some("3.12.1"), &["web"]),
+            versioned("mystery", None, &["web"]),
+        ];
+        let count = |python: Option<&str>, tag: Option<&str>| {
+            let mut envs = all.clone();
+            let filter = python.and_then(PythonVersion::parse);
+            filter_envs(&mut envs, filter.as_ref(), &count_opts(tag), Utc::now());
+            envs.len()
+        };
+
+        assert_eq!(count(None, None), 4);
+        assert_eq!(count(Some("3.11"), None), 2);
+        assert_eq!(count(Some("3.11"), Some("web")), 1);
+        assert_eq!(count(Some("3.13"), None), 0);
+        assert_eq!(count(None, Some("web")), 3);
+    }
+
+    #[test]
+    fn sizes_sum_to_total_size_bytes() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut envs = Vec::new();
+        for (name, bytes) in [("small", 1_000), ("large", 2_500)] {
+            let mut e = env(name, None, None);
+            e.path = tmp.path().join(name);
+            std::fs::create_dir_all(e.path.join("lib")).unwrap();
+            std::fs::write(e.path.join("lib/blob"), vec![0u8; bytes]).unwrap();
+            envs.push(e);
+        }
+
+        let sizes = env_sizes(&envs);
+        assert_eq!(sizes, [Some(1_000), Some(2_500)]);
+        let data = ListEnvsData {
+            virtualenvs: Vec::new(),
+            total: envs.len(),
+            total_size_bytes: Some(total_size(&sizes)),
+        };
+        let parsed = serde_json::to_value(&data).unwrap();
+        assert_eq!(parsed["total_size_bytes"], 3_500);
+
+        // Not computed: the key is left out rather than reported as 0.
+        let data = ListEnvsData {
+            total_size_bytes: None,
+            ..data
+        };
+        let parsed = serde_json::to_value(&data).unwrap();
+        assert!(parsed.get("total_size_bytes").is_none());
+    }
+
+    fn group_names(groups: &[(String, Vec<VirtualenvInfo>)]) -> Vec<(&str, Vec<&str>)> {
+        groups
+            .iter()
+            .map(|(key, entries)| {
+                let names = entries.iter().map(|e| e.name.as_str()).collect();
+                (key.as_str(), names)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn group_by_python_orders_minor_versions_semantically() {
+        let envs = vec![
+            versioned("api", Some("3.11.9"), &[]),
+            versioned("legacy", Some("3.9.18"), &[]),
+            versioned("mystery", None, &[]),
+            versioned("web", Some("3.11.2"), &[]),
+        ];
+        let system = ("3.12.3".to_string(), "/usr/bin/python3".to_string());
+        let entries = json_entries(&envs, Some(&system), None, None);
+        let groups = group_items(entries, ListGroupBy::Python, |e| {
+            group_keys(ListGroupBy::Python, e.python.as_deref(), &e.tags)
+        });
+
+        assert_eq!(
+            group_names(&groups),
+            [
+                ("3.9", vec!["legacy"]),
+                ("3.11", vec!["api", "web"]),
+                ("3.12", vec!["system"]),
+                ("unknown", vec!["mystery"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn group_by_tag_lists_multi_tag_envs_under_each_tag() {
+        let envs = vec![
+            versioned("api", None, &["work"]),
+            versioned("scratch", None, &[]),
+            versioned("web", None, &["client", "work"]),
+        ];
+        let entries = json_entries(&envs, None, None, None);
+        let groups = group_items(entries, ListGroupBy::Tag, |e| {
+            group_keys(ListGroupBy::Tag, e.python.as_deref(), &e.tags)
+        });
+
+        assert_eq!(
+            group_names(&groups),
+            [
+                ("client", vec!["web"]),
+                ("work", vec!["api", "web"]),
+                ("untagged", vec!["scratch"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn grouped_json_nests_entries_under_ordered_keys() {
+        let envs = vec![
+            versioned("new", Some("3.13.0"), &[]),
+            versioned("old", Some("3.10.4"), &[]),
+        ];
+        let entries = json_entries(&envs, None, None, None);
+        let total = entries.len();
+        let groups = group_items(entries, ListGroupBy::Python, |e| {
+            group_keys(ListGroupBy::Python, e.python.as_deref(), &e.tags)
+        });
+        let json = serde_json::to_string(&ListGroupedEnvsData {
+            group_by: "python",
+            groups: EnvGroups(groups),
+            total,
+            total_size_bytes: None,
+        })
+        .unwrap();
+
+        // String order would put "3.13" first; keys keep semantic order.
+        assert!(json.find("\"3.10\"").unwrap() < json.find("\"3.13\"").unwrap());
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["group_by"], "python");
+        assert_eq!(parsed["total"], 2);
+        assert_eq!(parsed["groups"]["3.13"][0]["name"], "new");
+        assert_eq!(parsed["groups"]["3.10"][0]["python"], "3.10.4");
+    }
 }
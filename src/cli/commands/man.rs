@@ -122,12 +122,13 @@ fn write_page(dir: &Path, filename: &str, cmd: &clap::Command) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::ColorChoice;
     use tempfile::TempDir;
 
     #[test]
     fn render_to_dir_emits_top_level_and_subcommand_pages() {
         let tmp = TempDir::new().unwrap();
-        let output = Output::new(0, true, true, false);
+        let output = Output::new(0, true, ColorChoice::Never, false);
         execute(&output, Some(tmp.path())).unwrap();
 
         // Top-level page must exist
@@ -157,7 +158,7 @@ mod tests {
     #[test]
     fn render_to_dir_emits_page_for_every_non_hidden_subcommand() {
         let tmp = TempDir::new().unwrap();
-        let output = Output::new(0, true, true, false);
+        let output = Output::new(0, true, ColorChoice::Never, false);
         execute(&output, Some(tmp.path())).unwrap();
 
         let cmd = Cli::command();
@@ -177,7 +178,7 @@ mod tests {
     #[test]
     fn render_to_dir_skips_hidden_subcommands() {
         let tmp = TempDir::new().unwrap();
-        let output = Output::new(0, true, true, false);
+        let output = Output::new(0, true, ColorChoice::Never, false);
         execute(&output, Some(tmp.path())).unwrap();
 
         // `activate`, `deactivate`, `resolve` are hidden (shell-internal).
@@ -209,7 +210,7 @@ mod tests {
         // fire and the canary content must stay intact.
         std::os::unix::fs::symlink(&canary, tmp.path().join("scuv.1")).unwrap();
 
-        let output = Output::new(0, true, true, false);
+        let output = Output::new(0, true, ColorChoice::Never, false);
         let result = execute(&output, Some(tmp.path()));
         assert!(result.is_err(), "must refuse to write through symlink");
         assert_eq!(
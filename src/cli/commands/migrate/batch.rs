@@ -10,11 +10,13 @@ use rayon::prelude::*;
 use rust_i18n::t;
 use serde::Serialize;
 
+use crate::core::cancel;
 use crate::core::migrate::{
     EnvironmentStatus, MigrateOptions, MigrationResult, Migrator, SourceEnvironment,
 };
 use crate::error::{Result, ScoopError};
 use crate::output::Output;
+use crate::uv::UvClient;
 
 use super::scan::{any_source_tool_available, scan_all_environments};
 use super::types::{
@@ -36,6 +38,9 @@ use super::types::{
 ///   (without `--force`). The full summary is already rendered to
 ///   stderr / stdout-as-JSON before this Err returns; `main.rs` MUST
 ///   NOT print the global `error:` prefix again.
+/// - [`ScoopError::Interrupted`] (exit 130) — Ctrl-C during the batch.
+///   Envs already migrated stay; the rest are listed under `skipped`
+///   with an "interrupted" reason.
 pub fn migrate_all_environments(output: &Output, opts: &MigrateExecuteOptions) -> Result<()> {
     if !opts.json {
         let source_name = opts
@@ -45,7 +50,7 @@ pub fn migrate_all_environments(output: &Output, opts: &MigrateExecuteOptions) -
         output.info(&t!("migrate.scanning", source = source_name));
     }
 
-    let environments = scan_all_environments(opts.source_filter);
+    let environments = scan_all_environments(opts.source_filter, opts.eol_minimum);
 
     // Empty scan branches:
     //   - No tool installed     → exit 3 via MigrationSourcesNotFound.
@@ -60,6 +65,14 @@ pub fn migrate_all_environments(output: &Output, opts: &MigrateExecuteOptions) -
         return Ok(());
     }
 
+    // Apply --strip-prefix/--add-prefix before partitioning so conflicts are
+    // judged under the new names. A prefix that makes any name invalid fails
+    // the whole batch here, before anything is migrated.
+    let environments = environments
+        .into_iter()
+        .map(|env| opts.name_transform.retarget(env, opts.eol_minimum))
+        .collect::<Result<Vec<_>>>()?;
+
     // Partition into migratable / conflicts (preflight) / skipped buckets.
     // Conflicts are tracked both as structured `MigrationConflictDetail`
     // entries (new in 0.14, see types.rs) AND, for backward compat, as
@@ -70,7 +83,7 @@ pub fn migrate_all_environments(output: &Output, opts: &MigrateExecuteOptions) -
     let PartitionedEnvs {
         migratable,
         conflicts,
-        skipped,
+        mut skipped,
     } = partition;
 
     let skipped_count = skipped.len();
@@ -85,6 +98,10 @@ pub fn migrate_all_environments(output: &Output, opts: &MigrateExecuteOptions) -
         if conflict_count > 0 {
             // Render summary first (Quiet contract: no global error:
             // prefix; main.rs trusts batch.rs already wrote everything).
+            let err = ScoopError::MigrationBatchFailed {
+                failed_count: 0,
+                conflict_count,
+            };
             if opts.json {
                 emit_migrate_all_json_outcome(
                     &[],
@@ -92,15 +109,12 @@ pub fn migrate_all_environments(output: &Output, opts: &MigrateExecuteOptions) -
                     &conflicts,
                     &skipped,
                     environments.len(),
-                    /* is_failure = */ true,
+                    Some(&err),
                 );
             } else {
                 render_no_migratable_with_conflicts(output, &conflicts, &skipped);
             }
-            return Err(ScoopError::MigrationBatchFailed {
-                failed_count: 0,
-                conflict_count,
-            });
+            return Err(err);
         }
         if opts.json {
             output.json_success(
@@ -171,24 +185,32 @@ pub fn migrate_all_environments(output: &Output, opts: &MigrateExecuteOptions) -
         }
     }
 
+    // From here on Ctrl-C stops the batch instead of killing scuv outright:
+    // the envs in flight are cleaned up, the rest are never started.
+    cancel::install_handler();
+
     // Perform migrations
-    let migrator = Migrator::new()?;
+    let migrator = Migrator::with_uv(UvClient::for_output(output)?);
     let options = MigrateOptions {
         dry_run: opts.dry_run,
         force: opts.force,
         skip_packages: false,
         rename_to: None,
         strict: opts.strict,
+        skip_unresolved: opts.skip_unresolved,
         delete_source: opts.delete_source,
         auto_install_python: false,
+        index_url: opts.index_url.clone(),
+        python_version: None,
     };
 
     // Wrapped in Mutex so the parallel branch below can collect results from
-    // multiple worker threads. The sequential branch (dry-run / single env)
-    // also goes through the locks for code-path uniformity — contention is
-    // zero there, so the cost is a few ns per env.
+    // multiple worker threads. The sequential branch (dry-run, single env,
+    // `--parallel 1`) also goes through the locks for code-path uniformity —
+    // contention is zero there, so the cost is a few ns per env.
     let migrated_lock: Mutex<Vec<MigrationResult>> = Mutex::new(Vec::new());
     let failed_lock: Mutex<Vec<MigrateFailure>> = Mutex::new(Vec::new());
+    let not_started_lock: Mutex<Vec<String>> = Mutex::new(Vec::new());
 
     if !opts.json {
         if opts.dry_run {
@@ -220,6 +242,17 @@ pub fn migrate_all_environments(output: &Output, opts: &MigrateExecuteOptions) -
     // serialises println/inc internally) and `output.{success,info,warn,error}`
     // emit one `eprintln!` per call, which is atomic at the line level.
     let run_one = |env: &SourceEnvironment| {
+        if cancel::is_cancelled() {
+            not_started_lock
+                .lock()
+                .expect("not-started lock poisoned")
+                .push(env.name.clone());
+            if let Some(ref pb) = progress {
+                pb.inc(1);
+            }
+            return;
+        }
+
         if let Some(ref pb) = progress {
             pb.set_message(t!("migrate.batch_item", name = &env.name).to_string());
         } else if !opts.json {
@@ -256,6 +289,24 @@ pub fn migrate_all_environments(output: &Output, opts: &MigrateExecuteOptions) -
                                 count = result.packages_failed.len()
                             ));
                         }
+                        if !result.packages_unresolved.is_empty() {
+                            output.warn(&t!(
+                                "migrate.unresolved_packages",
+                                count = result.packages_unresolved.len()
+                            ));
+                        }
+                        if !result.editable_packages.is_empty() {
+                            output.warn(&t!(
+                                "migrate.editable_packages",
+                                count = result.editable_packages.len()
+                            ));
+                            for pkg in &result.editable_packages {
+                                output.info(&format!(
+                                    "    - {}: pip install -e {}",
+                                    pkg.name, pkg.source
+                                ));
+                            }
+                        }
                     }
                 }
                 migrated_lock
@@ -288,18 +339,10 @@ pub fn migrate_all_environments(output: &Output, opts: &MigrateExecuteOptions) -
         }
     };
 
-    // Parallelise only when there's a real win: dry-run does no I/O work
-    // (sequential gives cleaner, deterministic preview output) and a single
-    // env has nothing to parallelise.
-    if opts.dry_run || migratable.len() <= 1 {
-        for env in migratable.iter() {
-            run_one(env);
-        }
-    } else {
-        // par_iter().for_each passes `&&T`; wrap so `run_one` keeps its
-        // `&T` signature (which the sequential branch also uses).
-        migratable.par_iter().for_each(|env| run_one(env));
-    }
+    // Dry-run does no I/O work, so it stays sequential for cleaner,
+    // deterministic preview output whatever `--parallel` says.
+    let parallel = if opts.dry_run { Some(1) } else { opts.parallel };
+    for_each_env(&migratable, parallel, run_one)?;
 
     let mut migrated = migrated_lock.into_inner().expect("results lock poisoned");
     let mut failed = failed_lock.into_inner().expect("failures lock poisoned");
@@ -312,13 +355,33 @@ pub fn migrate_all_environments(output: &Output, opts: &MigrateExecuteOptions) -
     migrated.sort_by(|a, b| a.name.cmp(&b.name));
     failed.sort_by(|a, b| a.name.cmp(&b.name));
 
+    let interrupted = cancel::is_cancelled();
+    let mut not_started = not_started_lock
+        .into_inner()
+        .expect("not-started lock poisoned");
+    not_started.sort();
+    let not_started_count = not_started.len();
+    skipped.extend(not_started.into_iter().map(|name| MigrateSkipped {
+        name,
+        reason: "interrupted before it started".to_string(),
+    }));
+
     // Finish progress bar
     if let Some(pb) = progress {
-        pb.finish_with_message("Done");
+        pb.finish_with_message(if interrupted { "Interrupted" } else { "Done" });
     }
 
     let failed_count = failed.len();
-    let is_failure = failed_count + conflict_count > 0;
+    let error = if interrupted {
+        Some(ScoopError::Interrupted)
+    } else if failed_count + conflict_count > 0 {
+        Some(ScoopError::MigrationBatchFailed {
+            failed_count,
+            conflict_count,
+        })
+    } else {
+        None
+    };
 
     // Render BEFORE returning Err so the Quiet render policy on
     // MigrationBatchFailed is satisfied (main.rs writes nothing extra).
@@ -329,7 +392,7 @@ pub fn migrate_all_environments(output: &Output, opts: &MigrateExecuteOptions) -
             &conflicts,
             &skipped,
             environments.len(),
-            is_failure,
+            error.as_ref(),
         );
     } else {
         render_human_summary(
@@ -340,22 +403,47 @@ pub fn migrate_all_environments(output: &Output, opts: &MigrateExecuteOptions) -
             &conflicts,
             migratable.len(),
         );
+        if interrupted {
+            output.warn(&t!("migrate.batch_interrupted", count = not_started_count));
+        }
     }
 
-    if is_failure {
-        return Err(ScoopError::MigrationBatchFailed {
-            failed_count,
-            conflict_count,
-        });
+    match error {
+        Some(err) => Err(err),
+        None => Ok(()),
     }
-
-    Ok(())
 }
 
 // ============================================================================
 // Helpers
 // ============================================================================
 
+/// Run `f` over `envs`, at most `parallel` at a time.
+///
+/// `Some(n)` with `n <= 1` (or a single env) runs them in order on this
+/// thread. `None` uses rayon's global pool, one thread per core. Otherwise
+/// a dedicated rayon pool of `n` threads is used rather than the global
+/// one, so `--parallel 4` means four concurrent uv installs on any machine.
+fn for_each_env<F>(envs: &[&SourceEnvironment], parallel: Option<usize>, f: F) -> Result<()>
+where
+    F: Fn(&SourceEnvironment) + Sync,
+{
+    // par_iter().for_each passes `&&T`; wrap so `f` keeps its `&T` signature.
+    match parallel {
+        _ if envs.len() <= 1 => envs.iter().for_each(|env| f(env)),
+        Some(n) if n <= 1 => envs.iter().for_each(|env| f(env)),
+        None => envs.par_iter().for_each(|env| f(env)),
+        Some(n) => {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(n.min(envs.len()))
+                .build()
+                .map_err(|e| ScoopError::Io(std::io::Error::other(e)))?;
+            pool.install(|| envs.par_iter().for_each(|env| f(env)));
+        }
+    }
+    Ok(())
+}
+
 struct PartitionedEnvs<'a> {
     migratable: Vec<&'a SourceEnvironment>,
     conflicts: Vec<MigrationConflictDetail>,
@@ -525,7 +613,7 @@ fn render_human_summary(
 ///
 /// Modelled on [`crate::cli::commands::verify::emit_strict_json_failure`]
 /// — one helper that emits either a `success` or `error` envelope based
-/// on `is_failure`. Both carry the full `MigrateAllData` so consumers
+/// on whether the batch ends in `error`. Both carry the full `MigrateAllData` so consumers
 /// don't lose detail on either path; the failure side adds an
 /// `error: { code, message, failed_count, conflict_count }` block.
 ///
@@ -538,7 +626,7 @@ fn emit_migrate_all_json_outcome(
     conflicts: &[MigrationConflictDetail],
     skipped: &[MigrateSkipped],
     total: usize,
-    is_failure: bool,
+    error: Option<&ScoopError>,
 ) {
     #[derive(Serialize)]
     struct SuccessEnvelope<'a> {
@@ -584,18 +672,13 @@ fn emit_migrate_all_json_outcome(
         summary,
     };
 
-    if is_failure {
+    if let Some(error) = error {
         let envelope = FailureEnvelope {
             status: "error",
             command: "migrate all",
             error: ErrorBody {
-                code: "MIGRATE_BATCH_FAILED",
-                message: t!(
-                    "error.migration_batch_failed",
-                    failed = failed.len().to_string(),
-                    conflicts = conflicts.len().to_string()
-                )
-                .to_string(),
+                code: error.code(),
+                message: error.to_string(),
                 failed_count: failed.len(),
                 conflict_count: conflicts.len(),
             },
@@ -646,7 +729,9 @@ fn emit_envelope_or_fallback<T: Serialize>(envelope: &T) {
 
 #[cfg(test)]
 mod tests {
+    use super::super::types::NameTransform;
     use super::*;
+    use crate::cli::ColorChoice;
     use crate::cli::MigrateSource;
     use crate::core::migrate::{SourceEnvironment, SourceType};
     use crate::test_utils::{
@@ -675,6 +760,39 @@ mod tests {
     // partition_envs Tests (replaces filter_migratable + collect_skipped)
     // =========================================================================
 
+    #[test]
+    fn for_each_env_runs_up_to_n_at_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::time::Duration;
+
+        let a = create_test_env("a", EnvironmentStatus::Ready);
+        let b = create_test_env("b", EnvironmentStatus::Ready);
+        let envs = vec![&a, &b];
+
+        for (parallel, expected_peak) in [(Some(1), 1), (Some(2), 2)] {
+            let running = AtomicUsize::new(0);
+            let peak = AtomicUsize::new(0);
+            let seen = Mutex::new(Vec::new());
+            for_each_env(&envs, parallel, |env| {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                std::thread::sleep(Duration::from_millis(100));
+                seen.lock().unwrap().push(env.name.clone());
+                running.fetch_sub(1, Ordering::SeqCst);
+            })
+            .unwrap();
+
+            let mut seen = seen.into_inner().unwrap();
+            seen.sort();
+            assert_eq!(seen, ["a", "b"], "parallel={parallel:?}");
+            assert_eq!(
+                peak.load(Ordering::SeqCst),
+                expected_peak,
+                "parallel={parallel:?}"
+            );
+        }
+    }
+
     #[test]
     fn partition_ready_always_migratable() {
         let envs = vec![
@@ -862,7 +980,7 @@ mod tests {
     #[serial]
     fn migrate_all_environments_empty_when_no_sources() {
         with_isolated_migrate_env(|| {
-            let output = Output::new(0, false, true, false);
+            let output = Output::new(0, false, ColorChoice::Never, false);
             let opts = MigrateExecuteOptions {
                 yes: true,
                 ..Default::default()
@@ -882,7 +1000,7 @@ mod tests {
     #[serial]
     fn migrate_all_environments_json_empty_no_sources() {
         with_isolated_migrate_env(|| {
-            let output = Output::new(0, true, true, true);
+            let output = Output::new(0, true, ColorChoice::Never, true);
             let opts = MigrateExecuteOptions {
                 json: true,
                 ..Default::default()
@@ -902,7 +1020,7 @@ mod tests {
     fn migrate_all_environments_empty_with_tools_present() {
         // pyenv root exists but has zero envs → Ok(()) (no exit 3).
         with_full_migrate_env(|_scoop, _pyenv| {
-            let output = Output::new(0, false, true, false);
+            let output = Output::new(0, false, ColorChoice::Never, false);
             let opts = MigrateExecuteOptions {
                 source_filter: Some(MigrateSource::Pyenv),
                 yes: true,
@@ -918,7 +1036,7 @@ mod tests {
     #[serial]
     fn migrate_all_environments_with_source_filter() {
         with_isolated_migrate_env(|| {
-            let output = Output::new(0, false, true, false);
+            let output = Output::new(0, false, ColorChoice::Never, false);
             let opts = MigrateExecuteOptions {
                 source_filter: Some(MigrateSource::Pyenv),
                 yes: true,
@@ -940,7 +1058,7 @@ mod tests {
         with_full_migrate_env(|_scoop, pyenv| {
             create_corrupted_pyenv_env(pyenv.path(), "corrupted_batch", "3.12.0");
 
-            let output = Output::new(0, false, true, false);
+            let output = Output::new(0, false, ColorChoice::Never, false);
             let opts = MigrateExecuteOptions {
                 source_filter: Some(MigrateSource::Pyenv),
                 yes: true,
@@ -953,6 +1071,74 @@ mod tests {
         });
     }
 
+    #[test]
+    #[serial]
+    fn migrate_all_environments_prefix_applies_to_every_env() {
+        with_full_migrate_env(|scoop, pyenv| {
+            create_mock_pyenv_env(pyenv.path(), "py-web", "3.12.0");
+            create_mock_pyenv_env(pyenv.path(), "api", "3.12.0");
+            for existing in ["legacy-web", "legacy-api"] {
+                std::fs::create_dir_all(scoop.path().join("virtualenvs").join(existing)).unwrap();
+            }
+
+            let output = Output::new(0, false, ColorChoice::Never, false);
+            let opts = MigrateExecuteOptions {
+                source_filter: Some(MigrateSource::Pyenv),
+                yes: true,
+                name_transform: NameTransform {
+                    strip_prefix: Some("py-".to_string()),
+                    add_prefix: Some("legacy-".to_string()),
+                },
+                ..Default::default()
+            };
+
+            // Both renamed targets already exist, so both are preflight
+            // conflicts even though the source names are free.
+            let result = migrate_all_environments(&output, &opts);
+            assert!(
+                matches!(
+                    result,
+                    Err(ScoopError::MigrationBatchFailed {
+                        failed_count: 0,
+                        conflict_count: 2,
+                    })
+                ),
+                "got {result:?}"
+            );
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn migrate_all_environments_rejects_prefix_that_makes_a_name_invalid() {
+        with_full_migrate_env(|scoop, pyenv| {
+            create_mock_pyenv_env(pyenv.path(), "web", "3.12.0");
+            create_mock_pyenv_env(pyenv.path(), "py-2to3", "3.12.0");
+
+            let output = Output::new(0, false, ColorChoice::Never, false);
+            let opts = MigrateExecuteOptions {
+                source_filter: Some(MigrateSource::Pyenv),
+                yes: true,
+                name_transform: NameTransform {
+                    strip_prefix: Some("py-".to_string()),
+                    add_prefix: None,
+                },
+                ..Default::default()
+            };
+
+            let result = migrate_all_environments(&output, &opts);
+            assert!(
+                matches!(result, Err(ScoopError::InvalidEnvName { ref name, .. }) if name == "2to3"),
+                "got {result:?}"
+            );
+            // Nothing was migrated, not even the env whose name stayed valid.
+            let created = std::fs::read_dir(scoop.path().join("virtualenvs"))
+                .unwrap()
+                .count();
+            assert_eq!(created, 0);
+        });
+    }
+
     #[test]
     #[serial]
     fn migrate_all_environments_dry_run_propagates_pip_failure() {
@@ -964,7 +1150,7 @@ mod tests {
         with_full_migrate_env(|_scoop, pyenv| {
             create_mock_pyenv_env(pyenv.path(), "dryrun_env", "3.12.0");
 
-            let output = Output::new(0, false, true, false);
+            let output = Output::new(0, false, ColorChoice::Never, false);
             let opts = MigrateExecuteOptions {
                 source_filter: Some(MigrateSource::Pyenv),
                 dry_run: true,
@@ -994,7 +1180,7 @@ mod tests {
             create_mock_pyenv_env(pyenv.path(), "json_batch", "3.12.0");
             create_corrupted_pyenv_env(pyenv.path(), "json_corrupted", "3.11.0");
 
-            let output = Output::new(0, true, true, true);
+            let output = Output::new(0, true, ColorChoice::Never, true);
             let opts = MigrateExecuteOptions {
                 source_filter: Some(MigrateSource::Pyenv),
                 json: true,
@@ -1009,4 +1195,34 @@ mod tests {
             ));
         });
     }
+
+    #[test]
+    #[serial]
+    #[ignore = "requires uv to be installed"]
+    fn migrate_all_environments_parallel_collects_every_result() {
+        // Two mock envs (no pip) migrated two at a time: both failures must
+        // land in the shared result vectors.
+        with_full_migrate_env(|_scoop, pyenv| {
+            create_mock_pyenv_env(pyenv.path(), "par_one", "3.12.0");
+            create_mock_pyenv_env(pyenv.path(), "par_two", "3.12.0");
+
+            let output = Output::new(0, true, ColorChoice::Never, true);
+            let opts = MigrateExecuteOptions {
+                source_filter: Some(MigrateSource::Pyenv),
+                json: true,
+                yes: true,
+                parallel: Some(2),
+                ..Default::default()
+            };
+
+            let result = migrate_all_environments(&output, &opts);
+            assert!(matches!(
+                result,
+                Err(ScoopError::MigrationBatchFailed {
+                    failed_count: 2,
+                    conflict_count: 0,
+                })
+            ));
+        });
+    }
 }
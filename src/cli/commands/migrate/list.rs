@@ -2,10 +2,12 @@
 //!
 //! Displays discovered environments in human-readable or JSON format.
 
+use rayon::prelude::*;
 use rust_i18n::t;
 
 use crate::cli::MigrateSource;
-use crate::core::migrate::{EnvironmentStatus, SourceType};
+use crate::core::migrate::common::{self, EolMinimum};
+use crate::core::migrate::{EnvironmentStatus, SourceEnvironment, SourceType};
 use crate::error::Result;
 use crate::output::Output;
 
@@ -18,10 +20,15 @@ use super::types::{MigrateListData, MigrateListSummary};
 /// - ✓ Ready to migrate
 /// - ⚠ Has warnings (conflict, EOL)
 /// - ✗ Corrupted
+///
+/// Sizes are left unset (shown as "-") unless `sizes` is true, in which case
+/// every environment's directory is walked in parallel to fill `size_bytes`.
 pub fn list_environments(
     output: &Output,
     json: bool,
     source_filter: Option<MigrateSource>,
+    eol_minimum: EolMinimum,
+    sizes: bool,
 ) -> Result<()> {
     if !json {
         let source_name = source_filter
@@ -30,7 +37,10 @@ pub fn list_environments(
         output.info(&t!("migrate.scanning", source = source_name));
     }
 
-    let environments = scan_all_environments(source_filter);
+    let mut environments = scan_all_environments(source_filter, eol_minimum);
+    if sizes {
+        fill_sizes(&mut environments);
+    }
 
     // JSON output
     if json {
@@ -120,6 +130,13 @@ pub fn list_environments(
     Ok(())
 }
 
+/// Populate `size_bytes` for every environment, walking directories in parallel.
+fn fill_sizes(environments: &mut [SourceEnvironment]) {
+    environments
+        .par_iter_mut()
+        .for_each(|env| env.size_bytes = Some(common::dir_size(&env.path)));
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -127,7 +144,7 @@ pub fn list_environments(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::migrate::SourceEnvironment;
+    use crate::cli::ColorChoice;
     use crate::test_utils::{
         create_mock_pyenv_env, with_full_migrate_env, with_isolated_migrate_env,
     };
@@ -363,8 +380,8 @@ mod tests {
     #[serial]
     fn list_environments_empty_when_no_sources() {
         with_isolated_migrate_env(|| {
-            let output = Output::new(0, false, true, false);
-            let result = list_environments(&output, false, None);
+            let output = Output::new(0, false, ColorChoice::Never, false);
+            let result = list_environments(&output, false, None, EolMinimum::default(), false);
             assert!(result.is_ok());
         });
     }
@@ -373,8 +390,8 @@ mod tests {
     #[serial]
     fn list_environments_json_mode_empty() {
         with_isolated_migrate_env(|| {
-            let output = Output::new(0, true, true, true);
-            let result = list_environments(&output, true, None);
+            let output = Output::new(0, true, ColorChoice::Never, true);
+            let result = list_environments(&output, true, None, EolMinimum::default(), false);
             assert!(result.is_ok());
         });
     }
@@ -383,8 +400,14 @@ mod tests {
     #[serial]
     fn list_environments_with_pyenv_filter() {
         with_isolated_migrate_env(|| {
-            let output = Output::new(0, false, true, false);
-            let result = list_environments(&output, false, Some(MigrateSource::Pyenv));
+            let output = Output::new(0, false, ColorChoice::Never, false);
+            let result = list_environments(
+                &output,
+                false,
+                Some(MigrateSource::Pyenv),
+                EolMinimum::default(),
+                false,
+            );
             assert!(result.is_ok());
         });
     }
@@ -397,8 +420,14 @@ mod tests {
             create_mock_pyenv_env(pyenv.path(), "testenv1", "3.12.0");
             create_mock_pyenv_env(pyenv.path(), "testenv2", "3.11.0");
 
-            let output = Output::new(0, false, true, false);
-            let result = list_environments(&output, false, Some(MigrateSource::Pyenv));
+            let output = Output::new(0, false, ColorChoice::Never, false);
+            let result = list_environments(
+                &output,
+                false,
+                Some(MigrateSource::Pyenv),
+                EolMinimum::default(),
+                false,
+            );
             assert!(result.is_ok());
         });
     }
@@ -409,9 +438,32 @@ mod tests {
         with_full_migrate_env(|_scoop, pyenv| {
             create_mock_pyenv_env(pyenv.path(), "jsontest", "3.12.0");
 
-            let output = Output::new(0, true, true, true);
-            let result = list_environments(&output, true, Some(MigrateSource::Pyenv));
+            let output = Output::new(0, true, ColorChoice::Never, true);
+            let result = list_environments(
+                &output,
+                true,
+                Some(MigrateSource::Pyenv),
+                EolMinimum::default(),
+                false,
+            );
             assert!(result.is_ok());
         });
     }
+
+    #[test]
+    fn fill_sizes_populates_size_bytes() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("a.txt"), "hello").unwrap();
+        let mut envs = vec![SourceEnvironment {
+            name: "sized".to_string(),
+            python_version: "3.12.0".to_string(),
+            path: dir.path().to_path_buf(),
+            source_type: SourceType::Pyenv,
+            size_bytes: None,
+            status: EnvironmentStatus::Ready,
+        }];
+
+        fill_sizes(&mut envs);
+        assert_eq!(envs[0].size_bytes, Some(5));
+    }
 }
@@ -8,6 +8,7 @@
 //! - `conflict`: Conflict resolution dialog and auto-rename logic
 //! - `scan`: Environment discovery from various sources
 //! - `list`: Environment listing display
+//! - `status`: Single environment inspection
 //! - `single`: Single environment migration
 //! - `batch`: Batch migration with progress tracking
 
@@ -16,34 +17,69 @@ mod conflict;
 mod list;
 mod scan;
 mod single;
+mod status;
 mod types;
 
+use rust_i18n::t;
+
 use crate::cli::MigrateCommand;
-use crate::error::Result;
+use crate::config::Config;
+use crate::core::migrate::common::EolMinimum;
+use crate::error::{Result, ScoopError};
 use crate::output::Output;
 
 use batch::migrate_all_environments;
 use list::list_environments;
 use single::migrate_environment;
-use types::MigrateExecuteOptions;
+use status::show_status;
+use types::{MigrateExecuteOptions, NameTransform};
 
 /// Execute migrate command.
 ///
 /// Dispatches to the appropriate subcommand handler:
 /// - `list`: Show available environments
+/// - `status`: Inspect one environment without migrating it
 /// - `@env`: Migrate single environment
 /// - `all`: Migrate all environments
 pub fn execute(output: &Output, command: Option<MigrateCommand>) -> Result<()> {
     match command {
-        Some(MigrateCommand::List { json, source }) => list_environments(output, json, source),
+        Some(MigrateCommand::List {
+            json,
+            source,
+            sizes,
+            eol_minimum,
+        }) => list_environments(
+            output,
+            json,
+            source,
+            resolve_eol_minimum(eol_minimum.as_deref())?,
+            sizes,
+        ),
+        Some(MigrateCommand::Status {
+            name,
+            json: _,
+            source,
+            eol_minimum,
+        }) => show_status(
+            output,
+            &name,
+            source,
+            resolve_eol_minimum(eol_minimum.as_deref())?,
+        ),
         Some(MigrateCommand::All {
             dry_run,
             force,
             yes,
             json,
             strict,
+            skip_unresolved,
             delete_source,
             source,
+            eol_minimum,
+            index_url,
+            strip_prefix,
+            add_prefix,
+            parallel,
         }) => {
             let opts = MigrateExecuteOptions {
                 dry_run,
@@ -51,8 +87,16 @@ pub fn execute(output: &Output, command: Option<MigrateCommand>) -> Result<()> {
                 yes,
                 json,
                 strict,
+                skip_unresolved,
                 delete_source,
                 source_filter: source,
+                eol_minimum: resolve_eol_minimum(eol_minimum.as_deref())?,
+                index_url: resolve_index_url(index_url),
+                name_transform: NameTransform {
+                    strip_prefix,
+                    add_prefix,
+                },
+                parallel: parallel.map(usize::from),
                 ..Default::default()
             };
             migrate_all_environments(output, &opts)
@@ -64,10 +108,16 @@ pub fn execute(output: &Output, command: Option<MigrateCommand>) -> Result<()> {
             yes,
             json,
             strict,
+            skip_unresolved,
             rename,
             auto_rename,
             delete_source,
             source,
+            eol_minimum,
+            index_url,
+            strip_prefix,
+            add_prefix,
+            python,
         }) => {
             let opts = MigrateExecuteOptions {
                 dry_run,
@@ -75,16 +125,133 @@ pub fn execute(output: &Output, command: Option<MigrateCommand>) -> Result<()> {
                 yes,
                 json,
                 strict,
+                skip_unresolved,
                 rename,
                 auto_rename,
                 delete_source,
                 source_filter: source,
+                eol_minimum: resolve_eol_minimum(eol_minimum.as_deref())?,
+                index_url: resolve_index_url(index_url),
+                name_transform: NameTransform {
+                    strip_prefix,
+                    add_prefix,
+                },
+                python,
+                ..Default::default()
             };
             migrate_environment(output, &name, &opts)
         }
         None => {
-            // No subcommand - show help or list
-            list_environments(output, output.is_json(), None)
+            // No subcommand - list; bare `migrate --json` arrives via `output`
+            list_environments(
+                output,
+                output.is_json(),
+                None,
+                resolve_eol_minimum(None)?,
+                false,
+            )
         }
     }
 }
+
+/// Resolve the migration EOL cutoff.
+///
+/// Priority: `--eol-minimum` flag, then `migrate.eol_minimum` in
+/// `config.json`, then [`EolMinimum::default`]. An unreadable config file
+/// falls back to the default, matching how the `lang` setting is loaded.
+///
+/// # Errors
+///
+/// Returns [`ScoopError::InvalidArgument`] if the chosen value is not a
+/// `MAJOR.MINOR` pair.
+fn resolve_eol_minimum(flag: Option<&str>) -> Result<EolMinimum> {
+    let configured = match flag {
+        Some(value) => Some(value.to_string()),
+        None => Config::load().ok().and_then(|c| c.migrate.eol_minimum),
+    };
+    match configured {
+        None => Ok(EolMinimum::default()),
+        Some(value) => EolMinimum::parse(&value).ok_or_else(|| ScoopError::InvalidArgument {
+            message: t!("migrate.invalid_eol_minimum", value = value).to_string(),
+        }),
+    }
+}
+
+/// Resolve the package index for reinstalling packages.
+///
+/// Priority: `--index-url` flag, then `$UV_INDEX_URL`, then
+/// `$PIP_INDEX_URL` (so a pip-only mirror setup carries over). Empty values
+/// are ignored. `None` leaves uv on its default index.
+fn resolve_index_url(flag: Option<String>) -> Option<String> {
+    flag.or_else(|| {
+        ["UV_INDEX_URL", "PIP_INDEX_URL"]
+            .into_iter()
+            .find_map(|var| std::env::var(var).ok().filter(|v| !v.is_empty()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paths;
+    use crate::test_utils::env_guard;
+    use serial_test::serial;
+    use tempfile::TempDir;
+
+    #[test]
+    #[serial]
+    fn resolve_eol_minimum_prefers_flag_then_config_then_default() {
+        let home = TempDir::new().unwrap();
+        let _env = env_guard(&[(paths::SCUV_HOME_ENV, Some(home.path().to_str().unwrap()))]);
+
+        assert_eq!(resolve_eol_minimum(None).unwrap(), EolMinimum::default());
+
+        std::fs::write(
+            home.path().join("config.json"),
+            r#"{"migrate":{"eol_minimum":"3.7"}}"#,
+        )
+        .unwrap();
+        assert_eq!(resolve_eol_minimum(None).unwrap().to_string(), "3.7");
+        assert_eq!(
+            resolve_eol_minimum(Some("3.11")).unwrap().to_string(),
+            "3.11"
+        );
+    }
+
+    #[test]
+    fn resolve_eol_minimum_rejects_malformed_flag() {
+        let err = resolve_eol_minimum(Some("latest")).unwrap_err();
+        assert!(matches!(err, ScoopError::InvalidArgument { .. }));
+    }
+
+    #[test]
+    #[serial]
+    fn resolve_index_url_prefers_flag_then_uv_then_pip_env() {
+        let _env = env_guard(&[
+            ("UV_INDEX_URL", Some("https://uv.example/simple")),
+            ("PIP_INDEX_URL", Some("https://pip.example/simple")),
+        ]);
+        assert_eq!(
+            resolve_index_url(Some("https://flag.example/simple".into())).as_deref(),
+            Some("https://flag.example/simple")
+        );
+        assert_eq!(
+            resolve_index_url(None).as_deref(),
+            Some("https://uv.example/simple")
+        );
+        drop(_env);
+
+        let _env = env_guard(&[
+            ("UV_INDEX_URL", Some("")),
+            ("PIP_INDEX_URL", Some("https://pip.example/simple")),
+        ]);
+        assert_eq!(
+            resolve_index_url(None).as_deref(),
+            Some("https://pip.example/simple")
+        );
+        drop(_env);
+
+        let _env = env_guard(&[("UV_INDEX_URL", None), ("PIP_INDEX_URL", None)]);
+        assert_eq!(resolve_index_url(None), None);
+    }
+}
@@ -1,74 +1,136 @@
 //! Environment scanning and discovery
 //!
-//! Discovers environments from pyenv, virtualenvwrapper, and conda sources.
+//! Discovers environments from every source in [`REGISTRY`]: pyenv,
+//! virtualenvwrapper, conda, Poetry and Hatch. Adding a source means adding an
+//! [`EnvironmentSource`] implementation and one registry entry; the scan
+//! and lookup functions below only ever walk the registry.
 
 use crate::cli::MigrateSource;
+use crate::core::migrate::common::EolMinimum;
 use crate::core::migrate::{
-    CondaDiscovery, EnvironmentSource, PyenvDiscovery, SourceEnvironment, SourceType,
-    VenvWrapperDiscovery,
+    CondaDiscovery, EnvironmentSource, HatchDiscovery, PoetryDiscovery, PyenvDiscovery,
+    SourceEnvironment, SourceType, VenvWrapperDiscovery,
 };
 use crate::error::{Result, ScoopError};
 
+/// One migration source scuv knows how to read.
+struct RegisteredSource {
+    /// The `--source` value selecting it.
+    filter: MigrateSource,
+    /// What its environments report as their source.
+    source_type: SourceType,
+    /// The discovery for this system, `None` when the tool isn't installed.
+    discover: fn(EolMinimum) -> Option<Box<dyn EnvironmentSource>>,
+    /// Error for a name this source doesn't have.
+    not_found: fn(String) -> ScoopError,
+}
+
+/// Every migration source, in search and listing order.
+///
+/// `migrate @env` without `--source` takes the first source that has the
+/// name, so the order matters when two tools use the same env name.
+const REGISTRY: &[RegisteredSource] = &[
+    RegisteredSource {
+        filter: MigrateSource::Pyenv,
+        source_type: SourceType::Pyenv,
+        discover: |eol| {
+            PyenvDiscovery::default_root()
+                .map(|d| Box::new(d.with_eol_minimum(eol)) as Box<dyn EnvironmentSource>)
+        },
+        not_found: |name| ScoopError::PyenvEnvNotFound { name },
+    },
+    RegisteredSource {
+        filter: MigrateSource::Virtualenvwrapper,
+        source_type: SourceType::VirtualenvWrapper,
+        discover: |eol| {
+            VenvWrapperDiscovery::default_root()
+                .map(|d| Box::new(d.with_eol_minimum(eol)) as Box<dyn EnvironmentSource>)
+        },
+        not_found: |name| ScoopError::VenvWrapperEnvNotFound { name },
+    },
+    RegisteredSource {
+        filter: MigrateSource::Conda,
+        source_type: SourceType::Conda,
+        discover: |eol| {
+            CondaDiscovery::default_roots()
+                .map(|d| Box::new(d.with_eol_minimum(eol)) as Box<dyn EnvironmentSource>)
+        },
+        not_found: |name| ScoopError::CondaEnvNotFound { name },
+    },
+    RegisteredSource {
+        filter: MigrateSource::Poetry,
+        source_type: SourceType::Poetry,
+        discover: |eol| {
+            PoetryDiscovery::default_root()
+                .map(|d| Box::new(d.with_eol_minimum(eol)) as Box<dyn EnvironmentSource>)
+        },
+        not_found: |name| ScoopError::PoetryEnvNotFound { name },
+    },
+    RegisteredSource {
+        filter: MigrateSource::Hatch,
+        source_type: SourceType::Hatch,
+        discover: |eol| {
+            HatchDiscovery::default_root()
+                .map(|d| Box::new(d.with_eol_minimum(eol)) as Box<dyn EnvironmentSource>)
+        },
+        not_found: |name| ScoopError::HatchEnvNotFound { name },
+    },
+];
+
+/// The installed sources matching `filter` (all of them for `None`), in
+/// registry order.
+fn sources(
+    filter: Option<MigrateSource>,
+    eol_minimum: EolMinimum,
+) -> Vec<Box<dyn EnvironmentSource>> {
+    REGISTRY
+        .iter()
+        .filter(|entry| filter.is_none_or(|f| f == entry.filter))
+        .filter_map(|entry| (entry.discover)(eol_minimum))
+        .collect()
+}
+
+/// Position of `source_type` in [`REGISTRY`], for sorting scan results.
+fn registry_position(source_type: SourceType) -> usize {
+    REGISTRY
+        .iter()
+        .position(|entry| entry.source_type == source_type)
+        .unwrap_or(usize::MAX)
+}
+
 /// Scan environments from all available sources or a specific source.
 ///
-/// Results are sorted by source type (pyenv, virtualenvwrapper, conda),
-/// then alphabetically by name.
+/// Results are sorted by source in registry order (pyenv,
+/// virtualenvwrapper, conda, poetry, hatch), then alphabetically by name. Python
+/// versions older than `eol_minimum` are reported as
+/// [`EnvironmentStatus::PythonEol`](crate::core::migrate::EnvironmentStatus::PythonEol).
 ///
 /// # Examples
 ///
 /// ```text
 /// // Scan all sources (returns empty if none installed)
-/// let all_envs = scan_all_environments(None);
+/// let all_envs = scan_all_environments(None, EolMinimum::default());
 /// println!("Found {} environments", all_envs.len());
 ///
 /// // Scan only pyenv
-/// let pyenv_only = scan_all_environments(Some(MigrateSource::Pyenv));
+/// let pyenv_only = scan_all_environments(Some(MigrateSource::Pyenv), EolMinimum::default());
 /// for env in pyenv_only {
 ///     println!("{}: Python {}", env.name, env.python_version);
 /// }
 /// ```
-pub fn scan_all_environments(source_filter: Option<MigrateSource>) -> Vec<SourceEnvironment> {
-    let mut all_envs = Vec::new();
-
-    // Scan sources based on filter
-    let scan_pyenv = source_filter.is_none() || source_filter == Some(MigrateSource::Pyenv);
-    let scan_venv =
-        source_filter.is_none() || source_filter == Some(MigrateSource::Virtualenvwrapper);
-    let scan_conda = source_filter.is_none() || source_filter == Some(MigrateSource::Conda);
-
-    if scan_pyenv {
-        if let Some(discovery) = PyenvDiscovery::default_root() {
-            if let Ok(envs) = discovery.scan_environments() {
-                all_envs.extend(envs);
-            }
-        }
-    }
-
-    if scan_venv {
-        if let Some(discovery) = VenvWrapperDiscovery::default_root() {
-            if let Ok(envs) = discovery.scan_environments() {
-                all_envs.extend(envs);
-            }
-        }
-    }
-
-    if scan_conda {
-        if let Some(discovery) = CondaDiscovery::default_roots() {
-            if let Ok(envs) = discovery.scan_environments() {
-                all_envs.extend(envs);
-            }
-        }
-    }
+pub fn scan_all_environments(
+    source_filter: Option<MigrateSource>,
+    eol_minimum: EolMinimum,
+) -> Vec<SourceEnvironment> {
+    let mut all_envs: Vec<SourceEnvironment> = sources(source_filter, eol_minimum)
+        .iter()
+        .filter_map(|source| source.scan_environments().ok())
+        .flatten()
+        .collect();
 
-    // Sort by source type, then by name
     all_envs.sort_by(|a, b| {
-        let source_order = |s: &SourceType| match s {
-            SourceType::Pyenv => 0,
-            SourceType::VirtualenvWrapper => 1,
-            SourceType::Conda => 2,
-        };
-        source_order(&a.source_type)
-            .cmp(&source_order(&b.source_type))
+        registry_position(a.source_type)
+            .cmp(&registry_position(b.source_type))
             .then(a.name.cmp(&b.name))
     });
 
@@ -76,8 +138,7 @@ pub fn scan_all_environments(source_filter: Option<MigrateSource>) -> Vec<Source
 }
 
 /// Returns `true` if at least one source tool matching `filter` is
-/// installed on this system (i.e. its `default_root()` /
-/// `default_roots()` returned `Some`).
+/// installed on this system (i.e. its registry entry discovered a root).
 ///
 /// Used by `batch.rs` to distinguish two empty-list cases:
 ///
@@ -87,87 +148,53 @@ pub fn scan_all_environments(source_filter: Option<MigrateSource>) -> Vec<Source
 ///   can detect a misconfigured environment.
 /// - **Tools present but no envs** — current `Ok(())` behaviour stays.
 ///
-/// `filter == None` checks all three sources; a specific `MigrateSource`
-/// only checks that one.
+/// `filter == None` checks every registered source; a specific
+/// `MigrateSource` only checks that one.
 pub fn any_source_tool_available(filter: Option<MigrateSource>) -> bool {
-    let want_pyenv = filter.is_none() || filter == Some(MigrateSource::Pyenv);
-    let want_venv = filter.is_none() || filter == Some(MigrateSource::Virtualenvwrapper);
-    let want_conda = filter.is_none() || filter == Some(MigrateSource::Conda);
-
-    (want_pyenv && PyenvDiscovery::default_root().is_some())
-        || (want_venv && VenvWrapperDiscovery::default_root().is_some())
-        || (want_conda && CondaDiscovery::default_roots().is_some())
+    !sources(filter, EolMinimum::default()).is_empty()
 }
 
 /// Find an environment by name, searching across sources.
 ///
-/// Searches in order: pyenv, virtualenvwrapper, conda.
-/// Returns the first match found.
+/// Searches in registry order (pyenv, virtualenvwrapper, conda, poetry,
+/// hatch) and returns the first match found.
 ///
 /// # Examples
 ///
 /// ```text
 /// // Search for non-existent environment returns error
-/// let result = find_environment_by_name("nonexistent-env-12345", None);
+/// let result = find_environment_by_name("nonexistent-env-12345", None, EolMinimum::default());
 /// assert!(result.is_err());
 ///
 /// // Search only in specific source
-/// let result = find_environment_by_name("myproject", Some(MigrateSource::Pyenv));
+/// let result = find_environment_by_name("myproject", Some(MigrateSource::Pyenv), EolMinimum::default());
 /// // Returns Ok(env) if found, Err if not
 /// ```
 ///
 /// # Errors
 ///
-/// Returns source-specific error if environment is not found:
-/// - [`ScoopError::PyenvEnvNotFound`]
-/// - [`ScoopError::VenvWrapperEnvNotFound`]
-/// - [`ScoopError::CondaEnvNotFound`]
+/// Returns the requested source's not-found error (e.g.
+/// [`ScoopError::CondaEnvNotFound`]) if the environment is not found;
+/// [`ScoopError::PyenvEnvNotFound`] when no source was requested.
 pub fn find_environment_by_name(
     name: &str,
     source_filter: Option<MigrateSource>,
+    eol_minimum: EolMinimum,
 ) -> Result<SourceEnvironment> {
-    // Try pyenv first
-    if source_filter.is_none() || source_filter == Some(MigrateSource::Pyenv) {
-        if let Some(discovery) = PyenvDiscovery::default_root() {
-            if let Ok(env) = discovery.find_environment(name) {
-                return Ok(env);
-            }
-        }
+    if let Some(env) = sources(source_filter, eol_minimum)
+        .iter()
+        .find_map(|source| source.find_environment(name).ok())
+    {
+        return Ok(env);
     }
 
-    // Try virtualenvwrapper
-    if source_filter.is_none() || source_filter == Some(MigrateSource::Virtualenvwrapper) {
-        if let Some(discovery) = VenvWrapperDiscovery::default_root() {
-            if let Ok(env) = discovery.find_environment(name) {
-                return Ok(env);
-            }
-        }
-    }
-
-    // Try conda
-    if source_filter.is_none() || source_filter == Some(MigrateSource::Conda) {
-        if let Some(discovery) = CondaDiscovery::default_roots() {
-            if let Ok(env) = discovery.find_environment(name) {
-                return Ok(env);
-            }
-        }
-    }
-
-    // If a specific source was requested, return that error
-    match source_filter {
-        Some(MigrateSource::Pyenv) => Err(ScoopError::PyenvEnvNotFound {
-            name: name.to_string(),
-        }),
-        Some(MigrateSource::Virtualenvwrapper) => Err(ScoopError::VenvWrapperEnvNotFound {
-            name: name.to_string(),
-        }),
-        Some(MigrateSource::Conda) => Err(ScoopError::CondaEnvNotFound {
-            name: name.to_string(),
-        }),
-        None => Err(ScoopError::PyenvEnvNotFound {
-            name: name.to_string(),
-        }),
-    }
+    // Without a filter, report it the way pyenv-only scuv always did.
+    let filter = source_filter.unwrap_or(MigrateSource::Pyenv);
+    let entry = REGISTRY
+        .iter()
+        .find(|entry| entry.filter == filter)
+        .expect("every MigrateSource has a registry entry");
+    Err((entry.not_found)(name.to_string()))
 }
 
 // ============================================================================
@@ -184,7 +211,8 @@ mod tests {
     #[test]
     fn find_environment_by_name_no_filter_returns_pyenv_error() {
         with_isolated_migrate_env(|| {
-            let result = find_environment_by_name("nonexistent_env_12345", None);
+            let result =
+                find_environment_by_name("nonexistent_env_12345", None, EolMinimum::default());
 
             assert!(result.is_err());
             assert!(matches!(
@@ -198,8 +226,11 @@ mod tests {
     #[test]
     fn find_environment_by_name_pyenv_filter_returns_pyenv_error() {
         with_isolated_migrate_env(|| {
-            let result =
-                find_environment_by_name("nonexistent_env_12345", Some(MigrateSource::Pyenv));
+            let result = find_environment_by_name(
+                "nonexistent_env_12345",
+                Some(MigrateSource::Pyenv),
+                EolMinimum::default(),
+            );
 
             assert!(result.is_err());
             let err = result.unwrap_err();
@@ -216,6 +247,7 @@ mod tests {
             let result = find_environment_by_name(
                 "nonexistent_env_12345",
                 Some(MigrateSource::Virtualenvwrapper),
+                EolMinimum::default(),
             );
 
             assert!(result.is_err());
@@ -230,8 +262,11 @@ mod tests {
     #[test]
     fn find_environment_by_name_conda_filter_returns_conda_error() {
         with_isolated_migrate_env(|| {
-            let result =
-                find_environment_by_name("nonexistent_env_12345", Some(MigrateSource::Conda));
+            let result = find_environment_by_name(
+                "nonexistent_env_12345",
+                Some(MigrateSource::Conda),
+                EolMinimum::default(),
+            );
 
             assert!(result.is_err());
             let err = result.unwrap_err();
@@ -245,7 +280,7 @@ mod tests {
     #[test]
     fn scan_all_environments_empty_when_no_sources() {
         with_isolated_migrate_env(|| {
-            let envs = scan_all_environments(None);
+            let envs = scan_all_environments(None, EolMinimum::default());
             assert!(envs.is_empty());
         });
     }
@@ -254,7 +289,7 @@ mod tests {
     #[test]
     fn scan_all_environments_with_pyenv_filter_returns_empty() {
         with_isolated_migrate_env(|| {
-            let envs = scan_all_environments(Some(MigrateSource::Pyenv));
+            let envs = scan_all_environments(Some(MigrateSource::Pyenv), EolMinimum::default());
             assert!(envs.is_empty());
         });
     }
@@ -262,7 +297,10 @@ mod tests {
     #[test]
     fn scan_all_environments_with_venvwrapper_filter_returns_empty() {
         with_isolated_migrate_env(|| {
-            let envs = scan_all_environments(Some(MigrateSource::Virtualenvwrapper));
+            let envs = scan_all_environments(
+                Some(MigrateSource::Virtualenvwrapper),
+                EolMinimum::default(),
+            );
             assert!(envs.is_empty());
         });
     }
@@ -270,11 +308,91 @@ mod tests {
     #[test]
     fn scan_all_environments_with_conda_filter_returns_empty() {
         with_isolated_migrate_env(|| {
-            let envs = scan_all_environments(Some(MigrateSource::Conda));
+            let envs = scan_all_environments(Some(MigrateSource::Conda), EolMinimum::default());
             assert!(envs.is_empty());
         });
     }
 
+    /// Tests that source_filter=Poetry returns PoetryEnvNotFound.
+    #[test]
+    fn find_environment_by_name_poetry_filter_returns_poetry_error() {
+        with_isolated_migrate_env(|| {
+            let err = find_environment_by_name(
+                "nonexistent_env_12345",
+                Some(MigrateSource::Poetry),
+                EolMinimum::default(),
+            )
+            .unwrap_err();
+            assert!(
+                matches!(err, ScoopError::PoetryEnvNotFound { ref name } if name == "nonexistent_env_12345")
+            );
+        });
+    }
+
+    /// Tests that source_filter=Hatch returns HatchEnvNotFound.
+    #[test]
+    fn find_environment_by_name_hatch_filter_returns_hatch_error() {
+        with_isolated_migrate_env(|| {
+            let err = find_environment_by_name(
+                "nonexistent_env_12345",
+                Some(MigrateSource::Hatch),
+                EolMinimum::default(),
+            )
+            .unwrap_err();
+            assert!(
+                matches!(err, ScoopError::HatchEnvNotFound { ref name } if name == "nonexistent_env_12345")
+            );
+        });
+    }
+
+    /// Every `--source` value must be reachable through the registry,
+    /// or `find_environment_by_name` would panic on its not-found path.
+    #[test]
+    fn registry_covers_every_migrate_source() {
+        use clap::ValueEnum;
+        for source in MigrateSource::value_variants() {
+            assert!(
+                REGISTRY.iter().any(|entry| entry.filter == *source),
+                "{source} is missing from REGISTRY"
+            );
+        }
+    }
+
+    /// Registered sources are scanned together and listed in registry
+    /// order, whatever the env names.
+    #[cfg(unix)]
+    #[test]
+    #[serial]
+    fn scan_all_environments_merges_sources_in_registry_order() {
+        with_isolated_migrate_env(|| {
+            let home = dirs::home_dir().unwrap();
+            let pyenv_root = home.join(".pyenv");
+            crate::test_utils::create_mock_pyenv_env(&pyenv_root, "zzz", "3.12.0");
+
+            let poetry_env = dirs::cache_dir()
+                .unwrap()
+                .join("pypoetry/virtualenvs/aaa-AbCd1234-py3.12");
+            std::fs::create_dir_all(poetry_env.join("bin")).unwrap();
+            std::fs::write(poetry_env.join("bin/python"), "").unwrap();
+            std::fs::write(poetry_env.join("pyvenv.cfg"), "version_info = 3.12.1\n").unwrap();
+
+            let envs = scan_all_environments(None, EolMinimum::default());
+            let found: Vec<_> = envs
+                .iter()
+                .map(|e| (e.source_type, e.name.as_str()))
+                .collect();
+            assert_eq!(
+                found,
+                [(SourceType::Pyenv, "zzz"), (SourceType::Poetry, "aaa"),]
+            );
+
+            let env = find_environment_by_name("aaa", None, EolMinimum::default()).unwrap();
+            assert_eq!(env.source_type, SourceType::Poetry);
+            assert!(any_source_tool_available(Some(MigrateSource::Poetry)));
+            assert!(!any_source_tool_available(Some(MigrateSource::Conda)));
+        });
+    }
+
     // =========================================================================
     // any_source_tool_available Tests (Inc 4)
     // =========================================================================
@@ -293,6 +411,8 @@ mod tests {
                 MigrateSource::Virtualenvwrapper
             )));
             assert!(!any_source_tool_available(Some(MigrateSource::Conda)));
+            assert!(!any_source_tool_available(Some(MigrateSource::Poetry)));
+            assert!(!any_source_tool_available(Some(MigrateSource::Hatch)));
         });
     }
 
@@ -4,9 +4,11 @@
 
 use rust_i18n::t;
 
+use crate::core::cancel;
 use crate::core::migrate::{EnvironmentStatus, MigrateOptions, MigrationResult, Migrator};
 use crate::error::{Result, ScoopError};
 use crate::output::Output;
+use crate::uv::UvClient;
 
 use super::conflict::{
     ConflictResolution, generate_unique_name, prompt_conflict_resolution, prompt_rename,
@@ -26,15 +28,7 @@ pub fn print_migration_result(output: &Output, result: &MigrationResult, dry_run
         output.info(&t!("migrate.python", version = &result.python_version));
         output.info(&t!("migrate.packages", count = result.packages_migrated));
 
-        if !result.packages_failed.is_empty() {
-            output.warn(&t!(
-                "migrate.failed_packages",
-                count = result.packages_failed.len()
-            ));
-            for pkg in &result.packages_failed {
-                output.info(&format!("    - {}", pkg));
-            }
-        }
+        print_package_issues(output, result);
 
         output.info("");
         output.info(&t!("migrate.dry_run_hint"));
@@ -47,21 +41,51 @@ pub fn print_migration_result(output: &Output, result: &MigrationResult, dry_run
         output.info(&t!("migrate.python", version = &result.python_version));
         output.info(&t!("migrate.packages", count = result.packages_migrated));
 
-        if !result.packages_failed.is_empty() {
-            output.warn(&t!(
-                "migrate.failed_packages",
-                count = result.packages_failed.len()
-            ));
-            for pkg in &result.packages_failed {
-                output.info(&format!("    - {}", pkg));
-            }
-        }
+        print_package_issues(output, result);
 
         output.info("");
         output.info(&t!("migrate.activate_hint", name = &result.name));
     }
 }
 
+/// Print packages that weren't migrated: install failures, then editable
+/// installs with the `pip install -e` command to recreate each.
+fn print_package_issues(output: &Output, result: &MigrationResult) {
+    if !result.packages_failed.is_empty() {
+        output.warn(&t!(
+            "migrate.failed_packages",
+            count = result.packages_failed.len()
+        ));
+        for pkg in &result.packages_failed {
+            output.info(&format!("    - {}", pkg));
+        }
+    }
+
+    if !result.packages_unresolved.is_empty() {
+        output.warn(&t!(
+            "migrate.unresolved_packages",
+            count = result.packages_unresolved.len()
+        ));
+        for pkg in &result.packages_unresolved {
+            output.info(&format!("    - {}", pkg));
+        }
+    }
+
+    if !result.editable_packages.is_empty() {
+        output.warn(&t!(
+            "migrate.editable_packages",
+            count = result.editable_packages.len()
+        ));
+        for pkg in &result.editable_packages {
+            output.info(&format!(
+                "    - {}: pip install -e {}",
+                pkg.name, pkg.source
+            ));
+        }
+        output.info(&t!("migrate.editable_hint"));
+    }
+}
+
 /// Migrate a single environment.
 ///
 /// Handles conflict resolution, status validation, and actual migration.
@@ -70,7 +94,14 @@ pub fn migrate_environment(
     name: &str,
     opts: &MigrateExecuteOptions,
 ) -> Result<()> {
-    let source = find_environment_by_name(name, opts.source_filter)?;
+    if let Some(python) = &opts.python {
+        crate::validate::validate_python_spec(python)?;
+    }
+    let source = find_environment_by_name(name, opts.source_filter, opts.eol_minimum)?;
+    // Rename via --strip-prefix/--add-prefix before any conflict check, so
+    // the checks below see the name the env will get in scuv.
+    let source = opts.name_transform.retarget(source, opts.eol_minimum)?;
+    let target = source.name.as_str();
 
     if !opts.json {
         // Show environment info
@@ -89,8 +120,12 @@ pub fn migrate_environment(
         }
     }
 
+    if target != name && !opts.json {
+        output.info(&t!("migrate.will_migrate_as", name = target));
+    }
+
     // Determine final name (may be renamed)
-    let mut final_name = opts.rename.clone().unwrap_or_else(|| name.to_string());
+    let mut final_name = opts.rename.clone().unwrap_or_else(|| target.to_string());
     let mut effective_force = opts.force;
 
     // Check status
@@ -99,7 +134,7 @@ pub fn migrate_environment(
         EnvironmentStatus::NameConflict { existing } => {
             if opts.auto_rename {
                 // Auto-rename: generate unique name
-                final_name = generate_unique_name(name)?;
+                final_name = generate_unique_name(target)?;
                 if !opts.json {
                     output.info(&t!("migrate.auto_rename", name = &final_name));
                 }
@@ -115,7 +150,7 @@ pub fn migrate_environment(
             } else if !opts.force {
                 // Interactive conflict resolution (if not json and not yes)
                 if !opts.json && !opts.yes {
-                    let resolution = prompt_conflict_resolution(output, name, existing)?;
+                    let resolution = prompt_conflict_resolution(output, target, existing)?;
                     match resolution {
                         ConflictResolution::Overwrite => {
                             effective_force = true;
@@ -124,7 +159,7 @@ pub fn migrate_environment(
                             }
                         }
                         ConflictResolution::Rename => {
-                            final_name = prompt_rename(name)?;
+                            final_name = prompt_rename(target)?;
                             if !opts.json {
                                 output.info(&t!("migrate.will_migrate_as", name = &final_name));
                             }
@@ -139,11 +174,11 @@ pub fn migrate_environment(
                 } else {
                     // Non-interactive mode: error out
                     if !opts.json {
-                        output.warn(&t!("migrate.name_exists", name = name));
+                        output.warn(&t!("migrate.name_exists", name = target));
                         output.info(&t!("migrate.use_flags"));
                     }
                     return Err(ScoopError::MigrationNameConflict {
-                        name: name.to_string(),
+                        name: target.to_string(),
                         existing: existing.clone(),
                     });
                 }
@@ -151,6 +186,8 @@ pub fn migrate_environment(
                 output.warn(&t!("migrate.overwriting"));
             }
         }
+        // The EOL Python is left behind; the override warning follows.
+        EnvironmentStatus::PythonEol { .. } if opts.python.is_some() => {}
         EnvironmentStatus::PythonEol { version } => {
             if !opts.force {
                 if !opts.json {
@@ -176,20 +213,34 @@ pub fn migrate_environment(
         }
     }
 
+    if let Some(python) = opts.python.as_deref().filter(|_| !opts.json) {
+        output.warn(&t!(
+            "migrate.python_override",
+            from = &source.python_version,
+            to = python
+        ));
+    }
+
+    // Conflict prompts are done; Ctrl-C now aborts the migration cleanly.
+    cancel::install_handler();
+
     // Create migrator and options
-    let migrator = Migrator::new()?;
+    let migrator = Migrator::with_uv(UvClient::for_output(output)?);
     let options = MigrateOptions {
         dry_run: opts.dry_run,
         force: effective_force,
         skip_packages: false,
-        rename_to: if final_name != name {
+        rename_to: if final_name != target {
             Some(final_name.clone())
         } else {
             None
         },
         strict: opts.strict,
+        skip_unresolved: opts.skip_unresolved,
         delete_source: opts.delete_source,
         auto_install_python: false,
+        index_url: opts.index_url.clone(),
+        python_version: opts.python.clone(),
     };
 
     if !opts.json {
@@ -222,7 +273,9 @@ pub fn migrate_environment(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::migrate::MigrationResult;
+    use crate::cli::ColorChoice;
+    use crate::core::migrate::{EditablePackage, MigrationResult};
+    use crate::error::MigrationExitCode;
     use crate::test_utils::{
         create_corrupted_pyenv_env, create_mock_pyenv_env, with_full_migrate_env,
         with_isolated_migrate_env,
@@ -245,10 +298,12 @@ mod tests {
             python_version: "3.12.0".to_string(),
             packages_migrated,
             packages_failed,
+            packages_unresolved: Vec::new(),
             dry_run,
             path: PathBuf::from(format!("/home/test/.scoop/virtualenvs/{}", name)),
             source_deleted: false,
             actual_python_version: "3.12.0".to_string(),
+            editable_packages: Vec::new(),
         }
     }
 
@@ -282,6 +337,27 @@ mod tests {
         assert_eq!(restored.packages_failed[0], "broken-pkg");
     }
 
+    /// Roundtrip with editable installs, and older JSON without the field
+    #[test]
+    fn migration_result_roundtrip_with_editable_packages() {
+        let mut original = create_test_result("editenv", false, 3, vec![]);
+        original.editable_packages = vec![EditablePackage {
+            name: "my-pkg".to_string(),
+            source: "/home/test/src/my-pkg".to_string(),
+        }];
+
+        let json = serde_json::to_string(&original).unwrap();
+        let restored: MigrationResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(original, restored);
+        assert_eq!(restored.exit_code(), MigrationExitCode::PartialSuccess);
+
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value.as_object_mut().unwrap().remove("editable_packages");
+        let legacy: MigrationResult = serde_json::from_value(value).unwrap();
+        assert!(legacy.editable_packages.is_empty());
+        assert_eq!(legacy.exit_code(), MigrationExitCode::Success);
+    }
+
     /// Roundtrip with dry_run flag - tests boolean field
     #[test]
     fn migration_result_roundtrip_dry_run_flag() {
@@ -316,6 +392,7 @@ mod tests {
             "python_version",
             "packages_migrated",
             "packages_failed",
+            "packages_unresolved",
             "dry_run",
             "path",
             "source_deleted",
@@ -344,10 +421,12 @@ mod tests {
             python_version: "3.12.0".to_string(),
             packages_migrated: 0,
             packages_failed: vec![],
+            packages_unresolved: Vec::new(),
             dry_run: false,
             path: PathBuf::from("/test/zero"),
             source_deleted: false,
             actual_python_version: "3.12.0".to_string(),
+            editable_packages: Vec::new(),
         };
 
         let json = serde_json::to_string(&zero).unwrap();
@@ -360,10 +439,12 @@ mod tests {
             python_version: "3.12.0".to_string(),
             packages_migrated: usize::MAX,
             packages_failed: vec![],
+            packages_unresolved: Vec::new(),
             dry_run: false,
             path: PathBuf::from("/test/max"),
             source_deleted: false,
             actual_python_version: "3.12.0".to_string(),
+            editable_packages: Vec::new(),
         };
 
         let json = serde_json::to_string(&max).unwrap();
@@ -389,10 +470,12 @@ mod tests {
                 python_version: "3.12.0".to_string(),
                 packages_migrated: 1,
                 packages_failed: vec![],
+                packages_unresolved: Vec::new(),
                 dry_run: false,
                 path: PathBuf::from("/test/path"),
                 source_deleted: false,
                 actual_python_version: "3.12.0".to_string(),
+                editable_packages: Vec::new(),
             };
 
             let json = serde_json::to_string(&result).unwrap();
@@ -414,10 +497,12 @@ mod tests {
             python_version: "3.12.0".to_string(),
             packages_migrated: 500,
             packages_failed: many_packages.clone(),
+            packages_unresolved: Vec::new(),
             dry_run: false,
             path: PathBuf::from("/test/large"),
             source_deleted: false,
             actual_python_version: "3.12.0".to_string(),
+            editable_packages: Vec::new(),
         };
 
         let json = serde_json::to_string(&result).unwrap();
@@ -446,10 +531,12 @@ mod tests {
             python_version: "3.12.0".to_string(),
             packages_migrated: 10,
             packages_failed: special_packages.clone(),
+            packages_unresolved: Vec::new(),
             dry_run: false,
             path: PathBuf::from("/test/special"),
             source_deleted: false,
             actual_python_version: "3.12.0".to_string(),
+            editable_packages: Vec::new(),
         };
 
         let json = serde_json::to_string(&result).unwrap();
@@ -469,10 +556,12 @@ mod tests {
             python_version: String::new(),
             packages_migrated: 0,
             packages_failed: vec![String::new(), String::new()],
+            packages_unresolved: Vec::new(),
             dry_run: false,
             path: PathBuf::from(""),
             source_deleted: false,
             actual_python_version: String::new(),
+            editable_packages: Vec::new(),
         };
 
         let json = serde_json::to_string(&result).unwrap();
@@ -492,7 +581,7 @@ mod tests {
     #[serial]
     fn migrate_environment_not_found_returns_error() {
         with_isolated_migrate_env(|| {
-            let output = Output::new(0, false, true, false);
+            let output = Output::new(0, false, ColorChoice::Never, false);
             let opts = MigrateExecuteOptions::default();
 
             let result = migrate_environment(&output, "nonexistent_env_12345", &opts);
@@ -504,7 +593,7 @@ mod tests {
     #[serial]
     fn migrate_environment_with_pyenv_filter_not_found() {
         with_isolated_migrate_env(|| {
-            let output = Output::new(0, false, true, false);
+            let output = Output::new(0, false, ColorChoice::Never, false);
             let opts = MigrateExecuteOptions {
                 source_filter: Some(crate::cli::MigrateSource::Pyenv),
                 ..Default::default()
@@ -522,7 +611,7 @@ mod tests {
             // Create a corrupted environment (no bin/python)
             create_corrupted_pyenv_env(pyenv.path(), "corrupted_env", "3.12.0");
 
-            let output = Output::new(0, false, true, false);
+            let output = Output::new(0, false, ColorChoice::Never, false);
             let opts = MigrateExecuteOptions {
                 source_filter: Some(crate::cli::MigrateSource::Pyenv),
                 json: false,
@@ -548,7 +637,7 @@ mod tests {
         with_full_migrate_env(|_scoop, pyenv| {
             create_corrupted_pyenv_env(pyenv.path(), "corrupted_json", "3.12.0");
 
-            let output = Output::new(0, false, true, true);
+            let output = Output::new(0, false, ColorChoice::Never, true);
             let opts = MigrateExecuteOptions {
                 source_filter: Some(crate::cli::MigrateSource::Pyenv),
                 json: true,
@@ -560,6 +649,75 @@ mod tests {
         });
     }
 
+    fn prefixed(add: &str) -> MigrateExecuteOptions {
+        MigrateExecuteOptions {
+            source_filter: Some(crate::cli::MigrateSource::Pyenv),
+            yes: true,
+            name_transform: super::super::types::NameTransform {
+                strip_prefix: None,
+                add_prefix: Some(add.to_string()),
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn migrate_environment_rejects_prefix_that_makes_name_invalid() {
+        with_full_migrate_env(|scoop, pyenv| {
+            create_mock_pyenv_env(pyenv.path(), "webapp", "3.12.0");
+
+            let output = Output::new(0, false, ColorChoice::Never, false);
+            let err = migrate_environment(&output, "webapp", &prefixed("9-")).unwrap_err();
+
+            assert!(
+                matches!(err, ScoopError::InvalidEnvName { ref name, .. } if name == "9-webapp"),
+                "got {err:?}"
+            );
+            assert!(!scoop.path().join("virtualenvs/9-webapp").exists());
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn migrate_environment_checks_conflict_under_prefixed_name() {
+        with_full_migrate_env(|scoop, pyenv| {
+            create_mock_pyenv_env(pyenv.path(), "webapp", "3.12.0");
+            std::fs::create_dir_all(scoop.path().join("virtualenvs/legacy-webapp")).unwrap();
+
+            let output = Output::new(0, false, ColorChoice::Never, false);
+            let err = migrate_environment(&output, "webapp", &prefixed("legacy-")).unwrap_err();
+
+            assert!(
+                matches!(err, ScoopError::MigrationNameConflict { ref name, .. } if name == "legacy-webapp"),
+                "got {err:?}"
+            );
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn migrate_environment_rejects_malformed_python_override() {
+        with_full_migrate_env(|scoop, pyenv| {
+            create_mock_pyenv_env(pyenv.path(), "webapp", "3.7.0");
+
+            let output = Output::new(0, false, ColorChoice::Never, false);
+            let opts = MigrateExecuteOptions {
+                source_filter: Some(crate::cli::MigrateSource::Pyenv),
+                yes: true,
+                python: Some("jython@2.7".to_string()),
+                ..Default::default()
+            };
+            let err = migrate_environment(&output, "webapp", &opts).unwrap_err();
+
+            assert!(
+                matches!(err, ScoopError::InvalidPythonVersion { .. }),
+                "got {err:?}"
+            );
+            assert!(!scoop.path().join("virtualenvs/webapp").exists());
+        });
+    }
+
     // =========================================================================
     // MigrateExecuteOptions Tests
     // =========================================================================
@@ -618,7 +776,7 @@ mod tests {
             // Create environment with Python 2.7 (EOL)
             create_mock_pyenv_env(pyenv.path(), "py27_env", "2.7.18");
 
-            let output = Output::new(0, false, true, false);
+            let output = Output::new(0, false, ColorChoice::Never, false);
             let opts = MigrateExecuteOptions {
                 source_filter: Some(crate::cli::MigrateSource::Pyenv),
                 force: false,
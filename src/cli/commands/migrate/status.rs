@@ -0,0 +1,184 @@
+//! Single environment inspection
+//!
+//! Shows everything `migrate @env` would look at for one source
+//! environment, without migrating it.
+
+use rust_i18n::t;
+
+use crate::cli::MigrateSource;
+use crate::core::migrate::common::{self, EolMinimum};
+use crate::core::migrate::{EnvironmentStatus, PackageExtractor};
+use crate::error::Result;
+use crate::output::{Output, format_size};
+
+use super::scan::find_environment_by_name;
+use super::types::MigrateStatusData;
+
+/// Show one source environment's size, packages and migration status.
+///
+/// Read-only: the environment is looked up the same way `migrate @env`
+/// does, its directory is walked for the size and `pip freeze` lists its
+/// packages. A failing `pip freeze` (a corrupted env, no pip) is reported
+/// rather than treated as an error, since the status is still useful.
+///
+/// # Errors
+///
+/// Returns the source-specific not-found error when no source has `name`.
+pub fn show_status(
+    output: &Output,
+    name: &str,
+    source_filter: Option<MigrateSource>,
+    eol_minimum: EolMinimum,
+) -> Result<()> {
+    let mut environment = find_environment_by_name(name, source_filter, eol_minimum)?;
+    environment.size_bytes = Some(common::dir_size(&environment.path));
+
+    let (packages, unparsed, package_error) =
+        match PackageExtractor::new().extract(&environment.path) {
+            Ok(result) => (result.packages, result.failed, None),
+            Err(e) => (Vec::new(), Vec::new(), Some(e.to_string())),
+        };
+
+    if output.is_json() {
+        output.json_success(
+            "migrate status",
+            MigrateStatusData {
+                environment,
+                packages,
+                unparsed,
+                package_error,
+            },
+        );
+        return Ok(());
+    }
+
+    let (icon, status) = status_detail(&environment.status);
+    println!(
+        "  {icon} {} [{}]",
+        environment.name, environment.source_type
+    );
+    println!(
+        "{}",
+        t!(
+            "migrate.status_python",
+            version = &environment.python_version
+        )
+    );
+    println!(
+        "{}",
+        t!("migrate.status_path", path = environment.path.display())
+    );
+    println!(
+        "{}",
+        t!(
+            "migrate.status_size",
+            size = format_size(environment.size_bytes.unwrap_or(0))
+        )
+    );
+    println!("{}", t!("migrate.status_status", status = status));
+
+    match &package_error {
+        Some(reason) => println!("{}", t!("migrate.status_packages_error", reason = reason)),
+        None => {
+            println!("{}", t!("migrate.status_packages", count = packages.len()));
+            for package in &packages {
+                println!("      {}", package.to_requirement());
+            }
+        }
+    }
+    for line in &unparsed {
+        println!("      ? {line}");
+    }
+
+    if !matches!(environment.status, EnvironmentStatus::Corrupted { .. }) {
+        println!();
+        output.info(&t!("migrate.hint_single"));
+    }
+    Ok(())
+}
+
+/// Status icon and a one-line description with the full detail.
+fn status_detail(status: &EnvironmentStatus) -> (&'static str, String) {
+    match status {
+        EnvironmentStatus::Ready => ("✓", t!("migrate.status_ready").to_string()),
+        EnvironmentStatus::NameConflict { existing } => (
+            "⚠",
+            t!("migrate.status_name_conflict", path = existing.display()).to_string(),
+        ),
+        EnvironmentStatus::PythonEol { version } => {
+            ("⚠", t!("migrate.status_eol", version = version).to_string())
+        }
+        EnvironmentStatus::Corrupted { reason } => (
+            "✗",
+            t!("migrate.status_corrupted", reason = reason).to_string(),
+        ),
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::ColorChoice;
+    use crate::error::ScoopError;
+    use crate::test_utils::{create_mock_pyenv_env, with_full_migrate_env};
+    use serial_test::serial;
+    use std::path::PathBuf;
+
+    #[test]
+    fn status_detail_covers_every_status() {
+        let (icon, text) = status_detail(&EnvironmentStatus::Ready);
+        assert_eq!((icon, text.as_str()), ("✓", "ready to migrate"));
+
+        let (icon, text) = status_detail(&EnvironmentStatus::NameConflict {
+            existing: PathBuf::from("/home/u/.scuv/virtualenvs/web"),
+        });
+        assert_eq!(icon, "⚠");
+        assert!(text.contains("virtualenvs/web") && text.contains("--rename"));
+
+        let (_, text) = status_detail(&EnvironmentStatus::PythonEol {
+            version: "2.7.18".into(),
+        });
+        assert!(text.contains("2.7.18 is EOL"));
+
+        let (icon, text) = status_detail(&EnvironmentStatus::Corrupted {
+            reason: "no python".into(),
+        });
+        assert_eq!((icon, text.as_str()), ("✗", "corrupted: no python"));
+    }
+
+    #[test]
+    #[serial]
+    fn show_status_reports_missing_pip_without_failing() {
+        with_full_migrate_env(|_scoop, pyenv| {
+            create_mock_pyenv_env(pyenv.path(), "nopip", "3.12.0");
+            let output = Output::new(0, true, ColorChoice::Never, true);
+            let result = show_status(
+                &output,
+                "nopip",
+                Some(MigrateSource::Pyenv),
+                EolMinimum::default(),
+            );
+            assert!(result.is_ok(), "{result:?}");
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn show_status_unknown_env_is_not_found() {
+        with_full_migrate_env(|_scoop, _pyenv| {
+            let output = Output::new(0, true, ColorChoice::Never, false);
+            let err = show_status(
+                &output,
+                "ghost",
+                Some(MigrateSource::Pyenv),
+                EolMinimum::default(),
+            )
+            .unwrap_err();
+            assert!(matches!(err, ScoopError::PyenvEnvNotFound { .. }));
+        });
+    }
+}
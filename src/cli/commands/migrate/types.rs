@@ -7,7 +7,11 @@ use std::path::PathBuf;
 use serde::Serialize;
 
 use crate::cli::MigrateSource;
-use crate::core::migrate::{MigrationResult, SourceEnvironment, SourceType};
+use crate::core::migrate::common::{EolMinimum, determine_status};
+use crate::core::migrate::{
+    EnvironmentStatus, MigrationResult, PackageSpec, SourceEnvironment, SourceType,
+};
+use crate::error::Result;
 
 /// JSON output for migrate list command
 #[derive(Debug, Serialize)]
@@ -27,6 +31,23 @@ pub struct MigrateListSummary {
     pub corrupted: usize,
 }
 
+/// JSON output for migrate status command.
+///
+/// The environment's own fields sit at the top level, exactly as in a
+/// `migrate list` entry (with `size_bytes` always filled in).
+#[derive(Debug, Serialize)]
+pub struct MigrateStatusData {
+    #[serde(flatten)]
+    pub environment: SourceEnvironment,
+    /// Packages `pip freeze` reported, as `migrate @env` would reinstall them.
+    pub packages: Vec<PackageSpec>,
+    /// `pip freeze` lines that couldn't be parsed into a package.
+    pub unparsed: Vec<String>,
+    /// Why the package list couldn't be read, when it couldn't.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package_error: Option<String>,
+}
+
 /// JSON output for migrate all command.
 ///
 /// `conflicts` (new in 0.14) is *additive*: name-conflicting envs
@@ -86,6 +107,59 @@ pub struct MigrateAllSummary {
     pub skipped: usize,
 }
 
+/// `--strip-prefix` / `--add-prefix` rewrite of the scuv-side env name.
+///
+/// The prefix is stripped first, so passing both replaces one prefix with
+/// another (`--strip-prefix py- --add-prefix legacy-`). A name without the
+/// strip prefix is left as is.
+#[derive(Debug, Clone, Default)]
+pub struct NameTransform {
+    pub strip_prefix: Option<String>,
+    pub add_prefix: Option<String>,
+}
+
+impl NameTransform {
+    /// The scuv name for source env `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::error::ScoopError::InvalidEnvName`] if the result is
+    /// not a valid env name (e.g. stripping leaves nothing, or the new
+    /// prefix starts with a digit).
+    pub fn apply(&self, name: &str) -> Result<String> {
+        let stripped = self
+            .strip_prefix
+            .as_deref()
+            .and_then(|prefix| name.strip_prefix(prefix))
+            .unwrap_or(name);
+        let target = format!("{}{stripped}", self.add_prefix.as_deref().unwrap_or(""));
+        crate::validate::validate_env_name(&target)?;
+        Ok(target)
+    }
+
+    /// Point `env` at its transformed name, re-judging its status so the
+    /// conflict checks see the name it will actually get in scuv.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::apply`].
+    pub fn retarget(
+        &self,
+        mut env: SourceEnvironment,
+        eol_minimum: EolMinimum,
+    ) -> Result<SourceEnvironment> {
+        if self.strip_prefix.is_none() && self.add_prefix.is_none() {
+            return Ok(env);
+        }
+        let target = self.apply(&env.name)?;
+        if target != env.name && !matches!(env.status, EnvironmentStatus::Corrupted { .. }) {
+            env.status = determine_status(&target, &env.python_version, eol_minimum);
+        }
+        env.name = target;
+        Ok(env)
+    }
+}
+
 /// Options for CLI migrate execution.
 ///
 /// This struct consolidates the many boolean flags used by migrate commands
@@ -102,6 +176,8 @@ pub struct MigrateExecuteOptions {
     pub json: bool,
     /// Fail on first package error
     pub strict: bool,
+    /// Drop packages missing from the index instead of failing on them
+    pub skip_unresolved: bool,
     /// Delete original environment after successful migration
     pub delete_source: bool,
     /// Migrate with a different name
@@ -110,6 +186,17 @@ pub struct MigrateExecuteOptions {
     pub auto_rename: bool,
     /// Filter by source tool
     pub source_filter: Option<MigrateSource>,
+    /// Python versions older than this are flagged EOL
+    pub eol_minimum: EolMinimum,
+    /// Package index for reinstalling packages
+    pub index_url: Option<String>,
+    /// Maximum environments `migrate all` migrates at once (`--parallel`);
+    /// `None` leaves it to rayon's global pool, one per core
+    pub parallel: Option<usize>,
+    /// Prefix rewrite applied to target names before conflict checks
+    pub name_transform: NameTransform,
+    /// Python for the new env instead of the source's (`@env --python`)
+    pub python: Option<String>,
 }
 
 #[cfg(test)]
@@ -127,6 +214,10 @@ mod tests {
         assert!(!opts.yes, "yes should default to false");
         assert!(!opts.json, "json should default to false");
         assert!(!opts.strict, "strict should default to false");
+        assert!(
+            !opts.skip_unresolved,
+            "skip_unresolved should default to false"
+        );
         assert!(!opts.delete_source, "delete_source should default to false");
         assert!(opts.rename.is_none(), "rename should default to None");
         assert!(!opts.auto_rename, "auto_rename should default to false");
@@ -134,6 +225,79 @@ mod tests {
             opts.source_filter.is_none(),
             "source_filter should default to None"
         );
+        assert!(opts.index_url.is_none(), "index_url should default to None");
+        assert!(opts.name_transform.strip_prefix.is_none());
+        assert!(opts.name_transform.add_prefix.is_none());
+        assert_eq!(
+            opts.parallel, None,
+            "parallel should default to rayon's pool"
+        );
+    }
+
+    fn transform(strip: Option<&str>, add: Option<&str>) -> NameTransform {
+        NameTransform {
+            strip_prefix: strip.map(str::to_string),
+            add_prefix: add.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn name_transform_adds_and_strips_prefixes() {
+        let add = transform(None, Some("legacy-"));
+        assert_eq!(add.apply("web").unwrap(), "legacy-web");
+
+        let strip = transform(Some("py-"), None);
+        assert_eq!(strip.apply("py-web").unwrap(), "web");
+        // No prefix to strip leaves the name alone.
+        assert_eq!(strip.apply("web").unwrap(), "web");
+
+        let replace = transform(Some("py-"), Some("legacy-"));
+        assert_eq!(replace.apply("py-web").unwrap(), "legacy-web");
+        assert_eq!(replace.apply("api").unwrap(), "legacy-api");
+
+        assert_eq!(NameTransform::default().apply("web").unwrap(), "web");
+    }
+
+    #[test]
+    fn name_transform_rejects_names_that_become_invalid() {
+        // Stripping the whole name leaves nothing.
+        assert!(transform(Some("web"), None).apply("web").is_err());
+        // Stripping exposes a leading digit.
+        assert!(transform(Some("py-"), None).apply("py-3tools").is_err());
+        // The new prefix itself makes the name invalid.
+        assert!(transform(None, Some("1-")).apply("web").is_err());
+        assert!(transform(None, Some("../")).apply("web").is_err());
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn retarget_rechecks_conflict_under_new_name() {
+        crate::test_utils::with_temp_scoop_home(|temp| {
+            std::fs::create_dir_all(temp.path().join("virtualenvs/legacy-web")).unwrap();
+            let env = SourceEnvironment {
+                name: "web".to_string(),
+                python_version: "3.12.0".to_string(),
+                path: PathBuf::from("/src/web"),
+                source_type: SourceType::Pyenv,
+                size_bytes: None,
+                status: EnvironmentStatus::Ready,
+            };
+
+            let moved = transform(None, Some("legacy-"))
+                .retarget(env.clone(), EolMinimum::default())
+                .unwrap();
+            assert_eq!(moved.name, "legacy-web");
+            assert!(matches!(
+                moved.status,
+                EnvironmentStatus::NameConflict { .. }
+            ));
+
+            let untouched = NameTransform::default()
+                .retarget(env, EolMinimum::default())
+                .unwrap();
+            assert_eq!(untouched.name, "web");
+            assert!(matches!(untouched.status, EnvironmentStatus::Ready));
+        });
     }
 
     /// MigrateListData 구조체 테스트
@@ -220,4 +384,34 @@ mod tests {
         assert!(json.contains("\"failed\":2"));
         assert!(json.contains("\"skipped\":1"));
     }
+
+    #[test]
+    fn migrate_status_data_flattens_environment() {
+        let data = MigrateStatusData {
+            environment: SourceEnvironment {
+                name: "web".to_string(),
+                python_version: "3.12.0".to_string(),
+                path: PathBuf::from("/pyenv/versions/3.12.0/envs/web"),
+                source_type: SourceType::Pyenv,
+                size_bytes: Some(2048),
+                status: EnvironmentStatus::Ready,
+            },
+            packages: vec![PackageSpec {
+                name: "requests".to_string(),
+                version: "2.31.0".to_string(),
+                editable: false,
+                editable_path: None,
+            }],
+            unparsed: Vec::new(),
+            package_error: None,
+        };
+        let json = serde_json::to_value(&data).unwrap();
+        assert_eq!(json["name"], "web");
+        assert_eq!(json["source_type"], "pyenv");
+        assert_eq!(json["size_bytes"], 2048);
+        assert_eq!(json["status"]["status"], "ready");
+        assert_eq!(json["packages"][0]["name"], "requests");
+        assert_eq!(json["packages"][0]["version"], "2.31.0");
+        assert!(json.get("package_error").is_none());
+    }
 }
@@ -10,6 +10,7 @@ mod doctor;
 mod duration;
 mod export;
 mod gc;
+mod hook;
 mod import;
 mod info;
 mod init;
@@ -24,11 +25,15 @@ mod resolve;
 mod run;
 mod self_update;
 mod shell;
+mod site_packages;
 mod status;
 mod sync;
+mod tag;
+mod template;
 mod uninstall;
 mod use_env;
 mod verify;
+mod version;
 mod which;
 
 // `duration` is module-private; Step 5's gc handler imports
@@ -38,18 +43,24 @@ mod which;
 pub use activate::execute as activate;
 pub use clone::execute as clone;
 pub use completions::execute as completions;
+pub use create::CreateOpts;
+pub use create::default_python as create_default_python;
 pub use create::execute as create;
 pub use deactivate::execute as deactivate;
 pub use diff::execute as diff;
 pub use diff::{DiffMode, DiffOpts};
-pub use doctor::execute as doctor;
+pub use doctor::{DoctorOpts, execute as doctor};
 pub use export::execute as export;
 pub use gc::execute as gc;
+pub use hook::execute_cd as hook_cd;
+pub use hook::execute_prompt as hook_prompt;
 pub use import::execute as import;
 pub use info::execute as info;
 pub use init::execute as init;
+pub use install::InstallOpts;
 pub use install::execute as install;
 pub use lang::execute as lang;
+pub use list::ListOpts;
 pub use list::execute as list;
 pub use man::execute as man;
 pub use migrate::execute as migrate;
@@ -59,9 +70,16 @@ pub use resolve::execute as resolve;
 pub use run::execute as run;
 pub use self_update::execute as self_update;
 pub use shell::execute as shell;
+pub use site_packages::execute as site_packages;
 pub use status::execute as status;
 pub use sync::execute as sync;
+pub use tag::execute as tag;
+pub use tag::execute_untag as untag;
+pub use template::execute_list as template_list;
+pub use template::execute_show as template_show;
 pub use uninstall::execute as uninstall;
+pub use use_env::PrintScript;
 pub use use_env::execute as use_env;
 pub use verify::execute as verify;
+pub use version::execute as version;
 pub use which::execute as which;
@@ -1,26 +1,98 @@
 //! Remove command
 
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
 use dialoguer::Confirm;
 use rust_i18n::t;
 
-use crate::core::VirtualenvService;
+use crate::core::processes::{self, EnvProcess};
+use crate::core::{VersionService, VirtualenvService};
 use crate::error::Result;
 use crate::output::{Output, RemoveData};
+use crate::paths::abbreviate_home;
 
 /// Execute the remove command
-pub fn execute(output: &Output, name: &str, force: bool) -> Result<()> {
+///
+/// With `dry_run`, reports what would be deleted and returns without
+/// prompting or touching disk.
+///
+/// Unless `keep_version_files`, the global version file and the current
+/// directory's version file are deleted when they pin the removed env, so
+/// resolution doesn't keep pointing at an env that no longer exists.
+///
+/// With `wait`, returns only once the directory has actually disappeared,
+/// failing with a `TimedOut` I/O error after that long. Network
+/// filesystems can report a removal before the directory is gone, which
+/// makes an immediate `scuv create` of the same name fail.
+///
+/// With `kill` (only offered together with `--force`), processes running
+/// from the env are killed first, each PID announced; on Windows they
+/// would otherwise keep the directory locked.
+pub fn execute(
+    output: &Output,
+    name: &str,
+    force: bool,
+    dry_run: bool,
+    keep_version_files: bool,
+    wait: Option<Duration>,
+    kill: bool,
+) -> Result<()> {
     let service = VirtualenvService::auto()?;
 
     // Verify environment exists
     let path = service.get_path(name)?;
 
+    let cwd = std::env::current_dir().ok();
+    let pins = match (&cwd, keep_version_files) {
+        (Some(cwd), false) => VersionService::pins_to(cwd, name),
+        _ => Vec::new(),
+    };
+
+    if dry_run {
+        let running = if kill {
+            processes::running_from(&path)
+        } else {
+            Vec::new()
+        };
+        if output.is_json() {
+            output.json_success(
+                "remove",
+                RemoveData {
+                    name: name.to_string(),
+                    path: path.display().to_string(),
+                    dry_run: true,
+                    cleaned_version_files: display_paths(&pins),
+                    killed_processes: running.iter().map(|p| p.pid).collect(),
+                },
+            );
+        } else {
+            output.info(&t!(
+                "remove.dry_run",
+                name = name,
+                path = abbreviate_home(&path)
+            ));
+            for pin in &pins {
+                output.info(&t!(
+                    "remove.dry_run_version_file",
+                    path = abbreviate_home(pin)
+                ));
+            }
+            for process in &running {
+                output.info(&t!(
+                    "remove.dry_run_kill",
+                    pid = process.pid,
+                    exe = abbreviate_home(&process.exe)
+                ));
+            }
+        }
+        return Ok(());
+    }
+
     // JSON mode always implies force (no interactive confirmation)
     if !force && !output.is_json() {
         // Show what will be deleted
-        output.info(&t!(
-            "remove.path",
-            path = crate::paths::abbreviate_home(&path)
-        ));
+        output.info(&t!("remove.path", path = abbreviate_home(&path)));
 
         let confirmed = Confirm::new()
             .with_prompt(t!("remove.confirm", name = name).to_string())
@@ -34,8 +106,24 @@ pub fn execute(output: &Output, name: &str, force: bool) -> Result<()> {
         }
     }
 
+    let killed = if kill {
+        kill_env_processes(output, &path, processes::running_from(&path))
+    } else {
+        Vec::new()
+    };
+
     output.info(&t!("remove.removing", name = name));
     service.delete(name)?;
+    if let Some(timeout) = wait {
+        wait_until_gone(output, &path, timeout)?;
+    }
+
+    let cleaned = clean_version_files(&pins)?;
+    if !keep_version_files {
+        if let Some(cwd) = &cwd {
+            warn_unreachable_pins(output, cwd, name);
+        }
+    }
 
     // JSON output
     if output.is_json() {
@@ -44,12 +132,202 @@ pub fn execute(output: &Output, name: &str, force: bool) -> Result<()> {
             RemoveData {
                 name: name.to_string(),
                 path: path.display().to_string(),
+                dry_run: false,
+                cleaned_version_files: display_paths(&cleaned),
+                killed_processes: killed,
             },
         );
         return Ok(());
     }
 
+    for file in &cleaned {
+        output.info(&t!(
+            "remove.version_file_cleaned",
+            path = abbreviate_home(file)
+        ));
+    }
     output.success(&t!("remove.success", name = name));
 
     Ok(())
 }
+
+/// How long killed processes get to exit before removal goes ahead anyway.
+const KILL_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Kill `running` (`--kill`), the processes found in the env at `path`,
+/// announcing each PID, and return the PIDs killed. Survivors only warn:
+/// the removal that follows reports the real failure if they still hold
+/// the directory.
+fn kill_env_processes(output: &Output, path: &Path, running: Vec<EnvProcess>) -> Vec<u32> {
+    for process in &running {
+        output.warn(&t!(
+            "remove.killing",
+            pid = process.pid,
+            exe = abbreviate_home(&process.exe)
+        ));
+    }
+    let survivors = processes::kill_and_wait(path, &running, KILL_TIMEOUT);
+    for pid in &survivors {
+        output.warn(&t!("remove.kill_failed", pid = pid));
+    }
+    running
+        .iter()
+        .map(|p| p.pid)
+        .filter(|pid| !survivors.contains(pid))
+        .collect()
+}
+
+/// How often `--wait` looks at the directory again.
+const WAIT_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Block until `path` no longer exists, or fail after `timeout`.
+fn wait_until_gone(output: &Output, path: &Path, timeout: Duration) -> Result<()> {
+    let exists = |p: &Path| std::fs::symlink_metadata(p).is_ok();
+    if !exists(path) {
+        return Ok(());
+    }
+    output.info(&t!("remove.waiting", path = abbreviate_home(path)));
+    let start = Instant::now();
+    let gone = poll_until_gone(path, timeout, WAIT_POLL_INTERVAL, exists, |step| {
+        std::thread::sleep(step);
+        start.elapsed()
+    });
+    if gone {
+        Ok(())
+    } else {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::TimedOut,
+            t!(
+                "remove.wait_timeout",
+                path = abbreviate_home(path),
+                seconds = timeout.as_secs()
+            )
+            .to_string(),
+        )
+        .into())
+    }
+}
+
+/// The polling loop behind [`wait_until_gone`], with the filesystem check
+/// and the clock passed in. `sleep` waits one `interval` and returns the
+/// time elapsed so far. Returns whether `path` disappeared in time.
+fn poll_until_gone(
+    path: &Path,
+    timeout: Duration,
+    interval: Duration,
+    exists: impl Fn(&Path) -> bool,
+    mut sleep: impl FnMut(Duration) -> Duration,
+) -> bool {
+    loop {
+        if !exists(path) {
+            return true;
+        }
+        if sleep(interval) >= timeout {
+            return !exists(path);
+        }
+    }
+}
+
+/// Delete the given version files, returning the ones actually removed.
+///
+/// A file already gone (e.g. a concurrent `scuv use --unset`) isn't an
+/// error; anything else is, since the env itself is already deleted and a
+/// silently kept pin is exactly what this cleanup exists to prevent.
+fn clean_version_files(pins: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let mut cleaned = Vec::new();
+    for pin in pins {
+        match std::fs::remove_file(pin) {
+            Ok(()) => cleaned.push(pin.clone()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(cleaned)
+}
+
+/// Warn about pins in enclosing directories, which belong to some other
+/// project and are left alone. Pins in unrelated projects can't be found
+/// at all.
+fn warn_unreachable_pins(output: &Output, cwd: &Path, name: &str) {
+    for pin in VersionService::pins_above(cwd, name) {
+        output.warn(&t!(
+            "remove.version_file_elsewhere",
+            path = abbreviate_home(&pin),
+            name = name
+        ));
+    }
+}
+
+fn display_paths(paths: &[PathBuf]) -> Vec<String> {
+    paths.iter().map(|p| p.display().to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn clean_version_files_skips_already_missing_files() {
+        let dir = TempDir::new().unwrap();
+        let present = dir.path().join(".scuv-version");
+        let missing = dir.path().join(".scoop-version");
+        std::fs::write(&present, "doomed\n").unwrap();
+
+        let cleaned = clean_version_files(&[present.clone(), missing]).unwrap();
+        assert_eq!(cleaned, vec![present.clone()]);
+        assert!(!present.exists());
+    }
+
+    #[test]
+    fn poll_until_gone_returns_once_the_directory_disappears() {
+        use std::cell::Cell;
+
+        // Still listed for three polls, then gone.
+        let checks = Cell::new(0);
+        let exists = |_: &Path| {
+            checks.set(checks.get() + 1);
+            checks.get() <= 3
+        };
+        let mut elapsed = Duration::ZERO;
+        let gone = poll_until_gone(
+            Path::new("/envs/web"),
+            Duration::from_secs(30),
+            Duration::from_millis(200),
+            exists,
+            |step| {
+                elapsed += step;
+                elapsed
+            },
+        );
+        assert!(gone);
+        assert_eq!(checks.get(), 4);
+        assert_eq!(elapsed, Duration::from_millis(600));
+    }
+
+    #[test]
+    fn poll_until_gone_gives_up_after_the_timeout() {
+        let mut sleeps = 0;
+        let gone = poll_until_gone(
+            Path::new("/envs/web"),
+            Duration::from_secs(1),
+            Duration::from_millis(200),
+            |_| true,
+            |step| {
+                sleeps += 1;
+                step * sleeps
+            },
+        );
+        assert!(!gone);
+        assert_eq!(sleeps, 5);
+    }
+
+    #[test]
+    fn wait_until_gone_is_immediate_for_a_missing_path() {
+        let dir = TempDir::new().unwrap();
+        let output = Output::new(0, true, crate::cli::ColorChoice::Never, false);
+        let start = Instant::now();
+        wait_until_gone(&output, &dir.path().join("gone"), Duration::from_secs(5)).unwrap();
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+}
@@ -1,12 +1,95 @@
 //! Resolve command
 
-use crate::core::VersionService;
+use std::path::Path;
+
+use crate::core::{ResolutionStep, VersionService};
 use crate::error::Result;
+use crate::output::{Output, ResolveData};
 
 /// Execute the resolve command
-pub fn execute() -> Result<()> {
-    if let Some(env_name) = VersionService::resolve_current() {
-        println!("{env_name}");
+///
+/// With `explain` (or `--json`) every source consulted is shown, not just
+/// the winner. The plain form stays a single line for the shell hooks.
+pub fn execute(output: &Output, explain: bool) -> Result<()> {
+    if !explain && !output.is_json() {
+        if let Some(env_name) = VersionService::resolve_current() {
+            println!("{env_name}");
+        }
+        return Ok(());
+    }
+
+    let cwd = std::env::current_dir()?;
+    let chain = VersionService::explain(&cwd);
+    let name = chain.last().and_then(|step| step.value.clone());
+
+    if output.is_json() {
+        output.json_success("resolve", ResolveData { name, chain });
+        return Ok(());
+    }
+
+    for step in &chain {
+        println!("{}", describe(step));
+    }
+    match chain.last() {
+        Some(ResolutionStep {
+            source,
+            value: Some(value),
+            ..
+        }) => println!("resolved: {value} ({source})"),
+        _ => println!("resolved: nothing (no environment selected)"),
     }
     Ok(())
 }
+
+/// One line of `--explain` output: where the step looked and what it found.
+fn describe(step: &ResolutionStep) -> String {
+    let location = step
+        .path
+        .as_deref()
+        .map(Path::display)
+        .map(|p| format!(" {p}"))
+        .unwrap_or_default();
+    let found = match (&step.value, &step.path) {
+        (Some(value), _) => format!("-> {value}"),
+        (None, Some(path)) if path.exists() => "(no valid name)".to_string(),
+        (None, Some(_)) => "(not found)".to_string(),
+        (None, None) => "(unset)".to_string(),
+    };
+    format!("{:<14}{location} {found}", step.source)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn describe_names_source_path_and_outcome() {
+        let tmp = tempfile::tempdir().unwrap();
+        let empty = tmp.path().join(".scuv-version");
+        std::fs::write(&empty, "\n").unwrap();
+        let missing = PathBuf::from("/nonexistent/.scuv-version");
+
+        let step = |source, path: Option<&Path>, value: Option<&str>| ResolutionStep {
+            source,
+            path: path.map(Path::to_path_buf),
+            value: value.map(String::from),
+        };
+        assert_eq!(
+            describe(&step("SCUV_VERSION", None, None)),
+            "SCUV_VERSION   (unset)"
+        );
+        assert_eq!(
+            describe(&step("SCUV_VERSION", None, Some("web"))),
+            "SCUV_VERSION   -> web"
+        );
+        assert_eq!(
+            describe(&step("local", Some(&missing), None)),
+            "local          /nonexistent/.scuv-version (not found)"
+        );
+        assert_eq!(
+            describe(&step("local", Some(&empty), None)),
+            format!("local          {} (no valid name)", empty.display())
+        );
+    }
+}
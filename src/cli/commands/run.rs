@@ -34,6 +34,11 @@ pub fn execute(_output: &Output, env_name: &str, command: &[String]) -> Result<(
 
     let venv_path = service.get_path(env_name)?;
     let bin_dir = paths::virtualenv_bin(env_name)?;
+    // Running the env's interpreter: say the env is broken rather than
+    // letting a dangling `bin/python` fall back to a system Python on PATH.
+    if matches!(command[0].as_str(), "python" | "python.exe") {
+        service.python_executable(env_name)?;
+    }
 
     // Touch BEFORE spawn: long-running commands shouldn't leave the env
     // looking idle, and a child crash shouldn't lose the "we used it"
@@ -110,6 +115,7 @@ fn signal_exit_code(_status: &std::process::ExitStatus) -> Option<i32> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::ColorChoice;
     use crate::test_utils::with_temp_scoop_home;
     use serial_test::serial;
     use std::path::PathBuf;
@@ -204,7 +210,7 @@ mod tests {
     fn execute_returns_invalid_env_name() {
         with_temp_scoop_home(|temp_dir| {
             std::fs::create_dir_all(temp_dir.path().join("virtualenvs")).unwrap();
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             let err = execute(&output, "../bad", &["python".to_string()]).unwrap_err();
             assert!(matches!(err, ScoopError::InvalidEnvName { .. }));
         });
@@ -215,7 +221,7 @@ mod tests {
     fn execute_returns_not_found_for_missing_env() {
         with_temp_scoop_home(|temp_dir| {
             std::fs::create_dir_all(temp_dir.path().join("virtualenvs")).unwrap();
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             let err = execute(&output, "ghost", &["python".to_string()]).unwrap_err();
             assert!(matches!(err, ScoopError::VirtualenvNotFound { .. }));
         });
@@ -231,7 +237,7 @@ mod tests {
                 .join("myenv")
                 .join("bin");
             std::fs::create_dir_all(&bin).unwrap();
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             let err = execute(&output, "myenv", &[]).unwrap_err();
             assert!(matches!(err, ScoopError::InvalidArgument { .. }));
         });
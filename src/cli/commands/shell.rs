@@ -8,16 +8,20 @@ use crate::error::{Result, ScoopError};
 use crate::output::Output;
 use crate::paths;
 use crate::shell::{
-    detect_shell, print_activate_script, print_deactivate_script, print_export_scoop_version,
-    print_unset_scoop_version,
+    PathPosition, detect_shell, print_activate_script, print_deactivate_script,
+    print_export_scoop_version, print_unset_scoop_version, stack,
 };
 use crate::validate::validate_env_name;
 
 /// Execute the shell command - outputs shell code for eval
+///
+/// With `stack`, the currently active env is pushed onto `_SCUV_STACK` so a
+/// later `scuv deactivate` returns to it (see [`stack`]).
 pub fn execute(
     output: &Output,
     name: Option<&str>,
     unset: bool,
+    stack: bool,
     shell: Option<ShellType>,
 ) -> Result<()> {
     // Detect shell or use specified
@@ -26,6 +30,9 @@ pub fn execute(
     // Handle --unset
     if unset {
         print_unset_scoop_version(shell_type);
+        // Without a pin, the prompt hook's own `scuv deactivate` calls
+        // would start popping stale entries.
+        stack::print_set(shell_type, None);
 
         if !output.is_json() && !output.is_quiet() {
             eprintln!("{}", t!("shell.unset"));
@@ -40,6 +47,9 @@ pub fn execute(
 
     // Handle "system" special value
     if name.eq_ignore_ascii_case("system") {
+        if stack {
+            push_active(shell_type, name);
+        }
         print_export_scoop_version(shell_type, "system");
         // Also output deactivate script
         print_deactivate_script(shell_type);
@@ -57,8 +67,17 @@ pub fn execute(
     let bin_path = paths::virtualenv_bin(name)?;
 
     // Output shell script for eval
+    if stack {
+        push_active(shell_type, name);
+    }
     print_export_scoop_version(shell_type, name);
-    print_activate_script(shell_type, &venv_path, &bin_path, name);
+    print_activate_script(
+        shell_type,
+        &venv_path,
+        &bin_path,
+        name,
+        PathPosition::resolve(false),
+    );
 
     // `scuv shell` activates without flowing through `scuv activate`, so
     // touch explicitly. Best-effort — never blocks the shell switch on
@@ -71,3 +90,18 @@ pub fn execute(
 
     Ok(())
 }
+
+/// Push the currently active env (if any, and if it isn't `target`).
+///
+/// Nothing active means there's nothing to return to: `deactivate` then
+/// does its usual full restore.
+fn push_active(shell_type: ShellType, target: &str) {
+    let Ok(active) = std::env::var("SCUV_ACTIVE") else {
+        return;
+    };
+    if active.is_empty() || active == target {
+        return;
+    }
+    let current = std::env::var(stack::STACK_ENV).ok();
+    stack::print_set(shell_type, Some(&stack::push(current.as_deref(), &active)));
+}
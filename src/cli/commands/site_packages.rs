@@ -0,0 +1,13 @@
+//! Hidden `__site-packages` helper for tools that integrate with scuv.
+
+use crate::core::VirtualenvService;
+use crate::error::Result;
+
+/// Print the `site-packages` directory of env `name`.
+///
+/// Only reads files inside the env, so it works without uv installed.
+pub fn execute(name: &str) -> Result<()> {
+    let service = VirtualenvService::filesystem_only();
+    println!("{}", service.site_packages(name)?.display());
+    Ok(())
+}
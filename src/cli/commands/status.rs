@@ -176,6 +176,7 @@ fn emit_env(output: &Output, json: bool, name: &str, source: &'static str) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::ColorChoice;
     use crate::test_utils::with_temp_scoop_home;
     use serial_test::serial;
     use tempfile::TempDir;
@@ -292,7 +293,7 @@ mod tests {
             let prev = std::env::current_dir().ok();
             std::env::set_current_dir(workdir.path()).unwrap();
 
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             assert!(execute(&output).is_ok(), "None state");
 
             // Active state with no on-disk env: must still succeed.
@@ -30,7 +30,7 @@ pub fn execute(output: &Output, extra_groups: &[String], dry_run: bool) -> Resul
         }
     }
 
-    let service = VirtualenvService::auto()?;
+    let service = VirtualenvService::for_output(output)?;
     let env_name = parsed.environment.name.clone();
     let wanted_python = parsed.environment.python.clone();
     let env_existed = service.exists(&env_name)?;
@@ -183,6 +183,7 @@ fn emit_plan(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::ColorChoice;
     use crate::test_utils::with_temp_scoop_home;
     use serial_test::serial;
     use tempfile::TempDir;
@@ -252,7 +253,7 @@ mod tests {
             let prev = std::env::current_dir().ok();
             std::env::set_current_dir(workdir.path()).unwrap();
 
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             let result = execute(&output, &[], false);
 
             if let Some(p) = prev {
@@ -294,7 +295,7 @@ mod tests {
             let prev = std::env::current_dir().ok();
             std::env::set_current_dir(workdir.path()).unwrap();
 
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             let result = execute(&output, &["ghost".to_string()], true /* dry-run */);
 
             if let Some(p) = prev {
@@ -327,7 +328,7 @@ mod tests {
             let prev = std::env::current_dir().ok();
             std::env::set_current_dir(workdir.path()).unwrap();
 
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             let result = execute(&output, &[], true /* dry-run */);
 
             if let Some(p) = prev {
@@ -0,0 +1,184 @@
+//! Handlers for the `scuv tag` and `scuv untag` commands.
+//!
+//! Tags are free-form labels stored in the env's metadata file and used by
+//! `scuv list --tag` to filter environments.
+
+use rust_i18n::t;
+
+use crate::core::{Metadata, VirtualenvService};
+use crate::error::{Result, ScoopError};
+use crate::output::{Output, TagData};
+use crate::validate;
+
+/// Execute the `tag` command: add tags to an environment.
+pub fn execute(output: &Output, name: &str, tags: &[String]) -> Result<()> {
+    let service = VirtualenvService::auto()?;
+    let (meta, changed) = update_tags(&service, name, |m| m.add_tags(tags.iter().cloned()), tags)?;
+    report(output, "tag", &meta, changed);
+    Ok(())
+}
+
+/// Execute the `untag` command: remove tags from an environment.
+///
+/// Removing a tag the env doesn't carry is not an error, mirroring how
+/// adding an existing tag is a no-op.
+pub fn execute_untag(output: &Output, name: &str, tags: &[String]) -> Result<()> {
+    let service = VirtualenvService::auto()?;
+    let (meta, changed) = update_tags(&service, name, |m| m.remove_tags(tags), tags)?;
+    report(output, "untag", &meta, changed);
+    Ok(())
+}
+
+/// Validate, load metadata, apply `edit`, and write back if it changed.
+///
+/// Envs without a metadata file are refused rather than given a synthesized
+/// one — same policy as `touch_metadata_best_effort`, which won't invent a
+/// Python version or creation time it doesn't know.
+fn update_tags(
+    service: &VirtualenvService,
+    name: &str,
+    edit: impl FnOnce(&mut Metadata) -> bool,
+    tags: &[String],
+) -> Result<(Metadata, bool)> {
+    for tag in tags {
+        validate::validate_tag(tag)?;
+    }
+
+    let path = service.get_path(name)?;
+    let mut meta =
+        service
+            .read_metadata_result(&path)?
+            .ok_or_else(|| ScoopError::InvalidArgument {
+                message: t!("tag.no_metadata", name = name).to_string(),
+            })?;
+
+    let changed = edit(&mut meta);
+    if changed {
+        service.write_metadata_atomic(&path, &meta)?;
+    }
+    Ok((meta, changed))
+}
+
+fn report(output: &Output, command: &'static str, meta: &Metadata, changed: bool) {
+    if output.is_json() {
+        output.json_success(
+            command,
+            TagData {
+                name: meta.name.clone(),
+                tags: meta.tags.clone(),
+                changed,
+            },
+        );
+        return;
+    }
+
+    let tags = if meta.tags.is_empty() {
+        "-".to_string()
+    } else {
+        meta.tags.join(", ")
+    };
+    if changed {
+        output.success(&t!("tag.updated", name = meta.name, tags = tags));
+    } else {
+        output.info(&t!("tag.unchanged", name = meta.name, tags = tags));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::with_temp_scoop_home;
+    use crate::uv::UvClient;
+    use serial_test::serial;
+    use std::path::PathBuf;
+
+    fn service() -> VirtualenvService {
+        VirtualenvService::new(UvClient::with_path(PathBuf::from("/mock/uv")))
+    }
+
+    fn make_env(name: &str, with_metadata: bool) -> PathBuf {
+        let path = crate::paths::virtualenv_path(name).unwrap();
+        std::fs::create_dir_all(&path).unwrap();
+        if with_metadata {
+            let meta = Metadata::new(name.to_string(), "3.12".to_string(), None);
+            service().write_metadata_atomic(&path, &meta).unwrap();
+        }
+        path
+    }
+
+    fn strings(tags: &[&str]) -> Vec<String> {
+        tags.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    #[serial]
+    fn add_then_remove_persists_tags() {
+        with_temp_scoop_home(|_| {
+            let path = make_env("myenv", true);
+            let svc = service();
+            let add = strings(&["web", "api"]);
+
+            let (meta, changed) =
+                update_tags(&svc, "myenv", |m| m.add_tags(add.iter().cloned()), &add).unwrap();
+            assert!(changed);
+            assert_eq!(meta.tags, vec!["api", "web"]);
+
+            let remove = strings(&["web"]);
+            update_tags(&svc, "myenv", |m| m.remove_tags(&remove), &remove).unwrap();
+
+            let on_disk = svc.read_metadata(&path).unwrap();
+            assert_eq!(on_disk.tags, vec!["api"]);
+            assert_eq!(on_disk.python_version, "3.12");
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn invalid_tag_is_rejected_before_writing() {
+        with_temp_scoop_home(|_| {
+            let path = make_env("myenv", true);
+            let svc = service();
+            let add = strings(&["ok", "not ok"]);
+
+            let err =
+                update_tags(&svc, "myenv", |m| m.add_tags(add.iter().cloned()), &add).unwrap_err();
+            assert!(matches!(err, ScoopError::InvalidArgument { .. }));
+            assert!(svc.read_metadata(&path).unwrap().tags.is_empty());
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn missing_metadata_is_refused() {
+        with_temp_scoop_home(|_| {
+            let path = make_env("legacy", false);
+            let add = strings(&["web"]);
+
+            let err = update_tags(
+                &service(),
+                "legacy",
+                |m| m.add_tags(add.iter().cloned()),
+                &add,
+            )
+            .unwrap_err();
+            assert!(matches!(err, ScoopError::InvalidArgument { .. }));
+            assert!(!path.join(Metadata::FILE_NAME).exists());
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn missing_env_is_not_found() {
+        with_temp_scoop_home(|_| {
+            let add = strings(&["web"]);
+            let err = update_tags(
+                &service(),
+                "ghost",
+                |m| m.add_tags(add.iter().cloned()),
+                &add,
+            )
+            .unwrap_err();
+            assert!(matches!(err, ScoopError::VirtualenvNotFound { .. }));
+        });
+    }
+}
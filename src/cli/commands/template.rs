@@ -0,0 +1,143 @@
+//! Handlers for `scuv template list` and `scuv template show`.
+//!
+//! Templates live under `templates` in `~/.scuv/config.json`; each names a
+//! Python version and packages that `scuv create --template` applies.
+
+use rust_i18n::t;
+
+use crate::config::{Config, TemplateConfig};
+use crate::error::{Result, ScoopError};
+use crate::output::{Output, TemplateData, TemplateListData};
+use crate::validate;
+
+/// Look up template `name` in config.json.
+///
+/// # Errors
+///
+/// Returns [`ScoopError::InvalidArgument`] if `name` is not a valid
+/// template name or no such template is defined.
+pub(super) fn load(name: &str) -> Result<TemplateConfig> {
+    validate::validate_template_name(name)?;
+    Config::load()?
+        .templates
+        .remove(name)
+        .ok_or_else(|| ScoopError::InvalidArgument {
+            message: t!("template.not_found", name = name).to_string(),
+        })
+}
+
+/// Execute `template list`: every template with its Python and package count.
+pub fn execute_list(output: &Output) -> Result<()> {
+    let templates: Vec<TemplateData> = Config::load()?
+        .templates
+        .into_iter()
+        .map(|(name, template)| template_data(name, template))
+        .collect();
+
+    if output.is_json() {
+        output.json_success("template list", TemplateListData { templates });
+        return Ok(());
+    }
+
+    if templates.is_empty() {
+        output.info(&t!("template.none"));
+        return Ok(());
+    }
+    let width = templates.iter().map(|t| t.name.len()).max().unwrap_or(0);
+    for template in &templates {
+        println!(
+            "{:width$}  python {:8}  {} package(s)",
+            template.name,
+            template.python.as_deref().unwrap_or("-"),
+            template.packages.len()
+        );
+    }
+    Ok(())
+}
+
+/// Execute `template show`: one template's Python and full package list.
+pub fn execute_show(output: &Output, name: &str) -> Result<()> {
+    let data = template_data(name.to_string(), load(name)?);
+
+    if output.is_json() {
+        output.json_success("template show", data);
+        return Ok(());
+    }
+
+    println!("Name:     {}", data.name);
+    println!("Python:   {}", data.python.as_deref().unwrap_or("-"));
+    if data.packages.is_empty() {
+        println!("Packages: -");
+    } else {
+        println!("Packages: {}", data.packages.len());
+        for package in &data.packages {
+            println!("  {package}");
+        }
+    }
+    Ok(())
+}
+
+fn template_data(name: String, template: TemplateConfig) -> TemplateData {
+    TemplateData {
+        name,
+        python: template.python,
+        packages: template.packages,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::ColorChoice;
+    use crate::test_utils::with_temp_scoop_home;
+    use serial_test::serial;
+
+    fn save_templates() {
+        let mut config = Config::default();
+        config.templates.insert(
+            "data-science".to_string(),
+            TemplateConfig {
+                python: Some("3.12".to_string()),
+                packages: vec!["numpy".to_string(), "pandas>=2".to_string()],
+            },
+        );
+        config.save().unwrap();
+    }
+
+    #[test]
+    #[serial]
+    fn load_finds_configured_template() {
+        with_temp_scoop_home(|_| {
+            save_templates();
+            let template = load("data-science").unwrap();
+            assert_eq!(template.python.as_deref(), Some("3.12"));
+            assert_eq!(template.packages, ["numpy", "pandas>=2"]);
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn load_rejects_unknown_and_invalid_names() {
+        with_temp_scoop_home(|_| {
+            save_templates();
+            let err = load("web").unwrap_err();
+            assert!(err.to_string().contains("'web'"), "{err}");
+            assert!(matches!(
+                load("../data-science"),
+                Err(ScoopError::InvalidArgument { .. })
+            ));
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn list_and_show_succeed_with_and_without_templates() {
+        with_temp_scoop_home(|_| {
+            let output = Output::new(0, true, ColorChoice::Never, true);
+            execute_list(&output).unwrap();
+            save_templates();
+            execute_list(&output).unwrap();
+            execute_show(&output, "data-science").unwrap();
+        });
+    }
+}
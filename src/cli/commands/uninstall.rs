@@ -12,7 +12,17 @@ use crate::uv::UvClient;
 use crate::validate::PythonVersion;
 
 /// Execute the uninstall command
-pub fn execute(output: &Output, version: &str, cascade: bool, force: bool) -> Result<()> {
+pub fn execute(
+    output: &Output,
+    version: &str,
+    cascade: bool,
+    force: bool,
+    dry_run: bool,
+) -> Result<()> {
+    if dry_run {
+        return preview(output, version, cascade);
+    }
+
     let uv = UvClient::new()?;
 
     let mut removed_envs: Option<Vec<String>> = None;
@@ -33,6 +43,8 @@ pub fn execute(output: &Output, version: &str, cascade: bool, force: bool) -> Re
             UninstallData {
                 version: version.to_string(),
                 removed_envs,
+                dependent_envs: None,
+                dry_run: false,
             },
         );
         return Ok(());
@@ -43,29 +55,73 @@ pub fn execute(output: &Output, version: &str, cascade: bool, force: bool) -> Re
     Ok(())
 }
 
-/// Handle cascade removal of environments using the target Python version.
-///
-/// Returns the list of environment names that were removed.
-fn handle_cascade(output: &Output, version: &str, force: bool) -> Result<Vec<String>> {
+/// `--dry-run`: report the environments that depend on `version` and what
+/// would happen to them, without touching uv or the filesystem.
+fn preview(output: &Output, version: &str, cascade: bool) -> Result<()> {
     let service = VirtualenvService::auto()?;
-    let envs = service.list()?;
+    let dependents = envs_using_python(&service, version)?;
+
+    if output.is_json() {
+        output.json_success(
+            "uninstall",
+            UninstallData {
+                version: version.to_string(),
+                removed_envs: None,
+                dependent_envs: Some(dependents),
+                dry_run: true,
+            },
+        );
+        return Ok(());
+    }
+
+    output.info(&t!("uninstall.dry_run", version = version));
+    if dependents.is_empty() {
+        output.info(&t!("uninstall.cascade_none", version = version));
+        return Ok(());
+    }
+
+    output.info(&t!(
+        "uninstall.cascade_found",
+        count = dependents.len(),
+        version = version
+    ));
+    for name in &dependents {
+        output.info(&t!("uninstall.cascade_env", name = name));
+    }
+    if cascade {
+        output.info(&t!("uninstall.dry_run_would_remove"));
+    } else {
+        output.warn(&t!("uninstall.dry_run_would_break"));
+    }
+
+    Ok(())
+}
 
-    // Parse the target version for prefix matching
+/// Names of environments whose metadata Python version matches `version`
+/// (prefix match, so `3.12` covers `3.12.1`). Envs without metadata are
+/// skipped: there is no recorded version to compare.
+pub(super) fn envs_using_python(service: &VirtualenvService, version: &str) -> Result<Vec<String>> {
     let version_filter = PythonVersion::parse(version);
 
-    // Find environments using this Python version
-    let matching_envs: Vec<String> = envs
+    Ok(service
+        .list()?
         .into_iter()
-        .filter(|env| {
-            match (&version_filter, &env.python_version) {
-                (Some(filter), Some(env_ver)) => {
-                    PythonVersion::parse(env_ver).is_some_and(|v| filter.matches(&v))
-                }
-                _ => false, // Skip envs with no metadata
+        .filter(|env| match (&version_filter, &env.python_version) {
+            (Some(filter), Some(env_ver)) => {
+                PythonVersion::parse(env_ver).is_some_and(|v| filter.matches(&v))
             }
+            _ => false,
         })
         .map(|env| env.name)
-        .collect();
+        .collect())
+}
+
+/// Handle cascade removal of environments using the target Python version.
+///
+/// Returns the list of environment names that were removed.
+fn handle_cascade(output: &Output, version: &str, force: bool) -> Result<Vec<String>> {
+    let service = VirtualenvService::auto()?;
+    let matching_envs = envs_using_python(&service, version)?;
 
     // No matching environments
     if matching_envs.is_empty() {
@@ -127,6 +183,7 @@ fn handle_cascade(output: &Output, version: &str, force: bool) -> Result<Vec<Str
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::ColorChoice;
     use crate::error::ScoopError;
     use crate::uv::UvClient;
 
@@ -139,6 +196,8 @@ mod tests {
         let data = UninstallData {
             version: "3.12.0".to_string(),
             removed_envs: None,
+            dependent_envs: None,
+            dry_run: false,
         };
 
         let json = serde_json::to_string(&data).unwrap();
@@ -158,6 +217,8 @@ mod tests {
         let original = UninstallData {
             version: "3.11.5".to_string(),
             removed_envs: None,
+            dependent_envs: None,
+            dry_run: false,
         };
 
         let json = serde_json::to_string(&original).unwrap();
@@ -171,6 +232,8 @@ mod tests {
         let data = UninstallData {
             version: "3.12".to_string(),
             removed_envs: Some(vec!["env1".to_string(), "env2".to_string()]),
+            dependent_envs: None,
+            dry_run: false,
         };
 
         let json = serde_json::to_string(&data).unwrap();
@@ -188,6 +251,8 @@ mod tests {
         let original = UninstallData {
             version: "3.12".to_string(),
             removed_envs: Some(vec!["web".to_string(), "api".to_string()]),
+            dependent_envs: None,
+            dry_run: false,
         };
 
         let json = serde_json::to_string(&original).unwrap();
@@ -201,6 +266,8 @@ mod tests {
         let data = UninstallData {
             version: "3.12".to_string(),
             removed_envs: Some(vec![]),
+            dependent_envs: None,
+            dry_run: false,
         };
 
         let json = serde_json::to_string(&data).unwrap();
@@ -219,6 +286,8 @@ mod tests {
         let original = UninstallData {
             version: String::new(),
             removed_envs: None,
+            dependent_envs: None,
+            dry_run: false,
         };
 
         let json = serde_json::to_string(&original).unwrap();
@@ -243,6 +312,8 @@ mod tests {
             let original = UninstallData {
                 version: version.to_string(),
                 removed_envs: None,
+                dependent_envs: None,
+                dry_run: false,
             };
 
             let json = serde_json::to_string(&original).unwrap();
@@ -273,6 +344,8 @@ mod tests {
             let original = UninstallData {
                 version: version.to_string(),
                 removed_envs: None,
+                dependent_envs: None,
+                dry_run: false,
             };
 
             let json = serde_json::to_string(&original).unwrap();
@@ -292,6 +365,8 @@ mod tests {
         let original = UninstallData {
             version: long_version,
             removed_envs: None,
+            dependent_envs: None,
+            dry_run: false,
         };
 
         let json = serde_json::to_string(&original).unwrap();
@@ -301,6 +376,44 @@ mod tests {
         assert_eq!(restored.version.len(), 10_007);
     }
 
+    #[test]
+    fn uninstall_data_dry_run_lists_dependents() {
+        let data = UninstallData {
+            version: "3.12".to_string(),
+            removed_envs: None,
+            dependent_envs: Some(vec!["web".to_string()]),
+            dry_run: true,
+        };
+
+        let parsed: serde_json::Value = serde_json::to_value(&data).unwrap();
+        assert_eq!(parsed["dry_run"], true);
+        assert_eq!(parsed["dependent_envs"][0], "web");
+        assert!(parsed.get("removed_envs").is_none());
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn envs_using_python_prefix_matches_metadata() {
+        crate::test_utils::with_temp_scoop_home(|_| {
+            let service =
+                VirtualenvService::new(UvClient::with_path(std::path::PathBuf::from("/mock/uv")));
+            for (name, ver) in [("web", "3.12.1"), ("api", "3.12"), ("old", "3.11.9")] {
+                let path = crate::paths::virtualenv_path(name).unwrap();
+                std::fs::create_dir_all(&path).unwrap();
+                let meta = crate::core::Metadata::new(name.to_string(), ver.to_string(), None);
+                service.write_metadata_atomic(&path, &meta).unwrap();
+            }
+            // No metadata: never counted as dependent.
+            std::fs::create_dir_all(crate::paths::virtualenv_path("bare").unwrap()).unwrap();
+
+            assert_eq!(
+                envs_using_python(&service, "3.12").unwrap(),
+                vec!["api", "web"]
+            );
+            assert!(envs_using_python(&service, "3.13").unwrap().is_empty());
+        });
+    }
+
     // =========================================================================
     // execute Error Handling Tests
     // =========================================================================
@@ -311,8 +424,8 @@ mod tests {
             return;
         }
 
-        let output = Output::new(0, true, true, false);
-        let result = execute(&output, "3.12.0", false, false);
+        let output = Output::new(0, true, ColorChoice::Never, false);
+        let result = execute(&output, "3.12.0", false, false, false);
 
         assert!(result.is_err());
         let err = result.unwrap_err();
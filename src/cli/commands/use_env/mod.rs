@@ -5,25 +5,44 @@
 //! - Handles the "system" special value
 //! - Supports --unset to remove version files
 //! - Optionally creates .venv symlinks
+//! - Optionally creates the environment itself (`--create`)
+//! - Optionally prints the activation script for eval (`--print`)
 
 mod normal;
 mod output;
-mod symlink;
+pub(super) mod symlink;
 mod system;
 mod unset;
 
 use rust_i18n::t;
 
+use crate::cli::ShellType;
 use crate::error::{Result, ScoopError};
 use crate::output::Output;
 
+/// `scuv use --print`: after pinning, print the activation script to stdout
+/// so `eval "$(scuv use NAME --print)"` works without the shell wrapper.
+#[derive(Debug, Clone, Copy)]
+pub struct PrintScript {
+    /// Shell to print for (`--shell`); detected when `None`.
+    pub shell: Option<ShellType>,
+    /// Put the env's bin at the end of `PATH` (`--append-path`).
+    pub append_path: bool,
+}
+
 /// Execute the use command
+///
+/// With `create` (the Python version from `--create [PYTHON]`), a missing
+/// environment is created before it is selected. With `print`, the script
+/// activating it (or, for `system`, deactivating) is printed afterwards.
 pub fn execute(
     output: &Output,
     name: Option<&str>,
     unset: bool,
     global: bool,
     link: bool,
+    create: Option<&str>,
+    print: Option<PrintScript>,
 ) -> Result<()> {
     let cwd = std::env::current_dir()?;
 
@@ -39,9 +58,20 @@ pub fn execute(
 
     // Handle "system" special value (case-insensitive)
     if name.eq_ignore_ascii_case("system") {
-        return system::handle(output, &cwd, global);
+        if create.is_some() {
+            return Err(ScoopError::InvalidArgument {
+                message: t!("error.use_create_system").to_string(),
+            });
+        }
+        system::handle(output, &cwd, global)?;
+        if let Some(print) = print {
+            crate::shell::print_deactivate_script(
+                print.shell.unwrap_or_else(crate::shell::detect_shell),
+            );
+        }
+        return Ok(());
     }
 
     // Handle normal environment
-    normal::handle(output, &cwd, name, global, link)
+    normal::handle(output, &cwd, name, global, link, create, print)
 }
@@ -7,13 +7,33 @@ use rust_i18n::t;
 use crate::core::{VersionService, VirtualenvService};
 use crate::error::Result;
 use crate::output::{Output, UseData};
+use crate::shell::{PathPosition, activate_script, detect_shell};
+use crate::{paths, validate};
 
+use super::super::create::reject_case_collision;
+use super::PrintScript;
 use super::output::output_result;
 use super::symlink::create_venv_symlink;
 
 /// Handle `scuv use <name>` (normal environment)
-pub fn handle(output: &Output, cwd: &Path, name: &str, global: bool, link: bool) -> Result<()> {
-    let service = VirtualenvService::auto()?;
+pub fn handle(
+    output: &Output,
+    cwd: &Path,
+    name: &str,
+    global: bool,
+    link: bool,
+    create: Option<&str>,
+    print: Option<PrintScript>,
+) -> Result<()> {
+    let service = VirtualenvService::for_output(output)?;
+
+    let created = match create {
+        Some(python) if !service.exists(name)? => {
+            create_env(output, &service, name, python)?;
+            true
+        }
+        _ => false,
+    };
 
     // Verify environment exists
     let venv_path = service.get_path(name)?;
@@ -27,9 +47,10 @@ pub fn handle(output: &Output, cwd: &Path, name: &str, global: bool, link: bool)
                 mode: "global",
                 version_file: None,
                 symlink: None,
+                env_created: created,
             },
             &t!("use.set_global", name = name),
-        )
+        )?;
     } else {
         VersionService::set_local(cwd, name)?;
 
@@ -38,8 +59,9 @@ pub fn handle(output: &Output, cwd: &Path, name: &str, global: bool, link: bool)
         // Create .venv symlink only if --link flag is provided
         if link {
             let venv_link = cwd.join(".venv");
-            create_venv_symlink(&venv_link, &venv_path, output)?;
-            symlink_path = Some(venv_link.display().to_string());
+            if create_venv_symlink(&venv_link, &venv_path, output)? {
+                symlink_path = Some(venv_link.display().to_string());
+            }
         }
 
         output_result(
@@ -49,8 +71,90 @@ pub fn handle(output: &Output, cwd: &Path, name: &str, global: bool, link: bool)
                 mode: "local",
                 version_file: Some(cwd.join(crate::paths::VERSION_FILE).display().to_string()),
                 symlink: symlink_path,
+                env_created: created,
             },
             &t!("use.set_local", name = name),
-        )
+        )?;
+    }
+
+    // Printed last, so a failure above leaves nothing for `eval` to run.
+    if let Some(print) = print {
+        print!("{}", activation_script(name, &venv_path, print)?);
+        // Like `scuv activate`, this counts as using the env.
+        service.touch_metadata_best_effort(name);
+    }
+    Ok(())
+}
+
+/// The `--print` script activating `name` at `venv_path`, as
+/// `scuv activate` prints it.
+fn activation_script(name: &str, venv_path: &Path, print: PrintScript) -> Result<String> {
+    Ok(activate_script(
+        print.shell.unwrap_or_else(detect_shell),
+        venv_path,
+        &paths::virtualenv_bin(name)?,
+        name,
+        PathPosition::resolve(print.append_path),
+    ))
+}
+
+/// Create `name` for `use --create`, with the same name checks and alias
+/// resolution as `scuv create`.
+fn create_env(
+    output: &Output,
+    service: &VirtualenvService,
+    name: &str,
+    python: &str,
+) -> Result<()> {
+    reject_case_collision(name)?;
+    let python = if validate::is_version_alias(python) {
+        service.resolve_python_alias(python)?
+    } else {
+        python.to_string()
+    };
+    output.info(&t!("create.creating", name = name, python = &python));
+    service.create(name, &python)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::ShellType;
+    use crate::test_utils::with_temp_scoop_home;
+    use serial_test::serial;
+
+    #[test]
+    #[serial]
+    fn print_script_activates_env_for_requested_shell() {
+        with_temp_scoop_home(|temp_dir| {
+            let venv = temp_dir.path().join("virtualenvs").join("web");
+            let bin = paths::virtualenv_bin("web").unwrap();
+
+            let bash = activation_script(
+                "web",
+                &venv,
+                PrintScript {
+                    shell: Some(ShellType::Bash),
+                    append_path: false,
+                },
+            )
+            .unwrap();
+            assert!(bash.contains(&format!("export VIRTUAL_ENV=\"{}\"", venv.display())));
+            assert!(bash.contains(&format!("export PATH=\"{}:$PATH\"", bin.display())));
+            assert!(bash.contains("export SCUV_ACTIVE=\"web\""));
+
+            let fish = activation_script(
+                "web",
+                &venv,
+                PrintScript {
+                    shell: Some(ShellType::Fish),
+                    append_path: true,
+                },
+            )
+            .unwrap();
+            assert!(fish.contains(&format!("set -gx PATH $PATH '{}'", bin.display())));
+            assert!(fish.contains("set -gx SCUV_ACTIVE 'web'"));
+        });
     }
 }
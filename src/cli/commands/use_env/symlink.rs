@@ -2,7 +2,7 @@
 
 use std::fs;
 use std::os::unix::fs::symlink;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use rust_i18n::t;
 
@@ -10,21 +10,113 @@ use crate::error::Result;
 use crate::output::Output;
 
 /// Create or update .venv symlink
-pub fn create_venv_symlink(link: &Path, target: &Path, output: &Output) -> Result<()> {
-    if link.exists() || link.is_symlink() {
-        if link.is_symlink() {
-            fs::remove_file(link)?;
-        } else {
-            output.warn(&t!("use.venv_not_symlink"));
-            return Ok(());
-        }
+///
+/// An existing symlink is replaced atomically: the new link is created under
+/// a temporary sibling name and renamed over `link`, so there is never a
+/// moment where `.venv` is missing. A real directory or file at `link` is
+/// never touched. Returns `false` when the link was skipped for that reason.
+pub fn create_venv_symlink(link: &Path, target: &Path, output: &Output) -> Result<bool> {
+    if link.exists() && !link.is_symlink() {
+        output.warn(&t!("use.venv_not_symlink"));
+        return Ok(false);
     }
 
-    symlink(target, link)?;
+    swap_symlink(link, target)?;
+
     output.info(&t!(
         "use.linked",
         path = crate::paths::abbreviate_home(target)
     ));
 
+    Ok(true)
+}
+
+/// Point `link` at `target` by creating the new link under a temporary
+/// sibling name and renaming it into place, so an existing `link` is
+/// replaced without ever going missing.
+pub(crate) fn swap_symlink(link: &Path, target: &Path) -> Result<()> {
+    let tmp = temp_link_path(link);
+    // Leftover from an interrupted run; it's always our own symlink.
+    if tmp.is_symlink() {
+        fs::remove_file(&tmp)?;
+    }
+    symlink(target, &tmp)?;
+    if let Err(e) = fs::rename(&tmp, link) {
+        let _ = fs::remove_file(&tmp);
+        return Err(e.into());
+    }
     Ok(())
 }
+
+/// Temporary sibling used for the create-then-rename swap.
+fn temp_link_path(link: &Path) -> PathBuf {
+    let mut name = link.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".scuv-tmp-{}", std::process::id()));
+    link.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::ColorChoice;
+    use tempfile::TempDir;
+
+    fn quiet() -> Output {
+        Output::new(0, true, ColorChoice::Never, false)
+    }
+
+    #[test]
+    fn creates_link_when_absent() {
+        let dir = TempDir::new().unwrap();
+        let target = dir.path().join("env");
+        fs::create_dir(&target).unwrap();
+        let link = dir.path().join(".venv");
+
+        assert!(create_venv_symlink(&link, &target, &quiet()).unwrap());
+        assert_eq!(fs::read_link(&link).unwrap(), target);
+    }
+
+    #[test]
+    fn replaces_existing_symlink() {
+        let dir = TempDir::new().unwrap();
+        let old = dir.path().join("old");
+        let new = dir.path().join("new");
+        fs::create_dir(&old).unwrap();
+        fs::create_dir(&new).unwrap();
+        let link = dir.path().join(".venv");
+        symlink(&old, &link).unwrap();
+
+        assert!(create_venv_symlink(&link, &new, &quiet()).unwrap());
+        assert_eq!(fs::read_link(&link).unwrap(), new);
+        assert!(
+            !temp_link_path(&link).is_symlink(),
+            "temporary link must not be left behind"
+        );
+    }
+
+    #[test]
+    fn replaces_dangling_symlink() {
+        let dir = TempDir::new().unwrap();
+        let target = dir.path().join("env");
+        fs::create_dir(&target).unwrap();
+        let link = dir.path().join(".venv");
+        symlink(dir.path().join("gone"), &link).unwrap();
+
+        assert!(create_venv_symlink(&link, &target, &quiet()).unwrap());
+        assert_eq!(fs::read_link(&link).unwrap(), target);
+    }
+
+    #[test]
+    fn refuses_to_clobber_real_directory() {
+        let dir = TempDir::new().unwrap();
+        let target = dir.path().join("env");
+        fs::create_dir(&target).unwrap();
+        let link = dir.path().join(".venv");
+        fs::create_dir(&link).unwrap();
+        fs::write(link.join("marker"), "keep").unwrap();
+
+        assert!(!create_venv_symlink(&link, &target, &quiet()).unwrap());
+        assert!(!link.is_symlink());
+        assert_eq!(fs::read_to_string(link.join("marker")).unwrap(), "keep");
+    }
+}
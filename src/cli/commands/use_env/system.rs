@@ -21,6 +21,7 @@ pub fn handle(output: &Output, cwd: &Path, global: bool) -> Result<()> {
                 mode: "global",
                 version_file: None,
                 symlink: None,
+                env_created: false,
             },
             &t!("use.system_global"),
         )
@@ -33,6 +34,7 @@ pub fn handle(output: &Output, cwd: &Path, global: bool) -> Result<()> {
                 mode: "local",
                 version_file: Some(cwd.join(crate::paths::VERSION_FILE).display().to_string()),
                 symlink: None,
+                env_created: false,
             },
             &t!("use.system_local"),
         )
@@ -21,6 +21,7 @@ pub fn handle(output: &Output, cwd: &Path, global: bool) -> Result<()> {
                 mode: "global_unset",
                 version_file: None,
                 symlink: None,
+                env_created: false,
             },
             &t!("use.global_unset"),
         )
@@ -33,6 +34,7 @@ pub fn handle(output: &Output, cwd: &Path, global: bool) -> Result<()> {
                 mode: "local_unset",
                 version_file: None,
                 symlink: None,
+                env_created: false,
             },
             &t!("use.local_unset"),
         )
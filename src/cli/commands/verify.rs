@@ -258,6 +258,7 @@ fn collect_target_envs(
                 python_version: None,
                 created_at: None,
                 last_used: None,
+                tags: Vec::new(),
             }])
         }
         None => {
@@ -534,6 +535,7 @@ fn label_for_check(name: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::ColorChoice;
     use crate::core::Metadata;
     use crate::output::Output;
     use crate::test_utils::with_temp_scoop_home;
@@ -553,11 +555,14 @@ mod tests {
         let meta = Metadata {
             name: name.to_string(),
             python_version: python_version.to_string(),
+            python_request: None,
             created_at: Utc::now(),
             created_by: "scoop test".to_string(),
             uv_version: None,
             python_path: None,
             last_used: None,
+            tags: Vec::new(),
+            deps_upgraded: false,
         };
         fs::write(
             env_path.join(".scoop-metadata.json"),
@@ -596,7 +601,7 @@ mod tests {
     fn healthy_env_passes_all_checks() {
         with_temp_scoop_home(|_| {
             let path = make_env("ok", "3.12.0");
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             // No panic, no error.
             execute(&output, Some("ok"), false).unwrap();
 
@@ -612,6 +617,7 @@ mod tests {
                 python_version: None,
                 created_at: None,
                 last_used: None,
+                tags: Vec::new(),
             };
             let report = verify_one(&service, &info, None);
             assert!(report.healthy, "report should be healthy: {:?}", report);
@@ -640,6 +646,7 @@ mod tests {
                 python_version: None,
                 created_at: None,
                 last_used: None,
+                tags: Vec::new(),
             };
             let report = verify_one(&service, &info, None);
             assert!(!report.healthy);
@@ -667,6 +674,7 @@ mod tests {
                 python_version: None,
                 created_at: None,
                 last_used: None,
+                tags: Vec::new(),
             };
             let report = verify_one(&service, &info, None);
             let py_bin = report
@@ -700,6 +708,7 @@ mod tests {
                 python_version: None,
                 created_at: None,
                 last_used: None,
+                tags: Vec::new(),
             };
             let report = verify_one(&service, &info, None);
             let cfg = report
@@ -725,6 +734,7 @@ mod tests {
                 python_version: None,
                 created_at: None,
                 last_used: None,
+                tags: Vec::new(),
             };
             let report = verify_one(&service, &info, None);
             let act = report
@@ -760,7 +770,7 @@ mod tests {
     #[serial]
     fn empty_scoop_home_emits_no_envs_message() {
         with_temp_scoop_home(|_| {
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             execute(&output, None, false).unwrap();
         });
     }
@@ -779,6 +789,7 @@ mod tests {
                 python_version: None,
                 created_at: None,
                 last_used: None,
+                tags: Vec::new(),
             };
             let report = verify_one(&service, &info, None);
             let exec = report
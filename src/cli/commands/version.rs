@@ -0,0 +1,88 @@
+//! Handler for the `scuv version` command.
+//!
+//! Prints the versions people paste into bug reports — scuv, uv, the active
+//! Python, and the OS — in one place. Unlike `--version`, this probes uv and
+//! the current environment, so it's slower but more useful for triage.
+
+use crate::core::VirtualenvService;
+use crate::error::Result;
+use crate::output::{Output, VersionData};
+use crate::uv::version as uv_version;
+
+use super::status::{State, resolve_state};
+
+/// Execute the `version` command.
+pub fn execute(output: &Output) -> Result<()> {
+    let (env, python) = active_python(resolve_state());
+    let data = VersionData {
+        scuv: env!("CARGO_PKG_VERSION"),
+        uv: uv_version::detect(),
+        env,
+        python,
+        os: os_label(),
+    };
+
+    if output.is_json() {
+        output.json_success("version", data);
+        return Ok(());
+    }
+
+    let w = 8;
+    // Direct stdout, like `status`: this *is* the command's output.
+    println!("{:w$}{}", "scuv:", data.scuv);
+    println!("{:w$}{}", "uv:", data.uv.as_deref().unwrap_or("-"));
+    match (&data.env, &data.python) {
+        (Some(env), Some(python)) => println!("{:w$}{} ({})", "python:", python, env),
+        (Some(env), None) => println!("{:w$}{}", "python:", env),
+        _ => println!("{:w$}-", "python:"),
+    }
+    println!("{:w$}{}", "os:", data.os);
+    Ok(())
+}
+
+/// Env name and Python version for the resolved state.
+///
+/// Metadata lookup is best-effort: a missing uv or env directory still
+/// reports the env name, just without a version.
+fn active_python(state: State) -> (Option<String>, Option<String>) {
+    let name = match state {
+        State::None => return (None, None),
+        State::System => return (Some("system".to_string()), None),
+        State::Active(name) | State::Configured(name) => name,
+    };
+    let python = VirtualenvService::auto().ok().and_then(|service| {
+        let path = service.get_path(&name).ok()?;
+        service.read_metadata(&path).map(|m| m.python_version)
+    });
+    (Some(name), python)
+}
+
+/// `"<os> <arch>"` for the running binary.
+fn os_label() -> String {
+    format!("{} {}", std::env::consts::OS, std::env::consts::ARCH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn active_python_none_state_reports_nothing() {
+        assert_eq!(active_python(State::None), (None, None));
+    }
+
+    #[test]
+    fn active_python_system_state_reports_system() {
+        assert_eq!(
+            active_python(State::System),
+            (Some("system".to_string()), None)
+        );
+    }
+
+    #[test]
+    fn os_label_includes_os_and_arch() {
+        let label = os_label();
+        assert!(label.starts_with(std::env::consts::OS));
+        assert!(label.ends_with(std::env::consts::ARCH));
+    }
+}
@@ -22,10 +22,17 @@ pub fn execute(output: &Output, exe: &str, env: Option<&str>) -> Result<()> {
         });
     }
 
-    let service = VirtualenvService::auto()?;
+    // Only files inside the env are read, so uv needn't be installed.
+    let service = VirtualenvService::filesystem_only();
     if !service.exists(&env_name)? {
         return Err(ScoopError::VirtualenvNotFound { name: env_name });
     }
+    // A missing or dangling interpreter means a broken env, which deserves
+    // a better answer than "not found". The printed path stays the env's
+    // own `bin/python`: the resolved base interpreter wouldn't run the env.
+    if matches!(exe, "python" | "python.exe") {
+        service.python_executable(&env_name)?;
+    }
 
     let bin_dir = paths::virtualenv_bin(&env_name)?;
     let resolved =
@@ -68,20 +75,21 @@ fn resolve_target_env(explicit: Option<&str>) -> Result<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::ColorChoice;
     use crate::test_utils::with_temp_scoop_home;
     use serial_test::serial;
     use tempfile::TempDir;
 
     #[test]
     fn execute_rejects_path_separator_in_exe() {
-        let output = Output::new(0, true, true, false);
+        let output = Output::new(0, true, ColorChoice::Never, false);
         let err = execute(&output, "../python", Some("myenv")).unwrap_err();
         assert!(matches!(err, ScoopError::ExecutableNotFound { .. }));
     }
 
     #[test]
     fn execute_rejects_empty_exe() {
-        let output = Output::new(0, true, true, false);
+        let output = Output::new(0, true, ColorChoice::Never, false);
         let err = execute(&output, "", Some("myenv")).unwrap_err();
         assert!(matches!(err, ScoopError::ExecutableNotFound { .. }));
     }
@@ -91,7 +99,7 @@ mod tests {
     fn execute_returns_not_found_for_missing_env() {
         with_temp_scoop_home(|temp_dir| {
             std::fs::create_dir_all(temp_dir.path().join("virtualenvs")).unwrap();
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             let err = execute(&output, "python", Some("nonexistent")).unwrap_err();
             assert!(matches!(err, ScoopError::VirtualenvNotFound { .. }));
         });
@@ -106,7 +114,7 @@ mod tests {
             unsafe {
                 std::env::remove_var("SCUV_ACTIVE");
             }
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             // Use a tempdir as CWD that has no .scuv-version file.
             let workdir = TempDir::new().unwrap();
             let prev = std::env::current_dir().ok();
@@ -133,9 +141,31 @@ mod tests {
             std::fs::create_dir_all(&bin).unwrap();
             std::fs::write(bin.join("pytest"), b"").unwrap();
 
-            let output = Output::new(0, true, true, false);
+            let output = Output::new(0, true, ColorChoice::Never, false);
             // The handler prints to stdout; we just need the Result to be Ok.
             assert!(execute(&output, "pytest", Some("myenv")).is_ok());
         });
     }
+
+    #[test]
+    #[serial]
+    #[cfg(unix)]
+    fn execute_reports_dangling_python_as_broken_env() {
+        with_temp_scoop_home(|temp_dir| {
+            let bin = temp_dir
+                .path()
+                .join("virtualenvs")
+                .join("myenv")
+                .join("bin");
+            std::fs::create_dir_all(&bin).unwrap();
+            std::os::unix::fs::symlink(temp_dir.path().join("gone"), bin.join("python")).unwrap();
+
+            let output = Output::new(0, true, ColorChoice::Never, false);
+            let err = execute(&output, "python", Some("myenv")).unwrap_err();
+            assert!(
+                matches!(err, ScoopError::InterpreterNotFound { ref env, .. } if env == "myenv"),
+                "got {err:?}"
+            );
+        });
+    }
 }
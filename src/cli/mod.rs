@@ -20,9 +20,51 @@ pub struct Cli {
     #[arg(short, long, global = true)]
     pub quiet: bool,
 
-    /// Disable colored output
-    #[arg(long, global = true, env = "NO_COLOR")]
+    /// Increase verbosity (can be repeated); also echoes uv's stderr
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    pub verbose: u8,
+
+    /// When to use colored output
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        value_name = "WHEN",
+        default_value_t = ColorChoice::Auto
+    )]
+    pub color: ColorChoice,
+
+    /// Disable colored output (same as --color never)
+    #[arg(long, global = true, conflicts_with = "color")]
     pub no_color: bool,
+
+    /// Use PATH as the scuv home for this command, overriding SCUV_HOME
+    /// (`doctor` accepts it more than once to check several homes)
+    #[arg(long, global = true, value_name = "PATH", action = clap::ArgAction::Append)]
+    pub root: Vec<PathBuf>,
+}
+
+impl Cli {
+    /// The color choice after folding `--no-color` into `--color`.
+    pub fn color_choice(&self) -> ColorChoice {
+        if self.no_color {
+            ColorChoice::Never
+        } else {
+            self.color
+        }
+    }
+}
+
+/// Color mode for `--color`
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Color when stderr is a terminal and `NO_COLOR` is unset
+    #[default]
+    Auto,
+    /// Always color, even when piped (e.g. into `less -R`)
+    Always,
+    /// Never color
+    Never,
 }
 
 /// Source type for migration
@@ -34,6 +76,10 @@ pub enum MigrateSource {
     Virtualenvwrapper,
     /// conda
     Conda,
+    /// Poetry-managed project virtualenvs
+    Poetry,
+    /// Hatch virtual environments
+    Hatch,
 }
 
 impl std::fmt::Display for MigrateSource {
@@ -42,10 +88,62 @@ impl std::fmt::Display for MigrateSource {
             Self::Pyenv => write!(f, "pyenv"),
             Self::Virtualenvwrapper => write!(f, "virtualenvwrapper"),
             Self::Conda => write!(f, "conda"),
+            Self::Poetry => write!(f, "poetry"),
+            Self::Hatch => write!(f, "hatch"),
         }
     }
 }
 
+/// Hook subcommands
+#[derive(Subcommand, Debug)]
+pub enum HookCommand {
+    /// Print the active environment as a prompt segment (nothing when none)
+    Prompt {
+        /// Text printed before the environment name
+        #[arg(long, default_value = "(", allow_hyphen_values = true)]
+        prefix: String,
+
+        /// Text printed after the environment name
+        #[arg(long, default_value = ")", allow_hyphen_values = true)]
+        suffix: String,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Print the directory-change hook (resolve and activate) for a shell
+    Cd {
+        /// Target shell type (auto-detected if not specified)
+        #[arg(long, value_enum)]
+        shell: Option<ShellType>,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// Template subcommands
+#[derive(Subcommand, Debug)]
+pub enum TemplateCommand {
+    /// List the templates defined in config.json
+    #[command(alias = "ls")]
+    List {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show a template's Python version and packages
+    Show {
+        /// Name of the template
+        name: String,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
 /// Migrate subcommands
 #[derive(Subcommand, Debug)]
 pub enum MigrateCommand {
@@ -58,6 +156,31 @@ pub enum MigrateCommand {
         /// Filter by source tool (pyenv, virtualenvwrapper, conda)
         #[arg(long, value_enum)]
         source: Option<MigrateSource>,
+
+        /// Compute each environment's disk size (walks every file; slower on large envs)
+        #[arg(long)]
+        sizes: bool,
+
+        /// Oldest Python MAJOR.MINOR not flagged EOL (default: 3.9)
+        #[arg(long, value_name = "VER")]
+        eol_minimum: Option<String>,
+    },
+    /// Show one environment's size, packages and status without migrating it
+    Status {
+        /// Name of the source environment
+        name: String,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Filter by source tool (pyenv, virtualenvwrapper, conda)
+        #[arg(long, value_enum)]
+        source: Option<MigrateSource>,
+
+        /// Oldest Python MAJOR.MINOR not flagged EOL (default: 3.9)
+        #[arg(long, value_name = "VER")]
+        eol_minimum: Option<String>,
     },
     /// Migrate all environments at once
     All {
@@ -81,6 +204,10 @@ pub enum MigrateCommand {
         #[arg(long)]
         strict: bool,
 
+        /// Drop packages the index no longer has instead of counting them as failures
+        #[arg(long)]
+        skip_unresolved: bool,
+
         /// Delete original environments after successful migration
         #[arg(long)]
         delete_source: bool,
@@ -88,6 +215,26 @@ pub enum MigrateCommand {
         /// Filter by source tool (pyenv, virtualenvwrapper, conda)
         #[arg(long, value_enum)]
         source: Option<MigrateSource>,
+
+        /// Oldest Python MAJOR.MINOR not flagged EOL (default: 3.9)
+        #[arg(long, value_name = "VER")]
+        eol_minimum: Option<String>,
+
+        /// Package index to reinstall packages from (default: $UV_INDEX_URL, then $PIP_INDEX_URL)
+        #[arg(long, value_name = "URL")]
+        index_url: Option<String>,
+
+        /// Remove this prefix from every environment name (applied before --add-prefix)
+        #[arg(long, value_name = "PREFIX")]
+        strip_prefix: Option<String>,
+
+        /// Prepend this prefix to every environment name (e.g. legacy-)
+        #[arg(long, value_name = "PREFIX")]
+        add_prefix: Option<String>,
+
+        /// Migrate up to N environments at once (default: one per CPU core)
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u16).range(1..))]
+        parallel: Option<u16>,
     },
     /// Migrate a specific environment
     #[command(name = "@env")]
@@ -115,6 +262,10 @@ pub enum MigrateCommand {
         #[arg(long)]
         strict: bool,
 
+        /// Drop packages the index no longer has instead of counting them as failures
+        #[arg(long)]
+        skip_unresolved: bool,
+
         /// Migrate with a different name
         #[arg(long, value_name = "NEW_NAME")]
         rename: Option<String>,
@@ -130,6 +281,26 @@ pub enum MigrateCommand {
         /// Source tool (pyenv, virtualenvwrapper, conda)
         #[arg(long, value_enum)]
         source: Option<MigrateSource>,
+
+        /// Oldest Python MAJOR.MINOR not flagged EOL (default: 3.9)
+        #[arg(long, value_name = "VER")]
+        eol_minimum: Option<String>,
+
+        /// Package index to reinstall packages from (default: $UV_INDEX_URL, then $PIP_INDEX_URL)
+        #[arg(long, value_name = "URL")]
+        index_url: Option<String>,
+
+        /// Remove this prefix from the name (applied before --add-prefix)
+        #[arg(long, value_name = "PREFIX", conflicts_with = "rename")]
+        strip_prefix: Option<String>,
+
+        /// Prepend this prefix to the name (e.g. legacy-)
+        #[arg(long, value_name = "PREFIX", conflicts_with = "rename")]
+        add_prefix: Option<String>,
+
+        /// Create the new environment with this Python instead of the source's (e.g. 3.12)
+        #[arg(long, value_name = "VERSION")]
+        python: Option<String>,
     },
 }
 
@@ -186,6 +357,19 @@ impl Default for ListSortMode {
     }
 }
 
+/// Groupings accepted by `scuv list --group-by`.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[value(rename_all = "kebab-case")]
+pub enum ListGroupBy {
+    /// One flat list (default).
+    #[default]
+    None,
+    /// Under Python minor-version headers (3.11, 3.12, ...), oldest first.
+    Python,
+    /// Under each tag; an env with several tags appears under each.
+    Tag,
+}
+
 /// Available commands
 #[derive(Subcommand, Debug)]
 pub enum Commands {
@@ -196,12 +380,25 @@ pub enum Commands {
         #[arg(long)]
         pythons: bool,
 
+        /// With --pythons: show only interpreters uv installed and manages
+        #[arg(long, requires = "pythons", conflicts_with = "system_only")]
+        managed_only: bool,
+
+        /// With --pythons: show only interpreters uv found on the system (Homebrew, distro, pyenv, ...)
+        #[arg(long, requires = "pythons")]
+        system_only: bool,
+
         /// Output names only, one per line (for scripting/completion)
         #[arg(long, hide = true)]
         bare: bool,
 
         /// Filter environments by Python version (e.g., 3.12)
-        #[arg(long, value_name = "VERSION", conflicts_with = "pythons")]
+        #[arg(
+            long,
+            visible_alias = "python",
+            value_name = "VERSION",
+            conflicts_with = "pythons"
+        )]
         python_version: Option<String>,
 
         /// Sort order for the env list (name / created / last-used).
@@ -210,6 +407,30 @@ pub enum Commands {
         #[arg(long, value_enum, default_value_t = ListSortMode::Name, conflicts_with = "pythons")]
         sort: ListSortMode,
 
+        /// Group the env list under Python minor-version or tag headers
+        #[arg(long, value_enum, value_name = "KEY", default_value_t = ListGroupBy::None, conflicts_with = "pythons")]
+        group_by: ListGroupBy,
+
+        /// Show only environments carrying this tag (see `scuv tag`)
+        #[arg(long, value_name = "TAG", conflicts_with = "pythons")]
+        tag: Option<String>,
+
+        /// Show only environments not used (or, if never used, created) in the last DAYS days
+        #[arg(long, value_name = "DAYS", conflicts_with = "pythons")]
+        older_than: Option<u32>,
+
+        /// Show only broken environments (missing Python executable or pyvenv.cfg)
+        #[arg(long, conflicts_with = "pythons")]
+        broken_only: bool,
+
+        /// Print only the number of environments (after any filters)
+        #[arg(long, conflicts_with_all = ["pythons", "bare", "group_by"])]
+        count: bool,
+
+        /// Compute each environment's disk size and the total (walks every file; slower on large envs)
+        #[arg(long, conflicts_with_all = ["pythons", "bare", "count"])]
+        sizes: bool,
+
         /// Output as JSON
         #[arg(long)]
         json: bool,
@@ -236,6 +457,29 @@ pub enum Commands {
         #[arg(long, conflicts_with = "link")]
         no_link: bool,
 
+        /// Create the environment first if it doesn't exist (optionally at PYTHON, default: latest)
+        #[arg(
+            long,
+            value_name = "PYTHON",
+            num_args = 0..=1,
+            default_missing_value = "3",
+            conflicts_with = "unset"
+        )]
+        create: Option<String>,
+
+        /// Put the environment's bin at the end of PATH instead of the front
+        /// (passed on to activation by the shell wrapper)
+        #[arg(long)]
+        append_path: bool,
+
+        /// Also print the activation script, for `eval "$(scuv use NAME --print)"`
+        #[arg(long, conflicts_with_all = ["unset", "json"])]
+        print: bool,
+
+        /// Shell to print the script for with --print (auto-detected if not specified)
+        #[arg(long, value_enum, requires = "print")]
+        shell: Option<ShellType>,
+
         /// Output as JSON
         #[arg(long)]
         json: bool,
@@ -243,12 +487,13 @@ pub enum Commands {
 
     /// Create a new virtual environment
     Create {
-        /// Name of the virtual environment
-        name: String,
+        /// Name of the virtual environment (with --name-from-cwd, the Python version instead)
+        #[arg(required_unless_present = "name_from_cwd")]
+        name: Option<String>,
 
         /// Python version or specifier (e.g., 3.12, cpython@3.12, pypy@3.10)
-        #[arg(default_value = "3")]
-        python: String,
+        /// [default: the --template's python, then create.default_python from config.json, else 3]
+        python: Option<String>,
 
         /// Path to a specific Python interpreter to use instead of a version
         #[arg(long = "python-path", value_name = "PATH")]
@@ -258,10 +503,58 @@ pub enum Commands {
         #[arg(short, long)]
         force: bool,
 
+        /// Rebuild an existing environment in place, keeping its directory (and symlinks to it)
+        #[arg(long, conflicts_with = "force")]
+        clear: bool,
+
         /// Install the requested Python version first if it is not already available
         #[arg(long, conflicts_with = "python_path")]
         install_python: bool,
 
+        /// Also create a symlink at PATH pointing to the new environment
+        #[arg(long, value_name = "PATH")]
+        link: Option<PathBuf>,
+
+        /// Show what would be created without calling uv
+        #[arg(short = 'n', long)]
+        dry_run: bool,
+
+        /// Don't print the "Activate: scuv use <name>" hint
+        #[arg(long)]
+        no_activate_hint: bool,
+
+        /// Skip the post_create_hook from config.json
+        #[arg(long)]
+        no_hooks: bool,
+
+        /// Run uv with -q and don't echo its output, whatever the verbosity
+        #[arg(long)]
+        quiet_uv: bool,
+
+        /// Constraints file for packages installed during create (template and post-create hook)
+        #[arg(long, value_name = "PATH")]
+        build_constraints: Option<PathBuf>,
+
+        /// Apply a template from config.json (its Python version and packages)
+        #[arg(long, value_name = "NAME")]
+        template: Option<String>,
+
+        /// Seed pip into the environment (uv venv --seed)
+        #[arg(long)]
+        seed: bool,
+
+        /// Upgrade the seeded pip (and setuptools/wheel) to the latest release; requires --seed
+        #[arg(long)]
+        upgrade_deps: bool,
+
+        /// Silence pip's "new release available" notice while seeding; requires --seed
+        #[arg(long)]
+        no_pip_upgrade_warning: bool,
+
+        /// Name the environment after the current directory and use it here locally
+        #[arg(long, conflicts_with = "python")]
+        name_from_cwd: bool,
+
         /// Output as JSON
         #[arg(long)]
         json: bool,
@@ -277,6 +570,23 @@ pub enum Commands {
         #[arg(short, long)]
         force: bool,
 
+        /// Show what would be deleted without deleting it
+        #[arg(short = 'n', long)]
+        dry_run: bool,
+
+        /// Don't delete global/local version files that point at the env
+        #[arg(long)]
+        keep_version_files: bool,
+
+        /// Return only once the directory is really gone (network filesystems
+        /// may delete it asynchronously), giving up after SECS (default 30)
+        #[arg(long, value_name = "SECS", num_args = 0..=1, require_equals = true, default_missing_value = "30")]
+        wait: Option<u64>,
+
+        /// Kill processes running from the environment before removing it (requires --force)
+        #[arg(long, requires = "force")]
+        kill: bool,
+
         /// Output as JSON
         #[arg(long)]
         json: bool,
@@ -296,6 +606,26 @@ pub enum Commands {
         #[arg(long)]
         stable: bool,
 
+        /// Uninstall the version first, then download it again
+        #[arg(long)]
+        reinstall: bool,
+
+        /// Reinstall even if environments depend on the version
+        #[arg(short, long, requires = "reinstall")]
+        force: bool,
+
+        /// Check the installed interpreter runs and reports the requested version
+        #[arg(long, overrides_with = "no_verify")]
+        verify: bool,
+
+        /// Skip the check even if install.verify is set in config.json
+        #[arg(long, overrides_with = "verify")]
+        no_verify: bool,
+
+        /// Also use this version for `scuv create` when none is given
+        #[arg(long = "default")]
+        set_default: bool,
+
         /// Output as JSON
         #[arg(long)]
         json: bool,
@@ -315,6 +645,10 @@ pub enum Commands {
         #[arg(short, long, requires = "cascade")]
         force: bool,
 
+        /// List environments that depend on this version without uninstalling
+        #[arg(short = 'n', long)]
+        dry_run: bool,
+
         /// Output as JSON
         #[arg(long)]
         json: bool,
@@ -322,10 +656,6 @@ pub enum Commands {
 
     /// Diagnose installation issues
     Doctor {
-        /// Increase verbosity (can be repeated)
-        #[arg(short, long, action = ArgAction::Count)]
-        verbose: u8,
-
         /// Output as JSON
         #[arg(long)]
         json: bool,
@@ -333,6 +663,26 @@ pub enum Commands {
         /// Automatically fix issues where possible
         #[arg(long)]
         fix: bool,
+
+        /// Print only the final tally, not each check
+        #[arg(long)]
+        summary_only: bool,
+
+        /// Also sum environment sizes and warn on excessive disk usage (implied by --verbose)
+        #[arg(long)]
+        with_sizes: bool,
+
+        /// Also start every environment's interpreter and report those that fail to launch (slower)
+        #[arg(long)]
+        deep: bool,
+
+        /// Apply fixes without asking for confirmation
+        #[arg(long, requires = "fix")]
+        no_input: bool,
+
+        /// Write the JSON report to this file (with --json, instead of stdout)
+        #[arg(long, value_name = "PATH")]
+        output: Option<PathBuf>,
     },
 
     /// Show detailed information about a virtual environment
@@ -351,6 +701,14 @@ pub enum Commands {
         /// Skip directory size calculation (faster)
         #[arg(long)]
         no_size: bool,
+
+        /// Skip the package listing; report filesystem info only (fast, offline)
+        #[arg(long)]
+        no_uv: bool,
+
+        /// Compare installed packages with another environment instead
+        #[arg(long, value_name = "OTHER", conflicts_with_all = ["all_packages", "no_size", "no_uv"])]
+        compare: Option<String>,
     },
 
     /// Output shell initialization script
@@ -365,11 +723,32 @@ pub enum Commands {
         /// Shell to generate completions for
         #[arg(value_enum)]
         shell: ShellType,
+
+        /// Write the script into this directory under the shell's
+        /// conventional file name (`scuv.bash`, `_scuv`, `scuv.fish`,
+        /// `_scuv.ps1`) instead of printing it
+        #[arg(long, value_name = "PATH")]
+        out_dir: Option<PathBuf>,
     },
 
     /// Resolve and print current environment name
     #[command(hide = true)]
-    Resolve,
+    Resolve {
+        /// List every source checked and which one won (also with --verbose)
+        #[arg(long)]
+        explain: bool,
+
+        /// Output the name and the resolution chain as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Print an environment's site-packages directory (for integrations)
+    #[command(name = "__site-packages", hide = true)]
+    SitePackages {
+        /// Name of the virtual environment
+        name: String,
+    },
 
     /// Output activation script for eval
     #[command(hide = true)]
@@ -380,6 +759,11 @@ pub enum Commands {
         /// Target shell type (auto-detected if not specified)
         #[arg(long, value_enum)]
         shell: Option<ShellType>,
+
+        /// Put the environment's bin at the end of PATH instead of the front
+        /// [default: activate.append_path from config.json, else prepend]
+        #[arg(long)]
+        append_path: bool,
     },
 
     /// Output deactivation script for eval
@@ -399,16 +783,31 @@ pub enum Commands {
         #[arg(long)]
         unset: bool,
 
+        /// Remember the active environment so `deactivate` returns to it
+        #[arg(long, conflicts_with = "unset")]
+        stack: bool,
+
         /// Target shell type (auto-detected if not specified)
         #[arg(long, value_enum)]
         shell: Option<ShellType>,
     },
 
+    /// Building blocks for custom prompts and shell hooks
+    Hook {
+        #[command(subcommand)]
+        command: HookCommand,
+    },
+
     /// Migrate environments from other tools (pyenv, virtualenvwrapper)
+    #[command(args_conflicts_with_subcommands = true)]
     Migrate {
         /// Subcommand or environment name to migrate
         #[command(subcommand)]
         command: Option<MigrateCommand>,
+
+        /// Output the default listing as JSON (same as `migrate list --json`)
+        #[arg(long)]
+        json: bool,
     },
 
     /// Set or show language preference
@@ -443,6 +842,13 @@ pub enum Commands {
         json: bool,
     },
 
+    /// Show scuv, uv, Python, and OS versions (for bug reports)
+    Version {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
     /// Clone an environment (optionally without copying packages)
     Clone {
         /// Name of the source environment
@@ -517,6 +923,40 @@ pub enum Commands {
         command: Vec<String>,
     },
 
+    /// Add tags to a virtual environment
+    Tag {
+        /// Name of the virtual environment
+        name: String,
+
+        /// Tags to add (no whitespace, at most 32 characters each)
+        #[arg(required = true, value_name = "TAG")]
+        tags: Vec<String>,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Remove tags from a virtual environment
+    Untag {
+        /// Name of the virtual environment
+        name: String,
+
+        /// Tags to remove
+        #[arg(required = true, value_name = "TAG")]
+        tags: Vec<String>,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Inspect the environment templates used by `create --template`
+    Template {
+        #[command(subcommand)]
+        command: TemplateCommand,
+    },
+
     /// Print the full path to an executable in an environment
     Which {
         /// Name of the executable to locate (e.g., python, pip)
@@ -628,4 +1068,6 @@ pub enum ShellType {
     /// PowerShell
     #[value(alias = "pwsh")]
     Powershell,
+    /// Windows Command Prompt (cmd.exe)
+    Cmd,
 }
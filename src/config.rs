@@ -3,9 +3,10 @@
 //! Handles persistent user settings stored in `~/.scuv/config.json`.
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
-use crate::error::Result;
+use crate::error::{Result, ScoopError};
 use crate::paths;
 
 /// User configuration stored in `~/.scuv/config.json`
@@ -14,6 +15,130 @@ pub struct Config {
     /// Preferred language code (e.g., "en", "ko")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lang: Option<String>,
+
+    /// Flush metadata and version files to disk after writing them
+    /// (`fsync`), trading speed for crash safety. Off by default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub durable_writes: Option<bool>,
+
+    /// Migration settings (`migrate.*`)
+    #[serde(default, skip_serializing_if = "MigrateConfig::is_empty")]
+    pub migrate: MigrateConfig,
+
+    /// Doctor settings (`doctor.*`)
+    #[serde(default, skip_serializing_if = "DoctorConfig::is_empty")]
+    pub doctor: DoctorConfig,
+
+    /// Create settings (`create.*`)
+    #[serde(default, skip_serializing_if = "CreateConfig::is_empty")]
+    pub create: CreateConfig,
+
+    /// Install settings (`install.*`)
+    #[serde(default, skip_serializing_if = "InstallConfig::is_empty")]
+    pub install: InstallConfig,
+
+    /// Activation settings (`activate.*`)
+    #[serde(default, skip_serializing_if = "ActivateConfig::is_empty")]
+    pub activate: ActivateConfig,
+
+    /// Environment templates for `scuv create --template`, by name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub templates: BTreeMap<String, TemplateConfig>,
+}
+
+/// `migrate` section of `~/.scuv/config.json`
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct MigrateConfig {
+    /// Oldest Python `MAJOR.MINOR` not flagged EOL (e.g., "3.9")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eol_minimum: Option<String>,
+}
+
+impl MigrateConfig {
+    fn is_empty(&self) -> bool {
+        self.eol_minimum.is_none()
+    }
+}
+
+/// `doctor` section of `~/.scuv/config.json`
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DoctorConfig {
+    /// Total environment size (GB) above which `doctor --with-sizes` warns
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disk_usage_threshold_gb: Option<u64>,
+}
+
+impl DoctorConfig {
+    fn is_empty(&self) -> bool {
+        self.disk_usage_threshold_gb.is_none()
+    }
+}
+
+/// `create` section of `~/.scuv/config.json`
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CreateConfig {
+    /// Shell command run after `scuv create`; `{name}` and `{python}` are
+    /// substituted (see [`crate::core::hooks`])
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_create_hook: Option<String>,
+
+    /// Python used when `scuv create` is given no version (set by
+    /// `scuv install --default`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_python: Option<String>,
+}
+
+impl CreateConfig {
+    fn is_empty(&self) -> bool {
+        self.post_create_hook.is_none() && self.default_python.is_none()
+    }
+}
+
+/// `install` section of `~/.scuv/config.json`
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct InstallConfig {
+    /// Check every installed interpreter runs, as if `--verify` were passed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verify: Option<bool>,
+}
+
+impl InstallConfig {
+    fn is_empty(&self) -> bool {
+        self.verify.is_none()
+    }
+}
+
+/// `activate` section of `~/.scuv/config.json`
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ActivateConfig {
+    /// Put the environment's `bin` after the existing `PATH` instead of
+    /// before it, as if `--append-path` were passed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub append_path: Option<bool>,
+
+    /// Have `scuv init` add a hook that deactivates the environment when
+    /// leaving the directory whose version file selected it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_deactivate: Option<bool>,
+}
+
+impl ActivateConfig {
+    fn is_empty(&self) -> bool {
+        self.append_path.is_none() && self.auto_deactivate.is_none()
+    }
+}
+
+/// One entry of the `templates` section of `~/.scuv/config.json`
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TemplateConfig {
+    /// Python used when `scuv create --template` is given no version
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub python: Option<String>,
+
+    /// Requirement specifiers installed into the new environment
+    /// (e.g. `numpy`, `pandas>=2`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub packages: Vec<String>,
 }
 
 impl Config {
@@ -60,9 +185,82 @@ impl Config {
     }
 }
 
+/// Expand a user-supplied path from config or the environment.
+///
+/// - A leading `~` (alone, or followed by `/` or `\`) becomes the home
+///   directory. `~user` is left as is.
+/// - `$VAR` and `${VAR}` are replaced with the variable's value. A `$` not
+///   followed by a variable name is kept literally.
+///
+/// An unset variable (or an unterminated `${`) is an error rather than
+/// being left in place, so a typo can't silently create a directory named
+/// `$TYPO`.
+///
+/// # Errors
+///
+/// Returns [`ScoopError::PathError`] for an unset or malformed variable
+/// reference, and [`ScoopError::HomeNotFound`] if `~` is used without a
+/// home directory.
+pub fn expand_path(raw: &str) -> Result<PathBuf> {
+    let rest = match raw.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with(['/', '\\']) => {
+            let home = dirs::home_dir().ok_or(ScoopError::HomeNotFound)?;
+            let rest = expand_vars(rest.trim_start_matches(['/', '\\']), raw)?;
+            return Ok(if rest.is_empty() {
+                home
+            } else {
+                home.join(rest)
+            });
+        }
+        _ => raw,
+    };
+    Ok(PathBuf::from(expand_vars(rest, raw)?))
+}
+
+/// Replace `$VAR` / `${VAR}` in `input`; `raw` is the full value for errors.
+fn expand_vars(input: &str, raw: &str) -> Result<String> {
+    fn is_name_char(c: char) -> bool {
+        c.is_ascii_alphanumeric() || c == '_'
+    }
+
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(idx) = rest.find('$') {
+        out.push_str(&rest[..idx]);
+        let after = &rest[idx + 1..];
+
+        let (name, remainder) = if let Some(braced) = after.strip_prefix('{') {
+            let end = braced
+                .find('}')
+                .ok_or_else(|| ScoopError::PathError(format!("unterminated '${{' in '{raw}'")))?;
+            (&braced[..end], &braced[end + 1..])
+        } else {
+            let end = after.find(|c| !is_name_char(c)).unwrap_or(after.len());
+            (&after[..end], &after[end..])
+        };
+
+        if name.is_empty() || !name.chars().all(is_name_char) {
+            // Not a variable reference (`$` at the end, `$-`, `${}`): keep it.
+            out.push('$');
+            rest = after;
+            continue;
+        }
+
+        let value = std::env::var(name).map_err(|_| {
+            ScoopError::PathError(format!("variable ${name} in '{raw}' is not set"))
+        })?;
+        out.push_str(&value);
+        rest = remainder;
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::env_guard;
+    use serial_test::serial;
 
     #[test]
     fn test_default_config() {
@@ -98,6 +296,65 @@ mod tests {
         let config = Config::default();
         let json = serde_json::to_string(&config).unwrap();
         assert!(!json.contains("lang"));
+        assert!(!json.contains("migrate"));
+        assert!(!json.contains("doctor"));
+        assert!(!json.contains("create"));
+        assert!(!json.contains("install"));
+        assert!(!json.contains("templates"));
+    }
+
+    #[test]
+    fn test_deserialize_templates() {
+        let json = r#"{"templates":{
+            "data-science":{"python":"3.12","packages":["numpy","pandas>=2"]},
+            "bare":{}
+        }}"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        let ds = &config.templates["data-science"];
+        assert_eq!(ds.python.as_deref(), Some("3.12"));
+        assert_eq!(ds.packages, ["numpy", "pandas>=2"]);
+        assert_eq!(config.templates["bare"], TemplateConfig::default());
+    }
+
+    #[test]
+    fn test_deserialize_migrate_eol_minimum() {
+        let json = r#"{"migrate":{"eol_minimum":"3.10"}}"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        assert_eq!(config.migrate.eol_minimum.as_deref(), Some("3.10"));
+    }
+
+    #[test]
+    fn test_deserialize_doctor_disk_usage_threshold() {
+        let json = r#"{"doctor":{"disk_usage_threshold_gb":25}}"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        assert_eq!(config.doctor.disk_usage_threshold_gb, Some(25));
+    }
+
+    #[test]
+    fn test_deserialize_create_post_create_hook() {
+        let json = r#"{"create":{"post_create_hook":"scuv run {name} -- pip install ruff"}}"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            config.create.post_create_hook.as_deref(),
+            Some("scuv run {name} -- pip install ruff")
+        );
+    }
+
+    #[test]
+    fn test_deserialize_install_verify() {
+        let json = r#"{"install":{"verify":true}}"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        assert_eq!(config.install.verify, Some(true));
+    }
+
+    #[test]
+    fn test_create_default_python_roundtrip() {
+        let mut config = Config::default();
+        config.create.default_python = Some("3.12".to_string());
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, r#"{"create":{"default_python":"3.12"}}"#);
+        let back: Config = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.create.default_python.as_deref(), Some("3.12"));
     }
 
     // =========================================================================
@@ -130,6 +387,7 @@ mod tests {
     fn test_config_roundtrip() {
         let original = Config {
             lang: Some("ko".to_string()),
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&original).unwrap();
@@ -199,6 +457,7 @@ mod tests {
     fn test_set_lang_some_to_none() {
         let mut config = Config {
             lang: Some("en".to_string()),
+            ..Default::default()
         };
         config.set_lang(None);
         assert!(config.lang.is_none());
@@ -215,8 +474,77 @@ mod tests {
     fn test_set_lang_overwrites() {
         let mut config = Config {
             lang: Some("en".to_string()),
+            ..Default::default()
         };
         config.set_lang(Some("ko".to_string()));
         assert_eq!(config.lang, Some("ko".to_string()));
     }
+
+    // =========================================================================
+    // expand_path Tests
+    // =========================================================================
+
+    #[test]
+    #[serial]
+    fn test_expand_path_tilde() {
+        let home = dirs::home_dir().unwrap();
+        assert_eq!(expand_path("~/foo").unwrap(), home.join("foo"));
+        assert_eq!(expand_path("~").unwrap(), home);
+        // `~user` and a `~` mid-path are not expanded
+        assert_eq!(expand_path("~bob/foo").unwrap(), PathBuf::from("~bob/foo"));
+        assert_eq!(expand_path("a/~/b").unwrap(), PathBuf::from("a/~/b"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_expand_path_env_vars() {
+        let _env = env_guard(&[("SCUV_TEST_EXPAND", Some("/opt/scuv"))]);
+        assert_eq!(
+            expand_path("$SCUV_TEST_EXPAND/foo").unwrap(),
+            PathBuf::from("/opt/scuv/foo")
+        );
+        assert_eq!(
+            expand_path("${SCUV_TEST_EXPAND}_x/foo").unwrap(),
+            PathBuf::from("/opt/scuv_x/foo")
+        );
+        #[cfg(unix)]
+        {
+            let home = std::env::var("HOME").unwrap();
+            assert_eq!(
+                expand_path("$HOME/foo").unwrap(),
+                PathBuf::from(home).join("foo")
+            );
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_expand_path_missing_var_errors() {
+        let _env = env_guard(&[("SCUV_TEST_UNSET", None)]);
+        for raw in [
+            "$SCUV_TEST_UNSET/foo",
+            "${SCUV_TEST_UNSET}",
+            "~/$SCUV_TEST_UNSET",
+        ] {
+            let err = expand_path(raw).unwrap_err();
+            assert!(
+                matches!(err, ScoopError::PathError(ref m) if m.contains("SCUV_TEST_UNSET")),
+                "{raw}: {err:?}"
+            );
+        }
+        assert!(matches!(
+            expand_path("${SCUV_TEST_UNSET"),
+            Err(ScoopError::PathError(_))
+        ));
+    }
+
+    #[test]
+    fn test_expand_path_literal_dollar() {
+        assert_eq!(expand_path("/a/$/b").unwrap(), PathBuf::from("/a/$/b"));
+        assert_eq!(expand_path("/a/b$").unwrap(), PathBuf::from("/a/b$"));
+        assert_eq!(
+            expand_path("/plain/path").unwrap(),
+            PathBuf::from("/plain/path")
+        );
+    }
 }
@@ -0,0 +1,95 @@
+//! Atomic (and optionally durable) file replacement.
+//!
+//! Metadata and version files are rewritten via a sibling temp file that
+//! is renamed over the target, so readers see either the old content or
+//! the new. With `durable_writes` set in `~/.scuv/config.json`, the temp
+//! file is also flushed to disk before the rename and, on Unix, the
+//! directory after it, so the new content survives a power loss or kernel
+//! crash too. That costs a disk flush per write, which is why it is off by
+//! default: environment metadata is rewritten on every activation.
+
+use std::io::Write;
+use std::path::Path;
+
+use crate::config::Config;
+use crate::error::{Result, ScoopError};
+
+/// Replace `path` with `contents`, durably when `durable_writes` is set in
+/// config.json.
+///
+/// # Errors
+///
+/// Returns [`ScoopError::Io`] if the temp file can't be written, flushed,
+/// or renamed over `path`.
+pub(crate) fn write_file(path: &Path, contents: &[u8]) -> Result<()> {
+    let durable = Config::load().ok().and_then(|c| c.durable_writes) == Some(true);
+    write_file_with(path, contents, durable)
+}
+
+fn write_file_with(path: &Path, contents: &[u8], durable: bool) -> Result<()> {
+    let dir = path.parent().ok_or_else(|| {
+        ScoopError::Io(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("{} has no parent directory", path.display()),
+        ))
+    })?;
+
+    let mut tmp = tempfile::NamedTempFile::new_in(dir)?;
+    tmp.write_all(contents)?;
+    if durable {
+        tmp.as_file().sync_all()?;
+    }
+    tmp.persist(path).map_err(|e| ScoopError::Io(e.error))?;
+    if durable {
+        sync_dir(dir)?;
+    }
+    Ok(())
+}
+
+/// Flush `dir`'s entries so the rename itself is on disk.
+#[cfg(unix)]
+fn sync_dir(dir: &Path) -> Result<()> {
+    std::fs::File::open(dir)?.sync_all()?;
+    Ok(())
+}
+
+/// Windows can't open a directory as a file to flush it; `MoveFileExW`
+/// already commits the rename with the file's metadata.
+#[cfg(not(unix))]
+fn sync_dir(_dir: &Path) -> Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+
+    #[test]
+    fn durable_write_replaces_content() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("file.json");
+        std::fs::write(&path, "a much longer old value").unwrap();
+
+        write_file_with(&path, b"new", true).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "new");
+        // Only the target is left behind, no temp file.
+        assert_eq!(std::fs::read_dir(temp.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    #[serial]
+    fn config_flag_selects_the_durable_path() {
+        crate::test_utils::with_temp_scoop_home(|temp| {
+            let config = Config {
+                durable_writes: Some(true),
+                ..Default::default()
+            };
+            config.save().unwrap();
+
+            let path = temp.path().join("version");
+            write_file(&path, b"myenv\n").unwrap();
+            assert_eq!(std::fs::read_to_string(&path).unwrap(), "myenv\n");
+        });
+    }
+}
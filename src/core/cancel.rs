@@ -0,0 +1,47 @@
+//! Ctrl-C handling for long-running commands.
+//!
+//! `install`, `create` and `migrate` call [`install_handler`] before they
+//! start work. The first Ctrl-C only sets a flag: the uv child running at
+//! that moment is killed (see [`crate::uv::UvClient`]), the half-built
+//! environment is removed by whoever was building it, and batch migrations
+//! stop starting new environments and print what did complete. A second
+//! Ctrl-C exits at once, for when the cleanup itself hangs.
+//!
+//! Other commands keep the default behaviour (Ctrl-C exits immediately),
+//! since they have nothing to clean up.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::error::{Result, ScoopError};
+
+static CANCELLED: AtomicBool = AtomicBool::new(false);
+
+/// Route Ctrl-C to the cancellation flag for the rest of the process.
+///
+/// Calling it again is harmless; only the first call installs a handler.
+pub fn install_handler() {
+    // Fails only when a handler is already installed, which is fine.
+    let _ = ctrlc::set_handler(|| {
+        if CANCELLED.swap(true, Ordering::SeqCst) {
+            std::process::exit(130);
+        }
+    });
+}
+
+/// Whether Ctrl-C has been pressed.
+pub fn is_cancelled() -> bool {
+    CANCELLED.load(Ordering::SeqCst)
+}
+
+/// `Err(Interrupted)` once Ctrl-C has been pressed, for use between steps.
+///
+/// # Errors
+///
+/// Returns [`ScoopError::Interrupted`] after Ctrl-C.
+pub fn check() -> Result<()> {
+    if is_cancelled() {
+        Err(ScoopError::Interrupted)
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+//! Check that the shell's activation state agrees with the scuv store.
+
+use std::path::{Path, PathBuf};
+
+use crate::paths;
+
+use super::super::types::{Check, CheckResult};
+
+/// Compare `SCUV_ACTIVE` and `VIRTUAL_ENV` against the environments on disk.
+///
+/// The activate script sets both together, so they only drift apart when
+/// another tool (`source .venv/bin/activate`, conda, an IDE) takes over the
+/// shell, or when the env was removed while active. Reads the environment of
+/// the process running doctor, so it only says something useful when run
+/// from the shell in question. Runs with `--verbose` only.
+pub(super) struct ActivationCheck;
+
+impl ActivationCheck {
+    /// Judge the active name and `VIRTUAL_ENV` against `venvs_dir`.
+    fn evaluate(
+        &self,
+        active: Option<&str>,
+        virtual_env: Option<&Path>,
+        venvs_dir: &Path,
+    ) -> CheckResult {
+        let inconsistent = |message: String| {
+            CheckResult::warn(self.id(), self.name(), message)
+                .with_details("activation may be inconsistent; another venv tool may have taken over this shell")
+                .with_suggestion("Run `deactivate`, then `scuv use <name>` again")
+        };
+
+        let active = active.filter(|name| !name.is_empty());
+        let in_store = virtual_env.filter(|venv| same_path(venv.parent(), Some(venvs_dir)));
+
+        match active {
+            None | Some("system") => match (virtual_env, in_store) {
+                (None, _) => {
+                    let details = match active {
+                        Some(_) => "system Python selected",
+                        None => "no environment active",
+                    };
+                    CheckResult::ok(self.id(), self.name()).with_details(details)
+                }
+                (Some(venv), Some(_)) => inconsistent(format!(
+                    "VIRTUAL_ENV points into the scuv store ({}) but SCUV_ACTIVE is {}",
+                    venv.display(),
+                    active.map_or("unset".to_string(), |name| format!("'{name}'"))
+                )),
+                // A venv some other tool activated; scuv isn't involved.
+                (Some(venv), None) => CheckResult::ok(self.id(), self.name())
+                    .with_details(format!("non-scuv venv active: {}", venv.display())),
+            },
+            Some(name) => {
+                let expected = venvs_dir.join(name);
+                if !expected.is_dir() {
+                    return inconsistent(format!(
+                        "SCUV_ACTIVE is '{name}' but no such environment exists"
+                    ));
+                }
+                match virtual_env {
+                    None => {
+                        inconsistent(format!("SCUV_ACTIVE is '{name}' but VIRTUAL_ENV is unset"))
+                    }
+                    Some(venv) if same_path(Some(venv), Some(&expected)) => {
+                        CheckResult::ok(self.id(), self.name())
+                            .with_details(format!("'{name}' active at {}", venv.display()))
+                    }
+                    Some(venv) if in_store.is_some() => inconsistent(format!(
+                        "SCUV_ACTIVE is '{name}' but VIRTUAL_ENV points to another environment ({})",
+                        venv.display()
+                    )),
+                    Some(venv) => inconsistent(format!(
+                        "SCUV_ACTIVE is '{name}' but VIRTUAL_ENV points outside the scuv store ({})",
+                        venv.display()
+                    )),
+                }
+            }
+        }
+    }
+}
+
+/// Whether two paths name the same directory, looking through symlinks
+/// when both exist (a symlinked SCUV_HOME is common).
+pub(super) fn same_path(a: Option<&Path>, b: Option<&Path>) -> bool {
+    let (Some(a), Some(b)) = (a, b) else {
+        return false;
+    };
+    let canonical = |p: &Path| std::fs::canonicalize(p).unwrap_or_else(|_| p.to_path_buf());
+    a == b || canonical(a) == canonical(b)
+}
+
+impl Check for ActivationCheck {
+    fn id(&self) -> &'static str {
+        "activation"
+    }
+
+    fn name(&self) -> &'static str {
+        "activation state"
+    }
+
+    fn run(&self) -> Vec<CheckResult> {
+        let Ok(venvs_dir) = paths::virtualenvs_dir() else {
+            // The home check already reports a missing directory.
+            return vec![
+                CheckResult::ok(self.id(), self.name())
+                    .with_details("skipped: virtualenvs directory not found"),
+            ];
+        };
+        let active = std::env::var("SCUV_ACTIVE").ok();
+        let virtual_env = std::env::var_os("VIRTUAL_ENV").map(PathBuf::from);
+        vec![self.evaluate(active.as_deref(), virtual_env.as_deref(), &venvs_dir)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::doctor::CheckStatus;
+    use tempfile::TempDir;
+
+    fn store() -> (TempDir, PathBuf) {
+        let temp = TempDir::new().unwrap();
+        let venvs = temp.path().join("virtualenvs");
+        std::fs::create_dir_all(venvs.join("web")).unwrap();
+        std::fs::create_dir_all(venvs.join("api")).unwrap();
+        (temp, venvs)
+    }
+
+    fn warning(result: &CheckResult) -> &str {
+        match &result.status {
+            CheckStatus::Warning(msg) => msg,
+            other => panic!("expected a warning, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn consistent_states_are_ok() {
+        let (_temp, venvs) = store();
+        let check = ActivationCheck;
+
+        assert!(check.evaluate(None, None, &venvs).is_ok());
+        assert!(check.evaluate(Some("system"), None, &venvs).is_ok());
+        let web = venvs.join("web");
+        let result = check.evaluate(Some("web"), Some(&web), &venvs);
+        assert!(result.is_ok(), "{result:?}");
+
+        // Someone else's venv with scuv idle is not ours to judge.
+        let result = check.evaluate(None, Some(Path::new("/work/proj/.venv")), &venvs);
+        assert!(result.is_ok());
+        assert!(result.details.unwrap().contains("non-scuv"));
+    }
+
+    #[test]
+    fn foreign_venv_over_active_env_warns() {
+        let (_temp, venvs) = store();
+        let result =
+            ActivationCheck.evaluate(Some("web"), Some(Path::new("/work/proj/.venv")), &venvs);
+        assert!(warning(&result).contains("outside the scuv store"));
+        assert!(result.suggestion.is_some());
+    }
+
+    #[test]
+    fn mismatched_or_missing_envs_warn() {
+        let (_temp, venvs) = store();
+        let check = ActivationCheck;
+        let api = venvs.join("api");
+
+        let result = check.evaluate(Some("web"), Some(&api), &venvs);
+        assert!(warning(&result).contains("another environment"));
+
+        let result = check.evaluate(Some("gone"), Some(&venvs.join("gone")), &venvs);
+        assert!(warning(&result).contains("no such environment"));
+
+        let result = check.evaluate(Some("web"), None, &venvs);
+        assert!(warning(&result).contains("VIRTUAL_ENV is unset"));
+
+        let result = check.evaluate(None, Some(&api), &venvs);
+        assert!(warning(&result).contains("SCUV_ACTIVE is unset"));
+    }
+}
@@ -0,0 +1,175 @@
+//! Check for cache files dated in the future (a wrong system clock).
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use chrono::{DateTime, Utc};
+
+use super::super::types::{Check, CheckResult};
+use crate::output::format_age;
+use crate::paths;
+
+/// Warn when files in `~/.scuv/cache` were modified "in the future".
+///
+/// Cache entries expire by comparing their mtime with the current time, so
+/// a clock that is far off (or was while the entry was written) makes
+/// entries look fresh forever or stale immediately. An mtime ahead of now
+/// is the visible symptom. Only runs when the cache directory exists.
+pub(super) struct ClockSkewCheck;
+
+/// How far ahead of now an mtime may be before it counts: coarse
+/// filesystem timestamps and network mounts can run slightly ahead.
+const TOLERANCE: Duration = Duration::from_secs(5 * 60);
+
+/// How many offending files the details list.
+const SHOWN: usize = 5;
+
+/// Files under `dir` whose mtime is more than [`TOLERANCE`] after `now`,
+/// with how far ahead each is, furthest first. Symlinks are not followed.
+fn future_files(dir: &Path, now: SystemTime) -> Vec<(PathBuf, Duration)> {
+    let mut found: Vec<(PathBuf, Duration)> = walkdir::WalkDir::new(dir)
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| {
+            let modified = entry.metadata().ok()?.modified().ok()?;
+            let ahead = modified.duration_since(now).ok()?;
+            (ahead > TOLERANCE).then(|| (entry.into_path(), ahead))
+        })
+        .collect();
+    found.sort_by(|a, b| b.1.cmp(&a.1));
+    found
+}
+
+impl ClockSkewCheck {
+    /// Judge the cache directory `dir` against `now`.
+    fn evaluate(&self, dir: &Path, now: SystemTime) -> CheckResult {
+        let future = future_files(dir, now);
+        let Some((_, furthest)) = future.first() else {
+            return CheckResult::ok(self.id(), self.name()).with_details(format!(
+                "no cache file in {} is dated in the future",
+                dir.display()
+            ));
+        };
+
+        let now_utc = DateTime::<Utc>::from(now);
+        let ahead = |by: &Duration| {
+            let then = now_utc + chrono::Duration::from_std(*by).unwrap_or(chrono::Duration::MAX);
+            // format_age with the arguments swapped reads "N hours ago".
+            format_age(now_utc, then)
+                .trim_end_matches(" ago")
+                .to_string()
+        };
+        let mut details: Vec<String> = future
+            .iter()
+            .take(SHOWN)
+            .map(|(path, by)| format!("{} ({} ahead)", path.display(), ahead(by)))
+            .collect();
+        if future.len() > SHOWN {
+            details.push(format!("... and {} more", future.len() - SHOWN));
+        }
+
+        CheckResult::warn(
+            self.id(),
+            self.name(),
+            format!(
+                "{} cache file(s) dated in the future, up to {} ahead",
+                future.len(),
+                ahead(furthest)
+            ),
+        )
+        .with_details(details.join("\n"))
+        .with_suggestion(format!(
+            "Check the system clock and time zone (enable automatic time sync), then remove {} so cached data is rebuilt",
+            dir.display()
+        ))
+    }
+}
+
+impl Check for ClockSkewCheck {
+    fn id(&self) -> &'static str {
+        "clock_skew"
+    }
+
+    fn name(&self) -> &'static str {
+        "cache timestamps"
+    }
+
+    fn run(&self) -> Vec<CheckResult> {
+        match paths::cache_dir() {
+            Ok(dir) if dir.is_dir() => vec![self.evaluate(&dir, SystemTime::now())],
+            _ => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::doctor::CheckStatus;
+    use crate::test_utils::with_temp_scoop_home;
+    use serial_test::serial;
+
+    fn write_with_mtime(path: &Path, mtime: SystemTime) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let file = std::fs::File::create(path).unwrap();
+        file.set_modified(mtime).unwrap();
+    }
+
+    #[test]
+    fn past_and_slightly_ahead_files_are_ok() {
+        let tmp = tempfile::tempdir().unwrap();
+        let now = SystemTime::now();
+        write_with_mtime(&tmp.path().join("old"), now - Duration::from_secs(86_400));
+        write_with_mtime(&tmp.path().join("nfs"), now + Duration::from_secs(60));
+
+        let result = ClockSkewCheck.evaluate(tmp.path(), now);
+        assert!(result.is_ok(), "got {result:#?}");
+    }
+
+    #[test]
+    fn future_files_warn_furthest_first() {
+        let tmp = tempfile::tempdir().unwrap();
+        let now = SystemTime::now();
+        write_with_mtime(
+            &tmp.path().join("uv/pythons.json"),
+            now + Duration::from_secs(2 * 3600),
+        );
+        write_with_mtime(
+            &tmp.path().join("update-check"),
+            now + Duration::from_secs(3 * 86_400),
+        );
+
+        let result = ClockSkewCheck.evaluate(tmp.path(), now);
+        match &result.status {
+            CheckStatus::Warning(msg) => assert_eq!(
+                msg,
+                "2 cache file(s) dated in the future, up to 3 days ahead"
+            ),
+            other => panic!("expected a warning, got {other:?}"),
+        }
+        let details = result.details.unwrap();
+        let lines: Vec<&str> = details.lines().collect();
+        assert!(
+            lines[0].contains("update-check (3 days ahead)"),
+            "{details}"
+        );
+        assert!(
+            lines[1].contains("pythons.json (2 hours ahead)"),
+            "{details}"
+        );
+        assert!(result.suggestion.unwrap().contains("system clock"));
+    }
+
+    #[test]
+    #[serial]
+    fn run_skips_without_cache_dir() {
+        with_temp_scoop_home(|_| {
+            assert!(ClockSkewCheck.run().is_empty());
+            paths::ensure_cache_dir().unwrap();
+            let results = ClockSkewCheck.run();
+            assert_eq!(results.len(), 1);
+            assert!(results[0].is_ok(), "got {results:#?}");
+        });
+    }
+}
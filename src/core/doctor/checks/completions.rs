@@ -0,0 +1,319 @@
+//! Check that tab completion for scuv is installed.
+
+use std::path::{Path, PathBuf};
+
+use super::super::types::{Check, CheckResult};
+use super::shell::{config_files, shell_name_of};
+
+/// Warn when neither `scuv init` nor an installed completion file gives the
+/// current shell tab completion for scuv.
+///
+/// `scuv init` registers completions itself, so an rc file with the init
+/// line counts. Otherwise the conventional completion directories for the
+/// shell named by `$SHELL` are searched for a `scuv completions` file.
+/// Shells whose completion setup can't be found by looking at files
+/// (PowerShell's `$PROFILE`, anything else) are skipped.
+///
+/// The suggestion only covers completion files; a missing init line is
+/// [`ShellCheck`](super::shell::ShellCheck)'s to point out.
+pub(super) struct CompletionsCheck;
+
+/// Where to look for one shell's completions.
+struct Locations {
+    /// Rc files where a `scuv init` line also loads completions.
+    init_files: Vec<PathBuf>,
+    /// Completion files, in the order searched.
+    completion_files: Vec<PathBuf>,
+    /// Per-user directory the shell loads completions from without further
+    /// setup (zsh: once it is on `$fpath`); where the suggestion installs to.
+    install_dir: PathBuf,
+}
+
+/// Search locations for `shell_name`, or `None` when detection isn't
+/// reliable for that shell.
+fn locations(
+    shell_name: &str,
+    home: &Path,
+    data_home: &Path,
+    config_home: &Path,
+) -> Option<Locations> {
+    let (user_dirs, system_dirs, file_names): (Vec<PathBuf>, &[&str], &[&str]) = match shell_name {
+        "bash" => (
+            vec![
+                data_home.join("bash-completion/completions"),
+                home.join(".bash_completion.d"),
+            ],
+            &[
+                "/usr/share/bash-completion/completions",
+                "/usr/local/share/bash-completion/completions",
+                "/opt/homebrew/share/bash-completion/completions",
+                "/etc/bash_completion.d",
+                "/usr/local/etc/bash_completion.d",
+                "/opt/homebrew/etc/bash_completion.d",
+            ],
+            // bash-completion loads `<cmd>` or `<cmd>.bash`
+            &["scuv", "scuv.bash"],
+        ),
+        "zsh" => (
+            vec![
+                home.join(".zsh/completions"),
+                home.join(".zfunc"),
+                data_home.join("zsh/site-functions"),
+            ],
+            &[
+                "/usr/share/zsh/site-functions",
+                "/usr/share/zsh/vendor-completions",
+                "/usr/local/share/zsh/site-functions",
+                "/opt/homebrew/share/zsh/site-functions",
+            ],
+            &["_scuv"],
+        ),
+        "fish" => (
+            vec![
+                config_home.join("fish/completions"),
+                data_home.join("fish/vendor_completions.d"),
+            ],
+            &[
+                "/usr/share/fish/vendor_completions.d",
+                "/usr/local/share/fish/vendor_completions.d",
+                "/opt/homebrew/share/fish/vendor_completions.d",
+            ],
+            &["scuv.fish"],
+        ),
+        _ => return None,
+    };
+
+    let init_files = match shell_name {
+        "fish" => vec![config_home.join("fish/config.fish")],
+        _ => config_files(shell_name, home)?,
+    };
+    let install_dir = user_dirs[0].clone();
+    let completion_files = user_dirs
+        .into_iter()
+        .chain(system_dirs.iter().map(PathBuf::from))
+        .flat_map(|dir| file_names.iter().map(move |name| dir.join(name)))
+        .collect();
+    Some(Locations {
+        init_files,
+        completion_files,
+        install_dir,
+    })
+}
+
+impl CompletionsCheck {
+    /// Judge what is installed at `locations` for `shell_name`.
+    fn evaluate(&self, shell_name: &str, locations: &Locations) -> CheckResult {
+        let via_init = locations.init_files.iter().find(|rc| {
+            std::fs::read_to_string(rc).is_ok_and(|content| content.contains("scuv init"))
+        });
+        if let Some(rc) = via_init {
+            return CheckResult::ok(self.id(), self.name())
+                .with_details(format!("loaded by `scuv init` in {}", rc.display()));
+        }
+        if let Some(file) = locations.completion_files.iter().find(|f| f.is_file()) {
+            return CheckResult::ok(self.id(), self.name())
+                .with_details(format!("found {}", file.display()));
+        }
+
+        let fpath_note = if shell_name == "zsh" {
+            " (and add that directory to $fpath)"
+        } else {
+            ""
+        };
+        let init_files = locations
+            .init_files
+            .iter()
+            .map(|f| f.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        CheckResult::warn(
+            self.id(),
+            self.name(),
+            format!("no scuv completions installed for {shell_name}"),
+        )
+        .with_details(format!(
+            "no `scuv init` line in {init_files}; no completion file in {} or other \
+             conventional locations",
+            locations.install_dir.display()
+        ))
+        .with_suggestion(format!(
+            "Run `scuv completions {shell_name} --out-dir {}`{fpath_note}",
+            locations.install_dir.display()
+        ))
+    }
+}
+
+impl Check for CompletionsCheck {
+    fn id(&self) -> &'static str {
+        "completions"
+    }
+
+    fn name(&self) -> &'static str {
+        "shell completions"
+    }
+
+    fn run(&self) -> Vec<CheckResult> {
+        let Some(home) = dirs::home_dir() else {
+            return vec![
+                CheckResult::ok(self.id(), self.name())
+                    .with_details("could not determine home directory; skipped"),
+            ];
+        };
+        // XDG base directories, falling back to the spec's defaults.
+        let xdg = |var: &str, default: &str| {
+            std::env::var_os(var)
+                .filter(|v| !v.is_empty())
+                .map(PathBuf::from)
+                .unwrap_or_else(|| home.join(default))
+        };
+        let data_home = xdg("XDG_DATA_HOME", ".local/share");
+        let config_home = xdg("XDG_CONFIG_HOME", ".config");
+
+        let shell_name = shell_name_of(&std::env::var("SHELL").unwrap_or_default());
+        match locations(&shell_name, &home, &data_home, &config_home) {
+            Some(locations) => vec![self.evaluate(&shell_name, &locations)],
+            None => vec![
+                CheckResult::ok(self.id(), self.name()).with_details(format!(
+                    "completion detection not supported for shell '{shell_name}'; skipped"
+                )),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serial_test::serial;
+
+    /// Locations rooted entirely in `tmp`, with one completion directory.
+    fn mock_locations(tmp: &Path, file_name: &str) -> Locations {
+        let dir = tmp.join("completions");
+        Locations {
+            init_files: vec![tmp.join(".zshrc")],
+            completion_files: vec![dir.join(file_name)],
+            install_dir: dir,
+        }
+    }
+
+    #[test]
+    fn installed_completion_file_is_ok() {
+        let tmp = tempfile::tempdir().unwrap();
+        let locations = mock_locations(tmp.path(), "_scuv");
+        std::fs::create_dir_all(&locations.install_dir).unwrap();
+        std::fs::write(&locations.completion_files[0], "#compdef scuv\n").unwrap();
+
+        let result = CompletionsCheck.evaluate("zsh", &locations);
+        assert!(result.is_ok(), "got {result:#?}");
+        let details = result.details.unwrap();
+        assert!(details.contains("_scuv"), "{details}");
+    }
+
+    #[test]
+    fn init_line_counts_as_installed() {
+        let tmp = tempfile::tempdir().unwrap();
+        let locations = mock_locations(tmp.path(), "_scuv");
+        std::fs::write(tmp.path().join(".zshrc"), "eval \"$(scuv init zsh)\"\n").unwrap();
+
+        let result = CompletionsCheck.evaluate("zsh", &locations);
+        assert!(result.is_ok(), "got {result:#?}");
+        assert!(result.details.unwrap().contains("scuv init"));
+    }
+
+    #[test]
+    fn missing_completions_warn_with_install_suggestion() {
+        let tmp = tempfile::tempdir().unwrap();
+        let locations = mock_locations(tmp.path(), "_scuv");
+        // An empty directory or a directory named like the file doesn't count.
+        std::fs::create_dir_all(locations.completion_files[0].join("nested")).unwrap();
+        std::fs::write(tmp.path().join(".zshrc"), "alias ll='ls -l'\n").unwrap();
+
+        let result = CompletionsCheck.evaluate("zsh", &locations);
+        assert!(result.is_warning(), "got {result:#?}");
+        let suggestion = result.suggestion.unwrap();
+        assert!(
+            suggestion.starts_with(&format!(
+                "Run `scuv completions zsh --out-dir {}`",
+                locations.install_dir.display()
+            )),
+            "{suggestion}"
+        );
+        assert!(suggestion.contains("$fpath"), "{suggestion}");
+        assert!(!suggestion.contains("scuv init"), "ShellCheck covers init");
+    }
+
+    #[test]
+    fn locations_follow_each_shells_conventions() {
+        let home = Path::new("/home/u");
+        let data = home.join(".local/share");
+        let config = home.join(".config");
+
+        let zsh = locations("zsh", home, &data, &config).unwrap();
+        assert_eq!(zsh.install_dir, home.join(".zsh/completions"));
+        assert!(
+            zsh.completion_files
+                .contains(&home.join(".zsh/completions/_scuv"))
+        );
+        assert_eq!(zsh.init_files, [home.join(".zshrc")]);
+
+        let bash = locations("bash", home, &data, &config).unwrap();
+        assert_eq!(bash.install_dir, data.join("bash-completion/completions"));
+        assert!(
+            bash.completion_files
+                .contains(&data.join("bash-completion/completions/scuv.bash"))
+        );
+
+        let fish = locations("fish", home, &data, &config).unwrap();
+        assert_eq!(fish.install_dir, config.join("fish/completions"));
+        assert_eq!(fish.init_files, [config.join("fish/config.fish")]);
+        assert!(
+            fish.completion_files
+                .iter()
+                .all(|f| f.file_name().unwrap() == "scuv.fish")
+        );
+    }
+
+    #[test]
+    fn unreliable_shells_are_skipped() {
+        let home = Path::new("/home/u");
+        for shell in ["pwsh", "tcsh", ""] {
+            assert!(locations(shell, home, home, home).is_none(), "{shell}");
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn run_finds_completions_in_xdg_data_home() {
+        let home_tmp = tempfile::tempdir().unwrap();
+        let data = home_tmp.path().join("data");
+        let dir = data.join("bash-completion/completions");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("scuv"), "complete -F _scuv scuv\n").unwrap();
+        let _g = crate::test_utils::env_guard(&[
+            ("SHELL", Some("/bin/bash")),
+            ("HOME", Some(home_tmp.path().to_str().unwrap())),
+            ("XDG_DATA_HOME", Some(data.to_str().unwrap())),
+        ]);
+
+        let results = CompletionsCheck.run();
+        assert_eq!(results.len(), 1, "got {results:#?}");
+        assert!(results[0].is_ok(), "got {results:#?}");
+        let details = results[0].details.as_deref().unwrap();
+        assert!(details.contains("bash-completion"), "{details}");
+    }
+
+    #[test]
+    #[serial]
+    fn run_skips_unsupported_shell() {
+        let home_tmp = tempfile::tempdir().unwrap();
+        let _g = crate::test_utils::env_guard(&[
+            ("SHELL", Some("/usr/bin/tcsh")),
+            ("HOME", Some(home_tmp.path().to_str().unwrap())),
+        ]);
+
+        let results = CompletionsCheck.run();
+        assert!(results[0].is_ok(), "got {results:#?}");
+        assert!(results[0].details.as_deref().unwrap().contains("skipped"));
+    }
+}
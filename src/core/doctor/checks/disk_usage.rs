@@ -0,0 +1,149 @@
+//! Check total disk usage of all virtual environments.
+
+use rayon::prelude::*;
+
+use crate::config::Config;
+use crate::core::migrate::common::dir_size;
+use crate::output::format_size;
+use crate::paths;
+
+use super::super::types::{Check, CheckResult};
+
+/// Warn threshold when `doctor.disk_usage_threshold_gb` isn't configured.
+const DEFAULT_THRESHOLD_GB: u64 = 10;
+
+/// How many of the largest environments to list in the details.
+const LARGEST_SHOWN: usize = 5;
+
+const GB: u64 = 1024 * 1024 * 1024;
+
+/// Sum the size of every environment and warn above a threshold.
+///
+/// Walking every env is slow on large installs, so this only runs with
+/// `doctor --with-sizes` or `--verbose` (see [`super::size_checks`]).
+pub(super) struct DiskUsageCheck;
+
+impl DiskUsageCheck {
+    /// Judge `sizes` (env name, bytes) against `threshold` bytes.
+    fn evaluate(&self, mut sizes: Vec<(String, u64)>, threshold: u64) -> CheckResult {
+        if sizes.is_empty() {
+            return CheckResult::ok(self.id(), self.name()).with_details("no environments yet");
+        }
+
+        let total: u64 = sizes.iter().map(|(_, size)| size).sum();
+        sizes.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        let largest = sizes
+            .iter()
+            .take(LARGEST_SHOWN)
+            .map(|(name, size)| format!("{name} ({})", format_size(*size)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let details = format!(
+            "{} across {} environment(s); largest: {largest}",
+            format_size(total),
+            sizes.len()
+        );
+
+        if total > threshold {
+            return CheckResult::warn(
+                self.id(),
+                self.name(),
+                format!(
+                    "environments use {} (threshold {})",
+                    format_size(total),
+                    format_size(threshold)
+                ),
+            )
+            .with_details(details)
+            .with_suggestion(
+                "Remove unused environments with `scuv remove <name>`, or raise doctor.disk_usage_threshold_gb in ~/.scuv/config.json",
+            );
+        }
+
+        CheckResult::ok(self.id(), self.name()).with_details(details)
+    }
+}
+
+impl Check for DiskUsageCheck {
+    fn id(&self) -> &'static str {
+        "disk-usage"
+    }
+
+    fn name(&self) -> &'static str {
+        "disk usage"
+    }
+
+    fn run(&self) -> Vec<CheckResult> {
+        let Ok(venvs_dir) = paths::virtualenvs_dir() else {
+            // The virtualenv check already reports a missing directory.
+            return vec![
+                CheckResult::ok(self.id(), self.name())
+                    .with_details("skipped: virtualenvs directory not found"),
+            ];
+        };
+
+        let envs: Vec<(String, std::path::PathBuf)> = std::fs::read_dir(&venvs_dir)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .map(|e| e.path())
+                    .filter(|p| p.is_dir())
+                    .filter_map(|p| Some((p.file_name()?.to_str()?.to_string(), p)))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let sizes = envs
+            .into_par_iter()
+            .map(|(name, path)| (name, dir_size(&path)))
+            .collect();
+
+        let threshold_gb = Config::load()
+            .ok()
+            .and_then(|c| c.doctor.disk_usage_threshold_gb)
+            .unwrap_or(DEFAULT_THRESHOLD_GB);
+
+        vec![self.evaluate(sizes, threshold_gb.saturating_mul(GB))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::doctor::CheckStatus;
+
+    const MB: u64 = 1024 * 1024;
+
+    #[test]
+    fn no_envs_is_ok() {
+        let result = DiskUsageCheck.evaluate(Vec::new(), GB);
+        assert!(result.is_ok());
+        assert_eq!(result.details.as_deref(), Some("no environments yet"));
+    }
+
+    #[test]
+    fn under_threshold_is_ok_with_details() {
+        let sizes = vec![("a".to_string(), 100 * MB), ("b".to_string(), 300 * MB)];
+        let result = DiskUsageCheck.evaluate(sizes, GB);
+        assert!(result.is_ok());
+        let details = result.details.unwrap();
+        assert!(details.contains("2 environment(s)"), "{details}");
+        assert!(details.starts_with("400"), "{details}");
+    }
+
+    #[test]
+    fn over_threshold_warns_and_lists_largest_first() {
+        let sizes = (0..7)
+            .map(|i| (format!("env{i}"), (i + 1) * 200 * MB))
+            .collect();
+        let result = DiskUsageCheck.evaluate(sizes, GB);
+        assert!(matches!(result.status, CheckStatus::Warning(_)));
+        assert!(result.suggestion.is_some());
+
+        let details = result.details.unwrap();
+        let largest = details.split("largest: ").nth(1).unwrap();
+        assert!(largest.starts_with("env6 "), "{largest}");
+        assert_eq!(largest.matches(", ").count(), LARGEST_SHOWN - 1);
+        assert!(!largest.contains("env1 "), "{largest}");
+    }
+}
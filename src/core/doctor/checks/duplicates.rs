@@ -0,0 +1,251 @@
+//! Check for environment names that are really the same environment.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::paths;
+
+use super::super::types::{Check, CheckResult};
+
+/// One entry of the virtualenvs directory.
+struct StoreEntry {
+    name: String,
+    path: PathBuf,
+    /// Where the entry resolves to once symlinks are followed.
+    target: PathBuf,
+    is_link: bool,
+}
+
+/// Warn when several names in the virtualenvs directory resolve to the same
+/// environment, or differ only in letter case.
+///
+/// A symlink from one store name to another (or two symlinks to one
+/// directory) makes `scuv list` show the environment twice and `remove`
+/// of one name surprising. Names like `Web` and `web` work on Linux but
+/// collide as soon as the store is copied to a case-insensitive
+/// filesystem. Only the store itself is walked: project `.venv` symlinks
+/// pointing into it live elsewhere and are not duplicates.
+pub(super) struct DuplicatesCheck;
+
+impl DuplicatesCheck {
+    /// Group `entries` by resolved target and by lowercased name.
+    fn evaluate(&self, entries: &[StoreEntry]) -> Vec<CheckResult> {
+        let mut by_target: BTreeMap<&Path, Vec<&StoreEntry>> = BTreeMap::new();
+        for entry in entries {
+            by_target.entry(&entry.target).or_default().push(entry);
+        }
+
+        let mut results = Vec::new();
+        for (target, group) in &by_target {
+            if group.len() < 2 {
+                continue;
+            }
+            let links: Vec<_> = group.iter().filter(|e| e.is_link).collect();
+            let suggestion = if links.is_empty() {
+                // Not symlinks (a bind mount?): `scuv remove` on either name
+                // would delete the shared environment.
+                "Unlink the extra name without `scuv remove`, which would delete the shared environment".to_string()
+            } else {
+                // Deleting the symlink itself leaves the environment alone.
+                let paths = links
+                    .iter()
+                    .map(|e| e.path.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                format!("Keep one name and delete the extra symlink(s): rm {paths}")
+            };
+            results.push(
+                CheckResult::warn(
+                    self.id(),
+                    self.name(),
+                    format!(
+                        "{} are the same environment ({})",
+                        quoted_names(group),
+                        target.display()
+                    ),
+                )
+                .with_details("one environment listed under several names")
+                .with_suggestion(suggestion),
+            );
+        }
+
+        // Case-only differences between *different* environments; names
+        // already grouped above resolve to one target and are skipped.
+        let mut by_folded: BTreeMap<String, Vec<&StoreEntry>> = BTreeMap::new();
+        for group in by_target.values() {
+            by_folded
+                .entry(group[0].name.to_lowercase())
+                .or_default()
+                .push(group[0]);
+        }
+        for group in by_folded.values().filter(|group| group.len() > 1) {
+            results.push(
+                CheckResult::warn(
+                    self.id(),
+                    self.name(),
+                    format!("{} differ only in letter case", quoted_names(group)),
+                )
+                .with_details(
+                    "they would collide on a case-insensitive filesystem (macOS, Windows)",
+                )
+                .with_suggestion("Rename one: `scuv clone <old> <new>`, then `scuv remove <old>`"),
+            );
+        }
+
+        if results.is_empty() {
+            results.push(
+                CheckResult::ok(self.id(), self.name()).with_details(format!(
+                    "{} environment name(s), all distinct",
+                    entries.len()
+                )),
+            );
+        }
+        results
+    }
+}
+
+/// `'a', 'b'` in name order.
+fn quoted_names(group: &[&StoreEntry]) -> String {
+    let mut names: Vec<_> = group.iter().map(|e| format!("'{}'", e.name)).collect();
+    names.sort();
+    names.join(", ")
+}
+
+impl Check for DuplicatesCheck {
+    fn id(&self) -> &'static str {
+        "duplicates"
+    }
+
+    fn name(&self) -> &'static str {
+        "duplicate environments"
+    }
+
+    fn run(&self) -> Vec<CheckResult> {
+        let Ok(venvs_dir) = paths::virtualenvs_dir() else {
+            // The virtualenv check already reports a missing directory.
+            return vec![
+                CheckResult::ok(self.id(), self.name())
+                    .with_details("skipped: virtualenvs directory not found"),
+            ];
+        };
+
+        let entries: Vec<StoreEntry> = std::fs::read_dir(&venvs_dir)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .filter_map(|entry| {
+                        let path = entry.path();
+                        let name = path.file_name()?.to_str()?.to_string();
+                        if name.starts_with('.') || !path.is_dir() {
+                            return None;
+                        }
+                        // Broken symlinks fail `is_dir` above and are the
+                        // symlink check's business.
+                        let target = std::fs::canonicalize(&path).ok()?;
+                        Some(StoreEntry {
+                            name,
+                            is_link: path.is_symlink(),
+                            path,
+                            target,
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        self.evaluate(&entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::doctor::CheckStatus;
+    use serial_test::serial;
+
+    fn entry(name: &str, target: &str, is_link: bool) -> StoreEntry {
+        StoreEntry {
+            name: name.to_string(),
+            path: PathBuf::from("/store").join(name),
+            target: PathBuf::from(target),
+            is_link,
+        }
+    }
+
+    fn warnings(results: &[CheckResult]) -> Vec<&str> {
+        results
+            .iter()
+            .filter_map(|r| match &r.status {
+                CheckStatus::Warning(msg) => Some(msg.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn distinct_names_are_ok() {
+        let results = DuplicatesCheck.evaluate(&[
+            entry("web", "/store/web", false),
+            entry("api", "/store/api", false),
+        ]);
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_ok());
+        assert!(results[0].details.as_deref().unwrap().starts_with("2 "));
+    }
+
+    #[test]
+    fn names_sharing_a_target_warn_and_suggest_removing_links() {
+        let results = DuplicatesCheck.evaluate(&[
+            entry("web", "/store/web", false),
+            entry("web-old", "/store/web", true),
+            entry("api", "/store/api", false),
+        ]);
+        assert_eq!(
+            warnings(&results),
+            ["'web', 'web-old' are the same environment (/store/web)"]
+        );
+        assert_eq!(
+            results[0].suggestion.as_deref(),
+            Some("Keep one name and delete the extra symlink(s): rm /store/web-old")
+        );
+    }
+
+    #[test]
+    fn case_only_differences_warn() {
+        let results = DuplicatesCheck.evaluate(&[
+            entry("Web", "/store/Web", false),
+            entry("web", "/store/web", false),
+        ]);
+        assert_eq!(
+            warnings(&results),
+            ["'Web', 'web' differ only in letter case"]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    #[serial]
+    fn run_groups_symlinks_inside_the_store_only() {
+        crate::test_utils::with_temp_scoop_home(|temp| {
+            let venvs = temp.path().join("virtualenvs");
+            std::fs::create_dir_all(venvs.join("web")).unwrap();
+            std::os::unix::fs::symlink(venvs.join("web"), venvs.join("alias")).unwrap();
+            // A project .venv pointing into the store is not a duplicate.
+            let project = temp.path().join("project");
+            std::fs::create_dir_all(&project).unwrap();
+            std::os::unix::fs::symlink(venvs.join("web"), project.join(".venv")).unwrap();
+
+            let results = DuplicatesCheck.run();
+            let found = warnings(&results);
+            assert_eq!(found.len(), 1, "{results:#?}");
+            assert!(
+                found[0].starts_with("'alias', 'web' are the same"),
+                "{}",
+                found[0]
+            );
+
+            std::fs::remove_file(venvs.join("alias")).unwrap();
+            assert!(DuplicatesCheck.run()[0].is_ok());
+        });
+    }
+}
@@ -0,0 +1,118 @@
+//! Check for a virtualenv from another tool being active.
+
+use std::path::{Path, PathBuf};
+
+use crate::paths;
+
+use super::super::types::{Check, CheckResult};
+use super::activation::same_path;
+
+/// Warn when `VIRTUAL_ENV` names a venv scuv doesn't manage while no scuv
+/// environment is active (`SCUV_ACTIVE` unset or `system`).
+///
+/// `source .venv/bin/activate`, Poetry or an IDE terminal put their own
+/// `bin/` first on `PATH`, so `python` runs that venv rather than the one
+/// `scuv use` selected. Reads the environment of the process running
+/// doctor, like the activation check.
+pub(super) struct ForeignVenvCheck;
+
+impl ForeignVenvCheck {
+    /// Judge the active name and `VIRTUAL_ENV` against `venvs_dir`.
+    fn evaluate(
+        &self,
+        active: Option<&str>,
+        virtual_env: Option<&Path>,
+        venvs_dir: &Path,
+    ) -> CheckResult {
+        let Some(venv) = virtual_env.filter(|venv| !venv.as_os_str().is_empty()) else {
+            return CheckResult::ok(self.id(), self.name()).with_details("no virtualenv active");
+        };
+        match active.filter(|name| !name.is_empty() && *name != "system") {
+            // Whether it matches `VIRTUAL_ENV` is the activation check's call.
+            Some(name) => CheckResult::ok(self.id(), self.name())
+                .with_details(format!("scuv environment '{name}' active")),
+            None if same_path(venv.parent(), Some(venvs_dir)) => {
+                CheckResult::ok(self.id(), self.name())
+                    .with_details(format!("scuv environment active: {}", venv.display()))
+            }
+            None => CheckResult::warn(
+                self.id(),
+                self.name(),
+                format!(
+                    "a virtualenv not managed by scuv is active: {}",
+                    venv.display()
+                ),
+            )
+            .with_details(format!(
+                "`python` runs {}, not a scuv environment",
+                paths::virtualenv_python_exe(venv).display()
+            ))
+            .with_suggestion("Run `deactivate` to leave it, then `scuv use <name>`"),
+        }
+    }
+}
+
+impl Check for ForeignVenvCheck {
+    fn id(&self) -> &'static str {
+        "foreign_venv"
+    }
+
+    fn name(&self) -> &'static str {
+        "active virtualenv"
+    }
+
+    fn run(&self) -> Vec<CheckResult> {
+        let Ok(venvs_dir) = paths::virtualenvs_dir() else {
+            return vec![CheckResult::ok(self.id(), self.name())];
+        };
+        let active = std::env::var("SCUV_ACTIVE").ok();
+        let virtual_env = std::env::var_os("VIRTUAL_ENV").map(PathBuf::from);
+        vec![self.evaluate(active.as_deref(), virtual_env.as_deref(), &venvs_dir)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::doctor::CheckStatus;
+    use tempfile::TempDir;
+
+    fn store() -> (TempDir, PathBuf) {
+        let temp = TempDir::new().unwrap();
+        let venvs = temp.path().join("virtualenvs");
+        std::fs::create_dir_all(venvs.join("web")).unwrap();
+        (temp, venvs)
+    }
+
+    #[test]
+    fn nothing_or_a_scuv_env_active_is_ok() {
+        let (_temp, venvs) = store();
+        let web = venvs.join("web");
+        let check = ForeignVenvCheck;
+
+        assert!(check.evaluate(None, None, &venvs).is_ok());
+        assert!(check.evaluate(Some("web"), Some(&web), &venvs).is_ok());
+        // In the store without SCUV_ACTIVE is drift, not a foreign venv.
+        assert!(check.evaluate(None, Some(&web), &venvs).is_ok());
+    }
+
+    #[test]
+    fn foreign_venv_without_scuv_env_warns_with_its_path() {
+        let (_temp, venvs) = store();
+        let project = Path::new("/work/proj/.venv");
+
+        for active in [None, Some(""), Some("system")] {
+            let result = ForeignVenvCheck.evaluate(active, Some(project), &venvs);
+            match &result.status {
+                CheckStatus::Warning(msg) => {
+                    assert_eq!(
+                        msg,
+                        "a virtualenv not managed by scuv is active: /work/proj/.venv"
+                    );
+                }
+                other => panic!("expected a warning for {active:?}, got {other:?}"),
+            }
+            assert!(result.suggestion.as_deref().unwrap().contains("deactivate"));
+        }
+    }
+}
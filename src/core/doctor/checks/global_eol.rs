@@ -0,0 +1,315 @@
+//! Check whether the global default environment runs an EOL Python.
+
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+
+use dialoguer::Confirm;
+
+use crate::core::migrate::common::EolMinimum;
+use crate::core::{VersionService, VirtualenvService, list_installed_packages};
+use crate::error::Result;
+use crate::paths;
+
+use super::super::types::{Check, CheckResult, CheckStatus};
+
+/// Message prefix for the EOL warning. [`GlobalEolCheck::fix`] keys on it
+/// so an unrelated warning on the same id is never "fixed".
+const EOL_DEFAULT: &str = "global default";
+
+/// Warn when the environment set with `scuv use --global` runs a Python
+/// older than the EOL cutoff (`migrate.eol_minimum`, default 3.9).
+///
+/// `--fix` can recreate it at the newest patch of the oldest supported
+/// minor (what `create <name> stable` picks), reinstalling its packages.
+/// That replaces the environment, so it always asks first (`--no-input`
+/// is the only way past the prompt) and without `--fix` this is just a
+/// warning.
+pub(super) struct GlobalEolCheck {
+    pub(super) no_input: bool,
+}
+
+impl GlobalEolCheck {
+    /// Judge the global default `name` whose Python is `version`.
+    fn evaluate(&self, name: &str, version: Option<&str>, eol: EolMinimum) -> CheckResult {
+        match version {
+            Some(version) if eol.is_eol(version) => CheckResult::warn(
+                self.id(),
+                self.name(),
+                format!("{EOL_DEFAULT} '{name}' uses Python {version}, which is end-of-life"),
+            )
+            .with_details(format!("versions older than {eol} are EOL"))
+            .with_suggestion(format!(
+                "Run: scuv doctor --fix to recreate '{name}' at a supported Python (asks first)"
+            )),
+            Some(version) => CheckResult::ok(self.id(), self.name())
+                .with_details(format!("'{name}' uses Python {version}")),
+            None => CheckResult::ok(self.id(), self.name())
+                .with_details(format!("'{name}': Python version unknown")),
+        }
+    }
+
+    /// Whether the fix may go ahead at all: with `--no-input`, or with a
+    /// terminal to ask on. JSON mode never prompts (same as `remove`).
+    fn can_confirm(&self, output: &crate::output::Output) -> bool {
+        self.no_input || (!output.is_json() && std::io::stdin().is_terminal())
+    }
+
+    /// Ask before replacing the environment.
+    fn confirmed(&self, prompt: String) -> bool {
+        self.no_input
+            || Confirm::new()
+                .with_prompt(prompt)
+                .default(false)
+                .interact()
+                .unwrap_or(false)
+    }
+}
+
+/// Replace the environment at `path` by whatever `build` creates there.
+///
+/// The old environment is moved to `backup` first and only deleted once
+/// `build` succeeds; on failure the half-built one is removed and the old
+/// one moved back, so a failed fix leaves the user where they started.
+fn replace_env(path: &Path, backup: &Path, build: impl FnOnce() -> Result<()>) -> Result<()> {
+    if backup.exists() {
+        std::fs::remove_dir_all(backup)?;
+    }
+    std::fs::rename(path, backup)?;
+
+    match build() {
+        Ok(()) => {
+            // The new env is in place; a leftover backup only costs space.
+            let _ = std::fs::remove_dir_all(backup);
+            Ok(())
+        }
+        Err(e) => {
+            if path.exists() {
+                let _ = std::fs::remove_dir_all(path);
+            }
+            std::fs::rename(backup, path)?;
+            Err(e)
+        }
+    }
+}
+
+/// Where the old environment waits while its replacement is built:
+/// next to the virtualenvs directory, so `scuv list` never shows it.
+fn backup_path(venvs_dir: &Path, name: &str) -> PathBuf {
+    venvs_dir.with_file_name(format!(".eol-backup-{name}"))
+}
+
+impl Check for GlobalEolCheck {
+    fn id(&self) -> &'static str {
+        "global_eol"
+    }
+
+    fn name(&self) -> &'static str {
+        "global default Python"
+    }
+
+    fn run(&self) -> Vec<CheckResult> {
+        let name = match VersionService::get_global() {
+            Some(name) if !name.eq_ignore_ascii_case("system") => name,
+            Some(_) => {
+                return vec![
+                    CheckResult::ok(self.id(), self.name())
+                        .with_details("system Python (no virtualenv)"),
+                ];
+            }
+            None => {
+                return vec![
+                    CheckResult::ok(self.id(), self.name()).with_details("no global default set"),
+                ];
+            }
+        };
+        let Ok(path) = paths::virtualenv_path(&name) else {
+            return vec![CheckResult::ok(self.id(), self.name())];
+        };
+        if !path.is_dir() {
+            // The version check already reports a default that doesn't exist.
+            return vec![
+                CheckResult::ok(self.id(), self.name())
+                    .with_details(format!("skipped: '{name}' not found")),
+            ];
+        }
+
+        let version = crate::core::parse_pyvenv_version(&path);
+        vec![self.evaluate(&name, version.as_deref(), EolMinimum::configured())]
+    }
+
+    fn fix(&self, result: &CheckResult, output: &crate::output::Output) -> Option<CheckResult> {
+        if !matches!(&result.status, CheckStatus::Warning(msg) if msg.starts_with(EOL_DEFAULT)) {
+            return None;
+        }
+        if !self.can_confirm(output) {
+            return None;
+        }
+        let name = VersionService::get_global()?;
+        let path = paths::virtualenv_path(&name).ok()?;
+        let old_version = crate::core::parse_pyvenv_version(&path)?;
+
+        let failed = |message: String| {
+            CheckResult::error(self.id(), self.name(), message).with_suggestion(format!(
+                "'{name}' was left unchanged on Python {old_version}"
+            ))
+        };
+
+        let service = match VirtualenvService::for_output(output) {
+            Ok(service) => service,
+            Err(e) => return Some(failed(format!("could not recreate '{name}': {e}"))),
+        };
+        let target = match service.resolve_python_alias("stable") {
+            Ok(target) => target,
+            Err(e) => {
+                return Some(failed(format!(
+                    "could not pick a supported Python for '{name}': {e}"
+                )));
+            }
+        };
+
+        let packages: Vec<String> = list_installed_packages(&path)
+            .into_iter()
+            .map(|(name, version)| format!("{name}=={version}"))
+            .collect();
+        let prompt = format!(
+            "Recreate '{name}' with Python {target} (now {old_version}) and reinstall {} package(s)?",
+            packages.len()
+        );
+        if !self.confirmed(prompt) {
+            return None;
+        }
+
+        output.info(&format!(
+            "Recreating '{name}': Python {old_version} -> {target}..."
+        ));
+        let backup = backup_path(path.parent()?, &name);
+        let rebuilt = replace_env(&path, &backup, || {
+            let new_path = service.create(&name, &target)?;
+            if !packages.is_empty() {
+                service.pip_install(&new_path, &packages)?;
+            }
+            Ok(())
+        });
+
+        Some(match rebuilt {
+            Ok(()) => CheckResult::ok(self.id(), self.name()).with_details(format!(
+                "'{name}' recreated: Python {old_version} -> {target} ({} package(s) reinstalled)",
+                packages.len()
+            )),
+            Err(e) => failed(format!(
+                "could not recreate '{name}' at Python {target}: {e}"
+            )),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::ColorChoice;
+    use crate::error::ScoopError;
+    use crate::output::Output;
+    use serial_test::serial;
+    use tempfile::TempDir;
+
+    fn check() -> GlobalEolCheck {
+        GlobalEolCheck { no_input: false }
+    }
+
+    #[test]
+    fn eol_default_warns_with_fix_suggestion() {
+        let result = check().evaluate("old", Some("3.8.18"), EolMinimum::default());
+        match &result.status {
+            CheckStatus::Warning(msg) => {
+                assert_eq!(
+                    msg,
+                    "global default 'old' uses Python 3.8.18, which is end-of-life"
+                );
+            }
+            other => panic!("expected a warning, got {other:?}"),
+        }
+        assert!(result.suggestion.unwrap().contains("scuv doctor --fix"));
+    }
+
+    #[test]
+    fn supported_or_unknown_versions_are_ok() {
+        let eol = EolMinimum::default();
+        assert!(check().evaluate("web", Some("3.12.1"), eol).is_ok());
+        assert!(check().evaluate("web", None, eol).is_ok());
+        let strict = EolMinimum::parse("3.13").unwrap();
+        assert!(!check().evaluate("web", Some("3.12.1"), strict).is_ok());
+    }
+
+    #[test]
+    #[serial]
+    fn run_reads_the_global_default_env() {
+        crate::test_utils::with_temp_scoop_home(|temp| {
+            let env = temp.path().join("virtualenvs/old");
+            std::fs::create_dir_all(&env).unwrap();
+            std::fs::write(env.join("pyvenv.cfg"), "version_info = 3.7.17\n").unwrap();
+
+            assert!(check().run()[0].is_ok(), "no global default yet");
+
+            VersionService::set_global("old").unwrap();
+            let results = check().run();
+            assert!(!results[0].is_ok(), "{results:#?}");
+        });
+    }
+
+    #[test]
+    fn fix_ignores_other_results_and_never_prompts_in_json_mode() {
+        let output = Output::new(0, true, ColorChoice::Never, true);
+        let unrelated = CheckResult::warn("global_eol", "global default Python", "something else");
+        assert!(check().fix(&unrelated, &output).is_none());
+
+        let eol = check().evaluate("old", Some("3.8.18"), EolMinimum::default());
+        assert!(!check().can_confirm(&output));
+        assert!(check().fix(&eol, &output).is_none());
+        assert!(GlobalEolCheck { no_input: true }.can_confirm(&output));
+    }
+
+    #[test]
+    fn replace_env_restores_the_old_env_when_building_fails() {
+        let temp = TempDir::new().unwrap();
+        let env = temp.path().join("virtualenvs/old");
+        std::fs::create_dir_all(&env).unwrap();
+        std::fs::write(env.join("marker"), "original").unwrap();
+        let backup = backup_path(&temp.path().join("virtualenvs"), "old");
+
+        let err = replace_env(&env, &backup, || {
+            std::fs::create_dir_all(&env).unwrap();
+            std::fs::write(env.join("marker"), "half-built").unwrap();
+            Err(ScoopError::InvalidArgument {
+                message: "uv failed".into(),
+            })
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("uv failed"));
+        assert_eq!(
+            std::fs::read_to_string(env.join("marker")).unwrap(),
+            "original"
+        );
+        assert!(!backup.exists());
+    }
+
+    #[test]
+    fn replace_env_drops_the_backup_on_success() {
+        let temp = TempDir::new().unwrap();
+        let env = temp.path().join("virtualenvs/old");
+        std::fs::create_dir_all(&env).unwrap();
+        std::fs::write(env.join("marker"), "original").unwrap();
+        let backup = backup_path(&temp.path().join("virtualenvs"), "old");
+
+        replace_env(&env, &backup, || {
+            std::fs::create_dir_all(&env).unwrap();
+            std::fs::write(env.join("marker"), "rebuilt").unwrap();
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(env.join("marker")).unwrap(),
+            "rebuilt"
+        );
+        assert!(!backup.exists());
+    }
+}
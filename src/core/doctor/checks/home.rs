@@ -7,16 +7,9 @@ use super::super::types::{Check, CheckResult, CheckStatus};
 /// Check for SCUV_HOME directory.
 pub(super) struct HomeCheck;
 
-impl Check for HomeCheck {
-    fn id(&self) -> &'static str {
-        "home"
-    }
-
-    fn name(&self) -> &'static str {
-        "SCUV_HOME directory"
-    }
-
-    fn run(&self) -> Vec<CheckResult> {
+impl HomeCheck {
+    /// Whether the home directory exists and is writable.
+    fn check_dir(&self) -> Vec<CheckResult> {
         match paths::scoop_home() {
             Ok(path) if path.exists() => {
                 // Check write permission
@@ -54,6 +47,40 @@ impl Check for HomeCheck {
         }
     }
 
+    /// Warning for a home taken from a relative `var=value`.
+    fn relative_warning(&self, var: &str, value: &str) -> CheckResult {
+        let resolved = paths::scoop_home()
+            .map(|home| home.display().to_string())
+            .unwrap_or_else(|_| value.to_string());
+        CheckResult::warn(
+            self.id(),
+            self.name(),
+            format!("{var} is a relative path ({value})"),
+        )
+        .with_details(format!(
+            "resolved against the directory scuv was started in, to {resolved}; from another directory it names a different store"
+        ))
+        .with_suggestion(format!("Set an absolute path: export {var}=\"{resolved}\""))
+    }
+}
+
+impl Check for HomeCheck {
+    fn id(&self) -> &'static str {
+        "home"
+    }
+
+    fn name(&self) -> &'static str {
+        "SCUV_HOME directory"
+    }
+
+    fn run(&self) -> Vec<CheckResult> {
+        let mut results = self.check_dir();
+        if let Some((var, value)) = paths::relative_home_env() {
+            results.push(self.relative_warning(var, &value));
+        }
+        results
+    }
+
     fn fix(&self, result: &CheckResult, output: &crate::output::Output) -> Option<CheckResult> {
         // Only fix "directory not found" errors
         if let CheckStatus::Error(msg) = &result.status {
@@ -93,6 +120,7 @@ impl Check for HomeCheck {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::ColorChoice;
 
     use crate::test_utils::with_temp_scoop_home;
     use serial_test::serial;
@@ -138,6 +166,32 @@ mod tests {
         assert_eq!(results[0].id, "home");
     }
 
+    #[test]
+    #[serial]
+    fn home_run_warns_when_scuv_home_is_relative() {
+        let _g = crate::test_utils::env_guard(&[
+            (paths::SCUV_HOME_ENV, Some("./scuv-relative-store")),
+            (paths::LEGACY_HOME_ENV, None),
+        ]);
+        let results = HomeCheck.run();
+        let warning = results
+            .iter()
+            .find(|r| r.is_warning())
+            .unwrap_or_else(|| panic!("expected a warning, got {results:#?}"));
+        let resolved = paths::scoop_home().unwrap();
+        assert!(resolved.is_absolute());
+        assert_eq!(
+            warning.suggestion.as_deref(),
+            Some(
+                format!(
+                    "Set an absolute path: export SCUV_HOME=\"{}\"",
+                    resolved.display()
+                )
+                .as_str()
+            )
+        );
+    }
+
     #[test]
     #[serial]
     fn home_run_errors_directory_not_found_when_missing() {
@@ -224,7 +278,7 @@ mod tests {
             Some(missing_home.to_str().unwrap()),
         )]);
 
-        let output = crate::output::Output::new(0, true, true, false);
+        let output = crate::output::Output::new(0, true, ColorChoice::Never, false);
         let broken = CheckResult::error("home", "SCUV_HOME directory", "directory not found");
 
         let fixed = HomeCheck.fix(&broken, &output);
@@ -246,7 +300,7 @@ mod tests {
             Some(tmp.path().to_str().unwrap()),
         )]);
 
-        let output = crate::output::Output::new(0, true, true, false);
+        let output = crate::output::Output::new(0, true, ColorChoice::Never, false);
         let unrelated = CheckResult::error("home", "SCUV_HOME directory", "permission denied");
         assert!(HomeCheck.fix(&unrelated, &output).is_none());
 
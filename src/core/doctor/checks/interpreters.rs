@@ -0,0 +1,299 @@
+//! Check that every environment's interpreter actually starts.
+
+use std::io::{Read, Seek};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use rayon::prelude::*;
+
+use crate::paths;
+
+use super::super::types::{Check, CheckResult};
+
+/// How long one interpreter gets to print its version before it is killed.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How often a running probe is polled for exit.
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// How many trailing lines of a failed probe's output the details keep.
+const OUTPUT_LINES: usize = 5;
+
+/// Run `bin/python -c "import sys; print(sys.version)"` in every
+/// environment and report the ones that don't launch.
+///
+/// Catches what the filesystem checks can't see: a base install with a
+/// missing shared library, a broken standard library, an interpreter built
+/// for another architecture. Starting every interpreter is slow, so this
+/// only runs with `doctor --deep` (see [`super::deep_checks`]). Envs with
+/// no interpreter at all are left to the virtualenv check.
+pub(super) struct InterpreterCheck {
+    timeout: Duration,
+}
+
+impl Default for InterpreterCheck {
+    fn default() -> Self {
+        Self {
+            timeout: PROBE_TIMEOUT,
+        }
+    }
+}
+
+/// How a probe ended.
+#[derive(Debug, PartialEq)]
+enum Probe {
+    /// Exited 0; the first line it printed (`sys.version`).
+    Launched(String),
+    /// Couldn't be started, or exited non-zero: a one-line reason and the
+    /// tail of its output.
+    Failed(String, String),
+    /// Still running at the timeout, and killed.
+    Hung,
+}
+
+/// Start `python` and wait up to `timeout` for it to print its version.
+///
+/// Output goes to a temp file rather than a pipe, so an interpreter that
+/// leaves a child behind holding stdout can't block the wait.
+fn probe(python: &Path, timeout: Duration) -> Probe {
+    let mut out = match tempfile::tempfile() {
+        Ok(file) => file,
+        Err(e) => return Probe::Failed(format!("could not capture output: {e}"), String::new()),
+    };
+    let spawned = out.try_clone().and_then(|stdout| {
+        let stderr = stdout.try_clone()?;
+        Command::new(python)
+            .args(["-c", "import sys; print(sys.version)"])
+            // Test the install itself, not the caller's environment; the
+            // pythonhome check covers a stray PYTHONHOME.
+            .env_remove("PYTHONHOME")
+            .env_remove("PYTHONPATH")
+            .stdin(Stdio::null())
+            .stdout(stdout)
+            .stderr(stderr)
+            .spawn()
+    });
+    let mut child = match spawned {
+        Ok(child) => child,
+        Err(e) => return Probe::Failed(format!("could not start: {e}"), String::new()),
+    };
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if Instant::now() < deadline => std::thread::sleep(POLL_INTERVAL),
+            Ok(None) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Probe::Hung;
+            }
+            Err(e) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Probe::Failed(format!("could not wait for it: {e}"), String::new());
+            }
+        }
+    };
+
+    let mut printed = String::new();
+    let _ = out.rewind().and_then(|()| out.read_to_string(&mut printed));
+    if status.success() {
+        let version = printed
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+        return Probe::Launched(version);
+    }
+    let reason = match status.code() {
+        Some(code) => format!("exited with status {code}"),
+        None => "was killed by a signal".to_string(),
+    };
+    let lines: Vec<&str> = printed.lines().collect();
+    let tail = lines[lines.len().saturating_sub(OUTPUT_LINES)..].join("\n");
+    Probe::Failed(reason, tail)
+}
+
+impl InterpreterCheck {
+    /// Judge the `probes` of each (env name, outcome), sorted by name.
+    fn evaluate(&self, probes: Vec<(String, Probe)>) -> Vec<CheckResult> {
+        if probes.is_empty() {
+            return vec![
+                CheckResult::ok(self.id(), self.name()).with_details("no environments yet"),
+            ];
+        }
+
+        let mut results = Vec::new();
+        let mut launched = Vec::new();
+        for (name, probe) in probes {
+            let result = match probe {
+                Probe::Launched(version) => {
+                    launched.push(format!("{name}: {version}"));
+                    continue;
+                }
+                Probe::Failed(reason, output) => {
+                    let result = CheckResult::error(
+                        self.id(),
+                        self.name(),
+                        format!("'{name}' interpreter {reason}"),
+                    );
+                    if output.is_empty() {
+                        result
+                    } else {
+                        result.with_details(output)
+                    }
+                }
+                Probe::Hung => CheckResult::error(
+                    self.id(),
+                    self.name(),
+                    format!(
+                        "'{name}' interpreter did not finish within {}s and was killed",
+                        self.timeout.as_secs_f32()
+                    ),
+                ),
+            };
+            results.push(result.with_suggestion(format!(
+                "Reinstall its Python or rebuild it: scuv create {name} <python-version> --force"
+            )));
+        }
+
+        if !launched.is_empty() {
+            results.push(
+                CheckResult::ok(self.id(), self.name()).with_details(format!(
+                    "{} interpreter(s) launched\n{}",
+                    launched.len(),
+                    launched.join("\n")
+                )),
+            );
+        }
+        results
+    }
+}
+
+impl Check for InterpreterCheck {
+    fn id(&self) -> &'static str {
+        "interpreters"
+    }
+
+    fn name(&self) -> &'static str {
+        "interpreter launch"
+    }
+
+    fn run(&self) -> Vec<CheckResult> {
+        let Ok(venvs_dir) = paths::virtualenvs_dir() else {
+            // The virtualenv check already reports a missing directory.
+            return vec![];
+        };
+        let mut envs: Vec<(String, PathBuf)> = std::fs::read_dir(&venvs_dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                let python = paths::virtualenv_python_exe(&entry.path());
+                python.exists().then_some((name, python))
+            })
+            .collect();
+        envs.sort();
+
+        let probes = envs
+            .into_par_iter()
+            .map(|(name, python)| {
+                let outcome = probe(&python, self.timeout);
+                (name, outcome)
+            })
+            .collect();
+        self.evaluate(probes)
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::test_utils::with_temp_scoop_home;
+    use serial_test::serial;
+    use std::os::unix::fs::PermissionsExt;
+
+    /// A fake `bin/python` for env `name` running the shell `body`.
+    fn fake_env(venvs: &Path, name: &str, body: &str) -> PathBuf {
+        let python = paths::virtualenv_python_exe(&venvs.join(name));
+        std::fs::create_dir_all(python.parent().unwrap()).unwrap();
+        std::fs::write(&python, format!("#!/bin/sh\n{body}\n")).unwrap();
+        std::fs::set_permissions(&python, std::fs::Permissions::from_mode(0o755)).unwrap();
+        python
+    }
+
+    #[test]
+    fn probe_reports_version_failure_and_hang() {
+        let tmp = tempfile::tempdir().unwrap();
+        let ok = fake_env(tmp.path(), "ok", "echo '3.12.4 (main) [Clang]'");
+        assert_eq!(
+            probe(&ok, PROBE_TIMEOUT),
+            Probe::Launched("3.12.4 (main) [Clang]".to_string())
+        );
+
+        let broken = fake_env(
+            tmp.path(),
+            "broken",
+            "echo 'Fatal Python error: init_fs_encoding' >&2; exit 1",
+        );
+        assert_eq!(
+            probe(&broken, PROBE_TIMEOUT),
+            Probe::Failed(
+                "exited with status 1".to_string(),
+                "Fatal Python error: init_fs_encoding".to_string()
+            )
+        );
+
+        let hung = fake_env(tmp.path(), "hung", "exec sleep 30");
+        let started = Instant::now();
+        assert_eq!(probe(&hung, Duration::from_millis(200)), Probe::Hung);
+        assert!(started.elapsed() < Duration::from_secs(10));
+
+        let missing = tmp.path().join("missing").join("bin").join("python");
+        assert!(matches!(
+            probe(&missing, PROBE_TIMEOUT),
+            Probe::Failed(reason, _) if reason.starts_with("could not start")
+        ));
+    }
+
+    #[test]
+    #[serial]
+    fn run_reports_each_failing_env_and_summarises_the_rest() {
+        with_temp_scoop_home(|home| {
+            let venvs = home.path().join("virtualenvs");
+            fake_env(&venvs, "web", "echo 3.12.4");
+            fake_env(&venvs, "api", "echo 3.11.9");
+            fake_env(&venvs, "old", "exit 127");
+            // No interpreter: the virtualenv check's business, skipped here.
+            std::fs::create_dir_all(venvs.join("empty")).unwrap();
+
+            let results = InterpreterCheck::default().run();
+            assert_eq!(results.len(), 2, "got {results:#?}");
+            assert!(results[0].is_error());
+            assert!(
+                results[0]
+                    .suggestion
+                    .as_deref()
+                    .unwrap()
+                    .contains("scuv create old")
+            );
+            assert!(results[1].is_ok());
+            let details = results[1].details.as_deref().unwrap();
+            assert_eq!(
+                details,
+                "2 interpreter(s) launched\napi: 3.11.9\nweb: 3.12.4"
+            );
+        });
+    }
+
+    #[test]
+    fn no_envs_is_ok() {
+        let results = InterpreterCheck::default().evaluate(Vec::new());
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_ok());
+    }
+}
@@ -46,6 +46,7 @@ fn check_legacy_remnants() -> CheckResult {
 
     if found.is_empty() {
         CheckResult::ok("legacy", "legacy scoop remnants")
+            .with_details(rust_i18n::t!("doctor.legacy_none"))
     } else {
         CheckResult::warn(
             "legacy",
@@ -1,9 +1,24 @@
 //! Individual doctor checks, one per file, registered via [`default_checks`].
 
+mod activation;
+mod clock_skew;
+mod completions;
+mod disk_usage;
+mod duplicates;
+mod foreign_venv;
+mod global_eol;
 mod home;
+mod interpreters;
 mod legacy;
+mod permissions;
+mod project_venv;
+mod python_dir;
+mod pythonhome;
+mod pyvenv_home;
 mod shell;
+mod shims;
 mod symlink;
+mod unknown_version;
 mod uv;
 mod version;
 mod virtualenv;
@@ -14,14 +29,46 @@ mod test_support;
 use super::types::Check;
 
 /// The default set of checks, in display order. Single place to register a check.
-pub(super) fn default_checks() -> Vec<Box<dyn Check>> {
+///
+/// `no_input` makes fixes that would ask for confirmation proceed without it.
+pub(super) fn default_checks(no_input: bool) -> Vec<Box<dyn Check>> {
     vec![
         Box::new(uv::UvCheck),
+        Box::new(python_dir::PythonDirCheck),
         Box::new(home::HomeCheck),
+        Box::new(permissions::PermissionsCheck),
         Box::new(virtualenv::VirtualenvCheck),
         Box::new(symlink::SymlinkCheck),
-        Box::new(shell::ShellCheck),
+        Box::new(pyvenv_home::PyvenvHomeCheck),
+        Box::new(unknown_version::UnknownVersionCheck),
+        Box::new(duplicates::DuplicatesCheck),
+        Box::new(project_venv::ProjectVenvCheck),
+        Box::new(shell::ShellCheck { no_input }),
+        Box::new(completions::CompletionsCheck),
+        Box::new(shims::ShimsCheck),
+        Box::new(foreign_venv::ForeignVenvCheck),
+        Box::new(pythonhome::PythonHomeCheck),
         Box::new(version::VersionCheck),
+        Box::new(global_eol::GlobalEolCheck { no_input }),
         Box::new(legacy::LegacyCheck),
+        Box::new(clock_skew::ClockSkewCheck),
     ]
 }
+
+/// Checks that walk every environment on disk. Opt-in (`--with-sizes` or
+/// `--verbose`) so a default `doctor` stays fast.
+pub(super) fn size_checks() -> Vec<Box<dyn Check>> {
+    vec![Box::new(disk_usage::DiskUsageCheck)]
+}
+
+/// Checks that start every environment's interpreter. Opt-in (`--deep`):
+/// each one costs a process launch.
+pub(super) fn deep_checks() -> Vec<Box<dyn Check>> {
+    vec![Box::new(interpreters::InterpreterCheck::default())]
+}
+
+/// Checks that only mean something to a user digging into a problem
+/// (`--verbose`), like whether this shell's activation state is consistent.
+pub(super) fn verbose_checks() -> Vec<Box<dyn Check>> {
+    vec![Box::new(activation::ActivationCheck)]
+}
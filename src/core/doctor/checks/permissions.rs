@@ -0,0 +1,162 @@
+//! Check that SCUV_HOME isn't writable by other users.
+
+use crate::paths;
+
+use super::super::types::{Check, CheckResult};
+
+/// Warn when SCUV_HOME or its virtualenvs directory grants group or other
+/// write access.
+///
+/// Anyone who can write there can swap the interpreters and activate
+/// scripts that every `scuv use` then runs with the owner's privileges.
+/// Unix only: Windows ACLs don't map onto mode bits, so there the check
+/// reports nothing to do.
+pub(super) struct PermissionsCheck;
+
+impl PermissionsCheck {
+    /// Judge each `(path, mode)` pair; directories that don't exist are
+    /// left to the home and virtualenv checks.
+    #[cfg_attr(not(unix), allow(dead_code))]
+    fn evaluate(&self, dirs: &[(std::path::PathBuf, u32)]) -> Vec<CheckResult> {
+        let results: Vec<CheckResult> = dirs
+            .iter()
+            .filter_map(|(path, mode)| {
+                let who = writable_by(*mode)?;
+                Some(
+                    CheckResult::warn(
+                        self.id(),
+                        self.name(),
+                        format!("{} is {who} (mode {:03o})", path.display(), mode & 0o777),
+                    )
+                    .with_details("other users could replace interpreters or activate scripts")
+                    .with_suggestion(format!(
+                        "chmod 755 {} (or 700 to keep it private)",
+                        path.display()
+                    )),
+                )
+            })
+            .collect();
+
+        if results.is_empty() {
+            let shown = dirs
+                .iter()
+                .map(|(path, mode)| format!("{} ({:03o})", path.display(), mode & 0o777))
+                .collect::<Vec<_>>()
+                .join(", ");
+            return vec![CheckResult::ok(self.id(), self.name()).with_details(shown)];
+        }
+        results
+    }
+}
+
+/// Who besides the owner may write, judged from the mode bits.
+#[cfg_attr(not(unix), allow(dead_code))]
+fn writable_by(mode: u32) -> Option<&'static str> {
+    if mode & 0o002 != 0 {
+        Some("world-writable")
+    } else if mode & 0o020 != 0 {
+        Some("group-writable")
+    } else {
+        None
+    }
+}
+
+impl Check for PermissionsCheck {
+    fn id(&self) -> &'static str {
+        "permissions"
+    }
+
+    fn name(&self) -> &'static str {
+        "SCUV_HOME permissions"
+    }
+
+    #[cfg(unix)]
+    fn run(&self) -> Vec<CheckResult> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dirs: Vec<_> = [paths::scoop_home(), paths::virtualenvs_dir()]
+            .into_iter()
+            .flatten()
+            .filter_map(|path| {
+                let mode = std::fs::metadata(&path).ok()?.permissions().mode();
+                Some((path, mode))
+            })
+            .collect();
+
+        if dirs.is_empty() {
+            // The home check already reports a missing directory.
+            return vec![
+                CheckResult::ok(self.id(), self.name())
+                    .with_details("skipped: SCUV_HOME not found"),
+            ];
+        }
+        self.evaluate(&dirs)
+    }
+
+    #[cfg(not(unix))]
+    fn run(&self) -> Vec<CheckResult> {
+        vec![
+            CheckResult::ok(self.id(), self.name()).with_details("skipped: not checked on Windows"),
+        ]
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::core::doctor::CheckStatus;
+    use serial_test::serial;
+    use std::os::unix::fs::PermissionsExt;
+
+    #[test]
+    fn writable_by_reads_group_and_other_bits() {
+        assert_eq!(writable_by(0o755), None);
+        assert_eq!(writable_by(0o700), None);
+        assert_eq!(writable_by(0o775), Some("group-writable"));
+        assert_eq!(writable_by(0o757), Some("world-writable"));
+        assert_eq!(writable_by(0o777), Some("world-writable"));
+        // The file-type bits above 0o777 don't matter.
+        assert_eq!(writable_by(0o40755), None);
+    }
+
+    #[test]
+    #[serial]
+    fn run_warns_until_home_is_locked_down() {
+        crate::test_utils::with_temp_scoop_home(|temp| {
+            let home = temp.path();
+            let venvs = home.join("virtualenvs");
+            std::fs::create_dir_all(&venvs).unwrap();
+            let set = |path: &std::path::Path, mode| {
+                std::fs::set_permissions(path, std::fs::Permissions::from_mode(mode)).unwrap();
+            };
+
+            set(home, 0o777);
+            set(&venvs, 0o775);
+            let results = PermissionsCheck.run();
+            assert_eq!(results.len(), 2, "{results:#?}");
+            let CheckStatus::Warning(msg) = &results[0].status else {
+                panic!("expected a warning, got {:#?}", results[0]);
+            };
+            assert!(msg.contains("world-writable"), "{msg}");
+            assert!(msg.contains("777"), "{msg}");
+            assert!(
+                results[0]
+                    .suggestion
+                    .as_deref()
+                    .unwrap()
+                    .starts_with("chmod 755 ")
+            );
+            let CheckStatus::Warning(msg) = &results[1].status else {
+                panic!("expected a warning, got {:#?}", results[1]);
+            };
+            assert!(msg.contains("virtualenvs is group-writable"), "{msg}");
+
+            set(home, 0o755);
+            set(&venvs, 0o700);
+            let results = PermissionsCheck.run();
+            assert_eq!(results.len(), 1);
+            assert!(results[0].is_ok(), "{results:#?}");
+            assert!(results[0].details.as_deref().unwrap().contains("(700)"));
+        });
+    }
+}
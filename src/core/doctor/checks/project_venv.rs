@@ -0,0 +1,207 @@
+//! Check the current project's `.venv` symlink.
+
+use std::path::{Path, PathBuf};
+
+use crate::paths;
+
+use super::super::types::{Check, CheckResult, CheckStatus};
+
+/// Message prefix for a `.venv` pointing at a removed store environment.
+/// [`ProjectVenvCheck::fix`] keys on it.
+const DANGLING_LINK: &str = "project .venv points to a removed environment";
+
+/// Warn when `./.venv` is a symlink (made by `scuv use --link`) into the
+/// virtualenvs directory whose environment no longer exists, as happens
+/// after the env was removed or renamed.
+///
+/// `--fix` deletes such a link. Nothing else is ever removed: a real
+/// `.venv` directory, a link to an env that exists, and a dangling link
+/// pointing outside the store all belong to the user.
+pub(super) struct ProjectVenvCheck;
+
+/// Where the symlink at `link` points, resolved against its directory.
+/// `None` when `link` is not a symlink.
+fn link_target(link: &Path) -> Option<PathBuf> {
+    let target = std::fs::read_link(link).ok()?;
+    Some(match link.parent() {
+        Some(dir) if target.is_relative() => dir.join(target),
+        _ => target,
+    })
+}
+
+/// `link`'s target when it is a dangling symlink into `venvs_dir`: the
+/// target itself is gone but would be a direct child of the store.
+fn dangling_store_target(link: &Path, venvs_dir: &Path) -> Option<PathBuf> {
+    let target = link_target(link)?;
+    if target.exists() {
+        return None;
+    }
+    let parent = target.parent()?;
+    let canonical = |p: &Path| std::fs::canonicalize(p).unwrap_or_else(|_| p.to_path_buf());
+    let in_store = parent == venvs_dir || canonical(parent) == canonical(venvs_dir);
+    in_store.then_some(target)
+}
+
+impl ProjectVenvCheck {
+    /// Judge `dir/.venv` against the store at `venvs_dir`.
+    fn evaluate(&self, dir: &Path, venvs_dir: &Path) -> CheckResult {
+        let link = dir.join(".venv");
+        let Some(target) = link_target(&link) else {
+            return CheckResult::ok(self.id(), self.name())
+                .with_details("no .venv symlink in the current directory");
+        };
+
+        match dangling_store_target(&link, venvs_dir) {
+            Some(target) => {
+                let name = target
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                CheckResult::warn(self.id(), self.name(), format!("{DANGLING_LINK} '{name}'"))
+                    .with_details(format!("{} -> {}", link.display(), target.display()))
+                    .with_suggestion(
+                        "Run: scuv doctor --fix to remove the link, then `scuv use <env> --link` to relink",
+                    )
+            }
+            None if target.exists() => CheckResult::ok(self.id(), self.name())
+                .with_details(format!(".venv -> {}", target.display())),
+            None => CheckResult::ok(self.id(), self.name()).with_details(format!(
+                ".venv -> {} is dangling but outside the scuv store; left alone",
+                target.display()
+            )),
+        }
+    }
+
+    /// Remove `dir/.venv` if, checked again now, it is still a dangling
+    /// link into `venvs_dir`. Returns the target it pointed at.
+    fn remove_dangling(&self, dir: &Path, venvs_dir: &Path) -> std::io::Result<Option<PathBuf>> {
+        let link = dir.join(".venv");
+        match dangling_store_target(&link, venvs_dir) {
+            Some(target) => {
+                std::fs::remove_file(&link)?;
+                Ok(Some(target))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+impl Check for ProjectVenvCheck {
+    fn id(&self) -> &'static str {
+        "project_venv"
+    }
+
+    fn name(&self) -> &'static str {
+        "project .venv link"
+    }
+
+    fn run(&self) -> Vec<CheckResult> {
+        let (Ok(dir), Ok(venvs_dir)) = (std::env::current_dir(), paths::virtualenvs_dir()) else {
+            return vec![CheckResult::ok(self.id(), self.name())];
+        };
+        vec![self.evaluate(&dir, &venvs_dir)]
+    }
+
+    fn fix(&self, result: &CheckResult, output: &crate::output::Output) -> Option<CheckResult> {
+        if !matches!(&result.status, CheckStatus::Warning(msg) if msg.starts_with(DANGLING_LINK)) {
+            return None;
+        }
+        let dir = std::env::current_dir().ok()?;
+        let venvs_dir = paths::virtualenvs_dir().ok()?;
+
+        output.info("Removing dangling .venv symlink...");
+        Some(match self.remove_dangling(&dir, &venvs_dir) {
+            Ok(Some(target)) => CheckResult::ok(self.id(), self.name())
+                .with_details(format!("removed .venv (pointed to {})", target.display())),
+            // Fixed (or replaced) since the check ran.
+            Ok(None) => self.evaluate(&dir, &venvs_dir),
+            Err(e) => CheckResult::error(
+                self.id(),
+                self.name(),
+                format!("could not remove {}: {e}", dir.join(".venv").display()),
+            ),
+        })
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::symlink;
+    use tempfile::TempDir;
+
+    /// A store with `web` in it and an empty project directory.
+    fn setup() -> (TempDir, PathBuf, PathBuf) {
+        let temp = TempDir::new().unwrap();
+        let venvs = temp.path().join("virtualenvs");
+        std::fs::create_dir_all(venvs.join("web")).unwrap();
+        let project = temp.path().join("project");
+        std::fs::create_dir_all(&project).unwrap();
+        (temp, venvs, project)
+    }
+
+    #[test]
+    fn dangling_store_link_warns_and_is_removed() {
+        let (_temp, venvs, project) = setup();
+        symlink(venvs.join("gone"), project.join(".venv")).unwrap();
+
+        let result = ProjectVenvCheck.evaluate(&project, &venvs);
+        match &result.status {
+            CheckStatus::Warning(msg) => assert_eq!(msg, &format!("{DANGLING_LINK} 'gone'")),
+            other => panic!("expected a warning, got {other:?}"),
+        }
+
+        let removed = ProjectVenvCheck.remove_dangling(&project, &venvs).unwrap();
+        assert_eq!(removed, Some(venvs.join("gone")));
+        assert!(!project.join(".venv").is_symlink());
+        assert!(ProjectVenvCheck.evaluate(&project, &venvs).is_ok());
+    }
+
+    #[test]
+    fn relative_dangling_link_is_resolved_against_the_project() {
+        let (_temp, venvs, project) = setup();
+        symlink("../virtualenvs/renamed", project.join(".venv")).unwrap();
+
+        assert!(!ProjectVenvCheck.evaluate(&project, &venvs).is_ok());
+        assert!(
+            ProjectVenvCheck
+                .remove_dangling(&project, &venvs)
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn never_removes_valid_links_real_dirs_or_foreign_links() {
+        let (temp, venvs, project) = setup();
+
+        // A link to an env that exists.
+        symlink(venvs.join("web"), project.join(".venv")).unwrap();
+        assert!(ProjectVenvCheck.evaluate(&project, &venvs).is_ok());
+        assert_eq!(
+            ProjectVenvCheck.remove_dangling(&project, &venvs).unwrap(),
+            None
+        );
+        assert!(project.join(".venv").is_symlink());
+        std::fs::remove_file(project.join(".venv")).unwrap();
+
+        // A dangling link that never pointed into the store.
+        symlink(temp.path().join("elsewhere/.venv"), project.join(".venv")).unwrap();
+        assert!(ProjectVenvCheck.evaluate(&project, &venvs).is_ok());
+        assert_eq!(
+            ProjectVenvCheck.remove_dangling(&project, &venvs).unwrap(),
+            None
+        );
+        assert!(project.join(".venv").is_symlink());
+        std::fs::remove_file(project.join(".venv")).unwrap();
+
+        // A real .venv directory.
+        std::fs::create_dir_all(project.join(".venv/bin")).unwrap();
+        assert!(ProjectVenvCheck.evaluate(&project, &venvs).is_ok());
+        assert_eq!(
+            ProjectVenvCheck.remove_dangling(&project, &venvs).unwrap(),
+            None
+        );
+        assert!(project.join(".venv/bin").is_dir());
+    }
+}
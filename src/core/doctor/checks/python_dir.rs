@@ -0,0 +1,128 @@
+//! Check that uv's managed-Python directory is where uv and scuv expect it.
+
+use std::ffi::OsString;
+use std::path::Path;
+
+use crate::uv::UvClient;
+
+use super::super::types::{Check, CheckResult};
+
+/// Env var uv reads to relocate its managed Pythons.
+const INSTALL_DIR_ENV: &str = "UV_PYTHON_INSTALL_DIR";
+
+/// Check uv's effective Python install directory (`uv python dir`).
+///
+/// A `UV_PYTHON_INSTALL_DIR` pointing somewhere stale makes previously
+/// installed versions vanish from uv's view — the classic "installed but
+/// not found" report.
+pub(super) struct PythonDirCheck;
+
+impl PythonDirCheck {
+    fn evaluate(&self, dir: &Path, override_var: Option<&OsString>) -> CheckResult {
+        let source = if override_var.is_some() {
+            format!(" (from ${INSTALL_DIR_ENV})")
+        } else {
+            String::new()
+        };
+        let shown = format!("{}{source}", dir.display());
+
+        if !dir.exists() {
+            if override_var.is_some() {
+                return CheckResult::warn(
+                    self.id(),
+                    self.name(),
+                    format!("${INSTALL_DIR_ENV} points to a nonexistent directory"),
+                )
+                .with_details(shown)
+                .with_suggestion(format!(
+                    "Unset {INSTALL_DIR_ENV} or point it at your uv Python installs, then re-run `scuv list --pythons`"
+                ));
+            }
+            // Default location, nothing installed through uv yet.
+            return CheckResult::ok(self.id(), self.name())
+                .with_details(format!("{shown} (not created yet)"));
+        }
+
+        if let Err(e) = std::fs::read_dir(dir) {
+            return CheckResult::warn(
+                self.id(),
+                self.name(),
+                format!("uv Python directory is not readable: {e}"),
+            )
+            .with_details(shown)
+            .with_suggestion("Fix the directory permissions so uv can list installed Pythons");
+        }
+
+        CheckResult::ok(self.id(), self.name()).with_details(shown)
+    }
+}
+
+impl Check for PythonDirCheck {
+    fn id(&self) -> &'static str {
+        "python-dir"
+    }
+
+    fn name(&self) -> &'static str {
+        "uv Python directory"
+    }
+
+    fn run(&self) -> Vec<CheckResult> {
+        // A missing uv is already an error from the uv check.
+        let Ok(uv) = UvClient::new() else {
+            return vec![
+                CheckResult::ok(self.id(), self.name()).with_details("skipped: uv not found"),
+            ];
+        };
+        let override_var = std::env::var_os(INSTALL_DIR_ENV);
+        match uv.python_dir() {
+            Ok(dir) => vec![self.evaluate(&dir, override_var.as_ref())],
+            Err(e) => vec![
+                CheckResult::warn(self.id(), self.name(), "could not query `uv python dir`")
+                    .with_details(e.to_string()),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn existing_dir_is_ok_with_path_in_details() {
+        let tmp = TempDir::new().unwrap();
+        let result = PythonDirCheck.evaluate(tmp.path(), None);
+        assert!(result.is_ok());
+        assert_eq!(result.details, Some(tmp.path().display().to_string()));
+    }
+
+    #[test]
+    fn override_to_missing_dir_warns() {
+        let tmp = TempDir::new().unwrap();
+        let missing = tmp.path().join("gone");
+        let var = OsString::from(&missing);
+
+        let result = PythonDirCheck.evaluate(&missing, Some(&var));
+        assert!(result.is_warning(), "got {result:#?}");
+        assert!(result.suggestion.is_some());
+        assert!(result.details.unwrap().contains(INSTALL_DIR_ENV));
+    }
+
+    #[test]
+    fn missing_default_dir_is_ok() {
+        let tmp = TempDir::new().unwrap();
+        let result = PythonDirCheck.evaluate(&tmp.path().join("python"), None);
+        assert!(result.is_ok());
+        assert!(result.details.unwrap().contains("not created yet"));
+    }
+
+    #[test]
+    fn override_to_existing_dir_is_ok_and_names_source() {
+        let tmp = TempDir::new().unwrap();
+        let var = OsString::from(tmp.path());
+        let result = PythonDirCheck.evaluate(tmp.path(), Some(&var));
+        assert!(result.is_ok());
+        assert!(result.details.unwrap().contains(INSTALL_DIR_ENV));
+    }
+}
@@ -0,0 +1,88 @@
+//! Check for `PYTHONHOME` set in the shell environment.
+
+use super::super::types::{Check, CheckResult};
+
+/// Warn when `PYTHONHOME` is set in the environment doctor runs in.
+///
+/// `PYTHONHOME` overrides where every interpreter looks for its standard
+/// library, so one value can't fit the several Pythons behind scuv's
+/// environments: `python` fails with "No module named 'encodings'" or
+/// silently loads another version's stdlib. Activation already unsets it
+/// (and restores it on `deactivate`); still being set here means it was
+/// exported after activation or no scuv environment is active.
+pub(super) struct PythonHomeCheck;
+
+impl PythonHomeCheck {
+    /// Judge the `PYTHONHOME` value, if any; `active` is `SCUV_ACTIVE`.
+    fn evaluate(&self, pythonhome: Option<&str>, active: Option<&str>) -> CheckResult {
+        let Some(home) = pythonhome.filter(|home| !home.is_empty()) else {
+            return CheckResult::ok(self.id(), self.name()).with_details("PYTHONHOME not set");
+        };
+        let details = match active.filter(|name| !name.is_empty() && *name != "system") {
+            Some(name) => format!(
+                "set after scuv activated '{name}'; interpreters look for their standard library under {home}"
+            ),
+            None => format!("interpreters look for their standard library under {home}"),
+        };
+        CheckResult::warn(
+            self.id(),
+            self.name(),
+            format!("PYTHONHOME is set ({home}) and can break Python interpreters"),
+        )
+        .with_details(details)
+        .with_suggestion(
+            "Remove `export PYTHONHOME=...` from your shell rc (e.g. ~/.bashrc), then run `unset PYTHONHOME`",
+        )
+    }
+}
+
+impl Check for PythonHomeCheck {
+    fn id(&self) -> &'static str {
+        "pythonhome"
+    }
+
+    fn name(&self) -> &'static str {
+        "PYTHONHOME"
+    }
+
+    fn run(&self) -> Vec<CheckResult> {
+        let pythonhome = std::env::var_os("PYTHONHOME").map(|v| v.to_string_lossy().into_owned());
+        let active = std::env::var("SCUV_ACTIVE").ok();
+        vec![self.evaluate(pythonhome.as_deref(), active.as_deref())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::doctor::CheckStatus;
+
+    #[test]
+    fn unset_or_empty_is_ok() {
+        assert!(PythonHomeCheck.evaluate(None, None).is_ok());
+        assert!(PythonHomeCheck.evaluate(Some(""), Some("web")).is_ok());
+    }
+
+    #[test]
+    fn set_pythonhome_warns_with_value_and_unset_suggestion() {
+        for active in [None, Some("system"), Some("web")] {
+            let result = PythonHomeCheck.evaluate(Some("/opt/python3.9"), active);
+            match &result.status {
+                CheckStatus::Warning(msg) => assert_eq!(
+                    msg,
+                    "PYTHONHOME is set (/opt/python3.9) and can break Python interpreters"
+                ),
+                other => panic!("expected a warning for {active:?}, got {other:?}"),
+            }
+            assert!(
+                result
+                    .suggestion
+                    .as_deref()
+                    .unwrap()
+                    .contains("unset PYTHONHOME")
+            );
+            let after_activation = result.details.as_deref().unwrap().contains("'web'");
+            assert_eq!(after_activation, active == Some("web"));
+        }
+    }
+}
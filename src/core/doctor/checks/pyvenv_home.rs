@@ -0,0 +1,160 @@
+//! Check that each environment's base interpreter is still installed.
+
+use std::path::Path;
+
+use crate::paths;
+
+use super::super::types::{Check, CheckResult};
+
+/// Warn when the `home` in an environment's `pyvenv.cfg` no longer exists.
+///
+/// `home` names the `bin` directory of the Python the venv was built from.
+/// Once that Python is uninstalled (`scuv uninstall`, a Homebrew upgrade,
+/// a removed pyenv version) the venv is broken even when `bin/python`
+/// still resolves somewhere, because the standard library is found
+/// through `home`. Environments without a `home` line are skipped.
+pub(super) struct PyvenvHomeCheck;
+
+impl PyvenvHomeCheck {
+    /// Judge the environment `name` at `path`; `None` when its
+    /// `pyvenv.cfg` has no `home` line.
+    fn evaluate(&self, name: &str, path: &Path) -> Option<CheckResult> {
+        let home = crate::core::parse_pyvenv_home(path)?;
+        if home.is_dir() {
+            return Some(CheckResult::ok(self.id(), self.name()));
+        }
+
+        let version = crate::core::parse_pyvenv_version(path);
+        let suggestion = match &version {
+            Some(version) => format!(
+                "Reinstall Python {version} (scuv install {version}), or recreate: scuv remove {name} && scuv create {name} {version}"
+            ),
+            None => {
+                format!("Recreate it: scuv remove {name} && scuv create {name} <python-version>")
+            }
+        };
+        Some(
+            CheckResult::warn(
+                self.id(),
+                self.name(),
+                format!(
+                    "'{name}' was built from a Python that is no longer installed ({})",
+                    home.display()
+                ),
+            )
+            .with_details(format!("pyvenv.cfg: home = {}", home.display()))
+            .with_suggestion(suggestion),
+        )
+    }
+}
+
+impl Check for PyvenvHomeCheck {
+    fn id(&self) -> &'static str {
+        "pyvenv_home"
+    }
+
+    fn name(&self) -> &'static str {
+        "base interpreters"
+    }
+
+    fn run(&self) -> Vec<CheckResult> {
+        let Ok(venvs_dir) = paths::virtualenvs_dir() else {
+            // The virtualenv check already reports a missing directory.
+            return vec![
+                CheckResult::ok(self.id(), self.name())
+                    .with_details("skipped: virtualenvs directory not found"),
+            ];
+        };
+
+        let mut envs: Vec<_> = std::fs::read_dir(&venvs_dir)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .filter_map(|entry| {
+                        let path = entry.path();
+                        let name = path.file_name()?.to_str()?.to_string();
+                        (!name.starts_with('.') && path.is_dir()).then_some((name, path))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        envs.sort();
+
+        let mut checked = 0;
+        let mut warnings = Vec::new();
+        for (name, path) in &envs {
+            match self.evaluate(name, path) {
+                Some(result) if result.is_ok() => checked += 1,
+                Some(result) => warnings.push(result),
+                None => {}
+            }
+        }
+
+        if warnings.is_empty() {
+            vec![
+                CheckResult::ok(self.id(), self.name()).with_details(format!(
+                    "{checked} environment(s), all base interpreters present"
+                )),
+            ]
+        } else {
+            warnings
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::doctor::CheckStatus;
+    use serial_test::serial;
+
+    fn write_cfg(env: &Path, contents: &str) {
+        std::fs::create_dir_all(env).unwrap();
+        std::fs::write(env.join("pyvenv.cfg"), contents).unwrap();
+    }
+
+    #[test]
+    #[serial]
+    fn missing_home_warns_and_present_home_is_ok() {
+        crate::test_utils::with_temp_scoop_home(|temp| {
+            let venvs = temp.path().join("virtualenvs");
+            let python_bin = temp.path().join("python/bin");
+            std::fs::create_dir_all(&python_bin).unwrap();
+            write_cfg(
+                &venvs.join("good"),
+                &format!("home = {}\nversion = 3.12.1\n", python_bin.display()),
+            );
+            write_cfg(
+                &venvs.join("orphan"),
+                "home = /nonexistent/python/3.11/bin\nversion = 3.11.9\n",
+            );
+            // No home line: nothing to compare against.
+            write_cfg(&venvs.join("bare"), "version = 3.12.1\n");
+
+            let results = PyvenvHomeCheck.run();
+            assert_eq!(results.len(), 1, "{results:#?}");
+            match &results[0].status {
+                CheckStatus::Warning(msg) => {
+                    assert!(msg.starts_with("'orphan' was built from"), "{msg}");
+                    assert!(msg.contains("/nonexistent/python/3.11/bin"));
+                }
+                other => panic!("expected a warning, got {other:?}"),
+            }
+            assert!(
+                results[0]
+                    .suggestion
+                    .as_deref()
+                    .unwrap()
+                    .contains("scuv install 3.11.9")
+            );
+
+            std::fs::remove_dir_all(venvs.join("orphan")).unwrap();
+            let results = PyvenvHomeCheck.run();
+            assert!(results[0].is_ok());
+            assert_eq!(
+                results[0].details.as_deref(),
+                Some("1 environment(s), all base interpreters present")
+            );
+        });
+    }
+}
@@ -1,9 +1,113 @@
 //! Check for shell configuration (scuv init).
 
-use super::super::types::{Check, CheckResult};
+use std::io::{IsTerminal, Write};
+use std::path::{Path, PathBuf};
+
+use dialoguer::Confirm;
+
+use super::super::types::{Check, CheckResult, CheckStatus};
+
+/// Error message for an rc file without any init line; the only result
+/// [`ShellCheck::fix`] acts on.
+const NOT_FOUND: &str = "scuv init not found in shell config";
 
 /// Check for shell configuration (scuv init).
-pub(super) struct ShellCheck;
+///
+/// With `doctor --fix` the missing init line is appended to the rc file
+/// after confirmation; `no_input` (`--no-input`) skips the prompt.
+pub(super) struct ShellCheck {
+    pub(super) no_input: bool,
+}
+
+/// Lowercased basename of `$SHELL` (e.g. `zsh`), empty if unset.
+fn current_shell_name() -> String {
+    shell_name_of(&std::env::var("SHELL").unwrap_or_default())
+}
+
+/// Lowercased basename of a `$SHELL` value.
+pub(super) fn shell_name_of(shell: &str) -> String {
+    Path::new(shell)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("")
+        .to_lowercase()
+}
+
+/// How the shell was detected and which rc files were read, for the
+/// details shown with `--verbose`. `$SHELL` is the login shell, which is
+/// not necessarily the one the user types into; spelling it out makes
+/// that mismatch visible.
+fn detection_details(shell_var: &str, shell_name: &str, files: &[PathBuf]) -> String {
+    let shell = if shell_var.is_empty() {
+        "$SHELL is unset".to_string()
+    } else {
+        format!("$SHELL={shell_var} (detected: {shell_name})")
+    };
+    if files.is_empty() {
+        return format!("{shell}; no config files checked (supported shells: bash, zsh)");
+    }
+    let checked = files
+        .iter()
+        .map(|f| {
+            let state = if f.exists() { "" } else { " (missing)" };
+            format!("{}{state}", f.display())
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("{shell}; checked: {checked}")
+}
+
+/// Rc files consulted for `shell_name`, in order; the first one is where
+/// the init line belongs. `None` for an unsupported shell.
+pub(super) fn config_files(shell_name: &str, home: &Path) -> Option<Vec<PathBuf>> {
+    match shell_name {
+        "zsh" => Some(vec![home.join(".zshrc")]),
+        // macOS uses .bash_profile, Linux uses .bashrc
+        "bash" if cfg!(target_os = "macos") => {
+            Some(vec![home.join(".bash_profile"), home.join(".bashrc")])
+        }
+        "bash" => Some(vec![home.join(".bashrc")]),
+        _ => None,
+    }
+}
+
+/// Whether `content` already mentions an init line, current or legacy,
+/// commented out or not.
+fn has_init_line(content: &str) -> bool {
+    content.contains("scuv init") || content.contains("scoop init")
+}
+
+/// Append the init line for `shell_name` to `rc`, backing up an existing
+/// file to `<name>.scuv-backup` first. Returns the backup path, if any.
+fn append_init_line(
+    rc: &Path,
+    existing: &str,
+    shell_name: &str,
+) -> std::io::Result<Option<PathBuf>> {
+    let backup = if rc.exists() {
+        let file_name = rc.file_name().and_then(|n| n.to_str()).unwrap_or("rc");
+        let backup = rc.with_file_name(format!("{file_name}.scuv-backup"));
+        std::fs::copy(rc, &backup)?;
+        Some(backup)
+    } else {
+        None
+    };
+
+    let separator = if existing.is_empty() || existing.ends_with('\n') {
+        ""
+    } else {
+        "\n"
+    };
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(rc)?;
+    write!(
+        file,
+        "{separator}\n# scuv\neval \"$(scuv init {shell_name})\"\n"
+    )?;
+    Ok(backup)
+}
 
 impl Check for ShellCheck {
     fn id(&self) -> &'static str {
@@ -27,40 +131,22 @@ impl Check for ShellCheck {
         };
 
         // Detect current shell from $SHELL environment variable
-        let shell = std::env::var("SHELL").unwrap_or_default();
-        let shell_name = std::path::Path::new(&shell)
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("")
-            .to_lowercase();
+        let shell_var = std::env::var("SHELL").unwrap_or_default();
+        let shell_name = shell_name_of(&shell_var);
 
         // Determine config files to check based on shell
-        let config_files: Vec<(&str, std::path::PathBuf)> = match shell_name.as_str() {
-            "zsh" => vec![("zsh", home.join(".zshrc"))],
-            "bash" => {
-                // macOS uses .bash_profile, Linux uses .bashrc
-                if cfg!(target_os = "macos") {
-                    vec![
-                        ("bash", home.join(".bash_profile")),
-                        ("bash", home.join(".bashrc")),
-                    ]
-                } else {
-                    vec![("bash", home.join(".bashrc"))]
-                }
-            }
-            _ => {
-                // Unknown shell - check both common configs
-                return vec![
-                    CheckResult::warn(
-                        self.id(),
-                        self.name(),
-                        format!("unsupported shell: {}", shell_name),
-                    )
-                    .with_details("Supported shells: bash, zsh")
-                    .with_suggestion("Manual setup may be required"),
-                ];
-            }
+        let Some(config_files) = config_files(&shell_name, &home) else {
+            return vec![
+                CheckResult::warn(
+                    self.id(),
+                    self.name(),
+                    format!("unsupported shell: {}", shell_name),
+                )
+                .with_details(detection_details(&shell_var, &shell_name, &[]))
+                .with_suggestion("Manual setup may be required"),
+            ];
         };
+        let detection = detection_details(&shell_var, &shell_name, &config_files);
 
         let shell_type = if shell_name == "zsh" { "zsh" } else { "bash" };
 
@@ -76,7 +162,7 @@ impl Check for ShellCheck {
         //
         // DEPRECATION(0.16.0): drop the legacy branch once the shim window
         // closes.
-        for (_shell_type, config_path) in &config_files {
+        for config_path in &config_files {
             if config_path.exists() {
                 match std::fs::read_to_string(config_path) {
                     Ok(content) => {
@@ -93,7 +179,10 @@ impl Check for ShellCheck {
                                     self.name(),
                                     "shell config still references the removed `scoop` command (init line fails at startup)",
                                 )
-                                .with_details(format!("found in {}", config_path.display()))
+                                .with_details(format!(
+                                    "found in {}; {detection}",
+                                    config_path.display()
+                                ))
                                 .with_suggestion(format!(
                                     "Replace with: eval \"$(scuv init {})\"",
                                     shell_type
@@ -102,11 +191,14 @@ impl Check for ShellCheck {
                         }
                     }
                     Err(_) => {
-                        return vec![CheckResult::warn(
-                            self.id(),
-                            self.name(),
-                            format!("could not read {}", config_path.display()),
-                        )];
+                        return vec![
+                            CheckResult::warn(
+                                self.id(),
+                                self.name(),
+                                format!("could not read {}", config_path.display()),
+                            )
+                            .with_details(detection),
+                        ];
                     }
                 }
             }
@@ -122,17 +214,93 @@ impl Check for ShellCheck {
         };
 
         vec![
-            CheckResult::error(
-                self.id(),
-                self.name(),
-                "scuv init not found in shell config",
-            )
-            .with_suggestion(format!(
-                "Add to {}: eval \"$(scuv init {})\"",
-                config_file, shell_type
-            )),
+            CheckResult::error(self.id(), self.name(), NOT_FOUND)
+                .with_details(detection)
+                .with_suggestion(format!(
+                    "Add to {}: eval \"$(scuv init {})\"",
+                    config_file, shell_type
+                )),
         ]
     }
+
+    fn fix(&self, result: &CheckResult, output: &crate::output::Output) -> Option<CheckResult> {
+        if !matches!(&result.status, CheckStatus::Error(msg) if msg == NOT_FOUND) {
+            return None;
+        }
+        let home = dirs::home_dir()?;
+        let shell_name = current_shell_name();
+        let rc = config_files(&shell_name, &home)?.into_iter().next()?;
+
+        let existing = match std::fs::read_to_string(&rc) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => {
+                return Some(CheckResult::error(
+                    self.id(),
+                    self.name(),
+                    format!("could not read {}: {}", rc.display(), e),
+                ));
+            }
+        };
+        // Never add a second line, even next to a commented-out or legacy one.
+        if has_init_line(&existing) {
+            return Some(
+                CheckResult::warn(
+                    self.id(),
+                    self.name(),
+                    format!("{} already has an init line; not modified", rc.display()),
+                )
+                .with_suggestion(format!(
+                    "Make sure it reads: eval \"$(scuv init {})\"",
+                    shell_name
+                )),
+            );
+        }
+
+        // JSON mode never prompts (same as `remove`); without a terminal to
+        // ask on, leave the rc file alone unless --no-input was given.
+        if !self.no_input && !output.is_json() {
+            if !std::io::stdin().is_terminal() {
+                return None;
+            }
+            let confirmed = Confirm::new()
+                .with_prompt(format!(
+                    "Append `eval \"$(scuv init {})\"` to {}?",
+                    shell_name,
+                    rc.display()
+                ))
+                .default(false)
+                .interact()
+                .unwrap_or(false);
+            if !confirmed {
+                return None;
+            }
+        }
+
+        output.info(&format!("Adding scuv init to {}...", rc.display()));
+        match append_init_line(&rc, &existing, &shell_name) {
+            Ok(backup) => {
+                let details = match backup {
+                    Some(backup) => {
+                        format!("added to {} (backup: {})", rc.display(), backup.display())
+                    }
+                    None => format!("created {}", rc.display()),
+                };
+                Some(
+                    CheckResult::ok(self.id(), self.name())
+                        .with_details(format!("{details}; restart your shell to load it")),
+                )
+            }
+            Err(e) => Some(
+                CheckResult::error(
+                    self.id(),
+                    self.name(),
+                    format!("failed to update {}: {}", rc.display(), e),
+                )
+                .with_suggestion("Check permissions"),
+            ),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -169,7 +337,7 @@ mod tests {
             ("HOME", Some(home_tmp.path().to_str().unwrap())),
         ]);
 
-        let results = ShellCheck.run();
+        let results = ShellCheck { no_input: false }.run();
         assert_eq!(results.len(), 1, "got {results:#?}");
         assert!(results[0].is_ok(), "expected Ok, got {:#?}", results[0]);
         assert_eq!(results[0].id, "shell");
@@ -194,7 +362,7 @@ mod tests {
             ("HOME", Some(home_tmp.path().to_str().unwrap())),
         ]);
 
-        let results = ShellCheck.run();
+        let results = ShellCheck { no_input: false }.run();
         assert_eq!(results.len(), 1, "got {results:#?}");
         assert!(results[0].is_ok(), "expected Ok, got {:#?}", results[0]);
     }
@@ -209,7 +377,7 @@ mod tests {
             ("HOME", Some(home_tmp.path().to_str().unwrap())),
         ]);
 
-        let results = ShellCheck.run();
+        let results = ShellCheck { no_input: false }.run();
         assert_eq!(results.len(), 1, "got {results:#?}");
         assert!(
             results[0].is_warning(),
@@ -242,7 +410,7 @@ mod tests {
             ("HOME", Some(home_tmp.path().to_str().unwrap())),
         ]);
 
-        let results = ShellCheck.run();
+        let results = ShellCheck { no_input: false }.run();
         assert_eq!(results.len(), 1, "got {results:#?}");
         assert!(results[0].is_warning(), "got {results:#?}");
         let suggestion = results[0].suggestion.as_deref().unwrap_or_default();
@@ -265,7 +433,7 @@ mod tests {
             ("HOME", Some(home_tmp.path().to_str().unwrap())),
         ]);
 
-        let results = ShellCheck.run();
+        let results = ShellCheck { no_input: false }.run();
         assert_eq!(results.len(), 1, "got {results:#?}");
         // no init line present -> error path selects config file by shell name.
         // A `== -> !=` mutant would pick the bash file for zsh.
@@ -279,6 +447,41 @@ mod tests {
             "zsh no-init suggestion must name ~/.zshrc, got {:?}",
             results[0].suggestion
         );
+        let details = results[0].details.as_deref().unwrap_or_default();
+        assert!(
+            details.starts_with("$SHELL=/bin/zsh (detected: zsh)"),
+            "{details}"
+        );
+        let zshrc = home_tmp.path().join(".zshrc");
+        assert!(details.contains(&zshrc.display().to_string()), "{details}");
+    }
+
+    #[test]
+    fn detection_details_name_the_shell_and_each_file() {
+        let tmp = tempfile::tempdir().unwrap();
+        let present = tmp.path().join(".bashrc");
+        std::fs::write(&present, "").unwrap();
+        let missing = tmp.path().join(".bash_profile");
+
+        let details =
+            detection_details("/usr/bin/bash", "bash", &[missing.clone(), present.clone()]);
+        assert_eq!(
+            details,
+            format!(
+                "$SHELL=/usr/bin/bash (detected: bash); checked: {} (missing), {}",
+                missing.display(),
+                present.display()
+            )
+        );
+
+        assert_eq!(
+            detection_details("", "", &[]),
+            "$SHELL is unset; no config files checked (supported shells: bash, zsh)"
+        );
+        assert!(
+            detection_details("/usr/bin/fish", "fish", &[])
+                .starts_with("$SHELL=/usr/bin/fish (detected: fish); no config")
+        );
     }
 
     #[test]
@@ -295,8 +498,100 @@ mod tests {
             ("HOME", Some(home_tmp.path().to_str().unwrap())),
         ]);
 
-        let results = ShellCheck.run();
+        let results = ShellCheck { no_input: false }.run();
         assert_eq!(results.len(), 1, "got {results:#?}");
         assert!(results[0].is_ok(), "got {results:#?}");
     }
+
+    // ==========================================================================
+    // ShellCheck::fix: append the init line (with a backup), never twice
+    // ==========================================================================
+
+    fn quiet_output() -> crate::output::Output {
+        crate::output::Output::new(0, true, crate::cli::ColorChoice::Never, false)
+    }
+
+    fn not_found() -> CheckResult {
+        CheckResult::error("shell", "shell configuration", NOT_FOUND)
+    }
+
+    #[test]
+    #[serial]
+    fn fix_appends_init_line_and_backs_up_rc_file() {
+        let home_tmp = tempfile::tempdir().unwrap();
+        let zshrc = home_tmp.path().join(".zshrc");
+        std::fs::write(&zshrc, "alias ll='ls -l'").unwrap();
+        let _g = crate::test_utils::env_guard(&[
+            ("SHELL", Some("/bin/zsh")),
+            ("HOME", Some(home_tmp.path().to_str().unwrap())),
+        ]);
+
+        let check = ShellCheck { no_input: true };
+        let fixed = check.fix(&not_found(), &quiet_output()).expect("fixable");
+        assert!(fixed.is_ok(), "got {fixed:#?}");
+
+        let content = std::fs::read_to_string(&zshrc).unwrap();
+        assert_eq!(
+            content,
+            "alias ll='ls -l'\n\n# scuv\neval \"$(scuv init zsh)\"\n"
+        );
+        let backup = std::fs::read_to_string(home_tmp.path().join(".zshrc.scuv-backup")).unwrap();
+        assert_eq!(backup, "alias ll='ls -l'");
+        assert!(check.run()[0].is_ok(), "re-running the check must pass");
+    }
+
+    #[test]
+    #[serial]
+    fn fix_creates_missing_rc_file_without_backup() {
+        let home_tmp = tempfile::tempdir().unwrap();
+        let _g = crate::test_utils::env_guard(&[
+            ("SHELL", Some("/bin/zsh")),
+            ("HOME", Some(home_tmp.path().to_str().unwrap())),
+        ]);
+
+        let fixed = ShellCheck { no_input: true }
+            .fix(&not_found(), &quiet_output())
+            .expect("fixable");
+        assert!(fixed.is_ok(), "got {fixed:#?}");
+        let content = std::fs::read_to_string(home_tmp.path().join(".zshrc")).unwrap();
+        assert_eq!(content, "\n# scuv\neval \"$(scuv init zsh)\"\n");
+        assert!(!home_tmp.path().join(".zshrc.scuv-backup").exists());
+    }
+
+    #[test]
+    #[serial]
+    fn fix_refuses_when_rc_file_already_mentions_init() {
+        let home_tmp = tempfile::tempdir().unwrap();
+        let zshrc = home_tmp.path().join(".zshrc");
+        let original = "# eval \"$(scuv init zsh)\"\n";
+        std::fs::write(&zshrc, original).unwrap();
+        let _g = crate::test_utils::env_guard(&[
+            ("SHELL", Some("/bin/zsh")),
+            ("HOME", Some(home_tmp.path().to_str().unwrap())),
+        ]);
+
+        let result = ShellCheck { no_input: true }
+            .fix(&not_found(), &quiet_output())
+            .expect("refusal is reported");
+        assert!(result.is_warning(), "got {result:#?}");
+        assert_eq!(std::fs::read_to_string(&zshrc).unwrap(), original);
+        assert!(!home_tmp.path().join(".zshrc.scuv-backup").exists());
+    }
+
+    #[test]
+    #[serial]
+    fn fix_ignores_results_other_than_missing_init() {
+        let home_tmp = tempfile::tempdir().unwrap();
+        let _g = crate::test_utils::env_guard(&[
+            ("SHELL", Some("/bin/fish")),
+            ("HOME", Some(home_tmp.path().to_str().unwrap())),
+        ]);
+
+        let check = ShellCheck { no_input: true };
+        let unsupported =
+            CheckResult::warn("shell", "shell configuration", "unsupported shell: fish");
+        assert!(check.fix(&unsupported, &quiet_output()).is_none());
+        // Unsupported shell: no rc file to write even for the missing-init error.
+        assert!(check.fix(&not_found(), &quiet_output()).is_none());
+    }
 }
@@ -0,0 +1,240 @@
+//! Check for pyenv shims / conda base Python shadowing scuv in `$PATH`.
+
+use std::path::{Path, PathBuf};
+
+use crate::paths;
+
+use super::super::types::{Check, CheckResult};
+
+/// What kind of foreign Python provider a PATH entry belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ShimKind {
+    Pyenv,
+    CondaBase,
+}
+
+impl ShimKind {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Pyenv => "pyenv shims",
+            Self::CondaBase => "conda base",
+        }
+    }
+}
+
+/// Classify a single PATH entry.
+///
+/// - pyenv: a `shims` dir under `$PYENV_ROOT` or any `*pyenv*` parent
+///   (covers the default `~/.pyenv/shims` and pyenv-win layouts).
+/// - conda base: a `bin` dir whose parent holds `conda-meta/` and is not
+///   itself under an `envs/` dir. Named conda envs are deliberate
+///   activations, only the auto-activated base install is a leftover.
+fn classify(entry: &Path, pyenv_root: Option<&Path>) -> Option<ShimKind> {
+    let parent = entry.parent()?;
+    let name = entry.file_name()?.to_str()?;
+
+    if name == "shims" {
+        let under_root = pyenv_root.is_some_and(|root| parent == root);
+        let pyenv_named = parent
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.to_lowercase().contains("pyenv"));
+        if under_root || pyenv_named {
+            return Some(ShimKind::Pyenv);
+        }
+    }
+
+    let is_env_dir = parent
+        .parent()
+        .and_then(|p| p.file_name())
+        .is_some_and(|n| n == "envs");
+    if name == "bin" && parent.join("conda-meta").is_dir() && !is_env_dir {
+        return Some(ShimKind::CondaBase);
+    }
+
+    None
+}
+
+/// PATH entries that would intercept `python` ahead of scuv.
+///
+/// An activated scuv env puts its `bin/` under `virtualenvs_dir` on PATH;
+/// only shims *before* the first such entry win the lookup. With no scuv
+/// entry on PATH (nothing activated yet), every shim counts, since it is
+/// what `python` resolves to right now.
+fn find_shadowing_entries(
+    entries: &[PathBuf],
+    virtualenvs_dir: &Path,
+    pyenv_root: Option<&Path>,
+) -> Vec<(PathBuf, ShimKind)> {
+    entries
+        .iter()
+        .take_while(|e| !e.starts_with(virtualenvs_dir))
+        .filter_map(|e| classify(e, pyenv_root).map(|kind| (e.clone(), kind)))
+        .collect()
+}
+
+/// Check for pyenv shims or conda base Python ahead of scuv in `$PATH`.
+pub(super) struct ShimsCheck;
+
+impl ShimsCheck {
+    fn check(&self, path_var: Option<&std::ffi::OsStr>) -> CheckResult {
+        let Some(path_var) = path_var else {
+            return CheckResult::ok(self.id(), self.name()).with_details("$PATH is not set");
+        };
+        let Ok(venvs) = paths::virtualenvs_dir() else {
+            return CheckResult::ok(self.id(), self.name())
+                .with_details("skipped: SCUV_HOME could not be resolved");
+        };
+        let pyenv_root = std::env::var_os("PYENV_ROOT").map(PathBuf::from);
+        let entries: Vec<PathBuf> = std::env::split_paths(path_var).collect();
+
+        let found = find_shadowing_entries(&entries, &venvs, pyenv_root.as_deref());
+        if found.is_empty() {
+            return CheckResult::ok(self.id(), self.name()).with_details(format!(
+                "no pyenv shims or conda base among {} PATH entries",
+                entries.len()
+            ));
+        }
+
+        let mut kinds: Vec<&str> = found.iter().map(|(_, k)| k.label()).collect();
+        kinds.sort_unstable();
+        kinds.dedup();
+        let details = found
+            .iter()
+            .map(|(p, k)| format!("{} ({})", p.display(), k.label()))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        CheckResult::warn(
+            self.id(),
+            self.name(),
+            format!("{} in PATH may intercept `python`", kinds.join(" and ")),
+        )
+        .with_details(details)
+        .with_suggestion(
+            "Remove `pyenv init` / conda initialize lines from your shell rc, \
+             or move them above `scuv init` (run `conda config --set auto_activate_base false` for conda)",
+        )
+    }
+}
+
+impl Check for ShimsCheck {
+    fn id(&self) -> &'static str {
+        "shims"
+    }
+
+    fn name(&self) -> &'static str {
+        "python shims in PATH"
+    }
+
+    fn run(&self) -> Vec<CheckResult> {
+        vec![self.check(std::env::var_os("PATH").as_deref())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{EnvGuard, env_guard};
+    use serial_test::serial;
+    use tempfile::TempDir;
+
+    fn scoop_home_guard(home: &TempDir) -> EnvGuard {
+        env_guard(&[
+            (paths::SCUV_HOME_ENV, Some(home.path().to_str().unwrap())),
+            ("PYENV_ROOT", None),
+        ])
+    }
+
+    fn conda_base(tmp: &TempDir) -> PathBuf {
+        let root = tmp.path().join("miniconda3");
+        std::fs::create_dir_all(root.join("conda-meta")).unwrap();
+        std::fs::create_dir_all(root.join("bin")).unwrap();
+        root
+    }
+
+    #[test]
+    fn classify_detects_pyenv_shims() {
+        assert_eq!(
+            classify(Path::new("/home/u/.pyenv/shims"), None),
+            Some(ShimKind::Pyenv)
+        );
+        assert_eq!(
+            classify(Path::new("/opt/py/shims"), Some(Path::new("/opt/py"))),
+            Some(ShimKind::Pyenv)
+        );
+        assert_eq!(classify(Path::new("/opt/rbenv/shims"), None), None);
+        assert_eq!(classify(Path::new("/usr/bin"), None), None);
+    }
+
+    #[test]
+    fn classify_detects_conda_base_but_not_named_envs() {
+        let tmp = TempDir::new().unwrap();
+        let root = conda_base(&tmp);
+        let named = root.join("envs").join("ml");
+        std::fs::create_dir_all(named.join("conda-meta")).unwrap();
+
+        assert_eq!(classify(&root.join("bin"), None), Some(ShimKind::CondaBase));
+        assert_eq!(classify(&named.join("bin"), None), None);
+        assert_eq!(classify(&root.join("condabin"), None), None);
+    }
+
+    #[test]
+    fn only_entries_before_scuv_env_are_reported() {
+        let venvs = PathBuf::from("/home/u/.scuv/virtualenvs");
+        let entries = vec![
+            PathBuf::from("/home/u/.pyenv/shims"),
+            venvs.join("myenv").join("bin"),
+            PathBuf::from("/opt/pyenv/shims"),
+        ];
+
+        let found = find_shadowing_entries(&entries, &venvs, None);
+        assert_eq!(
+            found,
+            vec![(PathBuf::from("/home/u/.pyenv/shims"), ShimKind::Pyenv)]
+        );
+    }
+
+    #[test]
+    fn all_shims_count_when_no_scuv_env_on_path() {
+        let venvs = PathBuf::from("/home/u/.scuv/virtualenvs");
+        let entries = vec![
+            PathBuf::from("/usr/bin"),
+            PathBuf::from("/home/u/.pyenv/shims"),
+        ];
+
+        assert_eq!(find_shadowing_entries(&entries, &venvs, None).len(), 1);
+    }
+
+    #[test]
+    #[serial]
+    fn check_warns_with_offending_entries_in_details() {
+        let home = TempDir::new().unwrap();
+        let _g = scoop_home_guard(&home);
+        let conda = TempDir::new().unwrap();
+        let conda_bin = conda_base(&conda).join("bin");
+        let env_bin = home.path().join("virtualenvs").join("myenv").join("bin");
+        let path =
+            std::env::join_paths([conda_bin.clone(), env_bin, PathBuf::from("/usr/bin")]).unwrap();
+
+        let result = ShimsCheck.check(Some(&path));
+        assert!(result.is_warning(), "got {result:#?}");
+        assert!(result.suggestion.is_some());
+        let details = result.details.unwrap();
+        assert!(details.contains(&conda_bin.display().to_string()));
+        assert!(details.contains("conda base"));
+    }
+
+    #[test]
+    #[serial]
+    fn check_is_ok_without_shims() {
+        let home = TempDir::new().unwrap();
+        let _g = scoop_home_guard(&home);
+        let path = std::env::join_paths(["/usr/local/bin", "/usr/bin"]).unwrap();
+
+        let check: &dyn Check = &ShimsCheck;
+        assert_eq!(check.id(), "shims");
+        assert!(ShimsCheck.check(Some(&path)).is_ok());
+        assert!(ShimsCheck.check(None).is_ok());
+    }
+}
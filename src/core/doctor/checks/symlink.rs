@@ -34,22 +34,18 @@ impl Check for SymlinkCheck {
                 if path.is_dir() {
                     let python_path = crate::paths::virtualenv_python_exe(&path);
 
+                    // Resolve the whole chain: a relative link is relative to
+                    // `bin/`, not to our working directory.
                     if python_path.is_symlink() {
-                        match std::fs::read_link(&python_path) {
-                            Ok(target) if target.exists() => {
-                                valid += 1;
-                            }
-                            Ok(_) => {
-                                let name = path
-                                    .file_name()
-                                    .and_then(|n| n.to_str())
-                                    .unwrap_or("unknown")
-                                    .to_string();
-                                broken_names.push(name);
-                            }
-                            Err(_) => {
-                                // Not a symlink or error reading
-                            }
+                        if crate::core::python_executable_at(&path).is_some() {
+                            valid += 1;
+                        } else {
+                            let name = path
+                                .file_name()
+                                .and_then(|n| n.to_str())
+                                .unwrap_or("unknown")
+                                .to_string();
+                            broken_names.push(name);
                         }
                     }
                 }
@@ -266,6 +262,7 @@ fn read_python_version(venv_path: &std::path::Path) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::ColorChoice;
 
     use crate::test_utils::with_temp_scoop_home;
     use serial_test::serial;
@@ -349,6 +346,27 @@ mod tests {
         });
     }
 
+    /// uv links `python3` to `python` relatively; that must resolve
+    /// against `bin/`, not doctor's working directory.
+    #[cfg(unix)]
+    #[test]
+    #[serial]
+    fn symlink_check_follows_relative_links_from_bin() {
+        with_temp_scoop_home(|temp| {
+            use std::os::unix::fs::symlink;
+
+            let env = temp.path().join("virtualenvs").join("relative");
+            let bin = env.join("bin");
+            std::fs::create_dir_all(&bin).unwrap();
+            std::fs::write(bin.join("python3.12"), "").unwrap();
+            symlink("python3.12", bin.join("python")).unwrap();
+
+            let results = SymlinkCheck.run();
+            assert_eq!(results.len(), 1, "got {results:#?}");
+            assert!(results[0].is_ok(), "got {results:#?}");
+        });
+    }
+
     #[test]
     #[serial]
     fn fix_symlink_returns_some_for_parseable_error_name() {
@@ -366,7 +384,7 @@ mod tests {
                 "broken symlink",
                 "Python symlink in 'fix-target' is broken".to_string(),
             );
-            let output = crate::output::Output::new(0, true, true, false);
+            let output = crate::output::Output::new(0, true, ColorChoice::Never, false);
 
             let fixed = SymlinkCheck.fix(&probe, &output);
             assert!(fixed.is_some(), "fix_symlink must return Some");
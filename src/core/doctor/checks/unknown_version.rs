@@ -0,0 +1,197 @@
+//! Check that every environment records which Python it was built with.
+
+use std::path::Path;
+
+use crate::core::metadata::Metadata;
+use crate::paths;
+
+use super::super::types::{Check, CheckResult};
+
+/// Warn when an environment's scuv metadata records its Python version as
+/// `"unknown"` or empty.
+///
+/// Creation from `--python-path` and migration write `"unknown"` when the
+/// interpreter's version can't be read (typically an unparseable
+/// `pyvenv.cfg`). Such envs misbehave wherever the version matters: the
+/// `site-packages` path, `list --python-version`, EOL checks.
+/// Environments without metadata aren't managed by scuv and are skipped.
+pub(super) struct UnknownVersionCheck;
+
+/// Whether `version` is a placeholder rather than a version.
+fn is_unknown(version: &str) -> bool {
+    let version = version.trim();
+    version.is_empty() || version.eq_ignore_ascii_case("unknown")
+}
+
+impl UnknownVersionCheck {
+    /// Judge the environment `name` at `path`; `None` when it has no
+    /// readable scuv metadata.
+    fn evaluate(&self, name: &str, path: &Path) -> Option<CheckResult> {
+        let content = std::fs::read_to_string(path.join(Metadata::FILE_NAME)).ok()?;
+        let metadata: Metadata = serde_json::from_str(&content).ok()?;
+        if !is_unknown(&metadata.python_version) {
+            return Some(CheckResult::ok(self.id(), self.name()));
+        }
+
+        // pyvenv.cfg may still know, even when it didn't at creation time.
+        let cfg_version = crate::core::parse_pyvenv_version(path);
+        let details = format!(
+            "metadata: python_version = {:?}; pyvenv.cfg: {}",
+            metadata.python_version,
+            cfg_version
+                .as_deref()
+                .map_or("no version".to_string(), |v| format!("version = {v}"))
+        );
+        let version = cfg_version.as_deref().unwrap_or("<python-version>");
+        Some(
+            CheckResult::warn(
+                self.id(),
+                self.name(),
+                format!("'{name}' has no recorded Python version"),
+            )
+            .with_details(details)
+            .with_suggestion(format!(
+                "Recreate it to restore its metadata: scuv create {name} {version} --force"
+            )),
+        )
+    }
+}
+
+impl Check for UnknownVersionCheck {
+    fn id(&self) -> &'static str {
+        "unknown_version"
+    }
+
+    fn name(&self) -> &'static str {
+        "recorded Python versions"
+    }
+
+    fn run(&self) -> Vec<CheckResult> {
+        let Ok(venvs_dir) = paths::virtualenvs_dir() else {
+            // The virtualenv check already reports a missing directory.
+            return vec![];
+        };
+
+        let mut envs: Vec<_> = std::fs::read_dir(&venvs_dir)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .filter_map(|entry| {
+                        let path = entry.path();
+                        let name = path.file_name()?.to_str()?.to_string();
+                        (!name.starts_with('.') && path.is_dir()).then_some((name, path))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        envs.sort();
+
+        let mut checked = 0;
+        let mut warnings = Vec::new();
+        for (name, path) in &envs {
+            match self.evaluate(name, path) {
+                Some(result) if result.is_ok() => checked += 1,
+                Some(result) => warnings.push(result),
+                None => {}
+            }
+        }
+
+        if warnings.is_empty() {
+            vec![
+                CheckResult::ok(self.id(), self.name()).with_details(format!(
+                    "{checked} environment(s), all with a recorded Python version"
+                )),
+            ]
+        } else {
+            warnings
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::doctor::CheckStatus;
+    use crate::test_utils::with_temp_scoop_home;
+    use serial_test::serial;
+
+    /// An env with scuv metadata recording `version` and the given
+    /// `pyvenv.cfg`.
+    fn write_env(env: &Path, version: &str, cfg: &str) {
+        std::fs::create_dir_all(env).unwrap();
+        let meta = Metadata::new("env".to_string(), version.to_string(), None);
+        std::fs::write(
+            env.join(Metadata::FILE_NAME),
+            serde_json::to_string(&meta).unwrap(),
+        )
+        .unwrap();
+        std::fs::write(env.join("pyvenv.cfg"), cfg).unwrap();
+    }
+
+    #[test]
+    fn placeholders_count_as_unknown() {
+        for version in ["unknown", "Unknown", "", "  "] {
+            assert!(is_unknown(version), "{version:?}");
+        }
+        assert!(!is_unknown("3.12.1"));
+    }
+
+    #[test]
+    #[serial]
+    fn unknown_version_warns_with_recreate_suggestion() {
+        with_temp_scoop_home(|temp| {
+            let venvs = temp.path().join("virtualenvs");
+            write_env(
+                &venvs.join("good"),
+                "3.12.1",
+                "home = /usr/bin\nversion = 3.12.1\n",
+            );
+            // pyvenv.cfg without a version key: nothing to recover from.
+            write_env(&venvs.join("mystery"), "unknown", "home = /usr/bin\n");
+            // Not created by scuv: skipped.
+            std::fs::create_dir_all(venvs.join("foreign")).unwrap();
+
+            let results = UnknownVersionCheck.run();
+            assert_eq!(results.len(), 1, "{results:#?}");
+            match &results[0].status {
+                CheckStatus::Warning(msg) => {
+                    assert_eq!(msg, "'mystery' has no recorded Python version")
+                }
+                other => panic!("expected a warning, got {other:?}"),
+            }
+            assert_eq!(
+                results[0].details.as_deref(),
+                Some("metadata: python_version = \"unknown\"; pyvenv.cfg: no version")
+            );
+            assert_eq!(
+                results[0].suggestion.as_deref(),
+                Some(
+                    "Recreate it to restore its metadata: scuv create mystery <python-version> --force"
+                )
+            );
+
+            std::fs::remove_dir_all(venvs.join("mystery")).unwrap();
+            let results = UnknownVersionCheck.run();
+            assert!(results[0].is_ok());
+            assert_eq!(
+                results[0].details.as_deref(),
+                Some("1 environment(s), all with a recorded Python version")
+            );
+        });
+    }
+
+    #[test]
+    fn suggestion_uses_version_from_pyvenv_cfg_when_present() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_env(tmp.path(), "", "version_info = 3.11.9.final.0\n");
+        let result = UnknownVersionCheck.evaluate("api", tmp.path()).unwrap();
+        assert!(result.is_warning(), "{result:#?}");
+        assert!(
+            result
+                .suggestion
+                .as_deref()
+                .unwrap()
+                .ends_with("scuv create api 3.11.9 --force")
+        );
+    }
+}
@@ -1,7 +1,5 @@
 //! Check for uv installation.
 
-use std::process::Command;
-
 use crate::uv::version as uv_version;
 
 use super::super::types::{Check, CheckResult};
@@ -32,10 +30,9 @@ impl Check for UvCheck {
     }
 
     fn run(&self) -> Vec<CheckResult> {
-        match Command::new("uv").arg("--version").output() {
-            Ok(output) if output.status.success() => {
-                let raw = String::from_utf8_lossy(&output.stdout);
-                let raw = raw.trim();
+        match uv_version::detect() {
+            Some(raw) => {
+                let raw = raw.as_str();
 
                 // Enforce the minimum supported uv version when parseable.
                 // Unparseable output (custom build, unknown format) is treated
@@ -60,7 +57,7 @@ impl Check for UvCheck {
 
                 vec![CheckResult::ok(self.id(), self.name()).with_details(raw.to_string())]
             }
-            _ => {
+            None => {
                 vec![
                     CheckResult::error(self.id(), self.name(), "uv not found in PATH")
                         .with_details("scuv requires uv to manage Python environments")
@@ -2,7 +2,7 @@
 
 use crate::paths;
 
-use super::super::types::{Check, CheckResult};
+use super::super::types::{Check, CheckResult, CheckStatus};
 
 /// Classify a non-empty version-file entry into a [`CheckResult`].
 ///
@@ -34,6 +34,98 @@ fn classify_version_entry(
     }
 }
 
+/// Message prefix for the multi-entry warning. [`VersionCheck::fix`] keys on
+/// it so an unrelated warning on the same id is never "fixed".
+const MULTIPLE_ENTRIES: &str = "contains multiple entries";
+
+/// Classify the full contents of a version file.
+///
+/// A version file holds exactly one env name; resolution reads the whole
+/// trimmed content, so a file with several non-empty lines never resolves
+/// to anything. Those are flagged as a fixable warning instead of being
+/// misreported as a reference to a non-existent env. Returns `None` for an
+/// empty (or whitespace-only) file.
+fn classify_version_content(
+    id: &'static str,
+    name: &'static str,
+    content: &str,
+    venvs_dir: Option<&std::path::Path>,
+) -> Option<CheckResult> {
+    let entries = version_file_entries(content);
+    match entries.as_slice() {
+        [] => None,
+        [entry] => Some(classify_version_entry(id, name, entry, venvs_dir)),
+        _ => Some(
+            CheckResult::warn(
+                id,
+                name,
+                format!("{} ({} lines)", MULTIPLE_ENTRIES, entries.len()),
+            )
+            .with_suggestion("Run: scuv doctor --fix (keeps the first valid env name)"),
+        ),
+    }
+}
+
+/// Message prefix for the line-ending warning, keyed on by
+/// [`VersionCheck::fix`] like [`MULTIPLE_ENTRIES`].
+const CRLF_LINE_ENDINGS: &str = "uses CRLF line endings";
+
+/// Warn about `\r` in a version file.
+///
+/// Resolution trims each value, so `myenv\r\n` still resolves to `myenv`;
+/// the warning is about hand-written tools (`cat .scuv-version` in a script,
+/// shell `read`) that would keep the `\r`.
+fn line_ending_warning(
+    id: &'static str,
+    name: &'static str,
+    content: &str,
+    path: &std::path::Path,
+) -> Option<CheckResult> {
+    content.contains('\r').then(|| {
+        CheckResult::warn(
+            id,
+            name,
+            format!("{} in {}", CRLF_LINE_ENDINGS, path.display()),
+        )
+        .with_suggestion("Run: scuv doctor --fix (rewrites the file with LF line endings)")
+    })
+}
+
+/// Rewrite `path` with `\n` line endings (`\r\n` and lone `\r` both become
+/// `\n`), atomically so a crash can't truncate the pin.
+fn normalize_line_endings(path: &std::path::Path) -> crate::error::Result<()> {
+    let content = std::fs::read_to_string(path)?;
+    let normalized = content.replace("\r\n", "\n").replace('\r', "\n");
+    crate::core::atomic::write_file(path, normalized.as_bytes())
+}
+
+/// Non-empty, trimmed lines of a version file.
+fn version_file_entries(content: &str) -> Vec<&str> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .collect()
+}
+
+/// Rewrite a multi-entry version file so it holds only its first valid
+/// entry (an env name or the `system` sentinel). Returns the kept entry, or
+/// `None` when no line is valid — in that case the file is left untouched
+/// so the user can inspect it. The rewrite is atomic, like every other
+/// version-file write.
+fn dedup_version_file(path: &std::path::Path) -> crate::error::Result<Option<String>> {
+    let content = std::fs::read_to_string(path)?;
+    let Some(keep) = version_file_entries(&content)
+        .into_iter()
+        .find(|e| e.eq_ignore_ascii_case("system") || crate::validate::is_valid_env_name(e))
+        .map(str::to_string)
+    else {
+        return Ok(None);
+    };
+    crate::core::atomic::write_file(path, format!("{keep}\n").as_bytes())?;
+    Ok(Some(keep))
+}
+
 /// Resolve the local version-file path for `dir` the way `doctor` should see
 /// it: the current `.scuv-version` name wins when present, otherwise falls
 /// back to the legacy `.scoop-version` name. Mirrors the per-directory
@@ -79,15 +171,18 @@ impl Check for VersionCheck {
             if global_file.exists() {
                 match std::fs::read_to_string(&global_file) {
                     Ok(content) => {
-                        let env_name = content.trim();
-                        if !env_name.is_empty() {
-                            results.push(classify_version_entry(
-                                "version:global",
-                                "global version",
-                                env_name,
-                                venvs_dir.as_deref(),
-                            ));
-                        }
+                        results.extend(classify_version_content(
+                            "version:global",
+                            "global version",
+                            &content,
+                            venvs_dir.as_deref(),
+                        ));
+                        results.extend(line_ending_warning(
+                            "version:global",
+                            "global version",
+                            &content,
+                            &global_file,
+                        ));
                     }
                     Err(_) => {
                         results.push(
@@ -112,15 +207,18 @@ impl Check for VersionCheck {
         if local_file.exists() {
             match std::fs::read_to_string(&local_file) {
                 Ok(content) => {
-                    let env_name = content.trim();
-                    if !env_name.is_empty() {
-                        results.push(classify_version_entry(
-                            "version:local",
-                            "local version",
-                            env_name,
-                            venvs_dir.as_deref(),
-                        ));
-                    }
+                    results.extend(classify_version_content(
+                        "version:local",
+                        "local version",
+                        &content,
+                        venvs_dir.as_deref(),
+                    ));
+                    results.extend(line_ending_warning(
+                        "version:local",
+                        "local version",
+                        &content,
+                        &local_file,
+                    ));
                 }
                 Err(_) => {
                     results.push(
@@ -144,11 +242,57 @@ impl Check for VersionCheck {
 
         results
     }
+
+    fn fix(&self, result: &CheckResult, output: &crate::output::Output) -> Option<CheckResult> {
+        // Only the multi-entry and line-ending warnings are auto-fixable; a
+        // dangling env reference needs the user to pick an env.
+        let CheckStatus::Warning(msg) = &result.status else {
+            return None;
+        };
+        let crlf = msg.starts_with(CRLF_LINE_ENDINGS);
+        if !crlf && !msg.starts_with(MULTIPLE_ENTRIES) {
+            return None;
+        }
+        let path = match result.id {
+            "version:global" => paths::global_version_file().ok()?,
+            "version:local" => {
+                resolve_local_version_file_for_doctor(&std::env::current_dir().ok()?)
+            }
+            _ => return None,
+        };
+
+        output.info(&format!("Normalizing {}...", path.display()));
+        if crlf {
+            return Some(match normalize_line_endings(&path) {
+                Ok(()) => CheckResult::ok(result.id, result.name)
+                    .with_details(format!("converted {} to LF line endings", path.display())),
+                Err(e) => {
+                    CheckResult::error(result.id, result.name, format!("failed to rewrite: {}", e))
+                        .with_suggestion(format!("Check file: {}", path.display()))
+                }
+            });
+        }
+        let fixed = match dedup_version_file(&path) {
+            Ok(Some(kept)) => CheckResult::ok(result.id, result.name).with_details(format!(
+                "kept '{}' in {}",
+                kept,
+                path.display()
+            )),
+            Ok(None) => CheckResult::error(result.id, result.name, "no valid env name to keep")
+                .with_suggestion(format!("Check file: {}", path.display())),
+            Err(e) => {
+                CheckResult::error(result.id, result.name, format!("failed to rewrite: {}", e))
+                    .with_suggestion(format!("Check file: {}", path.display()))
+            }
+        };
+        Some(fixed)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::ColorChoice;
 
     use crate::core::doctor::CheckStatus;
     use crate::core::doctor::checks::test_support::TempDirCwdGuard;
@@ -368,6 +512,135 @@ mod tests {
         });
     }
 
+    // ==========================================================================
+    // VersionCheck: multi-entry version files. Resolution reads the whole
+    // trimmed file as one name, so several non-empty lines never resolve —
+    // doctor flags them and `--fix` keeps the first valid entry.
+    // ==========================================================================
+
+    #[test]
+    fn classify_content_flags_multiple_entries_as_warning() {
+        let result = classify_version_content("version:test", "test", "alpha\n\nbeta\n", None)
+            .expect("non-empty content must classify");
+        assert!(result.is_warning(), "got {result:#?}");
+        assert!(
+            matches!(&result.status, CheckStatus::Warning(msg) if msg.contains("2 lines")),
+            "got {result:#?}"
+        );
+    }
+
+    #[test]
+    fn classify_content_ignores_blank_lines_around_single_entry() {
+        let result = classify_version_content("version:test", "test", "\n system \n\n", None)
+            .expect("non-empty content must classify");
+        assert!(result.is_ok(), "got {result:#?}");
+        assert!(classify_version_content("version:test", "test", " \n\n", None).is_none());
+    }
+
+    #[test]
+    #[serial]
+    fn version_check_fix_keeps_first_valid_entry() {
+        with_temp_scoop_home(|temp| {
+            let cwd_guard = TempDirCwdGuard::new();
+            let file = cwd_guard.path().join(paths::VERSION_FILE);
+            std::fs::write(&file, "../evil\nmyenv\nother\n").unwrap();
+            std::fs::create_dir_all(temp.path().join("virtualenvs").join("myenv")).unwrap();
+
+            let results = VersionCheck.run();
+            let local = results
+                .iter()
+                .find(|r| r.id == "version:local")
+                .expect("version:local must run");
+            assert!(local.is_warning(), "got {local:#?}");
+
+            let output = crate::output::Output::new(0, true, ColorChoice::Never, false);
+            let fixed = VersionCheck
+                .fix(local, &output)
+                .expect("multi-entry warning must be fixable");
+            assert!(fixed.is_ok(), "got {fixed:#?}");
+            assert_eq!(std::fs::read_to_string(&file).unwrap(), "myenv\n");
+
+            let rerun = VersionCheck.run();
+            let local = rerun.iter().find(|r| r.id == "version:local").unwrap();
+            assert!(local.is_ok(), "normalized file must pass: {local:#?}");
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn version_check_fix_leaves_file_without_valid_entry() {
+        with_temp_scoop_home(|_temp| {
+            let cwd_guard = TempDirCwdGuard::new();
+            let file = cwd_guard.path().join(paths::VERSION_FILE);
+            std::fs::write(&file, "1bad\n$(boom)\n").unwrap();
+
+            let warning = CheckResult::warn(
+                "version:local",
+                "local version",
+                format!("{MULTIPLE_ENTRIES} (2 lines)"),
+            );
+            let output = crate::output::Output::new(0, true, ColorChoice::Never, false);
+            let fixed = VersionCheck.fix(&warning, &output).unwrap();
+            assert!(fixed.is_error(), "got {fixed:#?}");
+            assert_eq!(
+                std::fs::read_to_string(&file).unwrap(),
+                "1bad\n$(boom)\n",
+                "file must be left untouched"
+            );
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn version_check_warns_on_crlf_and_fix_converts_to_lf() {
+        with_temp_scoop_home(|temp| {
+            let cwd_guard = TempDirCwdGuard::new();
+            let file = cwd_guard.path().join(paths::VERSION_FILE);
+            std::fs::write(&file, "myenv\r\n").unwrap();
+            std::fs::create_dir_all(temp.path().join("virtualenvs").join("myenv")).unwrap();
+
+            let results = VersionCheck.run();
+            let local: Vec<_> = results.iter().filter(|r| r.id == "version:local").collect();
+            assert_eq!(local.len(), 2, "got {results:#?}");
+            assert!(
+                local[0].is_ok(),
+                "CRLF must still resolve to myenv: {:#?}",
+                local[0]
+            );
+            assert!(local[1].is_warning(), "got {:#?}", local[1]);
+
+            let output = crate::output::Output::new(0, true, ColorChoice::Never, false);
+            let fixed = VersionCheck
+                .fix(local[1], &output)
+                .expect("CRLF warning must be fixable");
+            assert!(fixed.is_ok(), "got {fixed:#?}");
+            assert_eq!(std::fs::read_to_string(&file).unwrap(), "myenv\n");
+
+            let rerun = VersionCheck.run();
+            assert!(
+                rerun.iter().all(|r| r.is_ok()),
+                "normalized file must pass: {rerun:#?}"
+            );
+        });
+    }
+
+    #[test]
+    fn line_ending_warning_only_for_carriage_returns() {
+        let path = std::path::Path::new(".scuv-version");
+        assert!(line_ending_warning("version:local", "local version", "myenv\n", path).is_none());
+        assert!(line_ending_warning("version:local", "local version", "myenv\r\n", path).is_some());
+        assert!(line_ending_warning("version:local", "local version", "myenv\r", path).is_some());
+    }
+
+    #[test]
+    fn version_check_fix_ignores_other_results() {
+        let output = crate::output::Output::new(0, true, ColorChoice::Never, false);
+        let dangling = CheckResult::error("version:local", "local version", "references x");
+        assert!(VersionCheck.fix(&dangling, &output).is_none());
+        let other = CheckResult::warn("version:local", "local version", "could not read");
+        assert!(VersionCheck.fix(&other, &output).is_none());
+    }
+
     // ==========================================================================
     // VersionCheck::run — the "no version files configured" summary is the
     // only result carrying the check's own id/name ("version"/"version files");
@@ -42,16 +42,14 @@ impl Check for VirtualenvCheck {
             for entry in entries.flatten() {
                 let path = entry.path();
                 if path.is_dir() {
-                    let name = path
-                        .file_name()
-                        .and_then(|n| n.to_str())
-                        .unwrap_or("unknown")
-                        .to_string();
+                    // Non-UTF-8 names are reported separately below with a
+                    // rename hint; scuv commands can't address them by name.
+                    let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                        continue;
+                    };
+                    let name = name.to_string();
 
-                    let python_path = crate::paths::virtualenv_python_exe(&path);
-                    let pyvenv_cfg = path.join("pyvenv.cfg");
-
-                    if python_path.exists() && pyvenv_cfg.exists() {
+                    if crate::core::is_env_healthy(&path) {
                         healthy += 1;
                     } else {
                         broken_names.push(name);
@@ -75,6 +73,45 @@ impl Check for VirtualenvCheck {
             );
         }
 
+        // Report directories whose names aren't valid UTF-8. Every other
+        // walker skips these silently, so this is the user's only feedback.
+        for path in crate::core::non_utf8_env_dirs(&venvs_dir) {
+            let display = path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            results.push(
+                CheckResult::warn(
+                    "venv",
+                    "non-UTF-8 virtualenv name",
+                    format!("'{}' is not valid UTF-8 and is ignored by scuv", display),
+                )
+                .with_suggestion(format!(
+                    "Rename the directory to a valid env name: {}",
+                    path.display()
+                )),
+            );
+        }
+
+        // Names differing only in case coexist here but collide on
+        // case-insensitive filesystems (macOS, Windows).
+        for names in crate::core::case_colliding_env_names(&venvs_dir) {
+            results.push(
+                CheckResult::warn(
+                    "venv",
+                    "virtualenv name case collision",
+                    format!(
+                        "{} differ only in case and would collide on a case-insensitive filesystem",
+                        names.join(", ")
+                    ),
+                )
+                .with_suggestion(format!(
+                    "Rename all but one, e.g.: scuv clone {0} {0}-2 && scuv remove {0}",
+                    names[1]
+                )),
+            );
+        }
+
         // Summary
         if broken_names.is_empty() {
             if healthy > 0 {
@@ -221,6 +258,77 @@ mod tests {
         );
     }
 
+    #[cfg(unix)]
+    #[test]
+    #[serial]
+    fn venv_run_warns_on_non_utf8_env_dir() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let tmp = tempfile::tempdir().unwrap();
+        let _g = crate::test_utils::env_guard(&[(
+            paths::SCUV_HOME_ENV,
+            Some(tmp.path().to_str().unwrap()),
+        )]);
+        let venvs = crate::paths::virtualenvs_dir().unwrap();
+        make_venv(&venvs, "good", true, true);
+        if std::fs::create_dir(venvs.join(OsStr::from_bytes(b"caf\xe9"))).is_err() {
+            // Filesystem refuses non-UTF-8 names (e.g. APFS) — nothing to detect.
+            return;
+        }
+
+        let results = VirtualenvCheck.run();
+        let warning = results
+            .iter()
+            .find(|r| r.is_warning())
+            .unwrap_or_else(|| panic!("non-UTF-8 dir must produce a warning: {results:#?}"));
+        assert!(
+            matches!(&warning.status, CheckStatus::Warning(msg) if msg.contains("caf\u{fffd}")),
+            "warning should show the lossy name, got {warning:#?}"
+        );
+        assert!(
+            warning
+                .suggestion
+                .as_deref()
+                .is_some_and(|s| s.contains("Rename")),
+            "warning should suggest renaming, got {:?}",
+            warning.suggestion
+        );
+        assert!(
+            !results.iter().any(|r| r.is_error()),
+            "a non-UTF-8 dir must not be reported as a broken env: {results:#?}"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn venv_run_warns_on_case_colliding_names() {
+        let tmp = tempfile::tempdir().unwrap();
+        let _g = crate::test_utils::env_guard(&[(
+            paths::SCUV_HOME_ENV,
+            Some(tmp.path().to_str().unwrap()),
+        )]);
+        let venvs = crate::paths::virtualenvs_dir().unwrap();
+        make_venv(&venvs, "MyEnv", true, true);
+        if venvs.join("myenv").exists() {
+            // Case-insensitive filesystem — the pair can't coexist here.
+            return;
+        }
+        make_venv(&venvs, "myenv", true, true);
+
+        let results = VirtualenvCheck.run();
+        let warning = results
+            .iter()
+            .find(|r| r.name == "virtualenv name case collision")
+            .unwrap_or_else(|| panic!("case collision must produce a warning: {results:#?}"));
+        assert!(warning.is_warning());
+        assert!(
+            matches!(&warning.status, CheckStatus::Warning(msg) if msg.contains("MyEnv, myenv")),
+            "warning should name both envs, got {warning:#?}"
+        );
+        assert!(warning.suggestion.is_some());
+    }
+
     #[test]
     #[serial]
     fn venv_run_says_no_environments_when_dir_empty() {
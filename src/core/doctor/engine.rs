@@ -15,10 +15,42 @@ impl Doctor {
     /// Creates a new Doctor with default checks.
     pub fn new() -> Self {
         Self {
-            checks: super::checks::default_checks(),
+            checks: super::checks::default_checks(false),
         }
     }
 
+    /// Like [`new`](Self::new), but fixes apply without asking for
+    /// confirmation (`doctor --fix --no-input`).
+    pub fn without_prompts() -> Self {
+        Self {
+            checks: super::checks::default_checks(true),
+        }
+    }
+
+    /// Also runs the checks that measure environment sizes.
+    pub fn with_sizes(mut self, enabled: bool) -> Self {
+        if enabled {
+            self.checks.extend(super::checks::size_checks());
+        }
+        self
+    }
+
+    /// Also runs the checks that launch every environment's interpreter.
+    pub fn with_deep_checks(mut self, enabled: bool) -> Self {
+        if enabled {
+            self.checks.extend(super::checks::deep_checks());
+        }
+        self
+    }
+
+    /// Also runs the checks reserved for `--verbose`.
+    pub fn with_verbose_checks(mut self, enabled: bool) -> Self {
+        if enabled {
+            self.checks.extend(super::checks::verbose_checks());
+        }
+        self
+    }
+
     /// Runs all checks and returns results.
     pub fn run_all(&self) -> Vec<CheckResult> {
         self.checks.iter().flat_map(|c| c.run()).collect()
@@ -28,22 +60,33 @@ impl Doctor {
     ///
     /// Returns the results after attempting fixes.
     pub fn run_and_fix(&self, output: &crate::output::Output) -> Vec<CheckResult> {
+        self.run_and_fix_with(output, |result| output.doctor_check(result))
+    }
+
+    /// Like [`run_and_fix`](Self::run_and_fix), but hands each final result
+    /// to `report` instead of printing it (used by `--summary-only`).
+    pub fn run_and_fix_with(
+        &self,
+        output: &crate::output::Output,
+        mut report: impl FnMut(&CheckResult),
+    ) -> Vec<CheckResult> {
         let mut all_results = Vec::new();
 
         for check in &self.checks {
             let results = check.run();
 
             for result in results {
-                // Attempt auto-fix for specific error types
-                if result.is_error() {
+                // Attempt auto-fix for errors and warnings; each check's
+                // `fix` decides which of its results it can actually repair.
+                if !result.is_ok() {
                     if let Some(fixed_result) = check.fix(&result, output) {
-                        output.doctor_check(&fixed_result);
+                        report(&fixed_result);
                         all_results.push(fixed_result);
                         continue;
                     }
                 }
 
-                output.doctor_check(&result);
+                report(&result);
                 all_results.push(result);
             }
         }
@@ -61,6 +104,7 @@ impl Default for Doctor {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::ColorChoice;
 
     /// A check whose error IS fixable — its `fix` override returns `Some(ok)`.
     struct FixableCheck;
@@ -107,7 +151,7 @@ mod tests {
     }
 
     fn quiet_output() -> crate::output::Output {
-        crate::output::Output::new(0, true, true, false)
+        crate::output::Output::new(0, true, ColorChoice::Never, false)
     }
 
     #[test]
@@ -169,6 +213,45 @@ mod tests {
         );
     }
 
+    /// A check that only emits a warning, fixable via its `fix` override.
+    struct FixableWarningCheck;
+    impl Check for FixableWarningCheck {
+        fn id(&self) -> &'static str {
+            "fixable-warn"
+        }
+        fn name(&self) -> &'static str {
+            "fixable warning check"
+        }
+        fn run(&self) -> Vec<CheckResult> {
+            vec![CheckResult::warn(
+                "fixable-warn",
+                "fixable warning check",
+                "meh",
+            )]
+        }
+        fn fix(
+            &self,
+            _result: &CheckResult,
+            _output: &crate::output::Output,
+        ) -> Option<CheckResult> {
+            Some(CheckResult::ok("fixable-warn", "fixable warning check"))
+        }
+    }
+
+    #[test]
+    fn run_and_fix_offers_fix_for_warnings() {
+        let doctor = Doctor {
+            checks: vec![Box::new(FixableWarningCheck)],
+        };
+        let results = doctor.run_and_fix(&quiet_output());
+        assert_eq!(results.len(), 1);
+        assert!(
+            results[0].is_ok(),
+            "a fixable warning must be replaced by the fixed result: {:#?}",
+            results[0]
+        );
+    }
+
     #[test]
     fn test_doctor_has_default_checks() {
         let doctor = Doctor::new();
@@ -8,4 +8,4 @@ mod engine;
 mod types;
 
 pub use engine::Doctor;
-pub use types::{Check, CheckResult, CheckStatus};
+pub use types::{Check, CheckResult, CheckStatus, DoctorSummary};
@@ -93,6 +93,63 @@ impl CheckResult {
     }
 }
 
+// ============================================================================
+// Summary
+// ============================================================================
+
+/// Per-status tally of a doctor run.
+///
+/// Returned from `scuv doctor` so embedders (and `main.rs`) get the counts
+/// and the exit code without parsing the human summary line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DoctorSummary {
+    /// Total number of check results.
+    pub total: usize,
+    /// Results that passed.
+    pub ok: usize,
+    /// Results with a warning.
+    pub warnings: usize,
+    /// Results with an error.
+    pub errors: usize,
+}
+
+impl DoctorSummary {
+    /// Tallies `results` by status.
+    pub fn from_results(results: &[CheckResult]) -> Self {
+        Self {
+            total: results.len(),
+            ok: results.iter().filter(|r| r.is_ok()).count(),
+            warnings: results.iter().filter(|r| r.is_warning()).count(),
+            errors: results.iter().filter(|r| r.is_error()).count(),
+        }
+    }
+
+    /// Process exit code for this tally.
+    ///
+    /// - 0: All checks passed
+    /// - 1: Warnings found (no errors)
+    /// - 2: Errors found (takes priority over warnings)
+    pub fn exit_code(&self) -> i32 {
+        if self.errors > 0 {
+            2
+        } else if self.warnings > 0 {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+/// Combine the tallies of separate runs (one per home with `--root`).
+impl std::ops::AddAssign for DoctorSummary {
+    fn add_assign(&mut self, other: Self) {
+        self.total += other.total;
+        self.ok += other.ok;
+        self.warnings += other.warnings;
+        self.errors += other.errors;
+    }
+}
+
 // ============================================================================
 // Check Trait
 // ============================================================================
@@ -126,6 +183,26 @@ pub trait Check: Send + Sync {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_doctor_summary_counts_each_status() {
+        let results = vec![
+            CheckResult::ok("a", "A"),
+            CheckResult::warn("b", "B", "w"),
+            CheckResult::error("c", "C", "e"),
+            CheckResult::ok("d", "D"),
+        ];
+        let summary = DoctorSummary::from_results(&results);
+        assert_eq!(
+            summary,
+            DoctorSummary {
+                total: 4,
+                ok: 2,
+                warnings: 1,
+                errors: 1,
+            }
+        );
+    }
+
     #[test]
     fn test_check_result_ok() {
         let result = CheckResult::ok("test", "Test Check");
@@ -0,0 +1,144 @@
+//! User-configured hook commands.
+//!
+//! `create.post_create_hook` in `~/.scuv/config.json` is a command template
+//! run through the platform shell (`sh -c` / `cmd /C`) after `scuv create`
+//! builds an environment. It runs with the user's privileges, so it is only
+//! ever read from the user's own config file, never from a project.
+
+use std::path::Path;
+use std::process::Command;
+
+use crate::config::Config;
+
+/// Captured result of one hook run.
+#[derive(Debug, Clone)]
+pub struct HookOutcome {
+    /// The command after placeholder substitution.
+    pub command: String,
+    /// Exit code, `None` when the process was killed by a signal.
+    pub exit_code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl HookOutcome {
+    /// Whether the hook exited with status 0.
+    pub fn success(&self) -> bool {
+        self.exit_code == Some(0)
+    }
+}
+
+/// The configured post-create hook template, if any (empty counts as unset).
+pub fn post_create_hook() -> Option<String> {
+    Config::load()
+        .ok()
+        .and_then(|c| c.create.post_create_hook)
+        .filter(|hook| !hook.trim().is_empty())
+}
+
+/// Substitute `{name}` and `{python}` in `template`.
+///
+/// Values are shell-quoted, so a template must not add its own quotes
+/// around a placeholder: `pip install -e {name}`, not `"{name}"`.
+pub fn render(template: &str, name: &str, python: &str) -> String {
+    template
+        .replace("{name}", &shell_quote(name))
+        .replace("{python}", &shell_quote(python))
+}
+
+/// Run `command` through the platform shell, capturing its output.
+///
+/// # Errors
+///
+/// Only when the shell itself can't be spawned; a failing command is
+/// reported through [`HookOutcome::exit_code`].
+pub fn run(command: &str) -> std::io::Result<HookOutcome> {
+    run_with_env(command, &[])
+}
+
+/// Like [`run`], with extra environment variables set for the hook.
+///
+/// # Errors
+///
+/// Only when the shell itself can't be spawned.
+pub fn run_with_env(command: &str, env: &[(&str, &Path)]) -> std::io::Result<HookOutcome> {
+    let output = shell_command(command)
+        .envs(env.iter().map(|(key, value)| (key, value)))
+        .output()?;
+    Ok(HookOutcome {
+        command: command.to_string(),
+        exit_code: output.status.code(),
+        stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+        stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+    })
+}
+
+#[cfg(unix)]
+fn shell_command(command: &str) -> Command {
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c").arg(command);
+    cmd
+}
+
+#[cfg(not(unix))]
+fn shell_command(command: &str) -> Command {
+    let mut cmd = Command::new("cmd");
+    cmd.arg("/C").arg(command);
+    cmd
+}
+
+/// Quote `value` as a single shell word (`'...'` for sh, `"..."` for cmd).
+#[cfg(unix)]
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+#[cfg(not(unix))]
+fn shell_quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn render_substitutes_quoted_values() {
+        assert_eq!(
+            render("setup {name} --python {python}", "myenv", "3.12"),
+            "setup 'myenv' --python '3.12'"
+        );
+        assert_eq!(render("no placeholders", "a", "b"), "no placeholders");
+        assert_eq!(render("{name}", "it's", "3"), r"'it'\''s'");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn run_hook_receives_substituted_values() {
+        let command = render("printf '%s|%s' {name} {python}", "myenv", ">=3.11,<3.13");
+        let outcome = run(&command).unwrap();
+        assert!(outcome.success(), "{outcome:?}");
+        assert_eq!(outcome.stdout, "myenv|>=3.11,<3.13");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn run_with_env_exports_variables() {
+        let outcome = run_with_env(
+            "printf '%s' \"$UV_CONSTRAINT\"",
+            &[("UV_CONSTRAINT", Path::new("/c.txt"))],
+        )
+        .unwrap();
+        assert_eq!(outcome.stdout, "/c.txt");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn run_hook_reports_failure_with_captured_stderr() {
+        let outcome = run("echo boom >&2; exit 3").unwrap();
+        assert!(!outcome.success());
+        assert_eq!(outcome.exit_code, Some(3));
+        assert_eq!(outcome.stderr.trim(), "boom");
+    }
+}
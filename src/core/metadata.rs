@@ -12,6 +12,12 @@ pub struct Metadata {
     /// Python version used
     pub python_version: String,
 
+    /// What was asked for when it differs from the version it resolved to
+    /// (`>=3.11,<3.13`, `pypy@3.10`, `3.12`). Absent for envs created
+    /// from an exact version, by `--python-path`, or before this existed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub python_request: Option<String>,
+
     /// Creation timestamp
     pub created_at: DateTime<Utc>,
 
@@ -35,6 +41,17 @@ pub struct Metadata {
     /// activated should report "never", not its creation time.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub last_used: Option<DateTime<Utc>>,
+
+    /// User-assigned labels (`scuv tag`). Kept sorted and deduplicated;
+    /// omitted from the file when empty so untagged envs stay byte-for-byte
+    /// readable by older binaries.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
+    /// Whether `create --seed --upgrade-deps` upgraded the seeded pip (and
+    /// setuptools/wheel) to their latest releases. Omitted when false.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub deps_upgraded: bool,
 }
 
 impl Metadata {
@@ -43,11 +60,14 @@ impl Metadata {
         Self {
             name,
             python_version,
+            python_request: None,
             created_at: Utc::now(),
             created_by: format!("scuv {}", env!("CARGO_PKG_VERSION")),
             uv_version,
             python_path: None,
             last_used: None,
+            tags: Vec::new(),
+            deps_upgraded: false,
         }
     }
 
@@ -57,6 +77,15 @@ impl Metadata {
         self
     }
 
+    /// Record the Python request this env was created from, unless it is
+    /// just the resolved version again.
+    pub fn with_python_request(mut self, request: &str) -> Self {
+        if request != self.python_version {
+            self.python_request = Some(request.to_string());
+        }
+        self
+    }
+
     /// Update `last_used` to the given instant.
     ///
     /// Caller passes `now` explicitly so tests can pin the timestamp without
@@ -65,6 +94,28 @@ impl Metadata {
         self.last_used = Some(now);
     }
 
+    /// Add tags, keeping the list sorted and free of duplicates.
+    ///
+    /// Returns `true` if anything changed.
+    pub fn add_tags<I, S>(&mut self, tags: I) -> bool
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let before = self.tags.len();
+        self.tags.extend(tags.into_iter().map(Into::into));
+        self.tags.sort();
+        self.tags.dedup();
+        self.tags.len() != before
+    }
+
+    /// Remove tags. Returns `true` if anything changed.
+    pub fn remove_tags(&mut self, tags: &[String]) -> bool {
+        let before = self.tags.len();
+        self.tags.retain(|t| !tags.contains(t));
+        self.tags.len() != before
+    }
+
     /// Metadata file name.
     ///
     /// Deliberately keeps the legacy `scoop` name: this is on-disk storage
@@ -243,6 +294,22 @@ mod tests {
         assert!(meta.last_used.is_none());
     }
 
+    #[test]
+    fn test_metadata_records_python_request_only_when_it_differs() {
+        let exact = Metadata::new("web".to_string(), "3.12.1".to_string(), None)
+            .with_python_request("3.12.1");
+        assert!(exact.python_request.is_none());
+        let json = serde_json::to_string(&exact).unwrap();
+        assert!(!json.contains("python_request"), "{json}");
+
+        let ranged = Metadata::new("web".to_string(), "3.12.1".to_string(), None)
+            .with_python_request(">=3.11,<3.13");
+        let json = serde_json::to_string(&ranged).unwrap();
+        let restored: Metadata = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.python_version, "3.12.1");
+        assert_eq!(restored.python_request.as_deref(), Some(">=3.11,<3.13"));
+    }
+
     #[test]
     fn test_metadata_touch_updates_only_last_used() {
         // touch() must update last_used and nothing else — other fields
@@ -306,6 +373,43 @@ mod tests {
         assert_eq!(restored.last_used, Some(now));
     }
 
+    #[test]
+    fn test_metadata_legacy_without_tags() {
+        let legacy = r#"{
+            "name": "legacy",
+            "python_version": "3.11",
+            "created_at": "2024-01-01T00:00:00Z",
+            "created_by": "scoop 0.1.0",
+            "uv_version": null
+        }"#;
+
+        let meta: Metadata = serde_json::from_str(legacy).expect("parse legacy");
+        assert!(meta.tags.is_empty());
+
+        let serialized = serde_json::to_string(&meta).expect("serialize");
+        assert!(
+            !serialized.contains("tags"),
+            "empty tags must be omitted: {serialized}"
+        );
+    }
+
+    #[test]
+    fn test_metadata_add_and_remove_tags() {
+        let mut meta = Metadata::new("tagged".to_string(), "3.12".to_string(), None);
+
+        assert!(meta.add_tags(["web", "api", "web"]));
+        assert_eq!(meta.tags, vec!["api", "web"]);
+        assert!(!meta.add_tags(["api"]), "re-adding is a no-op");
+
+        assert!(meta.remove_tags(&["web".to_string(), "missing".to_string()]));
+        assert_eq!(meta.tags, vec!["api"]);
+        assert!(!meta.remove_tags(&["web".to_string()]));
+
+        let json = serde_json::to_string(&meta).expect("serialize");
+        let restored: Metadata = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(restored.tags, vec!["api"]);
+    }
+
     #[test]
     fn test_metadata_unicode_values() {
         // Unicode in values should work
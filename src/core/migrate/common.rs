@@ -9,8 +9,69 @@ use crate::paths;
 
 use super::source::EnvironmentStatus;
 
-/// EOL Python minor version threshold (3.8 and below are EOL as of 2024)
-const EOL_PYTHON_MINOR: u32 = 8;
+/// Oldest Python `major.minor` that is *not* flagged EOL during migration.
+///
+/// Defaults to 3.9 (3.8 and below are EOL as of 2024). Teams with
+/// long-support requirements can move it via `migrate.eol_minimum` in
+/// `config.json` or `scuv migrate --eol-minimum <VER>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EolMinimum {
+    /// Major version (e.g. `3`).
+    pub major: u32,
+    /// Minor version (e.g. `9`).
+    pub minor: u32,
+}
+
+impl EolMinimum {
+    /// Parse a `MAJOR.MINOR` string such as `"3.9"`.
+    ///
+    /// Returns `None` for anything else (patch components are rejected so
+    /// the cutoff stays unambiguous).
+    pub fn parse(raw: &str) -> Option<Self> {
+        let (major, minor) = raw.trim().split_once('.')?;
+        Some(Self {
+            major: major.parse().ok()?,
+            minor: minor.parse().ok()?,
+        })
+    }
+
+    /// The cutoff from `migrate.eol_minimum` in `config.json`, or the
+    /// default when it is unset, malformed or the config is unreadable.
+    pub fn configured() -> Self {
+        crate::config::Config::load()
+            .ok()
+            .and_then(|c| c.migrate.eol_minimum)
+            .and_then(|raw| Self::parse(&raw))
+            .unwrap_or_default()
+    }
+
+    /// Returns true if `python_version` is older than this cutoff.
+    ///
+    /// Versions whose `major.minor` prefix doesn't parse (e.g. `"unknown"`)
+    /// are never considered EOL.
+    pub fn is_eol(&self, python_version: &str) -> bool {
+        let mut parts = python_version.split('.');
+        let (Some(major), Some(minor)) = (parts.next(), parts.next()) else {
+            return false;
+        };
+        match (major.parse::<u32>(), minor.parse::<u32>()) {
+            (Ok(major), Ok(minor)) => (major, minor) < (self.major, self.minor),
+            _ => false,
+        }
+    }
+}
+
+impl Default for EolMinimum {
+    fn default() -> Self {
+        Self { major: 3, minor: 9 }
+    }
+}
+
+impl std::fmt::Display for EolMinimum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
 
 /// Calculate directory size in bytes.
 ///
@@ -18,7 +79,8 @@ const EOL_PYTHON_MINOR: u32 = 8;
 ///
 /// This function traverses the entire directory tree and calls `stat()` on every file.
 /// For large environments with thousands of files, this can be expensive.
-/// Consider using `Option<u64>` in `SourceEnvironment` and calculating size lazily.
+/// `SourceEnvironment::size_bytes` is therefore lazy; `scuv migrate list --sizes`
+/// opts in to computing it.
 ///
 /// # Examples
 ///
@@ -36,7 +98,6 @@ const EOL_PYTHON_MINOR: u32 = 8;
 /// let size_mb = size as f64 / 1_048_576.0;
 /// println!("Environment size: {:.1} MB", size_mb);
 /// ```
-#[allow(dead_code)]
 pub fn dir_size(path: &Path) -> u64 {
     walkdir::WalkDir::new(path)
         .into_iter()
@@ -91,13 +152,13 @@ pub fn check_name_conflict(name: &str) -> Option<PathBuf> {
 /// # Status Priority
 ///
 /// 1. Name conflict (existing scuv environment)
-/// 2. Python EOL (3.8 and below, or Python 2.x)
+/// 2. Python EOL (older than `eol_minimum`; 3.8 and below by default)
 /// 3. Ready to migrate
 ///
 /// # Examples
 ///
 /// ```
-/// use scoop_uv::core::migrate::common::determine_status;
+/// use scoop_uv::core::migrate::common::{EolMinimum, determine_status};
 /// use scoop_uv::core::migrate::EnvironmentStatus;
 ///
 /// # // Setup: isolated SCUV_HOME to avoid real conflicts
@@ -107,44 +168,34 @@ pub fn check_name_conflict(name: &str) -> Option<PathBuf> {
 /// # unsafe { std::env::set_var("SCUV_HOME", temp.path()); }
 /// #
 /// // Modern Python, no conflict
-/// let status = determine_status("new_env", "3.12.0");
+/// let eol = EolMinimum::default();
+/// let status = determine_status("new_env", "3.12.0", eol);
 /// assert!(matches!(status, EnvironmentStatus::Ready));
 ///
 /// // EOL Python version
-/// let status = determine_status("old_env", "3.7.0");
+/// let status = determine_status("old_env", "3.7.0", eol);
 /// assert!(matches!(status, EnvironmentStatus::PythonEol { .. }));
 ///
 /// // Python 2.x is definitely EOL
-/// let status = determine_status("ancient_env", "2.7.18");
+/// let status = determine_status("ancient_env", "2.7.18", eol);
 /// assert!(matches!(status, EnvironmentStatus::PythonEol { .. }));
 /// # // SAFETY: Restoring original environment
 /// # unsafe { std::env::remove_var("SCUV_HOME"); }
 /// ```
-pub fn determine_status(name: &str, python_version: &str) -> EnvironmentStatus {
+pub fn determine_status(
+    name: &str,
+    python_version: &str,
+    eol_minimum: EolMinimum,
+) -> EnvironmentStatus {
     // Check for name conflict first
     if let Some(existing) = check_name_conflict(name) {
         return EnvironmentStatus::NameConflict { existing };
     }
 
-    // Check for EOL Python versions
-    let major_minor: Vec<&str> = python_version.split('.').collect();
-    if major_minor.len() >= 2 {
-        if let (Ok(major), Ok(minor)) =
-            (major_minor[0].parse::<u32>(), major_minor[1].parse::<u32>())
-        {
-            // Python 3.8 and earlier are EOL (as of 2024)
-            if major == 3 && minor <= EOL_PYTHON_MINOR {
-                return EnvironmentStatus::PythonEol {
-                    version: python_version.to_string(),
-                };
-            }
-            // Python 2.x is definitely EOL
-            if major == 2 {
-                return EnvironmentStatus::PythonEol {
-                    version: python_version.to_string(),
-                };
-            }
-        }
+    if eol_minimum.is_eol(python_version) {
+        return EnvironmentStatus::PythonEol {
+            version: python_version.to_string(),
+        };
     }
 
     EnvironmentStatus::Ready
@@ -156,22 +207,63 @@ mod tests {
 
     #[test]
     fn test_determine_status_eol_python27() {
-        let status = determine_status("nonexistent_test_env_xyz", "2.7.18");
+        let status = determine_status("nonexistent_test_env_xyz", "2.7.18", EolMinimum::default());
         assert!(matches!(status, EnvironmentStatus::PythonEol { .. }));
     }
 
     #[test]
     fn test_determine_status_eol_python38() {
-        let status = determine_status("nonexistent_test_env_xyz", "3.8.0");
+        let status = determine_status("nonexistent_test_env_xyz", "3.8.0", EolMinimum::default());
         assert!(matches!(status, EnvironmentStatus::PythonEol { .. }));
     }
 
     #[test]
     fn test_determine_status_ready_python312() {
-        let status = determine_status("nonexistent_test_env_xyz", "3.12.0");
+        let status = determine_status("nonexistent_test_env_xyz", "3.12.0", EolMinimum::default());
         assert!(matches!(status, EnvironmentStatus::Ready));
     }
 
+    #[test]
+    fn test_determine_status_configured_boundary() {
+        let eol = EolMinimum::parse("3.10").unwrap();
+        let status = determine_status("nonexistent_test_env_xyz", "3.9.18", eol);
+        assert!(matches!(status, EnvironmentStatus::PythonEol { .. }));
+        let status = determine_status("nonexistent_test_env_xyz", "3.10.0", eol);
+        assert!(matches!(status, EnvironmentStatus::Ready));
+    }
+
+    #[test]
+    fn test_determine_status_lowered_boundary_keeps_old_python_ready() {
+        let eol = EolMinimum::parse("3.6").unwrap();
+        let status = determine_status("nonexistent_test_env_xyz", "3.6.15", eol);
+        assert!(matches!(status, EnvironmentStatus::Ready));
+        let status = determine_status("nonexistent_test_env_xyz", "2.7.18", eol);
+        assert!(matches!(status, EnvironmentStatus::PythonEol { .. }));
+    }
+
+    #[test]
+    fn test_determine_status_unknown_version_is_ready() {
+        let status = determine_status("nonexistent_test_env_xyz", "unknown", EolMinimum::default());
+        assert!(matches!(status, EnvironmentStatus::Ready));
+    }
+
+    #[test]
+    fn test_eol_minimum_parse() {
+        assert_eq!(
+            EolMinimum::parse("3.9"),
+            Some(EolMinimum { major: 3, minor: 9 })
+        );
+        assert_eq!(
+            EolMinimum::parse(" 3.10 ")
+                .map(|e| e.to_string())
+                .as_deref(),
+            Some("3.10")
+        );
+        assert_eq!(EolMinimum::parse("3"), None);
+        assert_eq!(EolMinimum::parse("3.9.1"), None);
+        assert_eq!(EolMinimum::parse("three.nine"), None);
+    }
+
     #[test]
     fn test_check_name_conflict_nonexistent() {
         let result = check_name_conflict("definitely_nonexistent_env_name_12345");
@@ -5,7 +5,7 @@ use std::path::{Path, PathBuf};
 
 use crate::error::{Result, ScoopError};
 
-use super::common;
+use super::common::{self, EolMinimum};
 use super::source::{EnvironmentSource, SourceEnvironment, SourceType};
 
 /// Discovers Conda environments
@@ -13,12 +13,23 @@ use super::source::{EnvironmentSource, SourceEnvironment, SourceType};
 pub struct CondaDiscovery {
     /// Root paths to search for conda environments
     roots: Vec<PathBuf>,
+    /// Versions older than this are reported as EOL
+    eol_minimum: EolMinimum,
 }
 
 impl CondaDiscovery {
     /// Creates a new discovery instance for the given conda roots.
     pub fn new(roots: Vec<PathBuf>) -> Self {
-        Self { roots }
+        Self {
+            roots,
+            eol_minimum: EolMinimum::default(),
+        }
+    }
+
+    /// Sets the EOL cutoff used when classifying discovered environments.
+    pub fn with_eol_minimum(mut self, eol_minimum: EolMinimum) -> Self {
+        self.eol_minimum = eol_minimum;
+        self
     }
 
     /// Creates a discovery instance using default conda locations.
@@ -140,7 +151,7 @@ impl CondaDiscovery {
             Self::get_python_version(env_path).unwrap_or_else(|| "unknown".to_string());
 
         // Determine status
-        let status = common::determine_status(&name, &python_version);
+        let status = common::determine_status(&name, &python_version, self.eol_minimum);
 
         Some(SourceEnvironment {
             name,
@@ -233,7 +244,8 @@ mod tests {
 
     #[test]
     fn test_determine_status_ready() {
-        let status = common::determine_status("nonexistent_conda_test", "3.12.0");
+        let status =
+            common::determine_status("nonexistent_conda_test", "3.12.0", EolMinimum::default());
         assert!(matches!(status, EnvironmentStatus::Ready));
     }
 
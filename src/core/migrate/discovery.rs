@@ -5,7 +5,7 @@ use std::path::{Path, PathBuf};
 
 use crate::error::{Result, ScoopError};
 
-use super::common;
+use super::common::{self, EolMinimum};
 use super::source::{EnvironmentSource, EnvironmentStatus, SourceEnvironment, SourceType};
 
 /// Discovers pyenv-virtualenv environments
@@ -13,12 +13,23 @@ use super::source::{EnvironmentSource, EnvironmentStatus, SourceEnvironment, Sou
 pub struct PyenvDiscovery {
     /// Root path of pyenv (typically ~/.pyenv)
     root: PathBuf,
+    /// Versions older than this are reported as EOL
+    eol_minimum: EolMinimum,
 }
 
 impl PyenvDiscovery {
     /// Creates a new discovery instance for the given pyenv root.
     pub fn new(root: PathBuf) -> Self {
-        Self { root }
+        Self {
+            root,
+            eol_minimum: EolMinimum::default(),
+        }
+    }
+
+    /// Sets the EOL cutoff used when classifying discovered environments.
+    pub fn with_eol_minimum(mut self, eol_minimum: EolMinimum) -> Self {
+        self.eol_minimum = eol_minimum;
+        self
     }
 
     /// Creates a discovery instance using the default pyenv root.
@@ -102,7 +113,7 @@ impl PyenvDiscovery {
         }
 
         // Determine status (no dir_size calculation here - lazy loading)
-        let status = common::determine_status(&name, &python_version);
+        let status = common::determine_status(&name, &python_version, self.eol_minimum);
 
         Some(SourceEnvironment {
             name,
@@ -560,27 +571,39 @@ mod tests {
     #[test]
     fn test_determine_status_eol_python() {
         // Use a unique name that definitely won't exist in scuv
-        let status = common::determine_status("nonexistent_eol_test_env_xyz", "3.7.0");
+        let status = common::determine_status(
+            "nonexistent_eol_test_env_xyz",
+            "3.7.0",
+            EolMinimum::default(),
+        );
         assert!(matches!(status, EnvironmentStatus::PythonEol { .. }));
 
-        let status = common::determine_status("nonexistent_eol_test_env_xyz", "2.7.18");
+        let status = common::determine_status(
+            "nonexistent_eol_test_env_xyz",
+            "2.7.18",
+            EolMinimum::default(),
+        );
         assert!(matches!(status, EnvironmentStatus::PythonEol { .. }));
     }
 
     #[test]
     fn test_determine_status_ready() {
-        let status = common::determine_status("nonexistent_env_name_12345", "3.12.0");
+        let status = common::determine_status(
+            "nonexistent_env_name_12345",
+            "3.12.0",
+            EolMinimum::default(),
+        );
         assert!(matches!(status, EnvironmentStatus::Ready));
     }
 
     #[test]
     fn test_determine_status_eol_boundary() {
         // 3.8 is the EOL boundary
-        let status = common::determine_status("boundary_test_env", "3.8.19");
+        let status = common::determine_status("boundary_test_env", "3.8.19", EolMinimum::default());
         assert!(matches!(status, EnvironmentStatus::PythonEol { .. }));
 
         // 3.9 is supported
-        let status = common::determine_status("boundary_test_env", "3.9.0");
+        let status = common::determine_status("boundary_test_env", "3.9.0", EolMinimum::default());
         assert!(matches!(status, EnvironmentStatus::Ready));
     }
 
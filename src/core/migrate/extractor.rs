@@ -3,10 +3,12 @@
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use serde::{Deserialize, Serialize};
+
 use crate::error::{Result, ScoopError};
 
 /// A package specification extracted from an environment
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct PackageSpec {
     /// Package name
     pub name: String,
@@ -30,6 +32,31 @@ impl PackageSpec {
     }
 }
 
+/// An editable install that can't be reinstalled by version.
+///
+/// Reported after migration so the user can re-run `pip install -e` from
+/// the original source checkout.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EditablePackage {
+    /// Package name
+    pub name: String,
+    /// Source directory (or VCS URL) the package was installed from
+    pub source: String,
+}
+
+impl From<&PackageSpec> for EditablePackage {
+    fn from(spec: &PackageSpec) -> Self {
+        Self {
+            name: spec.name.clone(),
+            source: spec
+                .editable_path
+                .as_ref()
+                .map(|p| p.display().to_string())
+                .unwrap_or_default(),
+        }
+    }
+}
+
 /// Result of package extraction
 #[derive(Debug)]
 pub struct ExtractionResult {
@@ -113,7 +140,17 @@ impl PackageExtractor {
         }
 
         let stdout = String::from_utf8_lossy(&output.stdout);
-        self.parse_freeze_output(&stdout)
+        let mut result = self.parse_freeze_output(&stdout)?;
+
+        // pip freeze names local editables after their directory and
+        // drops them entirely in some pip versions; the install metadata
+        // in site-packages is authoritative for name and source.
+        if self.include_editable {
+            if let Ok(site_packages) = crate::paths::virtualenv_site_packages(env_path) {
+                merge_editables(&mut result, scan_editables(&site_packages));
+            }
+        }
+        Ok(result)
     }
 
     /// Parses pip freeze output into package specs.
@@ -229,6 +266,115 @@ impl PackageExtractor {
     }
 }
 
+/// Find editable installs recorded in `site_packages`.
+///
+/// Two markers exist in the wild:
+/// - `<name>-<ver>.dist-info/direct_url.json` with `dir_info.editable`
+///   (PEP 610/660, modern pip and uv)
+/// - `<name>.egg-link`, whose first line is the source dir (legacy
+///   `setup.py develop`)
+fn scan_editables(site_packages: &Path) -> Vec<PackageSpec> {
+    let Ok(entries) = std::fs::read_dir(site_packages) else {
+        return Vec::new();
+    };
+
+    let mut found = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+
+        if let Some(stem) = file_name.strip_suffix(".dist-info") {
+            let Ok(json) = std::fs::read_to_string(path.join("direct_url.json")) else {
+                continue;
+            };
+            if let Some(source) = editable_source_from_direct_url(&json) {
+                let (name, version) = stem.split_once('-').unwrap_or((stem, "editable"));
+                found.push(PackageSpec {
+                    name: name.to_string(),
+                    version: version.to_string(),
+                    editable: true,
+                    editable_path: Some(source),
+                });
+            }
+        } else if let Some(name) = file_name.strip_suffix(".egg-link") {
+            let Ok(content) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            if let Some(source) = content
+                .lines()
+                .next()
+                .map(str::trim)
+                .filter(|l| !l.is_empty())
+            {
+                found.push(PackageSpec {
+                    name: name.to_string(),
+                    version: "editable".to_string(),
+                    editable: true,
+                    editable_path: Some(PathBuf::from(source)),
+                });
+            }
+        }
+    }
+    found.sort_by(|a, b| a.name.cmp(&b.name));
+    found
+}
+
+/// Source directory of an editable install from a PEP 610 `direct_url.json`.
+///
+/// Returns `None` for non-editable direct installs (wheels, VCS checkouts
+/// without `editable`) and for anything that isn't a `file://` URL.
+fn editable_source_from_direct_url(json: &str) -> Option<PathBuf> {
+    let value: serde_json::Value = serde_json::from_str(json).ok()?;
+    if !value["dir_info"]["editable"].as_bool().unwrap_or(false) {
+        return None;
+    }
+    let url = value["url"].as_str()?;
+    let path = url.strip_prefix("file://")?;
+    Some(PathBuf::from(percent_decode(path)))
+}
+
+/// Decode `%XX` escapes in a file URL path (spaces and non-ASCII names).
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+            if let Some(byte) = hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                out.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Replace freeze-derived entries with the editables found on disk.
+///
+/// Matching is by normalized name (PEP 503) or by source path, since pip
+/// freeze names a local editable after its directory rather than its
+/// distribution name.
+fn merge_editables(result: &mut ExtractionResult, editables: Vec<PackageSpec>) {
+    fn normalize(name: &str) -> String {
+        name.to_lowercase().replace(['_', '.'], "-")
+    }
+
+    for editable in editables {
+        let name = normalize(&editable.name);
+        result.packages.retain(|p| {
+            normalize(&p.name) != name
+                && (p.editable_path.is_none() || p.editable_path != editable.editable_path)
+        });
+        result.packages.push(editable);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -325,4 +471,99 @@ mod tests {
         assert!(requirements.contains("requests==2.31.0"));
         assert!(requirements.contains("flask==3.0.0"));
     }
+
+    const EDITABLE_DIRECT_URL: &str = r#"{
+        "dir_info": {"editable": true},
+        "url": "file:///home/user/src/my%20project"
+    }"#;
+
+    #[test]
+    fn test_direct_url_editable_source() {
+        assert_eq!(
+            editable_source_from_direct_url(EDITABLE_DIRECT_URL),
+            Some(PathBuf::from("/home/user/src/my project"))
+        );
+    }
+
+    #[test]
+    fn test_direct_url_not_editable() {
+        let wheel = r#"{"archive_info": {}, "url": "file:///tmp/pkg-1.0-py3-none-any.whl"}"#;
+        let vcs = r#"{"url": "https://github.com/x/y", "vcs_info": {"vcs": "git"}}"#;
+        let dir = r#"{"dir_info": {}, "url": "file:///home/user/src/pkg"}"#;
+        assert_eq!(editable_source_from_direct_url(wheel), None);
+        assert_eq!(editable_source_from_direct_url(vcs), None);
+        assert_eq!(editable_source_from_direct_url(dir), None);
+        assert_eq!(editable_source_from_direct_url("not json"), None);
+    }
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(percent_decode("/a%20b/%C3%A9"), "/a b/é");
+        assert_eq!(percent_decode("/plain"), "/plain");
+        assert_eq!(percent_decode("/bad%zz%"), "/bad%zz%");
+    }
+
+    #[test]
+    fn test_scan_editables_finds_both_markers() {
+        let temp = tempfile::tempdir().unwrap();
+        let sp = temp.path();
+
+        let dist_info = sp.join("my_pkg-0.1.0.dist-info");
+        std::fs::create_dir(&dist_info).unwrap();
+        std::fs::write(dist_info.join("direct_url.json"), EDITABLE_DIRECT_URL).unwrap();
+
+        let regular = sp.join("requests-2.31.0.dist-info");
+        std::fs::create_dir(&regular).unwrap();
+
+        std::fs::write(sp.join("legacy.egg-link"), "/home/user/src/legacy\n.\n").unwrap();
+
+        let found = scan_editables(sp);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].name, "legacy");
+        assert_eq!(
+            found[0].editable_path,
+            Some(PathBuf::from("/home/user/src/legacy"))
+        );
+        assert_eq!(found[1].name, "my_pkg");
+        assert_eq!(found[1].version, "0.1.0");
+        assert!(found[1].editable);
+        assert_eq!(
+            EditablePackage::from(&found[1]).source,
+            "/home/user/src/my project"
+        );
+    }
+
+    #[test]
+    fn test_merge_editables_replaces_freeze_entries() {
+        let extractor = PackageExtractor::new();
+        let mut result = extractor
+            .parse_freeze_output(
+                "requests==2.31.0\nmy-pkg @ file:///home/user/src/my%20project\nlegacy==1.0\n",
+            )
+            .unwrap();
+        assert_eq!(result.regular_packages().len(), 3);
+
+        merge_editables(
+            &mut result,
+            vec![
+                PackageSpec {
+                    name: "my_pkg".to_string(),
+                    version: "0.1.0".to_string(),
+                    editable: true,
+                    editable_path: Some(PathBuf::from("/home/user/src/my project")),
+                },
+                PackageSpec {
+                    name: "legacy".to_string(),
+                    version: "editable".to_string(),
+                    editable: true,
+                    editable_path: Some(PathBuf::from("/home/user/src/legacy")),
+                },
+            ],
+        );
+
+        assert!(result.failed.is_empty());
+        assert_eq!(result.regular_packages().len(), 1);
+        assert_eq!(result.regular_packages()[0].name, "requests");
+        assert_eq!(result.editable_packages().len(), 2);
+    }
 }
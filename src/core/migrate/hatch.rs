@@ -0,0 +1,284 @@
+//! Hatch environment discovery
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{Result, ScoopError};
+use crate::paths;
+
+use super::common::{self, EolMinimum};
+use super::source::{EnvironmentSource, EnvironmentStatus, SourceEnvironment, SourceType};
+
+/// Discovers Hatch virtual environments
+#[derive(Debug)]
+pub struct HatchDiscovery {
+    /// Virtual env storage (typically ~/.local/share/hatch/env/virtual)
+    root: PathBuf,
+    /// Versions older than this are reported as EOL
+    eol_minimum: EolMinimum,
+}
+
+/// One environment directory found under the root.
+struct HatchEnvDir {
+    project: String,
+    /// Hatch's per-checkout directory between project and env
+    checkout: String,
+    env: String,
+    path: PathBuf,
+}
+
+impl HatchEnvDir {
+    /// `web` for a project's `default` env, `web-test` otherwise.
+    fn name(&self) -> String {
+        if self.env == "default" {
+            self.project.clone()
+        } else {
+            format!("{}-{}", self.project, self.env)
+        }
+    }
+}
+
+impl HatchDiscovery {
+    /// Creates a new discovery instance for the given virtual env storage.
+    pub fn new(root: PathBuf) -> Self {
+        Self {
+            root,
+            eol_minimum: EolMinimum::default(),
+        }
+    }
+
+    /// Sets the EOL cutoff used when classifying discovered environments.
+    pub fn with_eol_minimum(mut self, eol_minimum: EolMinimum) -> Self {
+        self.eol_minimum = eol_minimum;
+        self
+    }
+
+    /// Creates a discovery instance using Hatch's data directory.
+    ///
+    /// Uses `$HATCH_DATA_DIR/env/virtual` if set, otherwise `env/virtual`
+    /// under the platform data directory (`~/.local/share/hatch` on Linux,
+    /// `~/Library/Application Support/hatch` on macOS).
+    pub fn default_root() -> Option<Self> {
+        let data_dir = std::env::var("HATCH_DATA_DIR")
+            .map(PathBuf::from)
+            .ok()
+            .or_else(|| dirs::data_local_dir().map(|d| d.join("hatch")))?;
+        let root = data_dir.join("env").join("virtual");
+
+        if root.exists() {
+            Some(Self::new(root))
+        } else {
+            None
+        }
+    }
+
+    /// Parse pyvenv.cfg to extract Python version
+    fn parse_pyvenv_cfg(path: &Path) -> Option<String> {
+        let content = fs::read_to_string(path.join("pyvenv.cfg")).ok()?;
+        content
+            .lines()
+            .find_map(crate::core::pyvenv_version_from_line)
+    }
+
+    /// Subdirectories of `path`, skipping symlinks and hidden entries.
+    fn subdirs(path: &Path) -> Vec<(String, PathBuf)> {
+        let Ok(entries) = fs::read_dir(path) else {
+            return Vec::new();
+        };
+        entries
+            .flatten()
+            .filter_map(|entry| {
+                let path = entry.path();
+                let name = path.file_name()?.to_str()?.to_string();
+                if name.starts_with('.') || path.is_symlink() || !path.is_dir() {
+                    return None;
+                }
+                Some((name, path))
+            })
+            .collect()
+    }
+
+    /// Walk `<root>/<project>/<checkout>/<env>` for directories holding a
+    /// `pyvenv.cfg`. Unreadable directories are skipped.
+    fn env_dirs(&self) -> Vec<HatchEnvDir> {
+        let mut found = Vec::new();
+        for (project, project_path) in Self::subdirs(&self.root) {
+            for (checkout, checkout_path) in Self::subdirs(&project_path) {
+                for (env, path) in Self::subdirs(&checkout_path) {
+                    if path.join("pyvenv.cfg").exists() {
+                        found.push(HatchEnvDir {
+                            project: project.clone(),
+                            checkout: checkout.clone(),
+                            env,
+                            path,
+                        });
+                    }
+                }
+            }
+        }
+        found
+    }
+
+    /// Parse a single environment directory into SourceEnvironment
+    fn parse_environment(&self, env_path: &Path, name: String) -> SourceEnvironment {
+        let python_version =
+            Self::parse_pyvenv_cfg(env_path).unwrap_or_else(|| "unknown".to_string());
+
+        let status = if paths::virtualenv_python_exe(env_path).exists() {
+            common::determine_status(&name, &python_version, self.eol_minimum)
+        } else {
+            EnvironmentStatus::Corrupted {
+                reason: "Python binary not found".to_string(),
+            }
+        };
+
+        SourceEnvironment {
+            name,
+            python_version,
+            path: env_path.to_path_buf(),
+            source_type: SourceType::Hatch,
+            size_bytes: None, // Lazy: calculated only when needed
+            status,
+        }
+    }
+}
+
+impl EnvironmentSource for HatchDiscovery {
+    fn source_type(&self) -> SourceType {
+        SourceType::Hatch
+    }
+
+    /// Environments are named `<project>` (the `default` env) or
+    /// `<project>-<env>`. Two checkouts of one project get the checkout
+    /// directory added (`<project>-<checkout>-<env>`) so names stay unique.
+    fn scan_environments(&self) -> Result<Vec<SourceEnvironment>> {
+        let dirs = self.env_dirs();
+        let mut name_counts: HashMap<String, usize> = HashMap::new();
+        for dir in &dirs {
+            *name_counts.entry(dir.name()).or_default() += 1;
+        }
+
+        let mut environments: Vec<_> = dirs
+            .iter()
+            .map(|dir| {
+                let mut name = dir.name();
+                if name_counts[&name] > 1 {
+                    name = format!("{}-{}-{}", dir.project, dir.checkout, dir.env);
+                }
+                self.parse_environment(&dir.path, name)
+            })
+            .collect();
+
+        environments.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(environments)
+    }
+
+    fn find_environment(&self, name: &str) -> Result<SourceEnvironment> {
+        self.scan_environments()?
+            .into_iter()
+            .find(|env| env.name == name)
+            .ok_or_else(|| ScoopError::HatchEnvNotFound {
+                name: name.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn create_env(root: &Path, project: &str, checkout: &str, env: &str, version: &str) -> PathBuf {
+        let path = root.join(project).join(checkout).join(env);
+        let python = paths::virtualenv_python_exe(&path);
+        fs::create_dir_all(python.parent().unwrap()).unwrap();
+        fs::write(&python, "").unwrap();
+        fs::write(
+            path.join("pyvenv.cfg"),
+            format!("home = /usr/bin\nversion = {version}\n"),
+        )
+        .unwrap();
+        path
+    }
+
+    #[test]
+    fn scan_names_envs_by_project_and_env() {
+        let temp = TempDir::new().unwrap();
+        create_env(temp.path(), "web", "AbCd1234", "default", "3.12.1");
+        create_env(temp.path(), "web", "AbCd1234", "test", "3.11.4");
+        // Not a venv: no pyvenv.cfg.
+        fs::create_dir_all(temp.path().join("web/AbCd1234/scratch")).unwrap();
+
+        let envs = HatchDiscovery::new(temp.path().to_path_buf())
+            .scan_environments()
+            .unwrap();
+
+        let found: Vec<_> = envs
+            .iter()
+            .map(|e| (e.name.as_str(), e.python_version.as_str()))
+            .collect();
+        assert_eq!(found, [("web", "3.12.1"), ("web-test", "3.11.4")]);
+        assert!(envs.iter().all(|e| e.source_type == SourceType::Hatch));
+    }
+
+    #[test]
+    fn corrupted_env_is_listed_as_corrupted() {
+        let temp = TempDir::new().unwrap();
+        create_env(temp.path(), "web", "AbCd1234", "default", "3.12.1");
+        let broken = create_env(temp.path(), "api", "EfGh5678", "default", "3.12.1");
+        fs::remove_file(paths::virtualenv_python_exe(&broken)).unwrap();
+
+        let discovery = HatchDiscovery::new(temp.path().to_path_buf());
+        let envs = discovery.scan_environments().unwrap();
+        assert_eq!(envs.len(), 2);
+        assert!(matches!(
+            envs[0].status,
+            EnvironmentStatus::Corrupted { .. }
+        ));
+
+        let found = discovery.find_environment("api").unwrap();
+        assert_eq!(found.path, broken);
+        assert!(matches!(found.status, EnvironmentStatus::Corrupted { .. }));
+    }
+
+    #[test]
+    fn two_checkouts_of_one_project_stay_distinct() {
+        let temp = TempDir::new().unwrap();
+        create_env(temp.path(), "web", "AbCd1234", "default", "3.12.1");
+        create_env(temp.path(), "web", "EfGh5678", "default", "3.12.1");
+
+        let envs = HatchDiscovery::new(temp.path().to_path_buf())
+            .scan_environments()
+            .unwrap();
+        let names: Vec<_> = envs.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["web-AbCd1234-default", "web-EfGh5678-default"]);
+    }
+
+    #[test]
+    fn find_unknown_env_is_hatch_not_found() {
+        let temp = TempDir::new().unwrap();
+        let err = HatchDiscovery::new(temp.path().to_path_buf())
+            .find_environment("ghost")
+            .unwrap_err();
+        assert!(matches!(err, ScoopError::HatchEnvNotFound { ref name } if name == "ghost"));
+    }
+
+    #[test]
+    fn default_root_uses_hatch_data_dir() {
+        crate::test_utils::with_isolated_migrate_env(|| {
+            let temp = TempDir::new().unwrap();
+            fs::create_dir_all(temp.path().join("env/virtual")).unwrap();
+
+            // SAFETY: with_isolated_migrate_env holds the migrate env lock
+            // and restores the variable afterwards.
+            unsafe { std::env::set_var("HATCH_DATA_DIR", temp.path()) };
+            let found = HatchDiscovery::default_root().unwrap();
+            assert_eq!(found.root, temp.path().join("env/virtual"));
+
+            unsafe { std::env::set_var("HATCH_DATA_DIR", temp.path().join("gone")) };
+            assert!(HatchDiscovery::default_root().is_none());
+        });
+    }
+}
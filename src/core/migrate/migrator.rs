@@ -12,7 +12,7 @@ use crate::uv::PythonInfo;
 use crate::uv::UvClient;
 use crate::validate::PythonVersion;
 
-use super::extractor::{ExtractionResult, PackageExtractor};
+use super::extractor::{EditablePackage, ExtractionResult, PackageExtractor};
 use super::source::{EnvironmentStatus, SourceEnvironment};
 
 /// Result of Python version availability check
@@ -54,10 +54,19 @@ pub struct MigrateOptions {
     pub rename_to: Option<String>,
     /// Fail on first package error (strict mode)
     pub strict: bool,
+    /// Drop packages the index doesn't have (name or version not found)
+    /// instead of counting them as failures; see
+    /// [`MigrationResult::packages_unresolved`]
+    pub skip_unresolved: bool,
     /// Delete original environment after successful migration
     pub delete_source: bool,
     /// Automatically install Python if missing
     pub auto_install_python: bool,
+    /// Package index to reinstall from (e.g. a private PyPI mirror)
+    pub index_url: Option<String>,
+    /// Python to create the new environment with instead of the source's;
+    /// an EOL source Python is then no obstacle
+    pub python_version: Option<String>,
 }
 
 /// Result of a migration operation
@@ -71,6 +80,10 @@ pub struct MigrationResult {
     pub packages_migrated: usize,
     /// Packages that failed to install
     pub packages_failed: Vec<String>,
+    /// Packages dropped under `skip_unresolved` because the index has no
+    /// such package or version
+    #[serde(default)]
+    pub packages_unresolved: Vec<String>,
     /// Whether this was a dry run
     pub dry_run: bool,
     /// Path to the new environment
@@ -79,15 +92,23 @@ pub struct MigrationResult {
     pub source_deleted: bool,
     /// Actual Python version used (may differ from requested if compatible version used)
     pub actual_python_version: String,
+    /// Editable installs that need a manual `pip install -e`
+    #[serde(default)]
+    pub editable_packages: Vec<EditablePackage>,
 }
 
 impl MigrationResult {
     /// Returns the exit code based on migration result.
     ///
     /// Returns `Success` if all packages were migrated successfully,
-    /// `PartialSuccess` if some packages failed to install.
+    /// `PartialSuccess` if some packages failed to install or were dropped
+    /// as unresolved, or editable installs were left for the user to
+    /// reinstall.
     pub fn exit_code(&self) -> MigrationExitCode {
-        if self.packages_failed.is_empty() {
+        if self.packages_failed.is_empty()
+            && self.packages_unresolved.is_empty()
+            && self.editable_packages.is_empty()
+        {
             MigrationExitCode::Success
         } else {
             MigrationExitCode::PartialSuccess
@@ -95,6 +116,40 @@ impl MigrationResult {
     }
 }
 
+/// Packages that didn't make it into the new environment.
+#[derive(Debug, Default, PartialEq)]
+struct InstallOutcome {
+    /// Install errors (including transient ones like network failures)
+    failed: Vec<String>,
+    /// Not in the index, dropped under `skip_unresolved`
+    unresolved: Vec<String>,
+}
+
+/// Whether a failed `uv pip install` means the index has no such package
+/// or version, as opposed to a network or build failure that may succeed
+/// on a retry. Matches uv's resolver wording.
+fn is_unresolved(err: &ScoopError) -> bool {
+    let ScoopError::UvCommandFailed { message, .. } = err else {
+        return false;
+    };
+    [
+        "not found in the package registry",
+        "there is no version of",
+        "no versions of",
+    ]
+    .iter()
+    .any(|needle| message.contains(needle))
+}
+
+/// Editable installs from an extraction, in report form.
+fn editables_of(packages: &ExtractionResult) -> Vec<EditablePackage> {
+    packages
+        .editable_packages()
+        .into_iter()
+        .map(EditablePackage::from)
+        .collect()
+}
+
 /// Guard for rollback on failure
 struct RollbackGuard {
     path: Option<PathBuf>,
@@ -205,7 +260,7 @@ impl Migrator {
                 }
             }
             EnvironmentStatus::PythonEol { version } => {
-                if options.force {
+                if options.force || options.python_version.is_some() {
                     Ok(())
                 } else {
                     Err(ScoopError::MigrationFailed {
@@ -253,8 +308,13 @@ impl Migrator {
             fs::create_dir_all(parent)?;
         }
 
-        // Create the virtual environment
-        self.uv.create_venv(&target_path, python_version)?;
+        // Create the virtual environment. Anything at `target_path` now is
+        // uv's partial output (an existing env was rejected or removed
+        // above), so a failed or interrupted create leaves nothing behind.
+        if let Err(e) = self.uv.create_venv(&target_path, python_version, false) {
+            let _ = fs::remove_dir_all(&target_path);
+            return Err(e);
+        }
 
         Ok(target_path)
     }
@@ -265,14 +325,17 @@ impl Migrator {
     ///
     /// * `target_path` - Path to the target virtual environment.
     /// * `packages` - Extracted packages to install.
-    /// * `strict` - If true, fail immediately on first package error.
+    /// * `options` - `strict` fails on the first package error,
+    ///   `skip_unresolved` drops packages the index doesn't have (even when
+    ///   strict), and `index_url` replaces uv's default index.
     fn install_packages(
         &self,
         target_path: &Path,
         packages: &ExtractionResult,
-        strict: bool,
-    ) -> Result<Vec<String>> {
-        let mut failed = Vec::new();
+        options: &MigrateOptions,
+    ) -> Result<InstallOutcome> {
+        let index_url = options.index_url.as_deref();
+        let mut outcome = InstallOutcome::default();
 
         // Install regular packages in one batch
         let regular_specs: Vec<String> = packages
@@ -282,40 +345,48 @@ impl Migrator {
             .collect();
 
         if !regular_specs.is_empty() {
-            if let Err(e) = self.uv.pip_install(target_path, &regular_specs) {
+            if let Err(e) = self
+                .uv
+                .pip_install_with_index(target_path, &regular_specs, index_url)
+            {
+                // Ctrl-C and an unreachable index aren't package failures;
+                // retrying one by one would only start (and fail) another uv
+                // per package.
+                if matches!(e, ScoopError::Interrupted | ScoopError::NetworkError { .. }) {
+                    return Err(e);
+                }
                 // Try installing packages one by one to identify failures
                 for spec in &regular_specs {
-                    if self
-                        .uv
-                        .pip_install(target_path, std::slice::from_ref(spec))
-                        .is_err()
-                    {
-                        if strict {
-                            return Err(ScoopError::MigrationFailed {
-                                reason: format!("Failed to install package: {}", spec),
-                            });
-                        }
-                        failed.push(spec.clone());
+                    let Err(err) = self.uv.pip_install_with_index(
+                        target_path,
+                        std::slice::from_ref(spec),
+                        index_url,
+                    ) else {
+                        continue;
+                    };
+                    if options.skip_unresolved && is_unresolved(&err) {
+                        outcome.unresolved.push(spec.clone());
+                        continue;
+                    }
+                    if options.strict {
+                        return Err(ScoopError::MigrationFailed {
+                            reason: format!("Failed to install package: {}", spec),
+                        });
                     }
+                    outcome.failed.push(spec.clone());
                 }
 
                 // If all failed, propagate the original error
-                if failed.len() == regular_specs.len() {
+                if outcome.failed.len() == regular_specs.len() {
                     return Err(e);
                 }
             }
         }
 
-        // Editable packages need special handling - we skip them for now
-        // since the source paths may not be valid in the new environment
-        for editable in packages.editable_packages() {
-            failed.push(format!(
-                "{} (editable - skipped)",
-                editable.to_requirement()
-            ));
-        }
-
-        Ok(failed)
+        // Editable packages aren't installed here: their source paths may
+        // not be valid for the new environment, so they're reported
+        // separately in `MigrationResult::editable_packages`.
+        Ok(outcome)
     }
 
     /// Deletes the source environment after successful migration.
@@ -340,15 +411,14 @@ impl Migrator {
         })
     }
 
-    /// Writes metadata for the migrated environment.
+    /// Writes metadata for the migrated environment, atomically (and
+    /// durably with `durable_writes`) like every other metadata write.
     fn write_metadata(&self, target_path: &Path, name: &str, python_version: &str) -> Result<()> {
         let uv_version = self.uv.version().ok();
         let metadata = Metadata::new(name.to_string(), python_version.to_string(), uv_version);
 
-        let metadata_path = target_path.join(".scoop-metadata.json");
         let content = serde_json::to_string_pretty(&metadata)?;
-        fs::write(metadata_path, content)?;
-        Ok(())
+        crate::core::atomic::write_file(&target_path.join(Metadata::FILE_NAME), content.as_bytes())
     }
 
     /// Migrates a single environment.
@@ -361,8 +431,12 @@ impl Migrator {
         source: &SourceEnvironment,
         options: &MigrateOptions,
     ) -> Result<MigrationResult> {
-        // Determine target name
+        // Determine target name and Python
         let target_name = options.rename_to.as_ref().unwrap_or(&source.name).clone();
+        let python_version = options
+            .python_version
+            .clone()
+            .unwrap_or_else(|| source.python_version.clone());
 
         // Dry run - just report what would happen
         if options.dry_run {
@@ -370,13 +444,15 @@ impl Migrator {
             let target_path = paths::virtualenv_path(&target_name)?;
             return Ok(MigrationResult {
                 name: target_name,
-                python_version: source.python_version.clone(),
-                packages_migrated: packages.packages.len(),
+                python_version: python_version.clone(),
+                packages_migrated: packages.regular_packages().len(),
                 packages_failed: packages.failed.clone(),
+                packages_unresolved: Vec::new(),
                 dry_run: true,
                 path: target_path,
                 source_deleted: false,
-                actual_python_version: source.python_version.clone(),
+                actual_python_version: python_version,
+                editable_packages: editables_of(&packages),
             });
         }
 
@@ -395,21 +471,20 @@ impl Migrator {
         };
 
         // Create target environment
-        let target_path =
-            self.create_target_env(&target_name, &source.python_version, options.force)?;
+        let target_path = self.create_target_env(&target_name, &python_version, options.force)?;
 
         // Set up rollback guard
         let mut rollback = RollbackGuard::new(target_path.clone());
 
         // Install packages
-        let failed = if options.skip_packages {
-            Vec::new()
+        let outcome = if options.skip_packages {
+            InstallOutcome::default()
         } else {
-            self.install_packages(&target_path, &packages, options.strict)?
+            self.install_packages(&target_path, &packages, options)?
         };
 
         // Write metadata
-        self.write_metadata(&target_path, &target_name, &source.python_version)?;
+        self.write_metadata(&target_path, &target_name, &python_version)?;
 
         // Success - disarm rollback
         rollback.disarm();
@@ -422,17 +497,20 @@ impl Migrator {
             false
         };
 
-        let packages_migrated = packages.packages.len() - failed.len();
+        let packages_migrated =
+            packages.regular_packages().len() - outcome.failed.len() - outcome.unresolved.len();
 
         Ok(MigrationResult {
             name: target_name,
-            python_version: source.python_version.clone(),
+            python_version: python_version.clone(),
             packages_migrated,
-            packages_failed: failed,
+            packages_failed: outcome.failed,
+            packages_unresolved: outcome.unresolved,
             dry_run: false,
             path: target_path,
             source_deleted,
-            actual_python_version: source.python_version.clone(),
+            actual_python_version: python_version,
+            editable_packages: editables_of(&packages),
         })
     }
 
@@ -456,6 +534,7 @@ impl Migrator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::migrate::extractor::PackageSpec;
     use crate::core::migrate::source::SourceType;
 
     fn mock_source(name: &str, status: EnvironmentStatus) -> SourceEnvironment {
@@ -552,6 +631,224 @@ mod tests {
         assert!(migrator.validate_source(&source, &options).is_err());
     }
 
+    #[test]
+    fn test_validate_source_eol_with_python_override() {
+        let migrator = Migrator {
+            uv: UvClient::with_path(PathBuf::from("/mock/uv")),
+            extractor: PackageExtractor::new(),
+        };
+        let source = mock_source(
+            "test",
+            EnvironmentStatus::PythonEol {
+                version: "3.7.0".to_string(),
+            },
+        );
+        let options = MigrateOptions {
+            python_version: Some("3.12".to_string()),
+            ..Default::default()
+        };
+
+        assert!(migrator.validate_source(&source, &options).is_ok());
+    }
+
+    /// `python_version` creates and records the new env with that Python,
+    /// even for an EOL source and without `force`.
+    #[cfg(unix)]
+    #[test]
+    #[serial_test::serial]
+    fn python_override_creates_target_with_that_version() {
+        use std::os::unix::fs::PermissionsExt;
+
+        crate::test_utils::with_temp_scoop_home(|home| {
+            let log = home.path().join("uv-args");
+            let uv = home.path().join("uv");
+            std::fs::write(
+                &uv,
+                format!(
+                    "#!/bin/sh\necho \"$@\" >> '{}'\n[ \"$1\" = venv ] && mkdir -p \"$2\"\nexit 0\n",
+                    log.display()
+                ),
+            )
+            .unwrap();
+            std::fs::set_permissions(&uv, std::fs::Permissions::from_mode(0o755)).unwrap();
+            let migrator = Migrator::with_uv(UvClient::with_path(uv));
+            let mut source = mock_source(
+                "legacy",
+                EnvironmentStatus::PythonEol {
+                    version: "3.7.0".to_string(),
+                },
+            );
+            source.python_version = "3.7.0".to_string();
+            let options = MigrateOptions {
+                skip_packages: true,
+                python_version: Some("3.12".to_string()),
+                ..Default::default()
+            };
+
+            let result = migrator.migrate(&source, &options).unwrap();
+            assert_eq!(result.python_version, "3.12");
+            assert_eq!(result.actual_python_version, "3.12");
+            let args = std::fs::read_to_string(&log).unwrap();
+            assert!(
+                args.lines()
+                    .any(|line| line == format!("venv {} --python 3.12", result.path.display())),
+                "{args}"
+            );
+            let metadata: Metadata = serde_json::from_str(
+                &std::fs::read_to_string(result.path.join(".scoop-metadata.json")).unwrap(),
+            )
+            .unwrap();
+            assert_eq!(metadata.python_version, "3.12");
+        });
+    }
+
+    fn spec(name: &str, version: &str) -> PackageSpec {
+        PackageSpec {
+            name: name.to_string(),
+            version: version.to_string(),
+            editable: false,
+            editable_path: None,
+        }
+    }
+
+    /// A migrator whose uv fails `pip install` for `gone-pkg` the way uv
+    /// reports a package missing from the index, and for `flaky` with a
+    /// network error. Everything else installs.
+    #[cfg(unix)]
+    fn migrator_with_mock_uv(dir: &Path) -> Migrator {
+        use std::os::unix::fs::PermissionsExt;
+
+        let uv = dir.join("uv");
+        std::fs::write(
+            &uv,
+            r#"#!/bin/sh
+case "$*" in
+  *gone-pkg*)
+    echo "  x No solution found when resolving dependencies:" >&2
+    echo "  -> Because gone-pkg was not found in the package registry and you require gone-pkg==1.0, we can conclude that your requirements are unsatisfiable." >&2
+    exit 1 ;;
+  *flaky*)
+    echo "error: Failed to fetch: https://pypi.org/simple/flaky/" >&2
+    echo "  Caused by: Request failed after 3 retries" >&2
+    exit 1 ;;
+esac
+exit 0
+"#,
+        )
+        .unwrap();
+        std::fs::set_permissions(&uv, std::fs::Permissions::from_mode(0o755)).unwrap();
+        Migrator::with_uv(UvClient::with_path(uv))
+    }
+
+    fn mixed_packages() -> ExtractionResult {
+        ExtractionResult {
+            packages: vec![
+                spec("requests", "2.31.0"),
+                spec("gone-pkg", "1.0"),
+                spec("flaky", "2.0"),
+            ],
+            failed: Vec::new(),
+            total_found: 3,
+        }
+    }
+
+    #[test]
+    fn is_unresolved_tells_missing_packages_from_other_failures() {
+        let failed = |message: &str| ScoopError::UvCommandFailed {
+            command: "uv pip install".to_string(),
+            message: message.to_string(),
+        };
+        assert!(is_unresolved(&failed(
+            "Because foo was not found in the package registry and you require foo"
+        )));
+        assert!(is_unresolved(&failed(
+            "Because there is no version of foo==9.9 and you require foo==9.9"
+        )));
+        assert!(!is_unresolved(&failed(
+            "error: Failed to fetch: https://pypi.org/simple/foo/"
+        )));
+        assert!(!is_unresolved(&ScoopError::Interrupted));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn skip_unresolved_separates_missing_packages_from_failures() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let migrator = migrator_with_mock_uv(temp.path());
+        let options = MigrateOptions {
+            skip_unresolved: true,
+            ..Default::default()
+        };
+
+        let outcome = migrator
+            .install_packages(temp.path(), &mixed_packages(), &options)
+            .unwrap();
+        assert_eq!(outcome.unresolved, vec!["gone-pkg==1.0".to_string()]);
+        // A network error is still a failure.
+        assert_eq!(outcome.failed, vec!["flaky==2.0".to_string()]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn without_skip_unresolved_missing_packages_are_failures() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let migrator = migrator_with_mock_uv(temp.path());
+
+        let outcome = migrator
+            .install_packages(temp.path(), &mixed_packages(), &MigrateOptions::default())
+            .unwrap();
+        assert!(outcome.unresolved.is_empty());
+        assert_eq!(
+            outcome.failed,
+            vec!["gone-pkg==1.0".to_string(), "flaky==2.0".to_string()]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn strict_still_tolerates_skipped_unresolved_packages() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let migrator = migrator_with_mock_uv(temp.path());
+        let options = MigrateOptions {
+            strict: true,
+            skip_unresolved: true,
+            ..Default::default()
+        };
+        let only_missing = ExtractionResult {
+            packages: vec![spec("requests", "2.31.0"), spec("gone-pkg", "1.0")],
+            failed: Vec::new(),
+            total_found: 2,
+        };
+
+        let outcome = migrator
+            .install_packages(temp.path(), &only_missing, &options)
+            .unwrap();
+        assert_eq!(outcome.unresolved, vec!["gone-pkg==1.0".to_string()]);
+        assert!(outcome.failed.is_empty());
+
+        let err = migrator
+            .install_packages(temp.path(), &mixed_packages(), &options)
+            .unwrap_err();
+        assert!(matches!(err, ScoopError::MigrationFailed { .. }));
+    }
+
+    #[test]
+    fn unresolved_packages_make_the_result_partial() {
+        let result = MigrationResult {
+            name: "web".to_string(),
+            python_version: "3.12.0".to_string(),
+            packages_migrated: 1,
+            packages_failed: Vec::new(),
+            packages_unresolved: vec!["gone-pkg==1.0".to_string()],
+            dry_run: false,
+            path: PathBuf::from("/mock/web"),
+            source_deleted: false,
+            actual_python_version: "3.12.0".to_string(),
+            editable_packages: Vec::new(),
+        };
+        assert_eq!(result.exit_code(), MigrationExitCode::PartialSuccess);
+    }
+
     #[test]
     fn test_extract_major_minor_full_version() {
         assert_eq!(extract_major_minor("3.12.1"), "3.12");
@@ -4,13 +4,17 @@ pub mod common;
 mod conda;
 mod discovery;
 mod extractor;
+mod hatch;
 mod migrator;
+mod poetry;
 mod source;
 mod venvwrapper;
 
 pub use conda::CondaDiscovery;
 pub use discovery::PyenvDiscovery;
-pub use extractor::{ExtractionResult, PackageExtractor, PackageSpec};
+pub use extractor::{EditablePackage, ExtractionResult, PackageExtractor, PackageSpec};
+pub use hatch::HatchDiscovery;
 pub use migrator::{MigrateOptions, MigrationResult, Migrator, PythonAvailability};
+pub use poetry::PoetryDiscovery;
 pub use source::{EnvironmentSource, EnvironmentStatus, SourceEnvironment, SourceType};
 pub use venvwrapper::VenvWrapperDiscovery;
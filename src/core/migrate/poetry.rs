@@ -0,0 +1,330 @@
+//! Poetry environment discovery
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{Result, ScoopError};
+use crate::paths;
+
+use super::common::{self, EolMinimum};
+use super::source::{EnvironmentSource, EnvironmentStatus, SourceEnvironment, SourceType};
+
+/// Discovers the virtualenvs Poetry creates for projects
+#[derive(Debug)]
+pub struct PoetryDiscovery {
+    /// Root path (typically ~/.cache/pypoetry/virtualenvs)
+    root: PathBuf,
+    /// Versions older than this are reported as EOL
+    eol_minimum: EolMinimum,
+}
+
+impl PoetryDiscovery {
+    /// Creates a new discovery instance for the given virtualenvs directory.
+    pub fn new(root: PathBuf) -> Self {
+        Self {
+            root,
+            eol_minimum: EolMinimum::default(),
+        }
+    }
+
+    /// Sets the EOL cutoff used when classifying discovered environments.
+    pub fn with_eol_minimum(mut self, eol_minimum: EolMinimum) -> Self {
+        self.eol_minimum = eol_minimum;
+        self
+    }
+
+    /// Creates a discovery instance using Poetry's virtualenvs directory.
+    ///
+    /// Searches in order, as Poetry itself resolves it:
+    /// 1. `$POETRY_VIRTUALENVS_PATH`
+    /// 2. `$POETRY_CACHE_DIR/virtualenvs`
+    /// 3. `virtualenvs` under Poetry's cache directory: `~/.cache/pypoetry`
+    ///    on Linux, `~/Library/Caches/pypoetry` on macOS,
+    ///    `%LOCALAPPDATA%\pypoetry\Cache` on Windows
+    pub fn default_root() -> Option<Self> {
+        let root = if let Some(path) = std::env::var_os("POETRY_VIRTUALENVS_PATH") {
+            PathBuf::from(path)
+        } else if let Some(cache) = std::env::var_os("POETRY_CACHE_DIR") {
+            PathBuf::from(cache).join("virtualenvs")
+        } else {
+            let cache = dirs::cache_dir()?;
+            if cfg!(windows) {
+                cache.join("pypoetry").join("Cache").join("virtualenvs")
+            } else {
+                cache.join("pypoetry").join("virtualenvs")
+            }
+        };
+
+        if root.exists() {
+            Some(Self::new(root))
+        } else {
+            None
+        }
+    }
+
+    /// Parse pyvenv.cfg to extract the Python version
+    fn parse_pyvenv_cfg(path: &Path) -> Option<String> {
+        let content = fs::read_to_string(path.join("pyvenv.cfg")).ok()?;
+        content
+            .lines()
+            .find_map(crate::core::pyvenv_version_from_line)
+    }
+
+    /// The project name in a Poetry virtualenv directory name.
+    ///
+    /// Poetry names them `<project>-<hash>-py<major>.<minor>`, the hash
+    /// being 8 characters of URL-safe base64 of the project path (so it may
+    /// itself contain `-`). `web-AbC-1234-py3.12` gives `web`; names not in
+    /// that shape give `None`.
+    fn project_name(dir_name: &str) -> Option<&str> {
+        let (rest, python) = dir_name.rsplit_once("-py")?;
+        let (major, minor) = python.split_once('.')?;
+        let all_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+        if !all_digits(major) || !all_digits(minor) {
+            return None;
+        }
+
+        let split = rest.len().checked_sub(9)?;
+        if split == 0 || !rest.is_char_boundary(split) || !rest[split..].starts_with('-') {
+            return None;
+        }
+        let hash = &rest[split + 1..];
+        if !hash
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+        {
+            return None;
+        }
+        Some(&rest[..split])
+    }
+
+    /// Parse a single environment directory into SourceEnvironment
+    fn parse_environment(&self, env_path: &Path, name: String) -> Option<SourceEnvironment> {
+        if name.starts_with('.') {
+            return None;
+        }
+
+        // Poetry only ever writes venvs here; anything without a
+        // pyvenv.cfg is someone else's directory.
+        if !env_path.join("pyvenv.cfg").exists() {
+            return None;
+        }
+
+        let python_version =
+            Self::parse_pyvenv_cfg(env_path).unwrap_or_else(|| "unknown".to_string());
+
+        let status = if paths::virtualenv_python_exe(env_path).exists() {
+            common::determine_status(&name, &python_version, self.eol_minimum)
+        } else {
+            EnvironmentStatus::Corrupted {
+                reason: "Python executable not found".to_string(),
+            }
+        };
+
+        Some(SourceEnvironment {
+            name,
+            python_version,
+            path: env_path.to_path_buf(),
+            source_type: SourceType::Poetry,
+            size_bytes: None, // Lazy: calculated only when needed
+            status,
+        })
+    }
+}
+
+impl EnvironmentSource for PoetryDiscovery {
+    fn source_type(&self) -> SourceType {
+        SourceType::Poetry
+    }
+
+    /// Environments are named after their project (`web` for
+    /// `web-AbCd1234-py3.12`). When several directories share a project
+    /// name (two Python versions, or two checkouts of one project), those
+    /// keep their full directory name so every name stays unique.
+    fn scan_environments(&self) -> Result<Vec<SourceEnvironment>> {
+        let mut environments = Vec::new();
+
+        if !self.root.exists() {
+            return Ok(environments);
+        }
+
+        let entries = fs::read_dir(&self.root).map_err(ScoopError::Io)?;
+
+        let mut dirs = Vec::new();
+        for entry in entries.flatten() {
+            let env_path = entry.path();
+
+            // Skip symlinks and non-directories
+            if env_path.is_symlink() || !env_path.is_dir() {
+                continue;
+            }
+
+            if let Some(dir_name) = env_path.file_name().and_then(|n| n.to_str()) {
+                dirs.push((dir_name.to_string(), env_path.clone()));
+            }
+        }
+
+        let mut project_counts: HashMap<&str, usize> = HashMap::new();
+        for (dir_name, _) in &dirs {
+            if let Some(project) = Self::project_name(dir_name) {
+                *project_counts.entry(project).or_default() += 1;
+            }
+        }
+
+        for (dir_name, env_path) in &dirs {
+            let name = match Self::project_name(dir_name) {
+                Some(project) if project_counts[project] == 1 => project.to_string(),
+                _ => dir_name.clone(),
+            };
+            if let Some(env) = self.parse_environment(env_path, name) {
+                environments.push(env);
+            }
+        }
+
+        environments.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(environments)
+    }
+
+    /// Accepts either the name shown by
+    /// [`scan_environments`](Self::scan_environments) or the directory
+    /// name; both resolve to the environment as the scan names it.
+    fn find_environment(&self, name: &str) -> Result<SourceEnvironment> {
+        self.scan_environments()?
+            .into_iter()
+            .find(|env| env.name == name || env.path.file_name().is_some_and(|dir| dir == name))
+            .ok_or_else(|| ScoopError::PoetryEnvNotFound {
+                name: name.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn create_env(root: &Path, name: &str, version: &str) -> PathBuf {
+        let env = root.join(name);
+        let python = paths::virtualenv_python_exe(&env);
+        fs::create_dir_all(python.parent().unwrap()).unwrap();
+        fs::write(&python, "").unwrap();
+        fs::write(
+            env.join("pyvenv.cfg"),
+            format!("home = /usr/bin\nversion_info = {version}\n"),
+        )
+        .unwrap();
+        env
+    }
+
+    #[test]
+    fn scan_lists_envs_with_pyvenv_cfg() {
+        let temp = TempDir::new().unwrap();
+        create_env(temp.path(), "web-AbCd1234-py3.12", "3.12.1");
+        create_env(temp.path(), "api-EfGh5678-py3.11", "3.11.4");
+        fs::create_dir_all(temp.path().join("not-a-venv")).unwrap();
+
+        let envs = PoetryDiscovery::new(temp.path().to_path_buf())
+            .scan_environments()
+            .unwrap();
+
+        let names: Vec<_> = envs.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["api", "web"]);
+        assert_eq!(envs[0].python_version, "3.11.4");
+        assert_eq!(envs[0].source_type, SourceType::Poetry);
+        assert!(envs[1].path.ends_with("web-AbCd1234-py3.12"));
+    }
+
+    #[test]
+    fn project_name_strips_hash_and_python_suffix() {
+        let project = PoetryDiscovery::project_name;
+        assert_eq!(project("web-AbCd1234-py3.12"), Some("web"));
+        assert_eq!(project("my-app-Ab_d-234-py3.9"), Some("my-app"));
+        assert_eq!(project("web-AbCd1234-py3.12.1"), None);
+        assert_eq!(project("web-short-py3.12"), None);
+        assert_eq!(project("-AbCd1234-py3.12"), None);
+        assert_eq!(project("plain"), None);
+    }
+
+    #[test]
+    fn shared_project_names_keep_directory_names() {
+        let temp = TempDir::new().unwrap();
+        create_env(temp.path(), "web-AbCd1234-py3.11", "3.11.4");
+        create_env(temp.path(), "web-AbCd1234-py3.12", "3.12.1");
+        create_env(temp.path(), "api-EfGh5678-py3.12", "3.12.1");
+        create_env(temp.path(), "handmade", "3.12.1");
+
+        let envs = PoetryDiscovery::new(temp.path().to_path_buf())
+            .scan_environments()
+            .unwrap();
+
+        let names: Vec<_> = envs.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "api",
+                "handmade",
+                "web-AbCd1234-py3.11",
+                "web-AbCd1234-py3.12"
+            ]
+        );
+    }
+
+    #[test]
+    fn find_accepts_project_or_directory_name() {
+        let temp = TempDir::new().unwrap();
+        create_env(temp.path(), "web-AbCd1234-py3.12", "3.12.1");
+        let discovery = PoetryDiscovery::new(temp.path().to_path_buf());
+
+        let by_project = discovery.find_environment("web").unwrap();
+        let by_dir = discovery.find_environment("web-AbCd1234-py3.12").unwrap();
+        assert_eq!(by_project.name, "web");
+        assert_eq!(by_dir.name, "web");
+        assert_eq!(by_project.path, by_dir.path);
+    }
+
+    #[test]
+    fn default_root_prefers_poetry_env_vars() {
+        crate::test_utils::with_isolated_migrate_env(|| {
+            let temp = TempDir::new().unwrap();
+            let custom = temp.path().join("venvs");
+            let cache = temp.path().join("cache");
+            fs::create_dir_all(&custom).unwrap();
+            fs::create_dir_all(cache.join("virtualenvs")).unwrap();
+
+            // SAFETY: with_isolated_migrate_env holds the migrate env lock
+            // and restores both variables afterwards.
+            unsafe { std::env::set_var("POETRY_CACHE_DIR", &cache) };
+            let found = PoetryDiscovery::default_root().unwrap();
+            assert_eq!(found.root, cache.join("virtualenvs"));
+
+            unsafe { std::env::set_var("POETRY_VIRTUALENVS_PATH", &custom) };
+            assert_eq!(PoetryDiscovery::default_root().unwrap().root, custom);
+
+            unsafe { std::env::set_var("POETRY_VIRTUALENVS_PATH", temp.path().join("gone")) };
+            assert!(PoetryDiscovery::default_root().is_none());
+        });
+    }
+
+    #[test]
+    fn missing_interpreter_is_corrupted() {
+        let temp = TempDir::new().unwrap();
+        let env = create_env(temp.path(), "broken-AbCd1234-py3.12", "3.12.1");
+        fs::remove_file(paths::virtualenv_python_exe(&env)).unwrap();
+
+        let found = PoetryDiscovery::new(temp.path().to_path_buf())
+            .find_environment("broken-AbCd1234-py3.12")
+            .unwrap();
+        assert!(matches!(found.status, EnvironmentStatus::Corrupted { .. }));
+    }
+
+    #[test]
+    fn find_unknown_env_is_poetry_not_found() {
+        let temp = TempDir::new().unwrap();
+        let err = PoetryDiscovery::new(temp.path().to_path_buf())
+            .find_environment("ghost")
+            .unwrap_err();
+        assert!(matches!(err, ScoopError::PoetryEnvNotFound { ref name } if name == "ghost"));
+    }
+}
@@ -12,10 +12,14 @@ use crate::error::Result;
 pub enum SourceType {
     /// pyenv-virtualenv
     Pyenv,
-    /// virtualenvwrapper (future)
+    /// virtualenvwrapper
     VirtualenvWrapper,
-    /// conda (future)
+    /// conda
     Conda,
+    /// Poetry's per-project virtualenv cache
+    Poetry,
+    /// Hatch virtual environments
+    Hatch,
 }
 
 impl std::fmt::Display for SourceType {
@@ -24,6 +28,8 @@ impl std::fmt::Display for SourceType {
             Self::Pyenv => write!(f, "pyenv"),
             Self::VirtualenvWrapper => write!(f, "virtualenvwrapper"),
             Self::Conda => write!(f, "conda"),
+            Self::Poetry => write!(f, "poetry"),
+            Self::Hatch => write!(f, "hatch"),
         }
     }
 }
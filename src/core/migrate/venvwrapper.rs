@@ -5,7 +5,7 @@ use std::path::{Path, PathBuf};
 
 use crate::error::{Result, ScoopError};
 
-use super::common;
+use super::common::{self, EolMinimum};
 use super::source::{EnvironmentSource, SourceEnvironment, SourceType};
 
 /// Discovers virtualenvwrapper environments
@@ -13,12 +13,23 @@ use super::source::{EnvironmentSource, SourceEnvironment, SourceType};
 pub struct VenvWrapperDiscovery {
     /// Root path (typically ~/.virtualenvs)
     root: PathBuf,
+    /// Versions older than this are reported as EOL
+    eol_minimum: EolMinimum,
 }
 
 impl VenvWrapperDiscovery {
     /// Creates a new discovery instance for the given virtualenvwrapper root.
     pub fn new(root: PathBuf) -> Self {
-        Self { root }
+        Self {
+            root,
+            eol_minimum: EolMinimum::default(),
+        }
+    }
+
+    /// Sets the EOL cutoff used when classifying discovered environments.
+    pub fn with_eol_minimum(mut self, eol_minimum: EolMinimum) -> Self {
+        self.eol_minimum = eol_minimum;
+        self
     }
 
     /// Creates a discovery instance using the default virtualenvwrapper root.
@@ -96,7 +107,7 @@ impl VenvWrapperDiscovery {
             Self::parse_pyvenv_cfg(env_path).unwrap_or_else(|| "unknown".to_string());
 
         // Determine status
-        let status = common::determine_status(&name, &python_version);
+        let status = common::determine_status(&name, &python_version, self.eol_minimum);
 
         Some(SourceEnvironment {
             name,
@@ -185,7 +196,11 @@ mod tests {
 
     #[test]
     fn test_determine_status_ready() {
-        let status = common::determine_status("nonexistent_venv_wrapper_test", "3.12.0");
+        let status = common::determine_status(
+            "nonexistent_venv_wrapper_test",
+            "3.12.0",
+            EolMinimum::default(),
+        );
         assert!(matches!(status, EnvironmentStatus::Ready));
     }
 }
@@ -1,18 +1,25 @@
 //! Core business logic
 
+mod atomic;
+pub mod cancel;
 pub mod doctor;
 pub mod export_schema;
+pub mod hooks;
 pub mod manifest;
 mod metadata;
 pub mod migrate;
+pub mod processes;
 mod version;
 mod virtualenv;
 
 pub use export_schema::{EXPORT_SCHEMA_VERSION, ExportSchema};
 pub use manifest::ScoopManifest;
 pub use metadata::Metadata;
-pub use version::VersionService;
-pub use virtualenv::{VirtualenvInfo, VirtualenvService};
+pub use version::{ResolutionStep, VersionService};
+pub use virtualenv::{
+    VirtualenvInfo, VirtualenvService, case_colliding_env_names, case_variant_of, is_env_healthy,
+    non_utf8_env_dirs, python_executable_at,
+};
 
 /// Environment variable for currently active virtualenv
 pub const SCUV_ACTIVE_ENV: &str = "SCUV_ACTIVE";
@@ -52,6 +59,21 @@ pub(crate) fn pyvenv_version_from_line(line: &str) -> Option<String> {
     matches!(key.trim(), "version" | "version_info").then(|| normalize_pyvenv_version(value.trim()))
 }
 
+/// Read the base interpreter directory (`home = ...`) from a venv's
+/// `pyvenv.cfg`.
+///
+/// That is the `bin` directory of the Python the venv was created from;
+/// the venv's own `bin/python` resolves through it. Returns `None` when the
+/// file or the key is missing.
+pub fn parse_pyvenv_home(venv_path: &std::path::Path) -> Option<std::path::PathBuf> {
+    let content = std::fs::read_to_string(venv_path.join("pyvenv.cfg")).ok()?;
+    content.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        let value = value.trim();
+        (key.trim() == "home" && !value.is_empty()).then(|| std::path::PathBuf::from(value))
+    })
+}
+
 /// Keep only the leading `MAJOR.MINOR.PATCH` numeric dotted components.
 ///
 /// uv's `version_info` can be `3.14.3.final.0`; this normalizes it to
@@ -164,6 +186,18 @@ mod tests {
         assert_eq!(parse_pyvenv_version(dir.path()), None);
     }
 
+    #[test]
+    fn parse_pyvenv_home_reads_only_the_home_key() {
+        let dir =
+            venv_with_cfg("homepage = /nope\nhome = /opt/python/3.12/bin \nversion = 3.12.1\n");
+        assert_eq!(
+            parse_pyvenv_home(dir.path()),
+            Some(std::path::PathBuf::from("/opt/python/3.12/bin"))
+        );
+        let dir = venv_with_cfg("version = 3.12.1\n");
+        assert_eq!(parse_pyvenv_home(dir.path()), None);
+    }
+
     #[test]
     fn normalize_pyvenv_version_truncates_suffix() {
         assert_eq!(normalize_pyvenv_version("3.14.3.final.0"), "3.14.3");
@@ -0,0 +1,254 @@
+//! Find and stop processes running from an environment.
+//!
+//! Used by `scuv remove --force --kill`: on Windows a running `python.exe`
+//! keeps its env directory locked, so deletion fails until it exits.
+
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+
+/// A process started from inside an environment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnvProcess {
+    pub pid: u32,
+    /// The path that placed it inside the env: its executable, or the
+    /// program path it was started with.
+    pub exe: PathBuf,
+}
+
+/// Whether a process with executable `exe` started as `argv0` runs from
+/// `dir`, returning the path that says so.
+///
+/// The executable alone isn't enough: on Unix `bin/python` is a symlink,
+/// and the OS reports the base interpreter it resolves to. The program
+/// path the process was started with (`/…/env/bin/python`) still names
+/// the env, so an absolute `argv0` counts too. A bare `python` found
+/// through `PATH` can't be attributed and is ignored.
+fn runs_from(dir: &Path, exe: Option<&Path>, argv0: Option<&OsStr>) -> Option<PathBuf> {
+    if let Some(exe) = exe.filter(|exe| exe.starts_with(dir)) {
+        return Some(exe.to_path_buf());
+    }
+    argv0
+        .map(Path::new)
+        .filter(|program| program.is_absolute() && program.starts_with(dir))
+        .map(Path::to_path_buf)
+}
+
+/// `dir` as given and canonicalized. The OS reports a resolved executable,
+/// but `argv0` keeps whatever path the process was started with, e.g.
+/// `$SCUV_HOME/virtualenvs/<env>/bin/python` under a symlinked home.
+fn env_dirs(dir: &Path) -> Vec<PathBuf> {
+    let mut dirs = vec![dir.to_path_buf()];
+    if let Ok(canonical) = std::fs::canonicalize(dir) {
+        if canonical != dir {
+            dirs.push(canonical);
+        }
+    }
+    dirs
+}
+
+/// [`runs_from`] for a live process, against any of `dirs`.
+fn process_runs_from(dirs: &[PathBuf], process: &sysinfo::Process) -> Option<PathBuf> {
+    let argv0 = process.cmd().first().map(|arg| arg.as_os_str());
+    dirs.iter()
+        .find_map(|dir| runs_from(dir, process.exe(), argv0))
+}
+
+fn refreshed_system() -> System {
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing()
+            .with_exe(UpdateKind::OnlyIfNotSet)
+            .with_cmd(UpdateKind::OnlyIfNotSet),
+    );
+    system
+}
+
+/// Processes (other than this one) whose executable or program path is
+/// inside `dir`, by ascending PID.
+///
+/// Paths are matched against `dir` both as given and canonicalized (see
+/// [`env_dirs`]). Processes this user may not inspect are invisible and
+/// never listed.
+pub fn running_from(dir: &Path) -> Vec<EnvProcess> {
+    let dirs = env_dirs(dir);
+    let own = std::process::id();
+    let system = refreshed_system();
+    let mut found: Vec<EnvProcess> = system
+        .processes()
+        .values()
+        .filter(|process| process.pid().as_u32() != own)
+        .filter_map(|process| {
+            process_runs_from(&dirs, process).map(|exe| EnvProcess {
+                pid: process.pid().as_u32(),
+                exe,
+            })
+        })
+        .collect();
+    found.sort_by_key(|process| process.pid);
+    found
+}
+
+/// Forcibly stop `processes` found by [`running_from`]`(dir)` (SIGKILL on
+/// Unix, `TerminateProcess` on Windows), then wait up to `timeout` for them
+/// to exit. Returns the PIDs still running afterwards: already gone counts
+/// as stopped.
+///
+/// Each PID is checked to still run from `dir` right before it is killed,
+/// so a PID recycled by an unrelated process since the scan is left alone.
+pub fn kill_and_wait(dir: &Path, processes: &[EnvProcess], timeout: Duration) -> Vec<u32> {
+    let dirs = env_dirs(dir);
+    let system = refreshed_system();
+    let killed: Vec<u32> = processes
+        .iter()
+        .map(|process| process.pid)
+        .filter(|&pid| {
+            system
+                .process(Pid::from_u32(pid))
+                .filter(|running| process_runs_from(&dirs, running).is_some())
+                .is_some_and(|running| running.kill())
+        })
+        .collect();
+
+    let deadline = Instant::now() + timeout;
+    loop {
+        let mut system = System::new();
+        system.refresh_processes_specifics(
+            ProcessesToUpdate::All,
+            true,
+            ProcessRefreshKind::nothing(),
+        );
+        let alive: Vec<u32> = killed
+            .iter()
+            .copied()
+            .filter(|&pid| {
+                // A killed child of ours lingers as a zombie until reaped.
+                system
+                    .process(Pid::from_u32(pid))
+                    .is_some_and(|p| p.status() != sysinfo::ProcessStatus::Zombie)
+            })
+            .collect();
+        if alive.is_empty() || Instant::now() >= deadline {
+            return alive;
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_from_matches_executable_or_absolute_program_path() {
+        let env = Path::new("/home/u/.scuv/virtualenvs/web");
+        let python = env.join("bin").join("python");
+
+        // Windows: the launcher itself lives in the env.
+        assert_eq!(runs_from(env, Some(&python), None), Some(python.clone()));
+        // Unix: exe is the resolved base interpreter, argv[0] names the env.
+        let base = Path::new("/usr/lib/python3.12/bin/python3.12");
+        assert_eq!(
+            runs_from(env, Some(base), Some(python.as_os_str())),
+            Some(python.clone())
+        );
+        // Found through PATH, or somewhere else entirely: not ours to kill.
+        assert_eq!(runs_from(env, Some(base), Some(OsStr::new("python"))), None);
+        assert_eq!(runs_from(env, None, None), None);
+        // A sibling env whose name merely starts the same.
+        let sibling = Path::new("/home/u/.scuv/virtualenvs/web-old/bin/python");
+        assert_eq!(
+            runs_from(env, Some(sibling), Some(sibling.as_os_str())),
+            None
+        );
+    }
+
+    /// Starts a real process from a temp "env" and kills it again.
+    #[test]
+    #[cfg(unix)]
+    fn finds_and_kills_a_process_started_from_the_env() {
+        let env = tempfile::tempdir().unwrap();
+        let bin = env.path().join("bin");
+        std::fs::create_dir_all(&bin).unwrap();
+        let sleep = bin.join("sleep");
+        std::fs::copy("/bin/sleep", &sleep).unwrap();
+
+        let mut child = std::process::Command::new(&sleep)
+            .arg("30")
+            .spawn()
+            .unwrap();
+        let pid = child.id();
+        let started = Instant::now();
+        let mut found = running_from(env.path());
+        // The process list can lag the spawn by a moment.
+        while found.is_empty() && started.elapsed() < Duration::from_secs(5) {
+            std::thread::sleep(Duration::from_millis(50));
+            found = running_from(env.path());
+        }
+        assert_eq!(
+            found.iter().map(|p| p.pid).collect::<Vec<_>>(),
+            [pid],
+            "{found:?}"
+        );
+
+        assert!(kill_and_wait(env.path(), &found, Duration::from_secs(5)).is_empty());
+        assert!(!child.wait().unwrap().success());
+        assert!(running_from(env.path()).is_empty());
+    }
+
+    /// Under a symlinked home, a process started through the env's
+    /// non-canonical `bin/` path (as `scuv run` does) is still found, even
+    /// though its executable resolves outside the env.
+    #[test]
+    #[cfg(unix)]
+    fn finds_a_process_started_through_a_symlinked_home() {
+        let tmp = tempfile::tempdir().unwrap();
+        let real = tmp.path().join("real");
+        let bin = real.join("env").join("bin");
+        std::fs::create_dir_all(&bin).unwrap();
+        // Like a venv's `bin/python`: a symlink to an interpreter elsewhere.
+        let sleep = std::fs::canonicalize("/bin/sleep").unwrap();
+        std::os::unix::fs::symlink(&sleep, bin.join("sleep")).unwrap();
+        let home = tmp.path().join("home");
+        std::os::unix::fs::symlink(&real, &home).unwrap();
+        let env = home.join("env");
+
+        let mut child = std::process::Command::new(env.join("bin").join("sleep"))
+            .arg("30")
+            .spawn()
+            .unwrap();
+        let pid = child.id();
+        let started = Instant::now();
+        let mut found = running_from(&env);
+        while found.is_empty() && started.elapsed() < Duration::from_secs(5) {
+            std::thread::sleep(Duration::from_millis(50));
+            found = running_from(&env);
+        }
+        assert_eq!(
+            found.iter().map(|p| p.pid).collect::<Vec<_>>(),
+            [pid],
+            "{found:?}"
+        );
+
+        // A stale entry whose PID now belongs to another process is spared.
+        let mut bystander = std::process::Command::new(&sleep)
+            .arg("30")
+            .spawn()
+            .unwrap();
+        let stale = EnvProcess {
+            pid: bystander.id(),
+            exe: env.join("bin").join("sleep"),
+        };
+        assert!(kill_and_wait(&env, &[stale], Duration::from_millis(200)).is_empty());
+        assert!(bystander.try_wait().unwrap().is_none(), "bystander killed");
+        bystander.kill().unwrap();
+        bystander.wait().unwrap();
+
+        assert!(kill_and_wait(&env, &found, Duration::from_secs(5)).is_empty());
+        assert!(!child.wait().unwrap().success());
+    }
+}
@@ -19,18 +19,40 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use crate::error::Result;
+use serde::Serialize;
+
+use crate::core::migrate::common::EolMinimum;
+use crate::error::{Result, ScoopError};
 use crate::paths;
+use crate::uv::{PythonInfo, UvClient};
+
+/// Oldest `MAJOR.MINOR` the `stable` alias may pick: the oldest CPython
+/// minor still receiving security fixes.
+///
+/// Kept separate from [`EolMinimum`], whose default only decides which
+/// migrated envs get an EOL warning; a configured cutoff above this one
+/// still wins.
+const STABLE_FLOOR: (u32, u32) = (3, 10);
 
 /// Service for managing version files
 pub struct VersionService;
 
+/// One place [`VersionService::explain`] looked for an environment name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ResolutionStep {
+    /// `SCUV_VERSION`, `SCOOP_VERSION`, `local` or `global`.
+    pub source: &'static str,
+    /// Version file read; `None` for environment variables.
+    pub path: Option<PathBuf>,
+    /// Valid name found there, if any. The first step with one wins.
+    pub value: Option<String>,
+}
+
 impl VersionService {
     /// Set the local version for a directory
     pub fn set_local(dir: &Path, env_name: &str) -> Result<()> {
         let version_file = paths::local_version_file(dir);
-        fs::write(&version_file, format!("{env_name}\n"))?;
-        Ok(())
+        Self::write_version_file(&version_file, env_name)
     }
 
     /// Set the global version
@@ -39,8 +61,19 @@ impl VersionService {
         if let Some(parent) = version_file.parent() {
             fs::create_dir_all(parent)?;
         }
-        fs::write(&version_file, format!("{env_name}\n"))?;
-        Ok(())
+        Self::write_version_file(&version_file, env_name)
+    }
+
+    /// Write `env_name` plus a trailing newline via temp-file-then-rename.
+    ///
+    /// Every shell prompt and `cd` hook reads these files, so a write cut
+    /// short (Ctrl-C, full disk) must never leave a truncated name behind:
+    /// readers see either the old content or the new, same as
+    /// `write_metadata_atomic`. The temp file lives in the target's own
+    /// directory so the rename never crosses filesystems. With
+    /// `durable_writes` in config.json the write is also fsynced.
+    fn write_version_file(path: &Path, env_name: &str) -> Result<()> {
+        crate::core::atomic::write_file(path, format!("{env_name}\n").as_bytes())
     }
 
     /// Get the local version for a directory
@@ -93,9 +126,47 @@ impl VersionService {
     ///   - `3` = current + up to 3 parent directories
     ///   - unset = unlimited (default behavior)
     pub fn resolve(dir: &Path) -> Option<String> {
+        Self::explain(dir).pop().and_then(|step| step.value)
+    }
+
+    /// Every place [`resolve`](Self::resolve) looks for `dir`, in order,
+    /// up to and including the one that wins.
+    ///
+    /// The last step carries the resolved value; if its `value` is `None`
+    /// nothing matched. `SCOOP_VERSION` only appears when it is set.
+    pub fn explain(dir: &Path) -> Vec<ResolutionStep> {
+        let mut steps = Vec::new();
+        let won = |steps: &[ResolutionStep]| steps.last().is_some_and(|s| s.value.is_some());
+
         // Priority 1: SCUV_VERSION / legacy SCOOP_VERSION environment variable.
-        if let Some(name) = Self::resolve_env_version() {
-            return Some(name);
+        // DEPRECATION(0.16.0): remove the legacy `SCOOP_VERSION` branch.
+        steps.push(ResolutionStep {
+            source: "SCUV_VERSION",
+            path: None,
+            value: std::env::var("SCUV_VERSION")
+                .ok()
+                .and_then(|raw| Self::normalize_version_value(&raw)),
+        });
+        if won(&steps) {
+            return steps;
+        }
+        if let Ok(raw) = std::env::var("SCOOP_VERSION") {
+            let value = Self::normalize_version_value(&raw);
+            if value.is_some() {
+                crate::output::deprecation::warn_once(&rust_i18n::t!(
+                    "deprecation.env_var",
+                    old = "SCOOP_VERSION",
+                    new = "SCUV_VERSION"
+                ));
+            }
+            steps.push(ResolutionStep {
+                source: "SCOOP_VERSION",
+                path: None,
+                value,
+            });
+            if won(&steps) {
+                return steps;
+            }
         }
 
         // Get max depth from environment variable (None = unlimited).
@@ -118,8 +189,15 @@ impl VersionService {
         let mut depth = 0;
 
         loop {
-            if let Some(version) = Self::get_local(&current) {
-                return Some(version);
+            let version_file = Self::resolve_local_version_file(&current);
+            let value = Self::read_version_file(&version_file);
+            steps.push(ResolutionStep {
+                source: "local",
+                path: Some(version_file),
+                value,
+            });
+            if won(&steps) {
+                return steps;
             }
 
             // Check depth limit for network filesystem optimization
@@ -136,7 +214,14 @@ impl VersionService {
         }
 
         // Fall back to global
-        Self::get_global()
+        let global = paths::global_version_file().ok();
+        let value = global.as_ref().and_then(Self::read_version_file);
+        steps.push(ResolutionStep {
+            source: "global",
+            path: global,
+            value,
+        });
+        steps
     }
 
     /// Resolve from current directory
@@ -175,31 +260,6 @@ impl VersionService {
         crate::validate::is_valid_env_name(trimmed).then(|| trimmed.to_string())
     }
 
-    /// Priority-1 environment-variable override: `SCUV_VERSION`, then legacy
-    /// `SCOOP_VERSION` (deprecated; emits a one-shot warning). Falls through
-    /// to `None` (i.e. file-based resolution) when neither is set to a
-    /// valid value.
-    ///
-    /// DEPRECATION(0.16.0): remove the legacy `SCOOP_VERSION` branch.
-    fn resolve_env_version() -> Option<String> {
-        if let Ok(raw) = std::env::var("SCUV_VERSION") {
-            if let Some(name) = Self::normalize_version_value(&raw) {
-                return Some(name);
-            }
-        }
-        if let Ok(raw) = std::env::var("SCOOP_VERSION") {
-            if let Some(name) = Self::normalize_version_value(&raw) {
-                crate::output::deprecation::warn_once(&rust_i18n::t!(
-                    "deprecation.env_var",
-                    old = "SCOOP_VERSION",
-                    new = "SCUV_VERSION"
-                ));
-                return Some(name);
-            }
-        }
-        None
-    }
-
     /// Unset local version
     ///
     /// Removes both the new `.scuv-version` file and a legacy
@@ -222,6 +282,41 @@ impl VersionService {
         Ok(())
     }
 
+    /// Version files that pin `env_name` and that `scuv remove` may clean up:
+    /// the global file, plus `.scuv-version` / legacy `.scoop-version`
+    /// directly in `dir`.
+    ///
+    /// Parent directories are deliberately left out — see
+    /// [`Self::pins_above`].
+    pub fn pins_to(dir: &Path, env_name: &str) -> Vec<PathBuf> {
+        let mut candidates: Vec<PathBuf> = paths::global_version_file().into_iter().collect();
+        candidates.push(dir.join(paths::VERSION_FILE));
+        candidates.push(dir.join(paths::LEGACY_VERSION_FILE));
+        candidates
+            .into_iter()
+            .filter(|path| Self::read_version_file(path).as_deref() == Some(env_name))
+            .collect()
+    }
+
+    /// Version files in the ancestors of `dir` (not `dir` itself) that pin
+    /// `env_name`.
+    ///
+    /// These belong to some enclosing project rather than the directory the
+    /// user is working in, so `scuv remove` reports them instead of
+    /// deleting them.
+    pub fn pins_above(dir: &Path, env_name: &str) -> Vec<PathBuf> {
+        dir.ancestors()
+            .skip(1)
+            .flat_map(|d| {
+                [
+                    d.join(paths::VERSION_FILE),
+                    d.join(paths::LEGACY_VERSION_FILE),
+                ]
+            })
+            .filter(|path| Self::read_version_file(path).as_deref() == Some(env_name))
+            .collect()
+    }
+
     /// Unset global version
     pub fn unset_global() -> Result<()> {
         let version_file = paths::global_version_file()?;
@@ -230,6 +325,66 @@ impl VersionService {
         }
         Ok(())
     }
+
+    /// Resolve a Python version alias to a concrete `MAJOR.MINOR.PATCH`.
+    ///
+    /// Picks from the CPython releases uv knows about (installed or
+    /// downloadable), ignoring pre-releases:
+    ///
+    /// - `latest`: the newest release.
+    /// - `stable`: the newest patch of the oldest minor at or above both
+    ///   `STABLE_FLOOR` (3.10) and `migrate.eol_minimum` in `config.json`.
+    ///
+    /// Shared by `install --latest/--stable` and `create <name> latest`.
+    pub fn resolve_alias(uv: &UvClient, alias: &str) -> Result<String> {
+        Self::select_alias(alias, &uv.list_pythons()?, EolMinimum::configured())
+    }
+
+    /// Selection behind [`Self::resolve_alias`], over a fixed version list.
+    fn select_alias(alias: &str, pythons: &[PythonInfo], eol: EolMinimum) -> Result<String> {
+        let mut releases: Vec<(u32, u32, u32)> = pythons
+            .iter()
+            .filter(|p| p.implementation.eq_ignore_ascii_case("cpython"))
+            .filter_map(|p| parse_release(&p.version))
+            .collect();
+        releases.sort_unstable();
+
+        let alias = alias.trim().to_lowercase();
+        let picked = match alias.as_str() {
+            "latest" => releases.last().copied(),
+            "stable" => {
+                let floor = STABLE_FLOOR.max((eol.major, eol.minor));
+                let oldest_minor = releases
+                    .iter()
+                    .find(|&&(major, minor, _)| (major, minor) >= floor)
+                    .map(|&(major, minor, _)| (major, minor));
+                oldest_minor.and_then(|(major, minor)| {
+                    releases
+                        .iter()
+                        .rev()
+                        .find(|&&(ma, mi, _)| (ma, mi) == (major, minor))
+                        .copied()
+                })
+            }
+            _ => return Err(ScoopError::InvalidPythonVersion { version: alias }),
+        };
+
+        picked
+            .map(|(major, minor, patch)| format!("{major}.{minor}.{patch}"))
+            .ok_or(ScoopError::NoPythonVersions { pattern: alias })
+    }
+}
+
+/// Parse a final release `MAJOR.MINOR.PATCH`; pre-releases (`3.14.0rc1`)
+/// and partial versions yield `None`.
+fn parse_release(version: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = version.split('.');
+    let release = (
+        parts.next()?.parse().ok()?,
+        parts.next()?.parse().ok()?,
+        parts.next()?.parse().ok()?,
+    );
+    parts.next().is_none().then_some(release)
 }
 
 #[cfg(test)]
@@ -252,6 +407,39 @@ mod tests {
         assert_eq!(VersionService::get_local(dir), Some("myenv".to_string()));
     }
 
+    #[test]
+    fn test_set_local_writes_exact_content_atomically() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        std::fs::write(dir.join(".scuv-version"), "a-much-longer-old-env-name\n").unwrap();
+
+        VersionService::set_local(dir, "myenv").unwrap();
+
+        let content = std::fs::read(dir.join(".scuv-version")).unwrap();
+        assert_eq!(content, b"myenv\n", "old content must be fully replaced");
+        let entries: Vec<_> = std::fs::read_dir(dir).unwrap().collect();
+        assert_eq!(entries.len(), 1, "no temp file may be left behind");
+    }
+
+    #[test]
+    #[serial]
+    fn test_crlf_version_file_resolves_to_env_name() {
+        with_temp_scoop_home(|_temp_dir| {
+            let temp = TempDir::new().unwrap();
+            let parent = temp.path();
+            let child = parent.join("subdir");
+            std::fs::create_dir(&child).unwrap();
+            std::fs::write(parent.join(".scuv-version"), "myenv\r\n").unwrap();
+
+            assert_eq!(VersionService::get_local(parent), Some("myenv".to_string()));
+            assert_eq!(
+                VersionService::resolve(&child),
+                Some("myenv".to_string()),
+                "the resolve walk must strip the \\r as well"
+            );
+        });
+    }
+
     #[test]
     fn test_get_local_nonexistent() {
         let temp = TempDir::new().unwrap();
@@ -382,6 +570,37 @@ mod tests {
         assert_eq!(VersionService::get_local(dir.path()).unwrap(), "oldenv");
     }
 
+    #[test]
+    #[serial]
+    fn pins_to_finds_global_and_local_files_for_env() {
+        with_temp_scoop_home(|_| {
+            let project = TempDir::new().unwrap();
+            let dir = project.path();
+            VersionService::set_global("doomed").unwrap();
+            VersionService::set_local(dir, "doomed").unwrap();
+            std::fs::write(dir.join(".scoop-version"), "other\n").unwrap();
+
+            let pins = VersionService::pins_to(dir, "doomed");
+            assert_eq!(pins.len(), 2);
+            assert!(pins.contains(&paths::global_version_file().unwrap()));
+            assert!(pins.contains(&dir.join(".scuv-version")));
+
+            assert!(VersionService::pins_to(dir, "unrelated").is_empty());
+        });
+    }
+
+    #[test]
+    fn pins_above_skips_dir_itself() {
+        let root = TempDir::new().unwrap();
+        let child = root.path().join("sub");
+        std::fs::create_dir(&child).unwrap();
+        VersionService::set_local(root.path(), "doomed").unwrap();
+        VersionService::set_local(&child, "doomed").unwrap();
+
+        let above = VersionService::pins_above(&child, "doomed");
+        assert_eq!(above, vec![root.path().join(".scuv-version")]);
+    }
+
     // =========================================================================
     // Global Version Tests
     // =========================================================================
@@ -650,6 +869,67 @@ mod tests {
         });
     }
 
+    #[test]
+    #[serial]
+    fn explain_lists_each_directory_tried_up_to_the_winner() {
+        with_temp_scoop_home(|_temp_dir| {
+            let temp = TempDir::new().unwrap();
+            let parent = temp.path();
+            let child = parent.join("subdir");
+            std::fs::create_dir(&child).unwrap();
+            VersionService::set_local(parent, "parentenv").unwrap();
+            VersionService::set_global("globalenv").unwrap();
+
+            let steps = VersionService::explain(&child);
+            let summary: Vec<_> = steps
+                .iter()
+                .map(|s| (s.source, s.path.clone(), s.value.as_deref()))
+                .collect();
+            assert_eq!(
+                summary,
+                [
+                    ("SCUV_VERSION", None, None),
+                    ("local", Some(child.join(".scuv-version")), None),
+                    (
+                        "local",
+                        Some(parent.join(".scuv-version")),
+                        Some("parentenv")
+                    ),
+                ]
+            );
+            assert_eq!(VersionService::resolve(&child), steps.last().unwrap().value);
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn explain_ends_at_global_or_stops_at_env_var() {
+        with_temp_scoop_home(|_temp_dir| {
+            let temp = TempDir::new().unwrap();
+
+            // SAFETY: This test runs in serial mode, so no concurrent access
+            unsafe { std::env::set_var("SCUV_RESOLVE_MAX_DEPTH", "0") };
+            let steps = VersionService::explain(temp.path());
+            let sources: Vec<_> = steps.iter().map(|s| s.source).collect();
+            assert_eq!(sources, ["SCUV_VERSION", "local", "global"]);
+            let global = steps.last().unwrap();
+            assert_eq!(global.path, paths::global_version_file().ok());
+            assert_eq!(global.value, None);
+
+            // SAFETY: see above
+            unsafe { std::env::set_var("SCUV_VERSION", "shellenv") };
+            let steps = VersionService::explain(temp.path());
+            // SAFETY: see above
+            unsafe {
+                std::env::remove_var("SCUV_VERSION");
+                std::env::remove_var("SCUV_RESOLVE_MAX_DEPTH");
+            }
+            assert_eq!(steps.len(), 1, "{steps:#?}");
+            assert_eq!(steps[0].source, "SCUV_VERSION");
+            assert_eq!(steps[0].value.as_deref(), Some("shellenv"));
+        });
+    }
+
     #[test]
     #[serial]
     fn test_resolve_child_overrides_parent() {
@@ -858,4 +1138,101 @@ mod tests {
             );
         }
     }
+
+    // =========================================================================
+    // Alias Resolution Tests (latest / stable)
+    // =========================================================================
+
+    fn pythons(versions: &[&str]) -> Vec<PythonInfo> {
+        versions
+            .iter()
+            .map(|v| PythonInfo {
+                version: v.to_string(),
+                path: None,
+                installed: false,
+                implementation: "cpython".to_string(),
+                managed: true,
+            })
+            .collect()
+    }
+
+    const AVAILABLE: &[&str] = &[
+        "3.14.0rc2",
+        "3.13.7",
+        "3.13.6",
+        "3.12.11",
+        "3.11.13",
+        "3.10.18",
+        "3.10.17",
+        "3.9.23",
+        "3.8.20",
+    ];
+
+    #[test]
+    fn select_alias_latest_picks_newest_final_release() {
+        let got =
+            VersionService::select_alias("latest", &pythons(AVAILABLE), EolMinimum::default());
+        assert_eq!(got.unwrap(), "3.13.7");
+    }
+
+    #[test]
+    fn select_alias_stable_picks_newest_patch_of_oldest_supported_minor() {
+        let got =
+            VersionService::select_alias("Stable", &pythons(AVAILABLE), EolMinimum::default());
+        assert_eq!(
+            got.unwrap(),
+            "3.10.18",
+            "EOL 3.9 is never stable; alias is case-insensitive"
+        );
+
+        let eol = EolMinimum::parse("3.11").unwrap();
+        let got = VersionService::select_alias("stable", &pythons(AVAILABLE), eol);
+        assert_eq!(got.unwrap(), "3.11.13", "a higher configured cutoff wins");
+    }
+
+    #[test]
+    fn select_alias_ignores_other_implementations() {
+        let mut available = pythons(&["3.12.4"]);
+        available.push(PythonInfo {
+            implementation: "pypy".to_string(),
+            ..pythons(&["3.13.0"]).remove(0)
+        });
+        let got = VersionService::select_alias("latest", &available, EolMinimum::default());
+        assert_eq!(got.unwrap(), "3.12.4");
+    }
+
+    #[test]
+    fn select_alias_errors_when_nothing_qualifies() {
+        let eol = EolMinimum::parse("3.15").unwrap();
+        let err = VersionService::select_alias("stable", &pythons(AVAILABLE), eol).unwrap_err();
+        assert!(
+            matches!(err, ScoopError::NoPythonVersions { .. }),
+            "{err:?}"
+        );
+
+        let err = VersionService::select_alias("latest", &[], EolMinimum::default()).unwrap_err();
+        assert!(
+            matches!(err, ScoopError::NoPythonVersions { .. }),
+            "{err:?}"
+        );
+    }
+
+    #[test]
+    fn select_alias_rejects_unknown_alias() {
+        let err =
+            VersionService::select_alias("newest", &pythons(AVAILABLE), EolMinimum::default())
+                .unwrap_err();
+        assert!(
+            matches!(err, ScoopError::InvalidPythonVersion { .. }),
+            "{err:?}"
+        );
+    }
+
+    #[test]
+    fn parse_release_accepts_only_full_final_versions() {
+        assert_eq!(parse_release("3.12.1"), Some((3, 12, 1)));
+        assert_eq!(parse_release("3.14.0rc1"), None);
+        assert_eq!(parse_release("3.12"), None);
+        assert_eq!(parse_release("3.12.1.2"), None);
+    }
 }
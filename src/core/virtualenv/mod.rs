@@ -1,7 +1,6 @@
 //! Virtual environment service
 
 use std::fs;
-use std::io::Write;
 use std::path::{Path, PathBuf};
 
 use chrono::{DateTime, Utc};
@@ -9,6 +8,7 @@ use tracing::warn;
 
 use crate::core::Metadata;
 use crate::error::{Result, ScoopError};
+use crate::output::Output;
 use crate::paths;
 use crate::uv::UvClient;
 use crate::validate;
@@ -38,17 +38,71 @@ pub struct VirtualenvInfo {
     /// Last-used timestamp from metadata, if present. Same rationale:
     /// list-time sort can use it without re-reading the JSON file.
     pub last_used: Option<DateTime<Utc>>,
+    /// Tags from metadata (empty when untagged or metadata is missing).
+    /// Read here so `list --tag` filters without another metadata pass.
+    pub tags: Vec<String>,
 }
 
 /// Service for managing virtual environments
 pub struct VirtualenvService {
-    uv: UvClient,
+    /// `None` for [`Self::filesystem_only`]
+    uv: Option<UvClient>,
+    clear_existing: bool,
+    seed: bool,
+    upgrade_deps: bool,
 }
 
 impl VirtualenvService {
     /// Create a new service with the given uv client
     pub fn new(uv: UvClient) -> Self {
-        Self { uv }
+        Self {
+            uv: Some(uv),
+            clear_existing: false,
+            seed: false,
+            upgrade_deps: false,
+        }
+    }
+
+    /// Create a service without uv, for commands that only read envs on
+    /// disk (existence, paths, metadata, interpreters) and must work where
+    /// uv isn't installed. Anything that would run uv fails with
+    /// [`ScoopError::UvNotFound`].
+    pub fn filesystem_only() -> Self {
+        Self {
+            uv: None,
+            clear_existing: false,
+            seed: false,
+            upgrade_deps: false,
+        }
+    }
+
+    /// The uv client; [`ScoopError::UvNotFound`] for a
+    /// [`Self::filesystem_only`] service.
+    fn uv(&self) -> Result<&UvClient> {
+        self.uv.as_ref().ok_or(ScoopError::UvNotFound)
+    }
+
+    /// Let [`Self::create`] rebuild an existing env in place (via
+    /// [`UvClient::clear_venv`]) instead of failing with
+    /// [`ScoopError::VirtualenvExists`] (`scuv create --clear`).
+    pub fn with_clear_existing(mut self, clear: bool) -> Self {
+        self.clear_existing = clear;
+        self
+    }
+
+    /// Have [`Self::create`] seed pip into new envs (`uv venv --seed`,
+    /// `scuv create --seed`).
+    pub fn with_seed(mut self, seed: bool) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// After seeding, upgrade the seed packages to their latest releases
+    /// (`scuv create --upgrade-deps`). Ignored unless [`Self::with_seed`]
+    /// is set.
+    pub fn with_upgrade_deps(mut self, upgrade: bool) -> Self {
+        self.upgrade_deps = upgrade;
+        self
     }
 
     /// Create a new service, finding uv automatically
@@ -56,6 +110,12 @@ impl VirtualenvService {
         Ok(Self::new(UvClient::new()?))
     }
 
+    /// Like [`Self::auto`], echoing uv's stderr when `output` is verbose
+    /// (see [`UvClient::for_output`]).
+    pub fn for_output(output: &Output) -> Result<Self> {
+        Ok(Self::new(UvClient::for_output(output)?))
+    }
+
     /// List all virtual environments
     pub fn list(&self) -> Result<Vec<VirtualenvInfo>> {
         let venvs_dir = paths::virtualenvs_dir()?;
@@ -97,9 +157,14 @@ impl VirtualenvService {
                 // valid bucket-end value for sort, and the legacy passive
                 // contract (display "-"/"never") is preserved.
                 let metadata = self.read_metadata(&path);
-                let (python_version, created_at, last_used) = match metadata {
-                    Some(m) => (Some(m.python_version), Some(m.created_at), m.last_used),
-                    None => (None, None, None),
+                let (python_version, created_at, last_used, tags) = match metadata {
+                    Some(m) => (
+                        Some(m.python_version),
+                        Some(m.created_at),
+                        m.last_used,
+                        m.tags,
+                    ),
+                    None => (None, None, None, Vec::new()),
                 };
                 envs.push(VirtualenvInfo {
                     name: name.to_string(),
@@ -107,6 +172,7 @@ impl VirtualenvService {
                     python_version,
                     created_at,
                     last_used,
+                    tags,
                 });
             }
         }
@@ -151,21 +217,34 @@ impl VirtualenvService {
         let path = paths::virtualenv_path(name)?;
 
         if path.exists() {
-            return Err(ScoopError::VirtualenvExists {
-                name: name.to_string(),
-            });
-        }
+            if !self.clear_existing {
+                return Err(ScoopError::VirtualenvExists {
+                    name: name.to_string(),
+                });
+            }
+            // The directory is kept, so nothing is cleaned up on failure.
+            self.uv()?.clear_venv(&path, uv_python_arg, self.seed)?;
+            self.upgrade_seeded(&path)?;
+        } else {
+            // Ensure parent directory exists
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
 
-        // Ensure parent directory exists
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent)?;
+            // Create the virtual environment. `path` didn't exist before, so
+            // whatever a failed or interrupted uv left there is removed.
+            if let Err(e) = self
+                .uv()?
+                .create_venv(&path, uv_python_arg, self.seed)
+                .and_then(|()| self.upgrade_seeded(&path))
+            {
+                let _ = fs::remove_dir_all(&path);
+                return Err(e);
+            }
         }
 
-        // Create the virtual environment
-        self.uv.create_venv(&path, uv_python_arg)?;
-
         // Write metadata
-        let uv_version = self.uv.version().ok();
+        let uv_version = self.uv()?.version().ok();
         // Resolve actual version: prefer pyvenv.cfg (handles specifiers like cpython@3.12),
         // then explicit python_path version, then fall back to the raw uv arg.
         let actual_version = super::parse_pyvenv_version(&path)
@@ -173,15 +252,26 @@ impl VirtualenvService {
             .unwrap_or_else(|| uv_python_arg.to_string());
         let mut metadata = Metadata::new(name.to_string(), actual_version, uv_version);
 
-        if let Some((_, pp)) = python_path_info {
-            metadata = metadata.with_python_path(pp.display().to_string());
-        }
+        metadata = match python_path_info {
+            Some((_, pp)) => metadata.with_python_path(pp.display().to_string()),
+            None => metadata.with_python_request(uv_python_arg),
+        };
+        metadata.deps_upgraded = self.seed && self.upgrade_deps;
 
         self.write_metadata_atomic(&path, &metadata)?;
 
         Ok(path)
     }
 
+    /// Run [`UvClient::upgrade_seed_packages`] when both seeding and
+    /// `--upgrade-deps` were asked for.
+    fn upgrade_seeded(&self, path: &Path) -> Result<()> {
+        if self.seed && self.upgrade_deps {
+            self.uv()?.upgrade_seed_packages(path)?;
+        }
+        Ok(())
+    }
+
     /// Delete a virtual environment.
     ///
     /// Validates `name` internally before touching the filesystem.
@@ -209,19 +299,27 @@ impl VirtualenvService {
     /// via uv. Thin pass-through to [`UvClient::find_python`] so command
     /// handlers don't need direct access to the private `uv` field.
     pub fn is_python_installed(&self, version: &str) -> Result<bool> {
-        Ok(self.uv.find_python(version)?.is_some())
+        Ok(self.uv()?.find_python(version)?.is_some())
     }
 
-    /// Install a Python version through uv. Thin pass-through that lets command
-    /// handlers stay decoupled from the private `uv` field.
-    pub fn install_python(&self, version: &str) -> Result<()> {
-        self.uv.install_python(version)
+    /// Resolve a `latest` / `stable` alias to a concrete version. Thin
+    /// pass-through to [`VersionService::resolve_alias`](crate::core::VersionService::resolve_alias)
+    /// so command handlers don't need direct access to the private `uv` field.
+    pub fn resolve_python_alias(&self, alias: &str) -> Result<String> {
+        crate::core::VersionService::resolve_alias(self.uv()?, alias)
+    }
+
+    /// Install a Python version through uv, returning the concrete version
+    /// installed. Thin pass-through that lets command handlers stay
+    /// decoupled from the private `uv` field.
+    pub fn install_python(&self, version: &str) -> Result<String> {
+        self.uv()?.install_python(version)
     }
 
     /// Install Python packages into the env via uv. Thin pass-through so the
     /// sync handler doesn't need direct access to the private `uv` field.
     pub fn pip_install(&self, venv_path: &Path, packages: &[String]) -> Result<()> {
-        self.uv.pip_install(venv_path, packages)
+        self.uv()?.pip_install(venv_path, packages)
     }
 
     /// Check if a virtual environment exists.
@@ -250,6 +348,38 @@ impl VirtualenvService {
         Ok(path)
     }
 
+    /// The Python executable of env `name` with every symlink resolved.
+    ///
+    /// Looks at `bin/python` (`Scripts\python.exe` on Windows) and follows
+    /// it to the real interpreter, so a relative link chain or one into a
+    /// uv-managed install comes back as that install's binary. Use it to
+    /// find out *which* Python an env runs; run the env through its own
+    /// `bin/python`, since the base interpreter doesn't know about the env.
+    ///
+    /// # Errors
+    ///
+    /// - [`ScoopError::VirtualenvNotFound`] if the env doesn't exist.
+    /// - [`ScoopError::InterpreterNotFound`] if it has no interpreter or
+    ///   the link is dangling.
+    pub fn python_executable(&self, name: &str) -> Result<PathBuf> {
+        let path = self.get_path(name)?;
+        python_executable_at(&path).ok_or_else(|| ScoopError::InterpreterNotFound {
+            env: name.to_string(),
+            path: paths::virtualenv_python_exe(&path),
+        })
+    }
+
+    /// The `site-packages` directory of env `name`, as found by
+    /// [`paths::virtualenv_site_packages`].
+    ///
+    /// # Errors
+    ///
+    /// - [`ScoopError::VirtualenvNotFound`] if the env doesn't exist.
+    /// - [`ScoopError::SitePackagesNotFound`] if it has no `site-packages`.
+    pub fn site_packages(&self, name: &str) -> Result<PathBuf> {
+        paths::virtualenv_site_packages(&self.get_path(name)?)
+    }
+
     /// Read metadata from a virtual environment.
     ///
     /// Returns `None` for both "file missing" and "file corrupt" — callers
@@ -296,27 +426,17 @@ impl VirtualenvService {
     /// therefore leaves either the old file intact or the new file
     /// in place — readers never observe a half-written file.
     ///
-    /// This is NOT a full power-loss durability promise. We don't `fsync`
-    /// the file or the parent directory: this is best-effort metadata
-    /// (timestamps for display + gc heuristics), and `sync_all` on every
-    /// auto-activation would put a disk flush on the `cd` hot path. If a
-    /// power loss hits between the rename and the cache flush, the
-    /// metadata may roll back to its previous state. We accept that.
+    /// By default this is NOT a full power-loss durability promise. We
+    /// don't `fsync` the file or the parent directory: this is best-effort
+    /// metadata (timestamps for display + gc heuristics), and `sync_all` on
+    /// every auto-activation would put a disk flush on the `cd` hot path.
+    /// If a power loss hits between the rename and the cache flush, the
+    /// metadata may roll back to its previous state. Users on crash-prone
+    /// machines can opt into both flushes with `durable_writes` in
+    /// config.json.
     pub fn write_metadata_atomic(&self, path: &Path, metadata: &Metadata) -> Result<()> {
-        let metadata_path = path.join(Metadata::FILE_NAME);
-        let dir = metadata_path.parent().ok_or_else(|| {
-            ScoopError::Io(std::io::Error::new(
-                std::io::ErrorKind::InvalidInput,
-                "metadata path has no parent",
-            ))
-        })?;
-
         let content = serde_json::to_string_pretty(metadata)?;
-        let mut tmp = tempfile::NamedTempFile::new_in(dir)?;
-        tmp.write_all(content.as_bytes())?;
-        tmp.persist(&metadata_path)
-            .map_err(|e| ScoopError::Io(e.error))?;
-        Ok(())
+        crate::core::atomic::write_file(&path.join(Metadata::FILE_NAME), content.as_bytes())
     }
 
     /// Touch an env's `last_used` to *now* (wall clock at write time),
@@ -392,5 +512,91 @@ impl VirtualenvService {
     }
 }
 
+/// Whether the env at `path` looks intact: its Python executable and
+/// `pyvenv.cfg` both exist. The single definition of "broken" shared by
+/// `doctor` and `list`.
+pub fn is_env_healthy(path: &Path) -> bool {
+    paths::virtualenv_python_exe(path).exists() && path.join("pyvenv.cfg").exists()
+}
+
+/// The interpreter of the env rooted at `path`, symlinks resolved; `None`
+/// when it is missing or its link dangles. See
+/// [`VirtualenvService::python_executable`].
+pub fn python_executable_at(path: &Path) -> Option<PathBuf> {
+    fs::canonicalize(paths::virtualenv_python_exe(path))
+        .ok()
+        .filter(|exe| exe.is_file())
+}
+
+/// Collect directories under `venvs_dir` whose names aren't valid UTF-8.
+///
+/// [`VirtualenvService::list`] (and every other `to_str()`-based walker)
+/// skips these entries silently, so a user who ends up with one gets no
+/// feedback at all. `doctor` and `list` call this to surface them with a
+/// rename hint instead. Symlinks are skipped for the same reason `list`
+/// skips them. A missing or unreadable `venvs_dir` yields an empty vector.
+pub fn non_utf8_env_dirs(venvs_dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(venvs_dir) else {
+        return Vec::new();
+    };
+    let mut found: Vec<PathBuf> = entries
+        .flatten()
+        .filter(|e| e.file_type().is_ok_and(|t| t.is_dir() && !t.is_symlink()))
+        .filter(|e| e.file_name().to_str().is_none())
+        .map(|e| e.path())
+        .collect();
+    found.sort();
+    found
+}
+
+/// Env directory names under `venvs_dir` that differ only in case, grouped
+/// and sorted.
+///
+/// `validate_env_name` accepts both `MyEnv` and `myenv`, but on a
+/// case-insensitive filesystem (macOS, Windows) they are the same directory,
+/// so a pair that coexists here breaks as soon as `SCUV_HOME` is synced or
+/// copied to such a machine. Symlinks and non-UTF-8 names are skipped, as in
+/// [`non_utf8_env_dirs`].
+pub fn case_colliding_env_names(venvs_dir: &Path) -> Vec<Vec<String>> {
+    let Ok(entries) = fs::read_dir(venvs_dir) else {
+        return Vec::new();
+    };
+    let mut by_folded: std::collections::BTreeMap<String, Vec<String>> = Default::default();
+    for entry in entries.flatten() {
+        if !entry
+            .file_type()
+            .is_ok_and(|t| t.is_dir() && !t.is_symlink())
+        {
+            continue;
+        }
+        if let Some(name) = entry.file_name().to_str() {
+            by_folded
+                .entry(name.to_lowercase())
+                .or_default()
+                .push(name.to_string());
+        }
+    }
+    by_folded
+        .into_values()
+        .filter(|names| names.len() > 1)
+        .map(|mut names| {
+            names.sort();
+            names
+        })
+        .collect()
+}
+
+/// An existing env directory whose name equals `name` ignoring case but
+/// isn't spelled the same, if any.
+pub fn case_variant_of(venvs_dir: &Path, name: &str) -> Option<String> {
+    let folded = name.to_lowercase();
+    fs::read_dir(venvs_dir)
+        .ok()?
+        .flatten()
+        .filter(|e| e.file_type().is_ok_and(|t| t.is_dir() && !t.is_symlink()))
+        .filter_map(|e| e.file_name().to_str().map(str::to_string))
+        .find(|existing| existing != name && existing.to_lowercase() == folded)
+}
+
 #[cfg(test)]
 mod tests;
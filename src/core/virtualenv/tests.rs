@@ -30,6 +30,7 @@ fn test_virtualenv_info_struct() {
         python_version: Some("3.12".to_string()),
         created_at: None,
         last_used: None,
+        tags: Vec::new(),
     };
 
     assert_eq!(info.name, "testenv");
@@ -618,3 +619,274 @@ fn test_list_skips_symlink_entries() {
         assert_eq!(envs[0].name, "real");
     });
 }
+
+#[test]
+fn test_is_env_healthy_requires_python_and_pyvenv_cfg() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let env = tmp.path().join("env");
+    let python = crate::paths::virtualenv_python_exe(&env);
+    fs::create_dir_all(python.parent().unwrap()).unwrap();
+    fs::write(&python, "").unwrap();
+    fs::write(env.join("pyvenv.cfg"), "version = 3.12.1\n").unwrap();
+    assert!(is_env_healthy(&env));
+
+    // Deliberately corrupt it, one piece at a time.
+    fs::remove_file(env.join("pyvenv.cfg")).unwrap();
+    assert!(!is_env_healthy(&env), "missing pyvenv.cfg is broken");
+    fs::write(env.join("pyvenv.cfg"), "version = 3.12.1\n").unwrap();
+    fs::remove_file(&python).unwrap();
+    assert!(!is_env_healthy(&env), "missing python is broken");
+}
+
+#[test]
+#[serial]
+fn test_site_packages_finds_existing_dir_by_pyvenv_cfg_or_layout() {
+    with_temp_scoop_home(|temp| {
+        create_mock_venv(temp, "web", Some("3.13"));
+        let service = VirtualenvService::filesystem_only();
+        let env = temp.path().join("virtualenvs").join("web");
+
+        assert!(matches!(
+            service.site_packages("web"),
+            Err(ScoopError::SitePackagesNotFound { .. })
+        ));
+
+        // Free-threaded builds use `python3.13t`, which the layout scan finds.
+        let expected = if cfg!(windows) {
+            env.join("Lib").join("site-packages")
+        } else {
+            env.join("lib").join("python3.13t").join("site-packages")
+        };
+        fs::create_dir_all(&expected).unwrap();
+        assert_eq!(service.site_packages("web").unwrap(), expected);
+
+        assert!(matches!(
+            service.site_packages("missing"),
+            Err(ScoopError::VirtualenvNotFound { .. })
+        ));
+    });
+}
+
+// Non-UTF-8 directory names are invisible to `list()` (it keys on
+// `to_str()`); `non_utf8_env_dirs` is what lets doctor/list report them.
+// Some filesystems (APFS) refuse non-UTF-8 names outright — skip there.
+#[cfg(unix)]
+#[test]
+fn test_non_utf8_env_dirs_reports_only_invalid_names() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let venvs_dir = tempfile::TempDir::new().unwrap();
+    fs::create_dir_all(venvs_dir.path().join("valid")).unwrap();
+    let bad = venvs_dir.path().join(OsStr::from_bytes(b"bad-\xff-env"));
+    if fs::create_dir(&bad).is_err() {
+        eprintln!("SKIPPED: filesystem rejects non-UTF-8 names");
+        return;
+    }
+
+    let found = non_utf8_env_dirs(venvs_dir.path());
+    assert_eq!(found, vec![bad]);
+}
+
+#[test]
+fn test_non_utf8_env_dirs_missing_dir_is_empty() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    assert!(non_utf8_env_dirs(&tmp.path().join("nope")).is_empty());
+}
+
+// Case collisions can only be planted on a case-sensitive filesystem; on
+// APFS/NTFS the second create_dir resolves to the first and there is
+// nothing to detect.
+#[test]
+fn test_case_colliding_env_names_groups_case_variants() {
+    let venvs_dir = tempfile::TempDir::new().unwrap();
+    for name in ["MyEnv", "other", "web"] {
+        fs::create_dir_all(venvs_dir.path().join(name)).unwrap();
+    }
+    if fs::create_dir(venvs_dir.path().join("myenv")).is_err() {
+        eprintln!("SKIPPED: case-insensitive filesystem");
+        assert!(case_colliding_env_names(venvs_dir.path()).is_empty());
+        return;
+    }
+
+    assert_eq!(
+        case_colliding_env_names(venvs_dir.path()),
+        vec![vec!["MyEnv".to_string(), "myenv".to_string()]]
+    );
+}
+
+#[test]
+fn test_case_colliding_env_names_missing_dir_is_empty() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    assert!(case_colliding_env_names(&tmp.path().join("nope")).is_empty());
+}
+
+#[test]
+fn test_case_variant_of_ignores_exact_match() {
+    let venvs_dir = tempfile::TempDir::new().unwrap();
+    fs::create_dir_all(venvs_dir.path().join("MyEnv")).unwrap();
+
+    assert_eq!(
+        case_variant_of(venvs_dir.path(), "myenv"),
+        Some("MyEnv".to_string())
+    );
+    assert_eq!(case_variant_of(venvs_dir.path(), "MyEnv"), None);
+    assert_eq!(case_variant_of(venvs_dir.path(), "other"), None);
+}
+
+#[cfg(unix)]
+#[test]
+#[serial]
+fn test_create_with_clear_on_old_uv_keeps_existing_env() {
+    use std::os::unix::fs::PermissionsExt;
+
+    with_temp_scoop_home(|temp_dir| {
+        create_mock_venv(temp_dir, "inplace", Some("3.12"));
+        let venv_path = temp_dir.path().join("virtualenvs").join("inplace");
+
+        let uv = temp_dir.path().join("fake-uv");
+        std::fs::write(&uv, "#!/bin/sh\necho 'uv 0.7.20'\n").unwrap();
+        std::fs::set_permissions(&uv, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let service = VirtualenvService::new(UvClient::with_path(uv));
+
+        let err = service.create("inplace", "3.12").unwrap_err();
+        assert!(
+            matches!(err, ScoopError::VirtualenvExists { .. }),
+            "{err:?}"
+        );
+
+        let err = service
+            .with_clear_existing(true)
+            .create("inplace", "3.12")
+            .unwrap_err();
+        assert!(
+            matches!(err, ScoopError::UvFeatureUnavailable { .. }),
+            "{err:?}"
+        );
+        // Nothing was touched: the env and its metadata are still there.
+        assert!(venv_path.join(crate::core::Metadata::FILE_NAME).exists());
+    });
+}
+
+#[cfg(unix)]
+#[test]
+#[serial]
+fn test_create_passes_upgrade_only_for_seeded_upgrade_deps() {
+    use std::os::unix::fs::PermissionsExt;
+
+    with_temp_scoop_home(|temp_dir| {
+        // Logs every call; `uv venv` lays out a seeded env with pip in it.
+        let log = temp_dir.path().join("uv-args");
+        let uv = temp_dir.path().join("fake-uv");
+        std::fs::write(
+            &uv,
+            format!(
+                "#!/bin/sh\necho \"$@\" >> '{}'\n\
+                 if [ \"$1\" = venv ]; then mkdir -p \"$2/lib/python3.12/site-packages/pip-24.0.dist-info\"; fi\n",
+                log.display()
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&uv, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let calls = || std::fs::read_to_string(&log).unwrap_or_default();
+
+        for (seed, upgrade, expect_upgrade) in [
+            (false, false, false),
+            (true, false, false),
+            (false, true, false),
+            (true, true, true),
+        ] {
+            let name = format!("env-{seed}-{upgrade}");
+            std::fs::remove_file(&log).ok();
+            let service = VirtualenvService::new(UvClient::with_path(uv.clone()))
+                .with_seed(seed)
+                .with_upgrade_deps(upgrade);
+            let path = service.create(&name, "3.12").unwrap();
+
+            let calls = calls();
+            assert_eq!(calls.contains(" --seed"), seed, "{calls}");
+            assert_eq!(calls.contains("--upgrade"), expect_upgrade, "{calls}");
+            let metadata = service.read_metadata(&path).unwrap();
+            assert_eq!(metadata.deps_upgraded, expect_upgrade, "{name}");
+        }
+    });
+}
+
+#[test]
+#[serial]
+#[cfg(unix)]
+fn test_python_executable_resolves_symlink_chain() {
+    use std::os::unix::fs::symlink;
+
+    with_temp_scoop_home(|temp| {
+        let service = VirtualenvService::filesystem_only();
+        let base = temp.path().join("pythons").join("cpython-3.12").join("bin");
+        fs::create_dir_all(&base).unwrap();
+        fs::write(base.join("python3.12"), "").unwrap();
+
+        // bin/python -> python3 (relative) -> <base>/python3.12
+        let bin = temp.path().join("virtualenvs").join("web").join("bin");
+        fs::create_dir_all(&bin).unwrap();
+        symlink(base.join("python3.12"), bin.join("python3")).unwrap();
+        symlink("python3", bin.join("python")).unwrap();
+
+        assert_eq!(
+            service.python_executable("web").unwrap(),
+            fs::canonicalize(base.join("python3.12")).unwrap()
+        );
+    });
+}
+
+#[test]
+#[serial]
+fn test_python_executable_errors_for_missing_env_or_interpreter() {
+    with_temp_scoop_home(|temp| {
+        let service = VirtualenvService::filesystem_only();
+        assert!(matches!(
+            service.python_executable("ghost"),
+            Err(ScoopError::VirtualenvNotFound { .. })
+        ));
+
+        let env = temp.path().join("virtualenvs").join("empty");
+        fs::create_dir_all(&env).unwrap();
+        match service.python_executable("empty") {
+            Err(ScoopError::InterpreterNotFound { env: name, path }) => {
+                assert_eq!(name, "empty");
+                assert_eq!(path, crate::paths::virtualenv_python_exe(&env));
+            }
+            other => panic!("expected InterpreterNotFound, got {other:?}"),
+        }
+
+        #[cfg(unix)]
+        {
+            let bin = env.join("bin");
+            fs::create_dir_all(&bin).unwrap();
+            std::os::unix::fs::symlink(temp.path().join("uninstalled"), bin.join("python"))
+                .unwrap();
+            assert!(matches!(
+                service.python_executable("empty"),
+                Err(ScoopError::InterpreterNotFound { .. })
+            ));
+        }
+    });
+}
+
+#[test]
+#[serial]
+fn test_filesystem_only_reads_envs_but_refuses_uv_work() {
+    with_temp_scoop_home(|temp| {
+        create_mock_venv(temp, "web", Some("3.12"));
+        let service = VirtualenvService::filesystem_only();
+        assert!(service.exists("web").unwrap());
+        assert!(!service.exists("ghost").unwrap());
+        assert!(matches!(
+            service.is_python_installed("3.12"),
+            Err(ScoopError::UvNotFound)
+        ));
+        assert!(matches!(
+            service.create("api", "3.12"),
+            Err(ScoopError::UvNotFound)
+        ));
+        assert!(!temp.path().join("virtualenvs").join("api").exists());
+    });
+}
@@ -17,6 +17,9 @@ impl ScoopError {
             Self::InvalidPythonVersion { .. } => "PYTHON_INVALID_VERSION",
             Self::UvNotFound => "UV_NOT_INSTALLED",
             Self::UvCommandFailed { .. } => "UV_COMMAND_FAILED",
+            Self::UvVersionIncompatible { .. } => "UV_VERSION_INCOMPATIBLE",
+            Self::UvFeatureUnavailable { .. } => "UV_FEATURE_UNAVAILABLE",
+            Self::NetworkError { .. } => "NETWORK_ERROR",
             Self::PathError(_) => "IO_PATH_ERROR",
             Self::HomeNotFound => "IO_HOME_NOT_FOUND",
             Self::Io(_) => "IO_ERROR",
@@ -32,15 +35,20 @@ impl ScoopError {
             Self::PyenvEnvNotFound { .. } => "SOURCE_PYENV_ENV_NOT_FOUND",
             Self::VenvWrapperEnvNotFound { .. } => "SOURCE_VENVWRAPPER_ENV_NOT_FOUND",
             Self::CondaEnvNotFound { .. } => "SOURCE_CONDA_ENV_NOT_FOUND",
+            Self::PoetryEnvNotFound { .. } => "SOURCE_POETRY_ENV_NOT_FOUND",
+            Self::HatchEnvNotFound { .. } => "SOURCE_HATCH_ENV_NOT_FOUND",
             Self::CorruptedEnvironment { .. } => "MIGRATE_CORRUPTED",
             Self::PackageExtractionFailed { .. } => "MIGRATE_EXTRACTION_FAILED",
             Self::MigrationFailed { .. } => "MIGRATE_FAILED",
             Self::MigrationNameConflict { .. } => "MIGRATE_NAME_CONFLICT",
             Self::InvalidPythonPath { .. } => "PYTHON_INVALID_PATH",
             Self::CascadeAborted => "UNINSTALL_CASCADE_ABORTED",
+            Self::ReinstallAborted { .. } => "INSTALL_REINSTALL_ABORTED",
+            Self::Interrupted => "INTERRUPTED",
             Self::SelfUpdateFailed { .. } => "SELF_UPDATE_FAILED",
             Self::NoActiveEnvironment => "NO_ACTIVE_ENV",
             Self::ExecutableNotFound { .. } => "EXE_NOT_FOUND",
+            Self::InterpreterNotFound { .. } => "ENV_INTERPRETER_MISSING",
             Self::ManifestNotFound { .. } => "MANIFEST_NOT_FOUND",
             Self::InvalidExportFile { .. } => "EXPORT_INVALID_FILE",
             Self::UnsupportedExportVersion { .. } => "EXPORT_UNSUPPORTED_VERSION",
@@ -51,4 +59,35 @@ impl ScoopError {
             Self::DiffMismatch { .. } => "DIFF_MISMATCH",
         }
     }
+
+    /// The error as a JSON object in an explicit `locale`: `code`,
+    /// `message`, and `suggestion` when there is one.
+    ///
+    /// This is the `error` object of every `--json` error response; tools
+    /// embedding scuv can put it in their own output as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scoop_uv::error::ScoopError;
+    /// let json = ScoopError::UvNotFound.to_json_in("en");
+    /// assert_eq!(json["code"], "UV_NOT_INSTALLED");
+    /// assert!(json["suggestion"].is_string());
+    /// ```
+    pub fn to_json_in(&self, locale: &str) -> serde_json::Value {
+        let mut json = serde_json::json!({
+            "code": self.code(),
+            "message": self.message_in(locale),
+        });
+        if let Some(suggestion) = self.suggestion_in(locale) {
+            json["suggestion"] = suggestion.into();
+        }
+        json
+    }
+
+    /// [`ScoopError::to_json_in`] in the current locale.
+    pub fn to_json(&self) -> serde_json::Value {
+        let locale = rust_i18n::locale();
+        self.to_json_in(&locale)
+    }
 }
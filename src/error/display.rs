@@ -50,6 +50,28 @@ impl ScoopError {
                 message = message
             )
             .to_string(),
+            Self::UvVersionIncompatible { found, required } => t!(
+                "error.uv_version_incompatible",
+                locale = locale,
+                found = found,
+                required = required
+            )
+            .to_string(),
+            Self::UvFeatureUnavailable {
+                feature,
+                min_version,
+                found,
+            } => t!(
+                "error.uv_feature_unavailable",
+                locale = locale,
+                feature = feature,
+                min_version = min_version,
+                found = found
+            )
+            .to_string(),
+            Self::NetworkError { context } => {
+                t!("error.network_error", locale = locale, context = context).to_string()
+            }
             Self::PathError(msg) => {
                 t!("error.path_error", locale = locale, message = msg).to_string()
             }
@@ -109,6 +131,12 @@ impl ScoopError {
             Self::CondaEnvNotFound { name } => {
                 t!("error.conda_env_not_found", locale = locale, name = name).to_string()
             }
+            Self::PoetryEnvNotFound { name } => {
+                t!("error.poetry_env_not_found", locale = locale, name = name).to_string()
+            }
+            Self::HatchEnvNotFound { name } => {
+                t!("error.hatch_env_not_found", locale = locale, name = name).to_string()
+            }
             Self::CorruptedEnvironment { name, reason } => t!(
                 "error.corrupted_environment",
                 locale = locale,
@@ -140,6 +168,13 @@ impl ScoopError {
             )
             .to_string(),
             Self::CascadeAborted => t!("error.cascade_aborted", locale = locale).to_string(),
+            Self::Interrupted => t!("error.interrupted", locale = locale).to_string(),
+            Self::ReinstallAborted { version } => t!(
+                "error.reinstall_aborted",
+                locale = locale,
+                version = version
+            )
+            .to_string(),
             Self::SelfUpdateFailed { message } => t!(
                 "error.self_update_failed",
                 locale = locale,
@@ -156,6 +191,13 @@ impl ScoopError {
                 env = env
             )
             .to_string(),
+            Self::InterpreterNotFound { env, path } => t!(
+                "error.interpreter_not_found",
+                locale = locale,
+                env = env,
+                path = path.display()
+            )
+            .to_string(),
             Self::ManifestNotFound { start_dir } => t!(
                 "error.manifest_not_found",
                 locale = locale,
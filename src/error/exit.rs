@@ -50,6 +50,7 @@ impl ScoopError {
     ///   (`MigrationFailed`, `MigrationNameConflict`).
     /// - `3` — migration source-discovery error (pyenv/conda/venvwrapper
     ///   missing, corrupted source env).
+    /// - `130` — interrupted with Ctrl-C (see [`crate::core::cancel`]).
     ///
     /// Diff/audit-specific exit codes are added alongside their feature
     /// variants in later increments; until those land, existing variants
@@ -78,6 +79,8 @@ impl ScoopError {
             | Self::PyenvEnvNotFound { .. }
             | Self::VenvWrapperEnvNotFound { .. }
             | Self::CondaEnvNotFound { .. }
+            | Self::PoetryEnvNotFound { .. }
+            | Self::HatchEnvNotFound { .. }
             | Self::CorruptedEnvironment { .. }
             | Self::MigrationSourcesNotFound { .. } => 3,
 
@@ -89,6 +92,10 @@ impl ScoopError {
             | Self::MigrationNameConflict { .. }
             | Self::MigrationBatchFailed { .. } => 2,
 
+            // Ctrl-C: the shell convention for death by SIGINT (128 + 2),
+            // which is what the exit would have been without the handler.
+            Self::Interrupted => 130,
+
             // Narrow policy: every other operational variant exits 1 to
             // preserve the historical contract every CI script already
             // expects from scuv. New diff/audit variants will be added
@@ -140,6 +147,15 @@ mod tests {
         assert_eq!(err.render_policy(), ErrorRenderPolicy::Quiet);
     }
 
+    #[test]
+    fn interrupted_exits_like_sigint() {
+        assert_eq!(ScoopError::Interrupted.exit_code(), 130);
+        assert_eq!(
+            ScoopError::Interrupted.render_policy(),
+            ErrorRenderPolicy::Default
+        );
+    }
+
     #[test]
     fn source_discovery_variants_exit_three() {
         let cases = [
@@ -153,6 +169,12 @@ mod tests {
             ScoopError::CondaEnvNotFound {
                 name: "x".to_string(),
             },
+            ScoopError::PoetryEnvNotFound {
+                name: "x".to_string(),
+            },
+            ScoopError::HatchEnvNotFound {
+                name: "x".to_string(),
+            },
             ScoopError::CorruptedEnvironment {
                 name: "x".to_string(),
                 reason: "y".to_string(),
@@ -44,6 +44,8 @@ impl ScoopError {
             | Self::PyenvEnvNotFound { .. }
             | Self::VenvWrapperEnvNotFound { .. }
             | Self::CondaEnvNotFound { .. }
+            | Self::PoetryEnvNotFound { .. }
+            | Self::HatchEnvNotFound { .. }
             | Self::CorruptedEnvironment { .. }
             | Self::MigrationSourcesNotFound { .. } => MigrationExitCode::SourceError,
             _ => MigrationExitCode::CompleteFailure,
@@ -75,6 +77,12 @@ mod tests {
             ScoopError::CondaEnvNotFound {
                 name: "x".to_string(),
             },
+            ScoopError::PoetryEnvNotFound {
+                name: "x".to_string(),
+            },
+            ScoopError::HatchEnvNotFound {
+                name: "x".to_string(),
+            },
             ScoopError::CorruptedEnvironment {
                 name: "x".to_string(),
                 reason: "y".to_string(),
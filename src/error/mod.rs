@@ -4,12 +4,12 @@
 //! `impl` blocks are split across submodules to keep each file under
 //! ~200 LOC of code:
 //!
-//! | Submodule          | Responsibility                                          |
-//! |--------------------|---------------------------------------------------------|
-//! | [`display`]        | i18n rendering ([`ScoopError::message_in`] + `Display`) |
-//! | [`code`]           | stable JSON error codes ([`ScoopError::code`])          |
-//! | [`suggestion`]     | locale-aware fix hints ([`ScoopError::suggestion_in`])  |
-//! | [`migrate`]        | [`MigrationExitCode`] + per-variant exit mapping        |
+//! | Submodule      | Responsibility                                                          |
+//! |----------------|-------------------------------------------------------------------------|
+//! | [`display`]    | i18n rendering ([`ScoopError::message_in`] + `Display`)                 |
+//! | [`code`]       | stable JSON error codes ([`ScoopError::code`], [`ScoopError::to_json`]) |
+//! | [`suggestion`] | locale-aware fix hints ([`ScoopError::suggestion_in`])                  |
+//! | [`migrate`]    | [`MigrationExitCode`] + per-variant exit mapping                        |
 //!
 //! All public API stays at `crate::error::ScoopError::*` regardless of
 //! which submodule defines the impl block.
@@ -51,6 +51,23 @@ pub enum ScoopError {
     /// uv command failed
     UvCommandFailed { command: String, message: String },
 
+    /// Installed uv is older than an operation requires. Raised only by
+    /// call sites that opt in via `UvClient::ensure_compatible`.
+    UvVersionIncompatible { found: String, required: String },
+
+    /// Installed uv predates a subcommand or flag scuv needs, e.g. uv
+    /// rejected it as an unknown subcommand. Raised by
+    /// `UvClient::ensure_feature` and when uv refuses a command outright.
+    UvFeatureUnavailable {
+        feature: String,
+        min_version: String,
+        found: String,
+    },
+
+    /// uv couldn't reach the network (connection refused, timeout, DNS
+    /// failure). `context` is the uv command and its error output.
+    NetworkError { context: String },
+
     /// Path error
     PathError(String),
 
@@ -96,6 +113,12 @@ pub enum ScoopError {
     /// conda environment not found
     CondaEnvNotFound { name: String },
 
+    /// Poetry environment not found
+    PoetryEnvNotFound { name: String },
+
+    /// Hatch environment not found
+    HatchEnvNotFound { name: String },
+
     /// Corrupted environment
     CorruptedEnvironment { name: String, reason: String },
 
@@ -114,6 +137,12 @@ pub enum ScoopError {
     /// Cascade uninstall aborted by user
     CascadeAborted,
 
+    /// `install --reinstall` declined because environments depend on the version
+    ReinstallAborted { version: String },
+
+    /// The user pressed Ctrl-C during a long operation
+    Interrupted,
+
     /// `scuv self update` failed (search, install, or post-install verify).
     SelfUpdateFailed { message: String },
 
@@ -123,6 +152,10 @@ pub enum ScoopError {
     /// Executable not found within an environment's bin directory.
     ExecutableNotFound { exe: String, env: String },
 
+    /// The environment exists but its Python executable is missing or a
+    /// dangling symlink. `path` is where the interpreter should be.
+    InterpreterNotFound { env: String, path: PathBuf },
+
     /// `.scuv.toml` could not be located walking up from `start_dir`.
     ManifestNotFound { start_dir: PathBuf },
 
@@ -281,6 +314,34 @@ mod tests {
         assert!(err.message_in("en").contains("Python not found"));
     }
 
+    #[test]
+    fn test_uv_version_incompatible_message_and_hint() {
+        let err = ScoopError::UvVersionIncompatible {
+            found: "0.5.14".to_string(),
+            required: "0.6.0".to_string(),
+        };
+        let msg = err.message_in("en");
+        assert!(msg.contains("0.5.14") && msg.contains("0.6.0"), "{msg}");
+        assert_eq!(err.code(), "UV_VERSION_INCOMPATIBLE");
+        let hint = err.suggestion_in("en").expect("should have suggestion");
+        assert!(hint.contains("uv self update"), "{hint}");
+    }
+
+    #[test]
+    fn test_uv_feature_unavailable_message_and_hint() {
+        let err = ScoopError::UvFeatureUnavailable {
+            feature: "uv python dir".to_string(),
+            min_version: "0.5.14".to_string(),
+            found: "0.4.0".to_string(),
+        };
+        let msg = err.message_in("en");
+        assert!(msg.contains("uv python dir"), "{msg}");
+        assert!(msg.contains("0.5.14") && msg.contains("0.4.0"), "{msg}");
+        assert_eq!(err.code(), "UV_FEATURE_UNAVAILABLE");
+        let hint = err.suggestion_in("en").expect("should have suggestion");
+        assert!(hint.contains("uv self update"), "{hint}");
+    }
+
     #[test]
     fn test_path_error_message() {
         let err = ScoopError::PathError("invalid UTF-8".to_string());
@@ -650,6 +711,17 @@ mod tests {
         assert_eq!(err.code(), "UV_COMMAND_FAILED");
     }
 
+    #[test]
+    fn test_error_code_network_error() {
+        let err = ScoopError::NetworkError {
+            context: "uv pip install: dns error".into(),
+        };
+        assert_eq!(err.code(), "NETWORK_ERROR");
+        assert!(err.to_string().contains("dns error"));
+        let suggestion = err.suggestion_in("en").expect("should have suggestion");
+        assert!(suggestion.contains("internet connection"), "{suggestion}");
+    }
+
     #[test]
     fn test_error_code_io_path_error() {
         let err = ScoopError::PathError("x".into());
@@ -676,6 +748,34 @@ mod tests {
         assert_eq!(err.code(), "INTERNAL_JSON_ERROR");
     }
 
+    #[test]
+    fn test_to_json_with_and_without_suggestion() {
+        let err = ScoopError::VirtualenvNotFound {
+            name: "web".to_string(),
+        };
+        assert_eq!(
+            err.to_json_in("en"),
+            serde_json::json!({
+                "code": "ENV_NOT_FOUND",
+                "message": err.message_in("en"),
+                "suggestion": err.suggestion_in("en").unwrap(),
+            })
+        );
+
+        let json_err: serde_json::Error =
+            serde_json::from_str::<serde_json::Value>("invalid").expect_err("should fail");
+        let err: ScoopError = json_err.into();
+        let json = err.to_json_in("en");
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "code": "INTERNAL_JSON_ERROR",
+                "message": err.message_in("en"),
+            })
+        );
+        assert!(json.get("suggestion").is_none());
+    }
+
     #[test]
     fn test_error_code_config_version_file_not_found() {
         let err = ScoopError::VersionFileNotFound {
@@ -756,6 +856,18 @@ mod tests {
         assert_eq!(err.code(), "SOURCE_CONDA_ENV_NOT_FOUND");
     }
 
+    #[test]
+    fn test_error_code_source_poetry_env_not_found() {
+        let err = ScoopError::PoetryEnvNotFound { name: "x".into() };
+        assert_eq!(err.code(), "SOURCE_POETRY_ENV_NOT_FOUND");
+    }
+
+    #[test]
+    fn test_error_code_source_hatch_env_not_found() {
+        let err = ScoopError::HatchEnvNotFound { name: "x".into() };
+        assert_eq!(err.code(), "SOURCE_HATCH_ENV_NOT_FOUND");
+    }
+
     #[test]
     fn test_error_code_migrate_corrupted() {
         let err = ScoopError::CorruptedEnvironment {
@@ -836,6 +948,18 @@ mod tests {
                 message: "".into(),
             }
             .code(),
+            ScoopError::UvVersionIncompatible {
+                found: "".into(),
+                required: "".into(),
+            }
+            .code(),
+            ScoopError::UvFeatureUnavailable {
+                feature: "".into(),
+                min_version: "".into(),
+                found: "".into(),
+            }
+            .code(),
+            ScoopError::NetworkError { context: "".into() }.code(),
             ScoopError::PathError("".into()).code(),
             ScoopError::HomeNotFound.code(),
             ScoopError::Io(io::Error::other("")).code(),
@@ -862,6 +986,8 @@ mod tests {
             ScoopError::PyenvEnvNotFound { name: "".into() }.code(),
             ScoopError::VenvWrapperEnvNotFound { name: "".into() }.code(),
             ScoopError::CondaEnvNotFound { name: "".into() }.code(),
+            ScoopError::PoetryEnvNotFound { name: "".into() }.code(),
+            ScoopError::HatchEnvNotFound { name: "".into() }.code(),
             ScoopError::CorruptedEnvironment {
                 name: "".into(),
                 reason: "".into(),
@@ -880,6 +1006,8 @@ mod tests {
             }
             .code(),
             ScoopError::CascadeAborted.code(),
+            ScoopError::ReinstallAborted { version: "".into() }.code(),
+            ScoopError::Interrupted.code(),
         ];
 
         let unique: HashSet<_> = codes.iter().collect();
@@ -896,6 +1024,7 @@ mod tests {
         let codes = vec![
             ScoopError::VirtualenvNotFound { name: "".into() }.code(),
             ScoopError::UvNotFound.code(),
+            ScoopError::NetworkError { context: "".into() }.code(),
             ScoopError::HomeNotFound.code(),
             ScoopError::InvalidArgument { message: "".into() }.code(),
             // Migration error codes
@@ -903,6 +1032,8 @@ mod tests {
             ScoopError::PyenvEnvNotFound { name: "".into() }.code(),
             ScoopError::VenvWrapperEnvNotFound { name: "".into() }.code(),
             ScoopError::CondaEnvNotFound { name: "".into() }.code(),
+            ScoopError::PoetryEnvNotFound { name: "".into() }.code(),
+            ScoopError::HatchEnvNotFound { name: "".into() }.code(),
             ScoopError::CorruptedEnvironment {
                 name: "".into(),
                 reason: "".into(),
@@ -1138,6 +1269,20 @@ mod tests {
         assert!(s.contains("scuv info"));
     }
 
+    #[test]
+    fn test_interpreter_not_found_names_env_and_path() {
+        let err = ScoopError::InterpreterNotFound {
+            env: "myenv".into(),
+            path: PathBuf::from("/envs/myenv/bin/python"),
+        };
+        assert_eq!(err.code(), "ENV_INTERPRETER_MISSING");
+        let message = err.to_string();
+        assert!(message.contains("myenv"), "{message}");
+        assert!(message.contains("/envs/myenv/bin/python"), "{message}");
+        let s = err.suggestion_in("en").unwrap();
+        assert!(s.contains("scuv create myenv"), "{s}");
+    }
+
     #[test]
     fn test_suggestion_manifest_not_found_points_at_docs() {
         // Deleting the match arm would collapse to `None`; asserting on the
@@ -34,6 +34,9 @@ impl ScoopError {
                 Some(t!("suggestion.invalid_env_name", locale = locale).to_string())
             }
             Self::UvNotFound => Some(t!("suggestion.uv_not_found", locale = locale).to_string()),
+            Self::UvVersionIncompatible { .. } | Self::UvFeatureUnavailable { .. } => {
+                Some(t!("suggestion.uv_version_incompatible", locale = locale).to_string())
+            }
             Self::PythonNotInstalled { version } => Some(
                 t!(
                     "suggestion.python_not_installed",
@@ -50,7 +53,9 @@ impl ScoopError {
             }
             Self::PyenvEnvNotFound { .. }
             | Self::VenvWrapperEnvNotFound { .. }
-            | Self::CondaEnvNotFound { .. } => {
+            | Self::CondaEnvNotFound { .. }
+            | Self::PoetryEnvNotFound { .. }
+            | Self::HatchEnvNotFound { .. } => {
                 Some(t!("suggestion.source_env_not_found", locale = locale).to_string())
             }
             Self::MigrationNameConflict { .. } => {
@@ -68,6 +73,17 @@ impl ScoopError {
             | Self::PythonUninstallFailed { .. } => {
                 Some(t!("suggestion.run_doctor", locale = locale).to_string())
             }
+            Self::NetworkError { .. } => {
+                Some(t!("suggestion.network_error", locale = locale).to_string())
+            }
+            Self::ReinstallAborted { version } => Some(
+                t!(
+                    "suggestion.reinstall_aborted",
+                    locale = locale,
+                    version = version
+                )
+                .to_string(),
+            ),
             Self::NoActiveEnvironment => {
                 Some(t!("suggestion.no_active_environment", locale = locale).to_string())
             }
@@ -79,6 +95,14 @@ impl ScoopError {
                 )
                 .to_string(),
             ),
+            Self::InterpreterNotFound { env, .. } => Some(
+                t!(
+                    "suggestion.interpreter_not_found",
+                    locale = locale,
+                    env = env
+                )
+                .to_string(),
+            ),
             Self::ManifestNotFound { .. } => {
                 Some(t!("suggestion.manifest_not_found", locale = locale).to_string())
             }
@@ -1,9 +1,9 @@
 //! scuv - Python virtual environment manager powered by uv
 
-use clap::Parser;
+use clap::{CommandFactory, Parser};
 use color_eyre::eyre::Result;
 
-use scoop_uv::cli::{Cli, Commands, MigrateCommand, SelfCommand};
+use scoop_uv::cli::{Cli, Commands, HookCommand, MigrateCommand, SelfCommand, TemplateCommand};
 use scoop_uv::output::Output;
 
 fn main() -> Result<()> {
@@ -23,49 +23,158 @@ fn main() -> Result<()> {
 
     // Parse CLI arguments
     let cli = Cli::parse();
+    let color = cli.color_choice();
+    match cli.root.as_slice() {
+        [] => {}
+        [root] => scoop_uv::paths::set_root_override(Some(root.clone())),
+        // Several homes: doctor points the override at each in turn.
+        _ if matches!(cli.command, Commands::Doctor { .. }) => {}
+        _ => Cli::command()
+            .error(
+                clap::error::ErrorKind::ArgumentConflict,
+                "--root can be given more than once only for `doctor`",
+            )
+            .exit(),
+    }
 
     // Execute command
     let result = match cli.command {
         Commands::List {
             pythons,
+            managed_only,
+            system_only,
             bare,
             python_version,
             sort,
+            group_by,
+            tag,
+            older_than,
+            broken_only,
+            count,
+            sizes,
             json,
         } => {
-            let output = Output::new(0, cli.quiet, cli.no_color, json);
-            scoop_uv::cli::commands::list(&output, pythons, bare, python_version.as_deref(), sort)
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
+            scoop_uv::cli::commands::list(
+                &output,
+                &scoop_uv::cli::commands::ListOpts {
+                    pythons,
+                    managed_only,
+                    system_only,
+                    bare,
+                    python_version,
+                    sort,
+                    group_by,
+                    tag,
+                    older_than,
+                    broken_only,
+                    count,
+                    sizes,
+                },
+            )
         }
         Commands::Create {
             name,
             python,
             python_path,
             force,
+            clear,
             install_python,
+            link,
+            dry_run,
+            no_activate_hint,
+            no_hooks,
+            quiet_uv,
+            build_constraints,
+            template,
+            seed,
+            upgrade_deps,
+            no_pip_upgrade_warning,
+            name_from_cwd,
             json,
         } => {
-            let output = Output::new(0, cli.quiet, cli.no_color, json);
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
+            // With --name-from-cwd the only positional is the Python version.
+            let (name, python) = if name_from_cwd {
+                (String::new(), name)
+            } else {
+                (name.unwrap_or_default(), python)
+            };
             scoop_uv::cli::commands::create(
                 &output,
-                &name,
-                &python,
-                python_path.as_deref(),
-                force,
-                install_python,
+                &scoop_uv::cli::commands::CreateOpts {
+                    name,
+                    python,
+                    python_path,
+                    force,
+                    clear,
+                    install_python,
+                    link,
+                    dry_run,
+                    no_activate_hint,
+                    no_hooks,
+                    quiet_uv,
+                    build_constraints,
+                    template,
+                    seed,
+                    upgrade_deps,
+                    no_pip_upgrade_warning,
+                    name_from_cwd,
+                },
             )
         }
-        Commands::Doctor { verbose, json, fix } => {
-            let output = Output::new(verbose, cli.quiet, cli.no_color, json);
-            scoop_uv::cli::commands::doctor(&output, fix)
+        Commands::Doctor {
+            json,
+            fix,
+            summary_only,
+            with_sizes,
+            deep,
+            no_input,
+            output: report,
+        } => {
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
+            // A single --root is already applied globally above.
+            let roots = if cli.root.len() > 1 {
+                cli.root.as_slice()
+            } else {
+                &[]
+            };
+            scoop_uv::cli::commands::doctor(
+                &output,
+                &scoop_uv::cli::commands::DoctorOpts {
+                    fix,
+                    summary_only,
+                    with_sizes,
+                    deep,
+                    no_input,
+                },
+                report.as_deref(),
+                roots,
+            )
+            .map(|summary| {
+                let code = summary.exit_code();
+                if code != 0 {
+                    std::process::exit(code);
+                }
+            })
         }
         Commands::Info {
             name,
             json,
             all_packages,
             no_size,
+            no_uv,
+            compare,
         } => {
-            let output = Output::new(0, cli.quiet, cli.no_color, json);
-            scoop_uv::cli::commands::info(&output, &name, all_packages, no_size)
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
+            scoop_uv::cli::commands::info(
+                &output,
+                &name,
+                all_packages,
+                no_size,
+                no_uv,
+                compare.as_deref(),
+            )
         }
         Commands::Use {
             name,
@@ -73,44 +182,125 @@ fn main() -> Result<()> {
             global,
             link,
             no_link: _, // explicit option, same as default (no symlink)
+            create,
+            append_path, // also read by the shell wrapper, which passes it to `activate`
+            print,
+            shell,
             json,
         } => {
-            let output = Output::new(0, cli.quiet, cli.no_color, json);
-            scoop_uv::cli::commands::use_env(&output, name.as_deref(), unset, global, link)
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
+            scoop_uv::cli::commands::use_env(
+                &output,
+                name.as_deref(),
+                unset,
+                global,
+                link,
+                create.as_deref(),
+                print.then_some(scoop_uv::cli::commands::PrintScript { shell, append_path }),
+            )
         }
-        Commands::Remove { name, force, json } => {
-            let output = Output::new(0, cli.quiet, cli.no_color, json);
-            scoop_uv::cli::commands::remove(&output, &name, force)
+        Commands::Remove {
+            name,
+            force,
+            dry_run,
+            keep_version_files,
+            wait,
+            kill,
+            json,
+        } => {
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
+            scoop_uv::cli::commands::remove(
+                &output,
+                &name,
+                force,
+                dry_run,
+                keep_version_files,
+                wait.map(std::time::Duration::from_secs),
+                kill,
+            )
         }
         Commands::Install {
             python_version,
             latest,
             stable,
+            reinstall,
+            force,
+            verify,
+            no_verify,
+            set_default,
             json,
         } => {
-            let output = Output::new(0, cli.quiet, cli.no_color, json);
-            scoop_uv::cli::commands::install(&output, python_version.as_deref(), latest, stable)
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
+            // `None` defers to install.verify in config.json.
+            let verify = match (verify, no_verify) {
+                (true, _) => Some(true),
+                (_, true) => Some(false),
+                _ => None,
+            };
+            scoop_uv::cli::commands::install(
+                &output,
+                &scoop_uv::cli::commands::InstallOpts {
+                    version: python_version,
+                    latest,
+                    stable,
+                    reinstall,
+                    force,
+                    verify,
+                    set_default,
+                },
+            )
         }
         Commands::Uninstall {
             python_version,
             cascade,
             force,
+            dry_run,
             json,
         } => {
-            let output = Output::new(0, cli.quiet, cli.no_color, json);
-            scoop_uv::cli::commands::uninstall(&output, &python_version, cascade, force)
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
+            scoop_uv::cli::commands::uninstall(&output, &python_version, cascade, force, dry_run)
         }
         Commands::Init { shell } => scoop_uv::cli::commands::init(shell),
-        Commands::Completions { shell } => scoop_uv::cli::commands::completions(shell),
-        Commands::Resolve => scoop_uv::cli::commands::resolve(),
-        Commands::Activate { name, shell } => scoop_uv::cli::commands::activate(&name, shell),
+        Commands::Completions { shell, out_dir } => {
+            let output = Output::new(cli.verbose, cli.quiet, color, false);
+            scoop_uv::cli::commands::completions(&output, shell, out_dir.as_deref())
+        }
+        Commands::Resolve { explain, json } => {
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
+            scoop_uv::cli::commands::resolve(&output, explain || cli.verbose > 0)
+        }
+        Commands::SitePackages { name } => scoop_uv::cli::commands::site_packages(&name),
+        Commands::Activate {
+            name,
+            shell,
+            append_path,
+        } => scoop_uv::cli::commands::activate(&name, shell, append_path),
         Commands::Deactivate { shell } => scoop_uv::cli::commands::deactivate(shell),
-        Commands::Shell { name, unset, shell } => {
-            let output = Output::new(0, cli.quiet, cli.no_color, false);
-            scoop_uv::cli::commands::shell(&output, name.as_deref(), unset, shell)
+        Commands::Shell {
+            name,
+            unset,
+            stack,
+            shell,
+        } => {
+            let output = Output::new(cli.verbose, cli.quiet, color, false);
+            scoop_uv::cli::commands::shell(&output, name.as_deref(), unset, stack, shell)
         }
-        Commands::Migrate { command } => {
-            // Subcommand carries its own --json flag (list / all / @env).
+        Commands::Hook { command } => match command {
+            HookCommand::Prompt {
+                prefix,
+                suffix,
+                json,
+            } => {
+                let output = Output::new(cli.verbose, cli.quiet, color, json);
+                scoop_uv::cli::commands::hook_prompt(&output, &prefix, &suffix)
+            }
+            HookCommand::Cd { shell, json } => {
+                let output = Output::new(cli.verbose, cli.quiet, color, json);
+                scoop_uv::cli::commands::hook_cd(&output, shell)
+            }
+        },
+        Commands::Migrate { command, json } => {
+            // Subcommand carries its own --json flag (list / all / @env / status).
             // Without threading it into Output here, output.json_success()
             // would no-op in production (only tests built Output directly
             // with json=true), so the new emit_migrate_all_json_outcome
@@ -119,10 +309,12 @@ fn main() -> Result<()> {
             let json = match &command {
                 Some(MigrateCommand::List { json, .. })
                 | Some(MigrateCommand::All { json, .. })
-                | Some(MigrateCommand::Env { json, .. }) => *json,
-                None => false,
+                | Some(MigrateCommand::Env { json, .. })
+                | Some(MigrateCommand::Status { json, .. }) => *json,
+                // Bare `migrate` lists; its own --json picks the format.
+                None => json,
             };
-            let output = Output::new(0, cli.quiet, cli.no_color, json);
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
             scoop_uv::cli::commands::migrate(&output, command)
         }
         Commands::Lang {
@@ -131,7 +323,7 @@ fn main() -> Result<()> {
             reset,
             json,
         } => {
-            let output = Output::new(0, cli.quiet, cli.no_color, json);
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
             scoop_uv::cli::commands::lang(&output, lang.as_deref(), list, reset)
         }
         Commands::Self_ { command } => match command {
@@ -141,16 +333,20 @@ fn main() -> Result<()> {
                 no_verify,
                 json,
             } => {
-                let output = Output::new(0, cli.quiet, cli.no_color, json);
+                let output = Output::new(cli.verbose, cli.quiet, color, json);
                 scoop_uv::cli::commands::self_update(&output, force, version.as_deref(), no_verify)
             }
         },
         Commands::Status { json } => {
-            let output = Output::new(0, cli.quiet, cli.no_color, json);
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
             scoop_uv::cli::commands::status(&output)
         }
+        Commands::Version { json } => {
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
+            scoop_uv::cli::commands::version(&output)
+        }
         Commands::Run { env, command } => {
-            let output = Output::new(0, cli.quiet, cli.no_color, false);
+            let output = Output::new(cli.verbose, cli.quiet, color, false);
             scoop_uv::cli::commands::run(&output, &env, &command)
         }
         Commands::Sync {
@@ -158,7 +354,7 @@ fn main() -> Result<()> {
             dry_run,
             json,
         } => {
-            let output = Output::new(0, cli.quiet, cli.no_color, json);
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
             scoop_uv::cli::commands::sync(&output, &with, dry_run)
         }
         Commands::Clone {
@@ -168,12 +364,12 @@ fn main() -> Result<()> {
             force,
             json,
         } => {
-            let out = Output::new(0, cli.quiet, cli.no_color, json);
+            let out = Output::new(cli.verbose, cli.quiet, color, json);
             scoop_uv::cli::commands::clone(&out, &src, &dst, no_packages, force)
         }
         Commands::Export { name, output } => {
             // Stdout is the schema itself; status messages go to stderr only.
-            let out = Output::new(0, cli.quiet, cli.no_color, false);
+            let out = Output::new(cli.verbose, cli.quiet, color, false);
             scoop_uv::cli::commands::export(&out, &name, output.as_deref())
         }
         Commands::Import {
@@ -182,15 +378,33 @@ fn main() -> Result<()> {
             force,
             json,
         } => {
-            let out = Output::new(0, cli.quiet, cli.no_color, json);
+            let out = Output::new(cli.verbose, cli.quiet, color, json);
             scoop_uv::cli::commands::import(&out, &path, name.as_deref(), force)
         }
+        Commands::Tag { name, tags, json } => {
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
+            scoop_uv::cli::commands::tag(&output, &name, &tags)
+        }
+        Commands::Untag { name, tags, json } => {
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
+            scoop_uv::cli::commands::untag(&output, &name, &tags)
+        }
+        Commands::Template { command } => match command {
+            TemplateCommand::List { json } => {
+                let output = Output::new(cli.verbose, cli.quiet, color, json);
+                scoop_uv::cli::commands::template_list(&output)
+            }
+            TemplateCommand::Show { name, json } => {
+                let output = Output::new(cli.verbose, cli.quiet, color, json);
+                scoop_uv::cli::commands::template_show(&output, &name)
+            }
+        },
         Commands::Which { exe, env, json } => {
-            let output = Output::new(0, cli.quiet, cli.no_color, json);
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
             scoop_uv::cli::commands::which(&output, &exe, env.as_deref())
         }
         Commands::Prune { json } => {
-            let output = Output::new(0, cli.quiet, cli.no_color, json);
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
             scoop_uv::cli::commands::prune(&output)
         }
         Commands::Gc {
@@ -199,15 +413,15 @@ fn main() -> Result<()> {
             older_than,
             json,
         } => {
-            let output = Output::new(0, cli.quiet, cli.no_color, json);
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
             scoop_uv::cli::commands::gc(&output, yes, aggressive, older_than.as_deref())
         }
         Commands::Man { output_dir, json } => {
-            let output = Output::new(0, cli.quiet, cli.no_color, json);
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
             scoop_uv::cli::commands::man(&output, output_dir.as_deref())
         }
         Commands::Verify { name, strict, json } => {
-            let output = Output::new(0, cli.quiet, cli.no_color, json);
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
             scoop_uv::cli::commands::verify(&output, name.as_deref(), strict)
         }
         Commands::Diff {
@@ -218,7 +432,7 @@ fn main() -> Result<()> {
             strict,
             json,
         } => {
-            let output = Output::new(0, cli.quiet, cli.no_color, json);
+            let output = Output::new(cli.verbose, cli.quiet, color, json);
             let mode = match (packages_only, metadata_only) {
                 (true, false) => scoop_uv::cli::commands::DiffMode::PackagesOnly,
                 (false, true) => scoop_uv::cli::commands::DiffMode::MetadataOnly,
@@ -245,7 +459,7 @@ fn main() -> Result<()> {
     //     scripts can distinguish source-discovery failures (migrate, exit 3)
     //     from generic operational errors (exit 1).
     if let Err(e) = result {
-        let output = Output::new(0, cli.quiet, cli.no_color, false);
+        let output = Output::new(cli.verbose, cli.quiet, color, false);
         if matches!(
             e.render_policy(),
             scoop_uv::error::ErrorRenderPolicy::Default
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::error::ScoopError;
+
 /// Success response wrapper
 #[derive(Serialize)]
 pub struct JsonResponse<T: Serialize> {
@@ -26,13 +28,13 @@ impl<T: Serialize> JsonResponse<T> {
 
 /// Error response wrapper
 #[derive(Serialize)]
-pub struct JsonErrorResponse {
+pub struct JsonErrorResponse<E: Serialize = JsonError> {
     /// Response status (always "error")
     pub status: &'static str,
     /// Command that was executed
     pub command: &'static str,
-    /// Error details
-    pub error: JsonError,
+    /// Error details: a [`JsonError`], or [`ScoopError::to_json`]'s object
+    pub error: E,
 }
 
 /// Error details
@@ -68,6 +70,17 @@ impl JsonErrorResponse {
     }
 }
 
+impl JsonErrorResponse<serde_json::Value> {
+    /// Create the error response for `error`, in the current locale
+    pub fn from_error(command: &'static str, error: &ScoopError) -> Self {
+        Self {
+            status: "error",
+            command,
+            error: error.to_json(),
+        }
+    }
+}
+
 // ============================================================================
 // Command-specific data types
 // ============================================================================
@@ -77,16 +90,54 @@ impl JsonErrorResponse {
 pub struct ListEnvsData {
     pub virtualenvs: Vec<VirtualenvInfo>,
     pub total: usize,
+    /// Sum of every listed env's `size_bytes`; only with `--sizes`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_size_bytes: Option<u64>,
 }
 
-/// Virtualenv info for JSON output
+/// `list --group-by` response data
+#[derive(Serialize)]
+pub struct ListGroupedEnvsData {
+    /// `python` or `tag`
+    pub group_by: &'static str,
+    /// Group key -> its environments, keys in display order
+    pub groups: EnvGroups,
+    /// Distinct environments listed (one with two tags counts once)
+    pub total: usize,
+    /// Sum of every listed env's `size_bytes`, each env counted once;
+    /// only with `--sizes`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_size_bytes: Option<u64>,
+}
+
+/// `list --count` response data
 #[derive(Serialize)]
+pub struct ListCountData {
+    /// Environments left after filtering (system Python not included)
+    pub count: usize,
+}
+
+/// Ordered group key -> entries, serialized as a JSON object whose keys
+/// keep the given order (`3.9` before `3.11`, not string order).
+pub struct EnvGroups(pub Vec<(String, Vec<VirtualenvInfo>)>);
+
+impl Serialize for EnvGroups {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.iter().map(|(key, entries)| (key, entries)))
+    }
+}
+
+/// Virtualenv info for JSON output
+#[derive(Serialize, Clone)]
 pub struct VirtualenvInfo {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub python: Option<String>,
     pub path: String,
+    /// In use here: named by `SCUV_ACTIVE`, or else by the version files.
     pub active: bool,
+    /// Named in the global version file (`scuv use --global`).
+    pub is_global_default: bool,
     /// Creation timestamp (RFC 3339). Omitted when metadata is missing
     /// or doesn't carry `created_at` yet (legacy envs).
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -95,6 +146,15 @@ pub struct VirtualenvInfo {
     /// been activated since the field landed.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_used: Option<String>,
+    /// Tags from metadata; always present (empty when untagged).
+    pub tags: Vec<String>,
+    /// `"ok"`, or `"broken"` when the env's Python executable or
+    /// `pyvenv.cfg` is missing (the same test `doctor` uses).
+    pub health: &'static str,
+    /// Disk size of the env directory; only with `list --sizes`, and
+    /// never for the system Python.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_bytes: Option<u64>,
 }
 
 /// List pythons response data
@@ -112,6 +172,8 @@ pub struct PythonInfo {
     pub implementation: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
+    /// Installed and managed by uv, as opposed to a discovered system Python.
+    pub managed: bool,
 }
 
 /// Create response data
@@ -122,6 +184,37 @@ pub struct CreateData {
     pub path: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub python_path: Option<String>,
+    /// Symlink created by `--link <PATH>`, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<String>,
+    /// `true` when `--dry-run` was passed (nothing was created).
+    pub dry_run: bool,
+    /// Shell command that activates the env, e.g. `scuv use myenv`.
+    pub activate_command: String,
+    /// Result of the configured post-create hook; omitted when none ran.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hook: Option<HookData>,
+    /// Template applied with `--template`, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    /// Packages installed from the template (planned ones under
+    /// `--dry-run`); omitted when there are none.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub packages: Vec<String>,
+    /// `.scuv-version` written by `--name-from-cwd`; omitted otherwise.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_file: Option<String>,
+}
+
+/// Post-create hook result (`create.post_create_hook`)
+#[derive(Serialize)]
+pub struct HookData {
+    pub command: String,
+    pub success: bool,
+    /// `null` when the hook couldn't be started or was killed by a signal.
+    pub exit_code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
 }
 
 /// Use response data
@@ -133,6 +226,8 @@ pub struct UseData {
     pub version_file: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub symlink: Option<String>,
+    /// `true` when `use --create` had to create the environment first.
+    pub env_created: bool,
 }
 
 /// Remove response data
@@ -140,14 +235,50 @@ pub struct UseData {
 pub struct RemoveData {
     pub name: String,
     pub path: String,
+    /// `true` when `--dry-run` was passed (nothing was deleted).
+    pub dry_run: bool,
+    /// Version files pinning the env that were deleted (or, with
+    /// `dry_run`, would be). Empty with `--keep-version-files`.
+    pub cleaned_version_files: Vec<String>,
+    /// PIDs killed by `--kill` (or, with `dry_run`, that would be);
+    /// omitted when there were none.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub killed_processes: Vec<u32>,
 }
 
 /// Install response data
 #[derive(Serialize)]
 pub struct InstallData {
+    /// The concrete version uv installed (`3.12.7`).
     pub version: String,
+    /// The version asked for (`3.12`), after `latest` / `stable` resolution.
+    pub requested: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
+    /// `true` when an existing install was removed first (`--reinstall`).
+    pub reinstalled: bool,
+    /// Wall-clock time of the whole command, in milliseconds.
+    pub elapsed_ms: u64,
+    /// Result of `--verify` (absent when verification was off).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification: Option<InstallVerification>,
+    /// The new `create` default, when `--default` was passed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_python: Option<String>,
+}
+
+/// What `install --verify` found for the new interpreter.
+///
+/// Only ever reported on success: a failed check aborts the install with
+/// `PYTHON_INSTALL_FAILED`.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct InstallVerification {
+    /// The interpreter that was run.
+    pub path: String,
+    /// Version it printed for `--version`.
+    pub reported_version: String,
+    /// Whether uv lists it as a uv-managed (downloaded) install.
+    pub managed: bool,
 }
 
 /// Uninstall response data
@@ -157,6 +288,11 @@ pub struct UninstallData {
     /// Environments removed by cascade (only present if --cascade used)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub removed_envs: Option<Vec<String>>,
+    /// Environments whose Python matches the target (only present with --dry-run)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dependent_envs: Option<Vec<String>>,
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
 /// Package info for JSON output
@@ -225,6 +361,58 @@ pub struct WhichData {
     pub path: String,
 }
 
+/// `scuv resolve --json` response data
+#[derive(Serialize)]
+pub struct ResolveData {
+    /// Resolved environment name (or `system`); `None` when nothing matched.
+    pub name: Option<String>,
+    /// Every source checked, in order; the last one decided.
+    pub chain: Vec<crate::core::ResolutionStep>,
+}
+
+/// `scuv tag` / `scuv untag` response data
+#[derive(Serialize)]
+pub struct TagData {
+    pub name: String,
+    /// Full tag set after the update, sorted.
+    pub tags: Vec<String>,
+    /// `false` when the request was a no-op (tags already present/absent).
+    pub changed: bool,
+}
+
+/// One environment template (`scuv template show`, and each entry of
+/// `scuv template list`)
+#[derive(Serialize)]
+pub struct TemplateData {
+    pub name: String,
+    /// `null` when the template leaves the Python version to `create`.
+    pub python: Option<String>,
+    pub packages: Vec<String>,
+}
+
+/// `scuv template list` response data
+#[derive(Serialize)]
+pub struct TemplateListData {
+    /// Sorted by name.
+    pub templates: Vec<TemplateData>,
+}
+
+/// `scuv hook prompt` response data
+#[derive(Serialize)]
+pub struct HookPromptData {
+    /// Active environment (`SCUV_ACTIVE`), `null` when none.
+    pub active: Option<String>,
+    /// Rendered prompt segment; empty when no env is active.
+    pub segment: String,
+}
+
+/// `scuv hook cd` response data
+#[derive(Serialize)]
+pub struct HookCdData {
+    pub shell: String,
+    pub script: String,
+}
+
 /// `scuv clone` response data
 #[derive(Serialize)]
 pub struct CloneData {
@@ -294,6 +482,21 @@ pub struct StatusData {
     pub packages: Option<usize>,
 }
 
+/// `scuv version` response data
+#[derive(Serialize)]
+pub struct VersionData {
+    /// scuv's own version (`CARGO_PKG_VERSION`).
+    pub scuv: &'static str,
+    /// Raw `uv --version` output; `None` when uv isn't on `PATH`.
+    pub uv: Option<String>,
+    /// Active or configured env name, `"system"`, or `None`.
+    pub env: Option<String>,
+    /// Python version of `env` (from metadata), when known.
+    pub python: Option<String>,
+    /// `"<os> <arch>"`, e.g. `"linux x86_64"`.
+    pub os: String,
+}
+
 /// Detailed environment info for JSON output
 #[derive(Serialize)]
 pub struct EnvInfoData {
@@ -301,18 +504,26 @@ pub struct EnvInfoData {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub python: Option<String>,
     pub path: String,
+    /// Named by `SCUV_ACTIVE`
     pub active: bool,
+    /// Named in the global version file (`scuv use --global`)
+    pub global_default: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
     /// RFC 3339 last-use timestamp, omitted when unknown. See
     /// `StatusData::last_used` for the full contract.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_used: Option<String>,
+    pub tags: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub size_bytes: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub size_display: Option<String>,
-    pub packages: PackagesInfo,
+    /// True when `info --no-uv` skipped the package listing; `packages` is
+    /// then omitted.
+    pub packages_skipped: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub packages: Option<PackagesInfo>,
 }
 
 // ============================================================================
@@ -342,6 +553,7 @@ mod tests {
             ListEnvsData {
                 virtualenvs: vec![],
                 total: 0,
+                total_size_bytes: None,
             },
         );
         let json = serde_json::to_string(&response).unwrap();
@@ -367,12 +579,20 @@ mod tests {
                 python: "3.12".into(),
                 path: "/path/to/env".into(),
                 python_path: None,
+                link: None,
+                dry_run: false,
+                activate_command: "scuv use myenv".into(),
+                hook: None,
+                template: None,
+                packages: Vec::new(),
+                version_file: None,
             },
         );
         let json = serde_json::to_string(&response).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed["data"]["name"], "myenv");
         assert_eq!(parsed["data"]["python"], "3.12");
+        assert_eq!(parsed["data"]["activate_command"], "scuv use myenv");
     }
 
     #[test]
@@ -419,6 +639,16 @@ mod tests {
         assert!(json.contains(r#""suggestion":"try this""#));
     }
 
+    #[test]
+    fn test_json_error_response_from_error_wraps_error_object() {
+        let err = ScoopError::UvNotFound;
+        let response = JsonErrorResponse::from_error("create", &err);
+        let json = serde_json::to_string(&response).unwrap();
+        assert!(json.starts_with(r#"{"status":"error","command":"create","error":{"#));
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["error"], err.to_json());
+    }
+
     #[test]
     fn test_json_error_response_serialization() {
         let response = JsonErrorResponse::error("create", "ENV_EXISTS", "already exists".into())
@@ -442,6 +672,7 @@ mod tests {
         let data = ListEnvsData {
             virtualenvs: vec![],
             total: 0,
+            total_size_bytes: None,
         };
         let json = serde_json::to_string(&data).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
@@ -458,19 +689,28 @@ mod tests {
                     python: Some("3.12".into()),
                     path: "/path1".into(),
                     active: true,
+                    is_global_default: false,
                     created_at: None,
                     last_used: None,
+                    tags: Vec::new(),
+                    health: "ok",
+                    size_bytes: None,
                 },
                 VirtualenvInfo {
                     name: "env2".into(),
                     python: None,
                     path: "/path2".into(),
                     active: false,
+                    is_global_default: false,
                     created_at: None,
                     last_used: None,
+                    tags: Vec::new(),
+                    health: "ok",
+                    size_bytes: None,
                 },
             ],
             total: 2,
+            total_size_bytes: None,
         };
         let json = serde_json::to_string(&data).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
@@ -485,8 +725,12 @@ mod tests {
             python: None,
             path: "/path".into(),
             active: false,
+            is_global_default: false,
             created_at: None,
             last_used: None,
+            tags: Vec::new(),
+            health: "ok",
+            size_bytes: None,
         };
         let json = serde_json::to_string(&info).unwrap();
         assert!(!json.contains("python"));
@@ -502,8 +746,12 @@ mod tests {
             python: Some("3.12".into()),
             path: "/p".into(),
             active: false,
+            is_global_default: false,
             created_at: Some("2024-01-15T10:30:00+00:00".to_string()),
             last_used: Some("2026-06-02T12:00:00+00:00".to_string()),
+            tags: Vec::new(),
+            health: "ok",
+            size_bytes: None,
         };
         let json = serde_json::to_string(&info).unwrap();
         assert!(
@@ -526,14 +774,36 @@ mod tests {
             python: None,
             path: "/p".into(),
             active: false,
+            is_global_default: false,
             created_at: None,
             last_used: None,
+            tags: Vec::new(),
+            health: "ok",
+            size_bytes: None,
         };
         let json = serde_json::to_string(&info).unwrap();
         assert!(!json.contains("created_at"), "{json}");
         assert!(!json.contains("last_used"), "{json}");
     }
 
+    #[test]
+    fn test_virtualenv_info_always_reports_health() {
+        let info = VirtualenvInfo {
+            name: "broken".into(),
+            python: None,
+            path: "/p".into(),
+            active: false,
+            is_global_default: false,
+            created_at: None,
+            last_used: None,
+            tags: Vec::new(),
+            health: "broken",
+            size_bytes: None,
+        };
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(json["health"], "broken");
+    }
+
     #[test]
     fn test_virtualenv_info_with_python() {
         let info = VirtualenvInfo {
@@ -541,14 +811,50 @@ mod tests {
             python: Some("3.11".into()),
             path: "/path".into(),
             active: true,
+            is_global_default: false,
             created_at: None,
             last_used: None,
+            tags: Vec::new(),
+            health: "ok",
+            size_bytes: None,
         };
         let json = serde_json::to_string(&info).unwrap();
         assert!(json.contains(r#""python":"3.11""#));
         assert!(json.contains(r#""active":true"#));
     }
 
+    fn env_info(packages: Option<PackagesInfo>) -> EnvInfoData {
+        EnvInfoData {
+            name: "test".into(),
+            python: Some("3.12".into()),
+            path: "/path".into(),
+            active: false,
+            global_default: false,
+            created_at: None,
+            last_used: None,
+            tags: Vec::new(),
+            size_bytes: None,
+            size_display: None,
+            packages_skipped: packages.is_none(),
+            packages,
+        }
+    }
+
+    #[test]
+    fn test_env_info_skipped_packages_omits_listing() {
+        let json = serde_json::to_value(env_info(None)).unwrap();
+        assert_eq!(json["packages_skipped"], true);
+        assert!(json.get("packages").is_none(), "{json}");
+    }
+
+    #[test]
+    fn test_env_info_with_packages_keeps_listing() {
+        let packages = vec![("pip".to_string(), "24.0".to_string())];
+        let json = serde_json::to_value(env_info(Some(PackagesInfo::new(&packages, 5)))).unwrap();
+        assert_eq!(json["packages_skipped"], false);
+        assert_eq!(json["packages"]["total"], 1);
+    }
+
     // ========================================
     // ListPythonsData / PythonInfo Tests
     // ========================================
@@ -572,11 +878,13 @@ mod tests {
                     version: "3.12.0".into(),
                     implementation: Some("cpython".into()),
                     path: Some("/usr/bin/python3.12".into()),
+                    managed: true,
                 },
                 PythonInfo {
                     version: "3.11.0".into(),
                     implementation: None,
                     path: None,
+                    managed: false,
                 },
             ],
             total: 2,
@@ -584,6 +892,8 @@ mod tests {
         let json = serde_json::to_string(&data).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed["pythons"].as_array().unwrap().len(), 2);
+        assert_eq!(parsed["pythons"][0]["managed"], true);
+        assert_eq!(parsed["pythons"][1]["managed"], false);
     }
 
     #[test]
@@ -592,6 +902,7 @@ mod tests {
             version: "3.12".into(),
             implementation: None,
             path: None,
+            managed: false,
         };
         let json = serde_json::to_string(&info).unwrap();
         assert!(!json.contains("path"));
@@ -604,6 +915,7 @@ mod tests {
             version: "3.12".into(),
             implementation: Some("cpython".into()),
             path: Some("/usr/bin/python3".into()),
+            managed: false,
         };
         let json = serde_json::to_string(&info).unwrap();
         assert!(json.contains(r#""path":"/usr/bin/python3""#));
@@ -616,6 +928,7 @@ mod tests {
             version: "3.12".into(),
             implementation: None,
             path: Some("/usr/bin/python3".into()),
+            managed: false,
         };
         let json = serde_json::to_string(&info).unwrap();
         assert!(!json.contains("implementation"));
@@ -633,6 +946,13 @@ mod tests {
             python: "3.12".into(),
             path: "/home/user/.scoop/virtualenvs/myenv".into(),
             python_path: None,
+            link: None,
+            dry_run: false,
+            activate_command: "scuv use myenv".into(),
+            hook: None,
+            template: None,
+            packages: Vec::new(),
+            version_file: None,
         };
         let json = serde_json::to_string(&data).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
@@ -641,6 +961,32 @@ mod tests {
         assert!(parsed["path"].as_str().unwrap().contains("myenv"));
     }
 
+    #[test]
+    fn test_create_data_reports_template_packages_only_when_used() {
+        let mut data = CreateData {
+            name: "ds".into(),
+            python: "3.12".into(),
+            path: "/envs/ds".into(),
+            python_path: None,
+            link: None,
+            dry_run: false,
+            activate_command: "scuv use ds".into(),
+            hook: None,
+            template: None,
+            packages: Vec::new(),
+            version_file: None,
+        };
+        let plain = serde_json::to_value(&data).unwrap();
+        assert!(plain.get("template").is_none(), "{plain}");
+        assert!(plain.get("packages").is_none(), "{plain}");
+
+        data.template = Some("data-science".into());
+        data.packages = vec!["numpy".into(), "pandas>=2".into()];
+        let json = serde_json::to_value(&data).unwrap();
+        assert_eq!(json["template"], "data-science");
+        assert_eq!(json["packages"], serde_json::json!(["numpy", "pandas>=2"]));
+    }
+
     // ========================================
     // UseData Tests
     // ========================================
@@ -652,6 +998,7 @@ mod tests {
             mode: "local",
             version_file: Some("/project/.scoop-version".into()),
             symlink: None,
+            env_created: false,
         };
         let json = serde_json::to_string(&data).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
@@ -667,6 +1014,7 @@ mod tests {
             mode: "global",
             version_file: None,
             symlink: None,
+            env_created: false,
         };
         let json = serde_json::to_string(&data).unwrap();
         assert_eq!(data.mode, "global");
@@ -681,11 +1029,29 @@ mod tests {
             mode: "local",
             version_file: Some("/project/.scoop-version".into()),
             symlink: Some("/project/.venv".into()),
+            env_created: false,
         };
         let json = serde_json::to_string(&data).unwrap();
         assert!(json.contains(r#""symlink":"/project/.venv""#));
     }
 
+    #[test]
+    fn test_use_data_always_reports_env_created() {
+        let mut data = UseData {
+            name: "myenv".into(),
+            mode: "global",
+            version_file: None,
+            symlink: None,
+            env_created: false,
+        };
+        let json = serde_json::to_value(&data).unwrap();
+        assert_eq!(json["env_created"], false);
+
+        data.env_created = true;
+        let json = serde_json::to_value(&data).unwrap();
+        assert_eq!(json["env_created"], true);
+    }
+
     // ========================================
     // RemoveData Tests
     // ========================================
@@ -695,10 +1061,17 @@ mod tests {
         let data = RemoveData {
             name: "oldenv".into(),
             path: "/home/user/.scoop/virtualenvs/oldenv".into(),
+            dry_run: false,
+            cleaned_version_files: vec!["/home/user/.scoop/version".into()],
+            killed_processes: Vec::new(),
         };
         let json = serde_json::to_string(&data).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed["name"], "oldenv");
+        assert_eq!(
+            parsed["cleaned_version_files"][0],
+            "/home/user/.scoop/version"
+        );
         assert!(parsed["path"].as_str().unwrap().contains("oldenv"));
     }
 
@@ -710,20 +1083,42 @@ mod tests {
     fn test_install_data_with_path() {
         let data = InstallData {
             version: "3.12.0".into(),
+            requested: "3.12".into(),
             path: Some("/usr/local/bin/python3.12".into()),
+            reinstalled: false,
+            elapsed_ms: 1500,
+            verification: Some(InstallVerification {
+                path: "/usr/local/bin/python3.12".into(),
+                reported_version: "3.12.0".into(),
+                managed: true,
+            }),
+            default_python: Some("3.12.0".into()),
         };
         let json = serde_json::to_string(&data).unwrap();
+        assert!(json.contains(r#""version":"3.12.0","requested":"3.12""#));
+        assert!(json.contains(r#""default_python":"3.12.0""#));
         assert!(json.contains(r#""path""#));
+        assert!(json.contains(r#""verification":{"#));
+        assert!(json.contains(r#""reported_version":"3.12.0""#));
     }
 
     #[test]
     fn test_install_data_without_path() {
         let data = InstallData {
             version: "3.12.0".into(),
+            requested: "3.12.0".into(),
             path: None,
+            reinstalled: true,
+            elapsed_ms: 0,
+            verification: None,
+            default_python: None,
         };
         let json = serde_json::to_string(&data).unwrap();
         assert!(!json.contains("path"));
+        assert!(!json.contains("verification"));
+        assert!(!json.contains("default_python"));
+        assert!(json.contains(r#""elapsed_ms":0"#));
+        assert!(json.contains(r#""reinstalled":true"#));
     }
 
     // ========================================
@@ -735,6 +1130,8 @@ mod tests {
         let data = UninstallData {
             version: "3.11.0".into(),
             removed_envs: None,
+            dependent_envs: None,
+            dry_run: false,
         };
         let json = serde_json::to_string(&data).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
@@ -752,8 +1149,12 @@ mod tests {
             python: Some("3.12".into()),
             path: "/경로/테스트".into(),
             active: true,
+            is_global_default: false,
             created_at: None,
             last_used: None,
+            tags: Vec::new(),
+            health: "ok",
+            size_bytes: None,
         };
         let json = serde_json::to_string(&info).unwrap();
         // Should serialize correctly
@@ -769,6 +1170,13 @@ mod tests {
             python: "".into(),
             path: "".into(),
             python_path: None,
+            link: None,
+            dry_run: false,
+            activate_command: "scuv use myenv".into(),
+            hook: None,
+            template: None,
+            packages: Vec::new(),
+            version_file: None,
         };
         let json = serde_json::to_string(&data).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
@@ -783,6 +1191,13 @@ mod tests {
             python: "3.12".into(),
             path: r#"/path/with\backslash"#.into(),
             python_path: None,
+            link: None,
+            dry_run: false,
+            activate_command: "scuv use myenv".into(),
+            hook: None,
+            template: None,
+            packages: Vec::new(),
+            version_file: None,
         };
         let json = serde_json::to_string(&data).unwrap();
         // JSON escaping should handle special chars
@@ -799,6 +1214,13 @@ mod tests {
             python: "3.12".into(),
             path: "/path".into(),
             python_path: None,
+            link: None,
+            dry_run: false,
+            activate_command: "scuv use myenv".into(),
+            hook: None,
+            template: None,
+            packages: Vec::new(),
+            version_file: None,
         };
         let json = serde_json::to_string(&data).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
@@ -812,6 +1234,13 @@ mod tests {
             python: "3.12".into(),
             path: "/path/with spaces/to/env".into(),
             python_path: None,
+            link: None,
+            dry_run: false,
+            activate_command: "scuv use myenv".into(),
+            hook: None,
+            template: None,
+            packages: Vec::new(),
+            version_file: None,
         };
         let json = serde_json::to_string(&data).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
@@ -835,19 +1264,28 @@ mod tests {
                     python: Some("3.12".into()),
                     path: "/path/to/env1".into(),
                     active: true,
+                    is_global_default: false,
                     created_at: None,
                     last_used: None,
+                    tags: Vec::new(),
+                    health: "ok",
+                    size_bytes: None,
                 },
                 VirtualenvInfo {
                     name: "env2".into(),
                     python: None,
                     path: "/path/to/env2".into(),
                     active: false,
+                    is_global_default: false,
                     created_at: None,
                     last_used: None,
+                    tags: Vec::new(),
+                    health: "ok",
+                    size_bytes: None,
                 },
             ],
             total: 2,
+            total_size_bytes: None,
         };
 
         let json = serde_json::to_string(&original).unwrap();
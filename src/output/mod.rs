@@ -9,9 +9,15 @@ pub use json::*;
 pub use spinner::Spinner;
 pub use time::{format_age, format_last_used_value};
 
+use std::io::{IsTerminal, Write};
+use std::path::PathBuf;
+use std::sync::Arc;
+
 use owo_colors::OwoColorize;
 
-use crate::core::doctor::{CheckResult, CheckStatus};
+use crate::cli::ColorChoice;
+use crate::core::doctor::{CheckResult, CheckStatus, DoctorSummary};
+use crate::uv::StderrEcho;
 
 // ============================================================================
 // Size Formatting
@@ -58,9 +64,17 @@ pub struct Output {
 
 impl Output {
     /// Create a new output handler
-    pub fn new(verbose: u8, quiet: bool, no_color: bool, json: bool) -> Self {
-        // Also check NO_COLOR environment variable
-        let no_color = no_color || std::env::var("NO_COLOR").is_ok();
+    ///
+    /// `ColorChoice::Auto` disables color when `NO_COLOR` is set or stderr
+    /// (where messages go) isn't a terminal; `Always` forces it on. JSON
+    /// mode never colors.
+    pub fn new(verbose: u8, quiet: bool, color: ColorChoice, json: bool) -> Self {
+        let no_color = resolve_no_color(
+            color,
+            std::io::stderr().is_terminal(),
+            std::env::var("NO_COLOR").is_ok(),
+            json,
+        );
 
         Self {
             verbose,
@@ -135,6 +149,26 @@ impl Output {
         }
     }
 
+    /// A printer for subprocess lines that should read as [`debug`](Self::debug)
+    /// output, each tagged with `prefix`; `None` when debug output is off.
+    ///
+    /// Owned rather than borrowing `self`, so it can be handed to
+    /// [`UvClient`](crate::uv::UvClient) and called from a reader thread.
+    pub fn debug_lines(&self, prefix: &'static str) -> Option<StderrEcho> {
+        if self.quiet || self.json || self.verbose == 0 {
+            return None;
+        }
+
+        let no_color = self.no_color;
+        Some(Arc::new(move |line: &str| {
+            if no_color {
+                eprintln!("  {prefix}{line}");
+            } else {
+                eprintln!("  {}", format!("{prefix}{line}").dimmed());
+            }
+        }))
+    }
+
     /// Print a line to stdout (for list output)
     pub fn println(&self, msg: &str) {
         if self.quiet {
@@ -189,10 +223,7 @@ impl Output {
         if !self.json {
             return;
         }
-        let mut response = JsonErrorResponse::error(command, error.code(), error.to_string());
-        if let Some(suggestion) = error.suggestion() {
-            response = response.with_suggestion(suggestion);
-        }
+        let response = JsonErrorResponse::from_error(command, error);
         eprintln!(
             "{}",
             serde_json::to_string_pretty(&response).unwrap_or_default()
@@ -200,9 +231,30 @@ impl Output {
     }
 }
 
+/// Decide whether to suppress color, given the environment it applies to.
+///
+/// Split from [`Output::new`] so the TTY and `NO_COLOR` inputs can be
+/// exercised without a real terminal.
+fn resolve_no_color(
+    color: ColorChoice,
+    stderr_is_terminal: bool,
+    no_color_env: bool,
+    json: bool,
+) -> bool {
+    if json {
+        // Escape codes would corrupt machine-readable output.
+        return true;
+    }
+    match color {
+        ColorChoice::Never => true,
+        ColorChoice::Always => false,
+        ColorChoice::Auto => no_color_env || !stderr_is_terminal,
+    }
+}
+
 impl Default for Output {
     fn default() -> Self {
-        Self::new(0, false, false, false)
+        Self::new(0, false, ColorChoice::Auto, false)
     }
 }
 
@@ -221,15 +273,35 @@ impl Output {
         eprintln!();
     }
 
+    /// Print the heading that opens one home's section of a multi-root
+    /// doctor report.
+    pub fn doctor_root_header(&self, root: &std::path::Path) {
+        if self.quiet || self.json {
+            return;
+        }
+        eprintln!();
+        let heading = format!("== {} ==", root.display());
+        if self.no_color {
+            eprintln!("{heading}");
+        } else {
+            eprintln!("{}", heading.bold());
+        }
+    }
+
     /// Print a single check result.
     pub fn doctor_check(&self, result: &CheckResult) {
+        let _ = self.write_doctor_check(&mut std::io::stderr().lock(), result);
+    }
+
+    /// Render a single check result to `w` (stderr for [`doctor_check`](Self::doctor_check)).
+    fn write_doctor_check(&self, w: &mut impl Write, result: &CheckResult) -> std::io::Result<()> {
         if self.json {
-            return;
+            return Ok(());
         }
 
         // Skip OK results in quiet mode
         if self.quiet && result.is_ok() {
-            return;
+            return Ok(());
         }
 
         let (icon, color_fn): (&str, fn(&str) -> String) = match &result.status {
@@ -247,18 +319,18 @@ impl Output {
 
         // Print with or without color
         if self.no_color {
-            eprintln!("{} {}", icon, message);
+            writeln!(w, "{} {}", icon, message)?;
         } else {
-            eprintln!("{} {}", color_fn(icon), message);
+            writeln!(w, "{} {}", color_fn(icon), message)?;
         }
 
         // Print details in verbose mode
         if self.verbose > 0 {
             if let Some(details) = &result.details {
                 if self.no_color {
-                    eprintln!("  {}", details);
+                    writeln!(w, "  {}", details)?;
                 } else {
-                    eprintln!("  {}", details.dimmed());
+                    writeln!(w, "  {}", details.dimmed())?;
                 }
             }
         }
@@ -266,30 +338,41 @@ impl Output {
         // Print suggestion for errors/warnings
         if let Some(suggestion) = &result.suggestion {
             if self.no_color {
-                eprintln!("  → {}", suggestion);
+                writeln!(w, "  → {}", suggestion)?;
             } else {
-                eprintln!("  {} {}", "→".cyan(), suggestion);
+                writeln!(w, "  {} {}", "→".cyan(), suggestion)?;
             }
         }
+        Ok(())
     }
 
     /// Print doctor report summary.
     pub fn doctor_summary(&self, results: &[CheckResult]) {
+        let _ = self.write_doctor_summary(&mut std::io::stderr().lock(), results);
+    }
+
+    /// Render the doctor summary to `w` (stderr for [`doctor_summary`](Self::doctor_summary)).
+    fn write_doctor_summary(
+        &self,
+        w: &mut impl Write,
+        results: &[CheckResult],
+    ) -> std::io::Result<()> {
         if self.json {
-            return;
+            return Ok(());
         }
 
-        let errors = results.iter().filter(|r| r.is_error()).count();
-        let warnings = results.iter().filter(|r| r.is_warning()).count();
+        let DoctorSummary {
+            errors, warnings, ..
+        } = DoctorSummary::from_results(results);
 
-        eprintln!();
-        eprintln!("──────────────────────────────────");
+        writeln!(w)?;
+        writeln!(w, "──────────────────────────────────")?;
 
         if errors == 0 && warnings == 0 {
             if self.no_color {
-                eprintln!("All checks passed!");
+                writeln!(w, "All checks passed!")?;
             } else {
-                eprintln!("{}", "All checks passed!".green());
+                writeln!(w, "{}", "All checks passed!".green())?;
             }
         } else {
             let mut parts = Vec::new();
@@ -302,67 +385,163 @@ impl Output {
 
             let summary = format!("Found {}.", parts.join(" and "));
             if self.no_color {
-                eprintln!("{}", summary);
+                writeln!(w, "{}", summary)?;
             } else {
-                eprintln!("{}", summary.yellow());
+                writeln!(w, "{}", summary.yellow())?;
             }
         }
+        Ok(())
     }
 
     /// Print doctor report as JSON.
+    ///
+    /// Unlike the text report, which only shows details under `--verbose`,
+    /// every check carries its `details` (`null` when it has none) so
+    /// monitoring tools get full context at any verbosity.
     pub fn doctor_json(&self, results: &[CheckResult]) {
         if !self.json {
             return;
         }
 
-        let json_results: Vec<serde_json::Value> = results
-            .iter()
-            .map(|r| {
-                let status = match &r.status {
-                    CheckStatus::Ok => "ok",
-                    CheckStatus::Warning(_) => "warning",
-                    CheckStatus::Error(_) => "error",
-                };
-
-                let message = match &r.status {
-                    CheckStatus::Ok => None,
-                    CheckStatus::Warning(msg) => Some(msg.clone()),
-                    CheckStatus::Error(msg) => Some(msg.clone()),
-                };
-
-                serde_json::json!({
-                    "id": r.id,
-                    "name": r.name,
-                    "status": status,
-                    "message": message,
-                    "suggestion": r.suggestion,
-                    "details": r.details,
-                })
-            })
-            .collect();
-
-        let errors = results.iter().filter(|r| r.is_error()).count();
-        let warnings = results.iter().filter(|r| r.is_warning()).count();
-        let ok = results.iter().filter(|r| r.is_ok()).count();
-
-        let output = serde_json::json!({
-            "version": env!("CARGO_PKG_VERSION"),
-            "summary": {
-                "total": results.len(),
-                "ok": ok,
-                "warnings": warnings,
-                "errors": errors,
-            },
-            "checks": json_results,
-        });
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&doctor_json_value(results)).unwrap_or_default()
+        );
+    }
+
+    /// Print a multi-root doctor report as JSON: an overall `summary`, then
+    /// one entry per home under `roots`, each with its own `summary` and
+    /// `checks`.
+    pub fn doctor_roots_json(&self, roots: &[(PathBuf, Vec<CheckResult>)]) {
+        if !self.json {
+            return;
+        }
 
         println!(
             "{}",
-            serde_json::to_string_pretty(&output).unwrap_or_default()
+            serde_json::to_string_pretty(&doctor_roots_json_value(roots)).unwrap_or_default()
         );
     }
 }
 
+/// Write the `doctor --json` document to `path`, creating parent
+/// directories as needed (for `doctor --output`).
+///
+/// # Errors
+///
+/// Returns [`crate::error::ScoopError::Io`] if the directory or file can't
+/// be written.
+pub fn write_doctor_json(
+    path: &std::path::Path,
+    results: &[CheckResult],
+) -> crate::error::Result<()> {
+    write_json_report(path, &doctor_json_value(results))
+}
+
+/// Like [`write_doctor_json`], for the multi-root document of
+/// [`Output::doctor_roots_json`].
+///
+/// # Errors
+///
+/// Returns [`crate::error::ScoopError::Io`] if the directory or file can't
+/// be written.
+pub fn write_doctor_roots_json(
+    path: &std::path::Path,
+    roots: &[(PathBuf, Vec<CheckResult>)],
+) -> crate::error::Result<()> {
+    write_json_report(path, &doctor_roots_json_value(roots))
+}
+
+fn write_json_report(
+    path: &std::path::Path,
+    value: &serde_json::Value,
+) -> crate::error::Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut json = serde_json::to_string_pretty(value)?;
+    json.push('\n');
+    std::fs::write(path, json)?;
+    Ok(())
+}
+
+/// Build the `doctor --json` document.
+fn doctor_json_value(results: &[CheckResult]) -> serde_json::Value {
+    serde_json::json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "generated_at": generated_at(),
+        "summary": summary_json(DoctorSummary::from_results(results)),
+        "checks": checks_json(results),
+    })
+}
+
+/// Build the multi-root `doctor --json` document.
+fn doctor_roots_json_value(roots: &[(PathBuf, Vec<CheckResult>)]) -> serde_json::Value {
+    let mut total = DoctorSummary::default();
+    let roots: Vec<serde_json::Value> = roots
+        .iter()
+        .map(|(root, results)| {
+            let summary = DoctorSummary::from_results(results);
+            total += summary;
+            serde_json::json!({
+                "root": root,
+                "summary": summary_json(summary),
+                "checks": checks_json(results),
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "generated_at": generated_at(),
+        "summary": summary_json(total),
+        "roots": roots,
+    })
+}
+
+/// RFC 3339 time the report was built, so archived reports say when
+/// doctor ran.
+fn generated_at() -> String {
+    chrono::Utc::now().to_rfc3339()
+}
+
+fn summary_json(summary: DoctorSummary) -> serde_json::Value {
+    serde_json::json!({
+        "total": summary.total,
+        "ok": summary.ok,
+        "warnings": summary.warnings,
+        "errors": summary.errors,
+    })
+}
+
+fn checks_json(results: &[CheckResult]) -> Vec<serde_json::Value> {
+    results
+        .iter()
+        .map(|r| {
+            let status = match &r.status {
+                CheckStatus::Ok => "ok",
+                CheckStatus::Warning(_) => "warning",
+                CheckStatus::Error(_) => "error",
+            };
+
+            let message = match &r.status {
+                CheckStatus::Ok => None,
+                CheckStatus::Warning(msg) => Some(msg.clone()),
+                CheckStatus::Error(msg) => Some(msg.clone()),
+            };
+
+            serde_json::json!({
+                "id": r.id,
+                "name": r.name,
+                "status": status,
+                "message": message,
+                "suggestion": r.suggestion,
+                "details": r.details,
+            })
+        })
+        .collect()
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -419,8 +598,8 @@ mod tests {
 
         #[test]
         fn is_json_returns_correct_value() {
-            let json_output = Output::new(0, false, false, true);
-            let normal_output = Output::new(0, false, false, false);
+            let json_output = Output::new(0, false, ColorChoice::Always, true);
+            let normal_output = Output::new(0, false, ColorChoice::Always, false);
 
             assert!(json_output.is_json());
             assert!(!normal_output.is_json());
@@ -428,8 +607,8 @@ mod tests {
 
         #[test]
         fn is_quiet_returns_correct_value() {
-            let quiet_output = Output::new(0, true, false, false);
-            let normal_output = Output::new(0, false, false, false);
+            let quiet_output = Output::new(0, true, ColorChoice::Always, false);
+            let normal_output = Output::new(0, false, ColorChoice::Always, false);
 
             assert!(quiet_output.is_quiet());
             assert!(!normal_output.is_quiet());
@@ -441,13 +620,25 @@ mod tests {
 
             assert!(!output.is_json());
             assert!(!output.is_quiet());
-            assert!(output.use_color()); // default should use color
+            // Default is `--color auto`: color only on a terminal without NO_COLOR
+            let expected = std::env::var("NO_COLOR").is_err() && std::io::stderr().is_terminal();
+            assert_eq!(output.use_color(), expected);
+        }
+
+        #[test]
+        #[serial_test::serial]
+        fn color_choice_always_and_never_ignore_environment() {
+            let _env = crate::test_utils::env_guard(&[("NO_COLOR", Some("1"))]);
+            assert!(Output::new(0, false, ColorChoice::Always, false).use_color());
+            assert!(!Output::new(0, false, ColorChoice::Never, false).use_color());
+            // Auto honors NO_COLOR regardless of the terminal
+            assert!(!Output::new(0, false, ColorChoice::Auto, false).use_color());
         }
 
         /// Boundary value: maximum verbosity level
         #[test]
         fn output_handles_max_verbosity() {
-            let output = Output::new(u8::MAX, false, false, false);
+            let output = Output::new(u8::MAX, false, ColorChoice::Always, false);
 
             // Should not panic, and verbosity should be preserved
             assert_eq!(output.verbosity(), u8::MAX);
@@ -457,7 +648,7 @@ mod tests {
         #[test]
         fn output_handles_all_flags_enabled() {
             // quiet=true, no_color=true, json=true - potentially conflicting
-            let output = Output::new(0, true, true, true);
+            let output = Output::new(0, true, ColorChoice::Never, true);
 
             // All flags should be set as specified
             assert!(output.is_quiet());
@@ -468,9 +659,9 @@ mod tests {
         /// Verbosity levels affect behavior correctly
         #[test]
         fn output_verbosity_levels() {
-            let v0 = Output::new(0, false, false, false);
-            let v1 = Output::new(1, false, false, false);
-            let v2 = Output::new(2, false, false, false);
+            let v0 = Output::new(0, false, ColorChoice::Always, false);
+            let v1 = Output::new(1, false, ColorChoice::Always, false);
+            let v2 = Output::new(2, false, ColorChoice::Always, false);
 
             assert_eq!(v0.verbosity(), 0);
             assert_eq!(v1.verbosity(), 1);
@@ -480,5 +671,116 @@ mod tests {
             assert!(v0.verbosity() < v1.verbosity());
             assert!(v1.verbosity() < v2.verbosity());
         }
+
+        /// uv stderr is only echoed when debug messages would print
+        #[test]
+        fn debug_lines_follow_debug_visibility() {
+            let on = |verbose, quiet, json| {
+                Output::new(verbose, quiet, ColorChoice::Never, json)
+                    .debug_lines("uv: ")
+                    .is_some()
+            };
+            assert!(on(1, false, false));
+            assert!(!on(0, false, false));
+            assert!(!on(1, true, false));
+            assert!(!on(1, false, true));
+        }
+    }
+
+    mod doctor_json_tests {
+        use super::*;
+
+        #[test]
+        fn ok_check_details_survive_into_json() {
+            let results = vec![
+                CheckResult::ok("uv", "uv installation").with_details("uv 0.5.14"),
+                CheckResult::ok("legacy", "legacy scoop remnants"),
+            ];
+
+            let value = doctor_json_value(&results);
+            let checks = value["checks"].as_array().unwrap();
+            assert_eq!(checks[0]["status"], "ok");
+            assert_eq!(checks[0]["details"], "uv 0.5.14");
+            // Present even when empty, so consumers can rely on the key.
+            assert!(checks[1].as_object().unwrap().contains_key("details"));
+            assert!(checks[1]["details"].is_null());
+        }
+
+        #[test]
+        fn generated_at_is_rfc3339() {
+            let single = doctor_json_value(&[CheckResult::ok("uv", "uv installation")]);
+            let multi = doctor_roots_json_value(&[(PathBuf::from("/a"), Vec::new())]);
+            for value in [single, multi] {
+                let stamp = value["generated_at"].as_str().unwrap();
+                chrono::DateTime::parse_from_rfc3339(stamp)
+                    .unwrap_or_else(|e| panic!("{stamp:?} is not RFC 3339: {e}"));
+            }
+        }
+    }
+
+    mod color_resolution_tests {
+        use super::*;
+
+        fn sample_results() -> Vec<CheckResult> {
+            vec![
+                CheckResult::ok("uv", "uv installation").with_details("uv 0.5.14"),
+                CheckResult::warn("shims", "Python shims", "pyenv shims first")
+                    .with_suggestion("Move scuv init after pyenv init"),
+                CheckResult::error("venv", "broken virtualenv", "'x' is corrupted"),
+            ]
+        }
+
+        fn render(output: &Output) -> String {
+            let mut buf = Vec::new();
+            for result in &sample_results() {
+                output.write_doctor_check(&mut buf, result).unwrap();
+            }
+            output
+                .write_doctor_summary(&mut buf, &sample_results())
+                .unwrap();
+            String::from_utf8(buf).unwrap()
+        }
+
+        fn output_with(color: ColorChoice, terminal: bool) -> Output {
+            Output {
+                verbose: 1,
+                quiet: false,
+                no_color: resolve_no_color(color, terminal, false, false),
+                json: false,
+            }
+        }
+
+        #[test]
+        fn auto_follows_terminal_and_no_color_env() {
+            assert!(!resolve_no_color(ColorChoice::Auto, true, false, false));
+            assert!(resolve_no_color(ColorChoice::Auto, false, false, false));
+            assert!(resolve_no_color(ColorChoice::Auto, true, true, false));
+        }
+
+        #[test]
+        fn json_never_colors() {
+            for color in [ColorChoice::Auto, ColorChoice::Always, ColorChoice::Never] {
+                assert!(resolve_no_color(color, true, false, true), "{color:?}");
+            }
+            assert!(!Output::new(0, false, ColorChoice::Always, true).use_color());
+        }
+
+        #[test]
+        fn piped_doctor_report_has_no_ansi_codes() {
+            let text = render(&output_with(ColorChoice::Auto, false));
+            assert!(!text.contains('\x1b'), "{text:?}");
+            assert!(text.contains("✓ uv installation"));
+            assert!(text.contains("  uv 0.5.14"));
+            assert!(text.contains("  → Move scuv init after pyenv init"));
+            assert!(text.contains("Found 1 error(s) and 1 warning(s)."));
+        }
+
+        #[test]
+        fn terminal_doctor_report_is_colored() {
+            let text = render(&output_with(ColorChoice::Auto, true));
+            assert!(text.contains('\x1b'), "{text:?}");
+            let forced = render(&output_with(ColorChoice::Always, false));
+            assert!(forced.contains('\x1b'), "{forced:?}");
+        }
     }
 }
@@ -1,10 +1,19 @@
 //! Spinner utilities
 
+use std::io::IsTerminal;
+
 use indicatif::{ProgressBar, ProgressStyle};
+use owo_colors::OwoColorize;
+
+use super::Output;
 
 /// A simple spinner for long-running operations
 pub struct Spinner {
     bar: ProgressBar,
+    /// `Some(no_color)` when stderr isn't a terminal: the bar is hidden and
+    /// each new message is printed as a plain info line instead, so piped
+    /// and CI runs still see what's happening.
+    echo: Option<bool>,
 }
 
 impl Spinner {
@@ -19,11 +28,44 @@ impl Spinner {
         bar.set_message(msg.to_string());
         bar.enable_steady_tick(std::time::Duration::from_millis(100));
 
-        Self { bar }
+        Self { bar, echo: None }
+    }
+
+    /// Create a spinner that follows `output`'s mode.
+    ///
+    /// Silent under `--quiet` / `--json`; falls back to one info line per
+    /// message when stderr isn't a terminal (indicatif draws nothing there).
+    pub fn for_output(output: &Output, msg: &str) -> Self {
+        if output.quiet || output.json {
+            return Self::hidden(msg, None);
+        }
+        if !std::io::stderr().is_terminal() {
+            let spinner = Self::hidden(msg, Some(output.no_color));
+            spinner.echo_line(msg);
+            return spinner;
+        }
+        Self::new(msg)
+    }
+
+    fn hidden(msg: &str, echo: Option<bool>) -> Self {
+        let bar = ProgressBar::hidden();
+        bar.set_message(msg.to_string());
+        Self { bar, echo }
+    }
+
+    fn echo_line(&self, msg: &str) {
+        match self.echo {
+            Some(true) => eprintln!("• {msg}"),
+            Some(false) => eprintln!("{} {msg}", "•".blue()),
+            None => {}
+        }
     }
 
     /// Update the spinner message
     pub fn set_message(&self, msg: &str) {
+        if self.bar.message() != msg {
+            self.echo_line(msg);
+        }
         self.bar.set_message(msg.to_string());
     }
 
@@ -45,6 +87,7 @@ impl Spinner {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::ColorChoice;
 
     // =========================================================================
     // Spinner Creation Tests
@@ -137,6 +180,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn for_output_is_hidden_under_quiet_and_json() {
+        for output in [
+            Output::new(0, true, ColorChoice::Never, false),
+            Output::new(0, false, ColorChoice::Never, true),
+        ] {
+            let spinner = Spinner::for_output(&output, "Installing...");
+            assert!(spinner.bar.is_hidden());
+            assert!(spinner.echo.is_none(), "quiet/json must not echo lines");
+            spinner.set_message("Downloading...");
+            assert_eq!(spinner.bar.message(), "Downloading...");
+        }
+    }
+
     // =========================================================================
     // Lifecycle Tests
     // =========================================================================
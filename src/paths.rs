@@ -1,6 +1,7 @@
 //! Path utilities for scuv
 
 use std::path::{Path, PathBuf};
+use std::sync::RwLock;
 
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -23,14 +24,65 @@ pub const VERSION_FILE: &str = ".scuv-version";
 /// DEPRECATION(0.16.0): remove legacy version-file fallback.
 pub const LEGACY_VERSION_FILE: &str = ".scoop-version";
 
+/// Home set by the global `--root` flag; wins over every other source.
+///
+/// Process-wide rather than thread-local so rayon workers resolve the same
+/// home as the main thread.
+static ROOT_OVERRIDE: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Point [`scoop_home`] at `root` for the rest of this process (`None`
+/// restores the normal resolution).
+///
+/// A relative `root` is made absolute against the current directory, so a
+/// later `cd` inside the process can't move the store.
+pub fn set_root_override(root: Option<PathBuf>) {
+    let root = root.map(|root| std::path::absolute(&root).unwrap_or(root));
+    *ROOT_OVERRIDE.write().unwrap_or_else(|e| e.into_inner()) = root;
+}
+
+/// Directory a relative `$SCUV_HOME` is resolved against: the working
+/// directory the first time it's needed. scuv never changes directory
+/// itself, so in practice that is the one it was started in, and a store
+/// picked this way can't move with a later `cd` inside the process.
+static STARTUP_DIR: Lazy<Option<PathBuf>> = Lazy::new(|| std::env::current_dir().ok());
+
+/// Make a home taken from an env var absolute against [`STARTUP_DIR`].
+fn absolutize_home(home: PathBuf) -> PathBuf {
+    if home.is_absolute() {
+        return home;
+    }
+    match STARTUP_DIR.as_deref() {
+        Some(dir) => std::path::absolute(dir.join(&home)).unwrap_or(home),
+        None => home,
+    }
+}
+
+/// The home set by [`set_root_override`], if any.
+pub fn root_override() -> Option<PathBuf> {
+    ROOT_OVERRIDE
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
 /// Get the scuv home directory.
 ///
-/// Resolution order: `$SCUV_HOME` > legacy `$SCOOP_HOME` > `~/.scuv` >
+/// Resolution order: `--root` (see [`set_root_override`]) > `$SCUV_HOME` > legacy `$SCOOP_HOME` > `~/.scuv` >
 /// legacy `~/.scoop` (only when `~/.scuv` doesn't exist yet). Reading either
 /// legacy fallback emits a one-shot deprecation warning on stderr.
+///
+/// The env var values go through [`crate::config::expand_path`], so a
+/// `~/scuv` or `$XDG_DATA_HOME/scuv` set somewhere the shell doesn't expand
+/// it (quoted, systemd units, IDE run configs) still works. A value that is
+/// still relative after that is made absolute against the directory scuv
+/// started in (see [`relative_home_env`]).
 pub fn scoop_home() -> Result<PathBuf> {
+    if let Some(root) = root_override() {
+        return Ok(root);
+    }
+
     if let Ok(home) = std::env::var(SCUV_HOME_ENV) {
-        return Ok(PathBuf::from(home));
+        return crate::config::expand_path(&home).map(absolutize_home);
     }
 
     // DEPRECATION(0.16.0): remove legacy env fallback.
@@ -40,7 +92,7 @@ pub fn scoop_home() -> Result<PathBuf> {
             old = LEGACY_HOME_ENV,
             new = SCUV_HOME_ENV
         ));
-        return Ok(PathBuf::from(home));
+        return crate::config::expand_path(&home).map(absolutize_home);
     }
 
     let base = dirs::home_dir().ok_or(ScoopError::HomeNotFound)?;
@@ -54,6 +106,25 @@ pub fn scoop_home() -> Result<PathBuf> {
     Ok(new)
 }
 
+/// The env var [`scoop_home`] takes the home from and its raw value, when
+/// that value is a relative path (`SCUV_HOME=./scuv`).
+///
+/// Such a home depends on where scuv happens to be started, so running it
+/// from a subdirectory finds a different store; doctor warns about it.
+/// `None` when `--root` is set or the value is absolute (after `~` and
+/// `$VAR` expansion).
+pub fn relative_home_env() -> Option<(&'static str, String)> {
+    if root_override().is_some() {
+        return None;
+    }
+    let (var, value) = [SCUV_HOME_ENV, LEGACY_HOME_ENV]
+        .into_iter()
+        .find_map(|var| std::env::var(var).ok().map(|value| (var, value)))?;
+    crate::config::expand_path(&value)
+        .is_ok_and(|path| path.is_relative())
+        .then_some((var, value))
+}
+
 /// Get the virtualenvs directory (~/.scuv/virtualenvs)
 pub fn virtualenvs_dir() -> Result<PathBuf> {
     Ok(scoop_home()?.join("virtualenvs"))
@@ -64,6 +135,19 @@ pub fn pythons_dir() -> Result<PathBuf> {
     Ok(scoop_home()?.join("pythons"))
 }
 
+/// Get the cache directory (~/.scuv/cache) for data scuv can rebuild, such
+/// as cached uv output. May not exist yet; see [`ensure_cache_dir`].
+pub fn cache_dir() -> Result<PathBuf> {
+    Ok(scoop_home()?.join("cache"))
+}
+
+/// [`cache_dir`], created first if it doesn't exist.
+pub fn ensure_cache_dir() -> Result<PathBuf> {
+    let dir = cache_dir()?;
+    std::fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
 /// Get the global version file path (~/.scuv/version)
 pub fn global_version_file() -> Result<PathBuf> {
     Ok(scoop_home()?.join("version"))
@@ -428,6 +512,63 @@ mod tests {
         assert_eq!(scoop_home().unwrap(), PathBuf::from("/tmp/newhome"));
     }
 
+    #[test]
+    #[serial]
+    fn scuv_home_env_is_expanded() {
+        let _g = crate::test_utils::env_guard(&[
+            (SCUV_HOME_ENV, Some("~/scuv-home")),
+            (LEGACY_HOME_ENV, None),
+        ]);
+        assert_eq!(
+            scoop_home().unwrap(),
+            dirs::home_dir().unwrap().join("scuv-home")
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn relative_home_env_is_made_absolute_once() {
+        let _g = crate::test_utils::env_guard(&[
+            (SCUV_HOME_ENV, Some("./rel-store")),
+            (LEGACY_HOME_ENV, None),
+        ]);
+        let startup = STARTUP_DIR.clone().unwrap();
+        let home = scoop_home().unwrap();
+        assert_eq!(home, startup.join("rel-store"));
+
+        // A later chdir doesn't move the store.
+        let elsewhere = tempfile::TempDir::new().unwrap();
+        let original = std::env::current_dir().unwrap();
+        std::env::set_current_dir(elsewhere.path()).unwrap();
+        let after_cd = scoop_home();
+        std::env::set_current_dir(original).unwrap();
+        assert_eq!(after_cd.unwrap(), home);
+    }
+
+    #[test]
+    #[serial]
+    fn relative_home_env_reports_only_relative_values() {
+        let _g = crate::test_utils::env_guard(&[
+            (SCUV_HOME_ENV, Some("scuv-store")),
+            (LEGACY_HOME_ENV, Some("/tmp/oldhome")),
+        ]);
+        assert_eq!(
+            relative_home_env(),
+            Some((SCUV_HOME_ENV, "scuv-store".to_string()))
+        );
+
+        set_root_override(Some(PathBuf::from("/tmp/root")));
+        let with_root = relative_home_env();
+        set_root_override(None);
+        assert_eq!(with_root, None);
+
+        for absolute in ["/tmp/newhome", "~/scuv-home"] {
+            // SAFETY: ENV_LOCK is held by `_g`, which restores the value.
+            unsafe { std::env::set_var(SCUV_HOME_ENV, absolute) };
+            assert_eq!(relative_home_env(), None, "{absolute}");
+        }
+    }
+
     #[test]
     #[serial]
     fn legacy_home_env_still_read() {
@@ -502,6 +643,21 @@ mod tests {
         });
     }
 
+    #[test]
+    #[serial]
+    fn test_root_override_wins_over_env() {
+        with_temp_scoop_home(|temp_dir| {
+            let root = temp_dir.path().join("other-store");
+            set_root_override(Some(root.clone()));
+            let overridden = (scoop_home().unwrap(), virtualenvs_dir().unwrap());
+            set_root_override(None);
+
+            assert_eq!(overridden.0, root);
+            assert_eq!(overridden.1, root.join("virtualenvs"));
+            assert_eq!(scoop_home().unwrap(), temp_dir.path());
+        });
+    }
+
     #[test]
     #[serial]
     fn test_virtualenvs_dir() {
@@ -520,6 +676,34 @@ mod tests {
         });
     }
 
+    #[test]
+    #[serial]
+    fn test_cache_dir_is_under_scoop_home() {
+        with_temp_scoop_home(|temp_dir| {
+            let cache = cache_dir().unwrap();
+            assert_eq!(cache, temp_dir.path().join("cache"));
+            assert!(!cache.exists(), "cache_dir must not create the directory");
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn test_ensure_cache_dir_is_idempotent() {
+        with_temp_scoop_home(|temp_dir| {
+            let first = ensure_cache_dir().unwrap();
+            assert!(first.is_dir());
+            std::fs::write(first.join("entry"), "kept").unwrap();
+
+            let second = ensure_cache_dir().unwrap();
+            assert_eq!(first, second);
+            assert_eq!(first, temp_dir.path().join("cache"));
+            assert_eq!(
+                std::fs::read_to_string(second.join("entry")).unwrap(),
+                "kept"
+            );
+        });
+    }
+
     #[test]
     #[serial]
     fn test_virtualenv_path() {
@@ -1,6 +1,6 @@
 //! Bash shell integration
 
-use crate::{file_resolution_check, scoop_version_check};
+use crate::{file_resolution_check, leave_hook, scoop_version_check};
 
 /// Generate bash initialization script
 pub fn init_script() -> &'static str {
@@ -24,9 +24,11 @@ scuv() {
                         *) name="$arg"; break ;;
                     esac
                 done
+                local append=""
+                [[ " $* " == *" --append-path "* ]] && append=1
                 if [[ -n "$name" ]]; then
                     # 'use' above already warned about any legacy config; don't warn twice
-                    eval "$(SCUV_SUPPRESS_DEPRECATION=1 command scuv activate "$name")"
+                    eval "$(SCUV_SUPPRESS_DEPRECATION=1 command scuv activate ${append:+--append-path} "$name")"
                 fi
             fi
             return $ret
@@ -137,10 +139,11 @@ _scuv_complete() {
                 COMPREPLY=($(compgen -W "$opts" -- "$cur"))
                 ;;
             use)
-                local opts="--unset --link --global --no-link -q --quiet --no-color --help"
+                local opts="--unset --link --global --no-link --append-path -q --quiet --no-color --help"
                 for word in "${COMP_WORDS[@]}"; do
                     case "$word" in
                         --unset) opts="${opts//--unset }" ;;
+                        --append-path) opts="${opts//--append-path }" ;;
                         --global) opts="${opts//--global }" ;;
                         --link|--no-link) opts="${opts//--link }"; opts="${opts//--no-link }" ;;
                         -q|--quiet) opts="${opts//-q }"; opts="${opts//--quiet }" ;;
@@ -209,10 +212,11 @@ _scuv_complete() {
                 COMPREPLY=($(compgen -W "$opts" -- "$cur"))
                 ;;
             shell)
-                local opts="--unset -q --quiet --no-color --help"
+                local opts="--unset --stack -q --quiet --no-color --help"
                 for word in "${COMP_WORDS[@]}"; do
                     case "$word" in
                         --unset) opts="${opts//--unset }" ;;
+                        --stack) opts="${opts//--stack }" ;;
                         -q|--quiet) opts="${opts//-q }"; opts="${opts//--quiet }" ;;
                         --no-color) opts="${opts//--no-color }" ;;
                     esac
@@ -284,6 +288,24 @@ fi
     )
 }
 
+/// Generate the bash auto-deactivate hook, which `scuv init` appends to
+/// [`init_script`] when `activate.auto_deactivate` is set in config.json.
+pub fn auto_deactivate_script() -> &'static str {
+    concat!(
+        leave_hook!(bash),
+        r#"
+if [[ -z "$PROMPT_COMMAND" ]]; then
+    PROMPT_COMMAND="_scuv_leave_hook"
+else
+    PROMPT_COMMAND="_scuv_leave_hook;$PROMPT_COMMAND"
+fi
+
+# Remember what applies where the shell starts
+_scuv_leave_hook
+"#
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -322,6 +344,62 @@ mod tests {
         }
     }
 
+    /// Runs the auto-deactivate hook in a real bash against a stub `scuv`
+    /// that resolves from `.scuv-version` in the current directory.
+    #[test]
+    #[cfg(unix)]
+    fn auto_deactivate_hook_deactivates_on_leaving_the_pinned_directory() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let tmp = tempfile::tempdir().unwrap();
+        let bin = tmp.path().join("bin");
+        let project = tmp.path().join("project");
+        std::fs::create_dir_all(&bin).unwrap();
+        std::fs::create_dir_all(&project).unwrap();
+        std::fs::write(project.join(".scuv-version"), "web\n").unwrap();
+        let stub = bin.join("scuv");
+        std::fs::write(
+            &stub,
+            "#!/bin/sh\ncase \"$1\" in\n  resolve) cat .scuv-version 2>/dev/null ;;\n  deactivate) echo 'unset SCUV_ACTIVE' ;;\nesac\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&stub, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let script = format!(
+            r#"export PATH="{bin}:$PATH"
+cd "{project}"
+export SCUV_ACTIVE=web
+{hook}
+cd "{project}" && _scuv_leave_hook && echo "stay=$SCUV_ACTIVE"
+cd .. && _scuv_leave_hook; echo "left=$SCUV_ACTIVE"
+export SCUV_ACTIVE=manual
+cd "{project}" && _scuv_leave_hook; cd .. && _scuv_leave_hook; echo "manual=$SCUV_ACTIVE"
+"#,
+            bin = bin.display(),
+            project = project.display(),
+            hook = auto_deactivate_script(),
+        );
+        let output = match std::process::Command::new("bash")
+            .arg("-c")
+            .arg(script)
+            .output()
+        {
+            Ok(output) => output,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                eprintln!("Skipping auto-deactivate test: bash not found");
+                return;
+            }
+            Err(e) => panic!("Failed to run bash: {}", e),
+        };
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert_eq!(
+            stdout.lines().collect::<Vec<_>>(),
+            ["stay=web", "left=", "manual=manual"],
+            "stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
     // =========================================================================
     // Structural Tests: Minimal checks for required components
     // =========================================================================
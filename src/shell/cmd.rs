@@ -0,0 +1,201 @@
+//! cmd.exe shell integration
+//!
+//! cmd.exe has no `eval`, no functions and no prompt hook, so the
+//! integration is narrower than for the other shells: scuv prints batch
+//! lines (`set "NAME=value"`), and the doskey macros from `scuv init cmd`
+//! write them to a temp `.cmd` file and `call` it. There is no
+//! auto-activation on directory change and no tab completion.
+
+use super::PathPosition;
+use std::path::Path;
+
+/// Generate the cmd.exe initialization script.
+///
+/// A batch file defining `scuv-activate`, `scuv-deactivate` and
+/// `scuv-shell` doskey macros. Save it and run it from cmd's `AutoRun`:
+///
+/// ```bat
+/// scuv init cmd > "%USERPROFILE%\scuv-init.cmd"
+/// reg add "HKCU\Software\Microsoft\Command Processor" /v AutoRun /t REG_EXPAND_SZ /d "\"%USERPROFILE%\scuv-init.cmd\"" /f
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// let script = scoop_uv::shell::cmd::init_script();
+///
+/// assert!(script.contains("doskey scuv-activate="));
+/// assert!(script.contains("--shell cmd"));
+/// ```
+pub fn init_script() -> &'static str {
+    r#"@echo off
+rem scuv shell integration for cmd.exe
+rem Save this output as %USERPROFILE%\scuv-init.cmd, then run it from
+rem cmd's AutoRun so every new cmd.exe window picks it up:
+rem   reg add "HKCU\Software\Microsoft\Command Processor" /v AutoRun /t REG_EXPAND_SZ /d "\"%USERPROFILE%\scuv-init.cmd\"" /f
+rem
+rem cmd.exe can't eval scuv's output, so each macro writes it to a temp
+rem batch file and calls that. Directory-based auto-activation is not
+rem available in cmd.exe.
+doskey scuv-activate=scuv activate --shell cmd $* $G "%TEMP%\scuv-env.cmd" $T call "%TEMP%\scuv-env.cmd"
+doskey scuv-deactivate=scuv deactivate --shell cmd $G "%TEMP%\scuv-env.cmd" $T call "%TEMP%\scuv-env.cmd"
+doskey scuv-shell=scuv shell --shell cmd $* $G "%TEMP%\scuv-env.cmd" $T call "%TEMP%\scuv-env.cmd"
+"#
+}
+
+/// Escape a literal for a batch file: `%` would otherwise start a variable
+/// reference.
+fn escape(value: &str) -> String {
+    value.replace('%', "%%")
+}
+
+/// `set "NAME=value"`, or `set "NAME="` (which unsets) for `None`.
+pub fn set_var(name: &str, value: Option<&str>) -> String {
+    format!("set \"{name}={}\"", value.map(escape).unwrap_or_default())
+}
+
+/// Activation script: saves `PATH` (first activation only) and any
+/// `PYTHONHOME` for [`deactivate_script`] to restore.
+pub fn activate_script(
+    venv_path: &Path,
+    bin_path: &Path,
+    name: &str,
+    position: PathPosition,
+) -> String {
+    let bin = escape(&bin_path.display().to_string());
+    let path = match position {
+        PathPosition::Prepend => format!("set \"PATH={bin};%PATH%\""),
+        PathPosition::Append => format!("set \"PATH=%PATH%;{bin}\""),
+    };
+    format!(
+        r#"if not defined _SCUV_OLD_PATH set "_SCUV_OLD_PATH=%PATH%"
+if defined PYTHONHOME set "_SCUV_OLD_PYTHONHOME=%PYTHONHOME%"
+{}
+{path}
+{}
+set "PYTHONHOME="
+"#,
+        set_var("VIRTUAL_ENV", Some(&venv_path.display().to_string())),
+        set_var("SCUV_ACTIVE", Some(name)),
+    )
+}
+
+/// Deactivation script: restores `PATH` and `PYTHONHOME` saved by
+/// [`activate_script`].
+pub fn deactivate_script() -> &'static str {
+    r#"if defined VIRTUAL_ENV (
+    rem Restore original PATH
+    if defined _SCUV_OLD_PATH set "PATH=%_SCUV_OLD_PATH%"
+    set "_SCUV_OLD_PATH="
+    rem Restore PYTHONHOME if it was saved
+    if defined _SCUV_OLD_PYTHONHOME set "PYTHONHOME=%_SCUV_OLD_PYTHONHOME%"
+    set "_SCUV_OLD_PYTHONHOME="
+    set "VIRTUAL_ENV="
+    set "SCUV_ACTIVE="
+)"#
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn script(position: PathPosition) -> String {
+        // Spelled out rather than `join`ed so the test reads the same off Windows.
+        let venv = PathBuf::from(r"C:\Users\u\.scuv\virtualenvs\web");
+        let bin = PathBuf::from(r"C:\Users\u\.scuv\virtualenvs\web\Scripts");
+        activate_script(&venv, &bin, "web", position)
+    }
+
+    #[test]
+    fn activate_saves_then_sets_every_variable() {
+        let lines: Vec<String> = script(PathPosition::Prepend)
+            .lines()
+            .map(String::from)
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                r#"if not defined _SCUV_OLD_PATH set "_SCUV_OLD_PATH=%PATH%""#,
+                r#"if defined PYTHONHOME set "_SCUV_OLD_PYTHONHOME=%PYTHONHOME%""#,
+                r#"set "VIRTUAL_ENV=C:\Users\u\.scuv\virtualenvs\web""#,
+                r#"set "PATH=C:\Users\u\.scuv\virtualenvs\web\Scripts;%PATH%""#,
+                r#"set "SCUV_ACTIVE=web""#,
+                r#"set "PYTHONHOME=""#,
+            ]
+        );
+    }
+
+    #[test]
+    fn path_line_follows_position() {
+        let append = script(PathPosition::Append);
+        assert!(
+            append.contains("set \"PATH=%PATH%;C:\\Users\\u\\.scuv\\virtualenvs\\web\\Scripts\"\n")
+        );
+    }
+
+    #[test]
+    fn literal_percent_is_doubled() {
+        assert_eq!(
+            set_var("VIRTUAL_ENV", Some(r"C:\100%\web")),
+            r#"set "VIRTUAL_ENV=C:\100%%\web""#
+        );
+        assert_eq!(set_var("SCUV_VERSION", None), r#"set "SCUV_VERSION=""#);
+    }
+
+    #[test]
+    fn deactivate_block_is_balanced_and_quoted() {
+        let script = deactivate_script();
+        assert!(script.starts_with("if defined VIRTUAL_ENV ("));
+        assert!(script.ends_with(')'));
+        // `::` comments break parenthesized blocks; only `rem` is safe.
+        assert!(!script.contains("::"));
+        // Unquoted `set` would let a `)` in PATH (Program Files (x86))
+        // close the block early.
+        for line in script.lines().filter(|l| l.contains("set ")) {
+            let set = &line[line.find("set ").unwrap()..];
+            assert!(set.starts_with("set \"") && set.ends_with('"'), "{line}");
+        }
+        assert!(script.contains(r#"set "PATH=%_SCUV_OLD_PATH%""#));
+        assert!(script.contains(r#"set "PYTHONHOME=%_SCUV_OLD_PYTHONHOME%""#));
+    }
+
+    #[test]
+    fn init_macros_call_scuv_with_cmd_shell() {
+        let script = init_script();
+        for name in ["scuv-activate", "scuv-deactivate", "scuv-shell"] {
+            let line = script
+                .lines()
+                .find(|l| l.starts_with(&format!("doskey {name}=")))
+                .unwrap_or_else(|| panic!("no {name} macro"));
+            assert!(line.contains("--shell cmd"), "{line}");
+            assert!(line.contains("$T call \"%TEMP%\\scuv-env.cmd\""), "{line}");
+        }
+    }
+
+    /// Activate then deactivate in a real cmd.exe and check the round trip.
+    #[test]
+    #[cfg(windows)]
+    fn activate_then_deactivate_round_trips_in_cmd() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let file = temp.path().join("round-trip.cmd");
+        let body = format!(
+            "@echo off\nset \"PYTHONHOME=C:\\old\"\n{}set SCUV_ACTIVE\n{}\n\
+             if defined VIRTUAL_ENV echo STILL_ACTIVE\necho PYTHONHOME=%PYTHONHOME%\n",
+            script(PathPosition::Prepend),
+            deactivate_script()
+        );
+        std::fs::write(&file, body).unwrap();
+
+        let out = std::process::Command::new("cmd")
+            .arg("/d")
+            .arg("/c")
+            .arg(&file)
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8_lossy(&out.stdout);
+        assert!(stdout.contains("SCUV_ACTIVE=web"), "{stdout}");
+        assert!(!stdout.contains("STILL_ACTIVE"), "{stdout}");
+        assert!(stdout.contains("PYTHONHOME=C:\\old"), "{stdout}");
+    }
+}
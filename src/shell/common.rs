@@ -145,8 +145,51 @@ macro_rules! file_resolution_check {
     };
 }
 
+/// Generate the `_scuv_leave_hook` function for `activate.auto_deactivate`.
+///
+/// On every directory change it resolves again and deactivates when the
+/// active env was the one resolved in the previous directory but no longer
+/// is, i.e. the user left the directory whose version file selected it.
+/// Envs that never came from resolution (a manual `scuv activate`) are left
+/// alone. Runs independently of the auto-activate hook, so it also works
+/// with `SCUV_NO_AUTO` set.
+#[macro_export]
+macro_rules! leave_hook {
+    (bash) => {
+        r#"
+# Auto-deactivate hook (activate.auto_deactivate in config.json)
+_scuv_leave_hook() {
+    [[ "$PWD" == "$_scuv_last_pwd" ]] && return
+    _scuv_last_pwd="$PWD"
+    local resolved
+    resolved="$(command scuv resolve 2>/dev/null)"
+    if [[ -n "$SCUV_ACTIVE" && "$_scuv_last_resolved" == "$SCUV_ACTIVE" && "$resolved" != "$SCUV_ACTIVE" ]]; then
+        eval "$(command scuv deactivate)"
+    fi
+    _scuv_last_resolved="$resolved"
+}
+"#
+    };
+    (zsh) => {
+        leave_hook!(bash)
+    };
+    (fish) => {
+        r#"
+# Auto-deactivate hook (activate.auto_deactivate in config.json)
+function _scuv_leave_hook --on-variable PWD
+    set -l resolved (command scuv resolve 2>/dev/null)
+    if test -n "$SCUV_ACTIVE" -a "$_scuv_last_resolved" = "$SCUV_ACTIVE" -a "$resolved" != "$SCUV_ACTIVE"
+        eval (command scuv deactivate)
+    end
+    set -g _scuv_last_resolved "$resolved"
+end
+"#
+    };
+}
+
 // Re-export macros for use in sibling modules
 pub use file_resolution_check;
+pub use leave_hook;
 pub use scoop_version_check;
 
 #[cfg(test)]
@@ -203,6 +246,16 @@ mod tests {
         assert!(script.contains("$script:ScuvBin"));
     }
 
+    /// Verify the leave hook only deactivates what resolution selected
+    #[test]
+    fn test_leave_hook_deactivates_only_previously_resolved_env() {
+        for script in [leave_hook!(bash), leave_hook!(fish)] {
+            assert!(script.contains("_scuv_last_resolved"));
+            assert!(script.contains("command scuv deactivate"));
+        }
+        assert_eq!(leave_hook!(bash), leave_hook!(zsh));
+    }
+
     /// Verify PowerShell file resolution uses proper variable
     #[test]
     fn test_file_resolution_powershell_uses_env_name() {
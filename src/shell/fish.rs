@@ -5,7 +5,7 @@
 //! - Auto-activate hook via `--on-variable PWD`
 //! - Tab completion with option deduplication
 
-use crate::{file_resolution_check, scoop_version_check};
+use crate::{file_resolution_check, leave_hook, scoop_version_check};
 
 /// Generate fish initialization script.
 ///
@@ -47,10 +47,12 @@ function scuv
             command scuv $argv
             set -l ret $status
             if test $ret -eq 0
+                set -l opts
+                contains -- --append-path $argv; and set opts --append-path
                 for arg in $argv[2..-1]
                     if not string match -q -- '-*' "$arg"
                         # 'use' above already warned about any legacy config; don't warn twice
-                        eval (env SCUV_SUPPRESS_DEPRECATION=1 scuv activate "$arg")
+                        eval (env SCUV_SUPPRESS_DEPRECATION=1 scuv activate $opts "$arg")
                         break
                     end
                 end
@@ -118,6 +120,7 @@ complete -c scuv -n "__fish_seen_subcommand_from use; and not __fish_contains_op
 complete -c scuv -n "__fish_seen_subcommand_from use; and not __fish_contains_opt global" -l global -d "Set as global default"
 complete -c scuv -n "__fish_seen_subcommand_from use; and not __fish_contains_opt link no-link" -l link -d "Create .venv symlink"
 complete -c scuv -n "__fish_seen_subcommand_from use; and not __fish_contains_opt link no-link" -l no-link -d "Do not create .venv symlink"
+complete -c scuv -n "__fish_seen_subcommand_from use; and not __fish_contains_opt append-path" -l append-path -d "Put the env at the end of PATH"
 complete -c scuv -n "__fish_seen_subcommand_from use; and not __fish_contains_opt -s q quiet" -s q -l quiet -d "Suppress output"
 complete -c scuv -n "__fish_seen_subcommand_from use; and not __fish_contains_opt no-color" -l no-color -d "Disable colored output"
 
@@ -156,6 +159,7 @@ complete -c scuv -n "__fish_seen_subcommand_from doctor; and not __fish_contains
 
 # Options for 'shell' (with duplicate prevention)
 complete -c scuv -n "__fish_seen_subcommand_from shell; and not __fish_contains_opt unset" -l unset -d "Clear shell-specific environment"
+complete -c scuv -n "__fish_seen_subcommand_from shell; and not __fish_contains_opt stack" -l stack -d "Return to the current environment on deactivate"
 complete -c scuv -n "__fish_seen_subcommand_from shell; and not __fish_contains_opt -s q quiet" -s q -l quiet -d "Suppress output"
 complete -c scuv -n "__fish_seen_subcommand_from shell; and not __fish_contains_opt no-color" -l no-color -d "Disable colored output"
 
@@ -199,6 +203,18 @@ end
     )
 }
 
+/// Generate the fish auto-deactivate hook, which `scuv init` appends to
+/// [`init_script`] when `activate.auto_deactivate` is set in config.json.
+pub fn auto_deactivate_script() -> &'static str {
+    concat!(
+        leave_hook!(fish),
+        r#"
+# Remember what applies where the shell starts
+_scuv_leave_hook
+"#
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
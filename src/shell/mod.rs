@@ -1,73 +1,217 @@
 //! Shell integration module
 
 use crate::cli::ShellType;
+use crate::{file_resolution_check, scoop_version_check};
 use std::path::Path;
 
 pub mod bash;
+pub mod cmd;
 pub mod common;
 pub mod fish;
 pub mod powershell;
+pub mod stack;
 pub mod zsh;
 
 /// Detect current shell from environment variables
 pub fn detect_shell() -> ShellType {
+    detect_shell_from(|name| std::env::var(name).ok(), cfg!(windows))
+}
+
+fn detect_shell_from(var: impl Fn(&str) -> Option<String>, windows: bool) -> ShellType {
     // Check Fish first (has unique env var)
-    if std::env::var("FISH_VERSION").is_ok() {
+    if var("FISH_VERSION").is_some() {
         ShellType::Fish
+    // cmd.exe inherits the machine-wide PSModulePath, so rule it out first
+    } else if windows && is_cmd(&var) {
+        ShellType::Cmd
     // Check PowerShell (PSModulePath exists in pwsh, but also check it's not Fish)
-    } else if std::env::var("PSModulePath").is_ok() {
+    } else if var("PSModulePath").is_some() {
         ShellType::Powershell
-    } else if std::env::var("ZSH_VERSION").is_ok() {
+    } else if var("ZSH_VERSION").is_some() {
         ShellType::Zsh
     } else {
         ShellType::Bash
     }
 }
 
-/// Print activation script for the given shell
-pub fn print_activate_script(shell: ShellType, venv_path: &Path, bin_path: &Path, name: &str) {
+/// Whether a Windows process was started from cmd.exe.
+///
+/// `%ComSpec%` names cmd.exe on every Windows box, so it only rules things
+/// out. Git Bash and MSYS export `SHELL`; cmd.exe doesn't. PowerShell
+/// prepends the user's own module directory (under `%USERPROFILE%`) to
+/// `PSModulePath` at startup, while cmd.exe only has the machine-wide
+/// entries.
+fn is_cmd(var: &impl Fn(&str) -> Option<String>) -> bool {
+    let comspec_is_cmd =
+        var("ComSpec").is_some_and(|comspec| comspec.to_ascii_lowercase().ends_with("cmd.exe"));
+    if !comspec_is_cmd || var("SHELL").is_some() {
+        return false;
+    }
+    let profile = var("USERPROFILE")
+        .filter(|p| !p.is_empty())
+        .map(|p| p.to_ascii_lowercase());
+    let user_modules = match (var("PSModulePath"), profile) {
+        (Some(paths), Some(profile)) => paths
+            .split(';')
+            .any(|p| p.to_ascii_lowercase().starts_with(&profile)),
+        _ => false,
+    };
+    !user_modules
+}
+
+/// The directory-change hook for `shell`, as a standalone `_scuv_hook`
+/// function definition.
+///
+/// Same resolve-and-activate logic `scuv init` installs (both are built
+/// from the [`common`] macros), minus the wiring: callers decide when to
+/// run it (prompt command, `chpwd`, `--on-variable PWD`, ...). `None` for
+/// cmd.exe, which has nothing to run a hook from.
+pub fn cd_hook_script(shell: ShellType) -> Option<&'static str> {
+    let script = match shell {
+        ShellType::Bash => concat!(
+            "_scuv_hook() {",
+            scoop_version_check!(bash),
+            file_resolution_check!(bash),
+            "\n}\n"
+        ),
+        ShellType::Zsh => concat!(
+            "_scuv_hook() {",
+            scoop_version_check!(zsh),
+            file_resolution_check!(zsh),
+            "\n}\n"
+        ),
+        ShellType::Fish => concat!(
+            "function _scuv_hook",
+            scoop_version_check!(fish),
+            file_resolution_check!(fish),
+            "\nend\n"
+        ),
+        ShellType::Cmd => return None,
+        ShellType::Powershell => concat!(
+            // The hook body calls the binary through $script:ScuvBin, which
+            // `scuv init` normally sets up.
+            "if (-not $script:ScuvBin) {\n",
+            "    $script:ScuvBin = (Get-Command scuv -CommandType Application -ErrorAction SilentlyContinue).Source\n",
+            "}\n",
+            "function _scuv_hook {",
+            scoop_version_check!(powershell),
+            file_resolution_check!(powershell),
+            "\n}\n"
+        ),
+    };
+    Some(script)
+}
+
+/// The `activate.auto_deactivate` hook for `shell`, appended to its init
+/// script: deactivates when leaving the directory whose version file
+/// selected the active env.
+///
+/// `None` for PowerShell and cmd.exe, which don't support it.
+pub fn auto_deactivate_script(shell: ShellType) -> Option<&'static str> {
     match shell {
+        ShellType::Bash => Some(bash::auto_deactivate_script()),
+        ShellType::Zsh => Some(zsh::auto_deactivate_script()),
+        ShellType::Fish => Some(fish::auto_deactivate_script()),
+        ShellType::Powershell | ShellType::Cmd => None,
+    }
+}
+
+/// Where activation puts the environment's `bin` directory in `PATH`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PathPosition {
+    /// Before the existing entries, so the env's `python` wins (default).
+    #[default]
+    Prepend,
+    /// After the existing entries, so tools already on `PATH` win.
+    Append,
+}
+
+impl PathPosition {
+    /// `Append` when `append` is set (`--append-path`) or
+    /// `activate.append_path` is true in config.json, else `Prepend`.
+    pub fn resolve(append: bool) -> Self {
+        let configured = || {
+            crate::config::Config::load()
+                .ok()
+                .and_then(|c| c.activate.append_path)
+                == Some(true)
+        };
+        if append || configured() {
+            Self::Append
+        } else {
+            Self::Prepend
+        }
+    }
+}
+
+/// Activation script for the given shell
+pub fn activate_script(
+    shell: ShellType,
+    venv_path: &Path,
+    bin_path: &Path,
+    name: &str,
+    position: PathPosition,
+) -> String {
+    let append = position == PathPosition::Append;
+    match shell {
+        ShellType::Cmd => cmd::activate_script(venv_path, bin_path, name, position),
         ShellType::Fish => {
+            let path = if append {
+                format!("set -gx PATH $PATH '{}'", bin_path.display())
+            } else {
+                format!("set -gx PATH '{}' $PATH", bin_path.display())
+            };
             // Save original PATH only on first activation
-            println!(
+            format!(
                 r#"if not set -q _SCUV_OLD_PATH
     set -gx _SCUV_OLD_PATH $PATH
 end
 if set -q PYTHONHOME
     set -gx _SCUV_OLD_PYTHONHOME $PYTHONHOME
-end"#
-            );
-            println!("set -gx VIRTUAL_ENV '{}'", venv_path.display());
-            println!("set -gx PATH '{}' $PATH", bin_path.display());
-            println!("set -gx SCUV_ACTIVE '{}'", name);
-            println!("set -e PYTHONHOME");
+end
+set -gx VIRTUAL_ENV '{}'
+{path}
+set -gx SCUV_ACTIVE '{}'
+set -e PYTHONHOME
+"#,
+                venv_path.display(),
+                name
+            )
         }
         ShellType::Powershell => {
+            // Use [IO.Path]::PathSeparator for cross-platform
+            // Escape single quotes in paths by doubling them (PowerShell string escape)
+            let venv_escaped = venv_path.display().to_string().replace('\'', "''");
+            let bin_escaped = bin_path.display().to_string().replace('\'', "''");
+            let name_escaped = name.replace('\'', "''");
+            let path = if append {
+                format!("$env:PATH = $env:PATH + [IO.Path]::PathSeparator + '{bin_escaped}'")
+            } else {
+                format!("$env:PATH = '{bin_escaped}' + [IO.Path]::PathSeparator + $env:PATH")
+            };
             // Save original PATH only on first activation
-            println!(
+            format!(
                 r#"if (-not $env:_SCUV_OLD_PATH) {{
     $env:_SCUV_OLD_PATH = $env:PATH
 }}
 if ($env:PYTHONHOME) {{
     $env:_SCUV_OLD_PYTHONHOME = $env:PYTHONHOME
-}}"#
-            );
-            // Use [IO.Path]::PathSeparator for cross-platform
-            // Escape single quotes in paths by doubling them (PowerShell string escape)
-            let venv_escaped = venv_path.display().to_string().replace('\'', "''");
-            let bin_escaped = bin_path.display().to_string().replace('\'', "''");
-            let name_escaped = name.replace('\'', "''");
-            println!("$env:VIRTUAL_ENV = '{}'", venv_escaped);
-            println!(
-                "$env:PATH = '{}' + [IO.Path]::PathSeparator + $env:PATH",
-                bin_escaped
-            );
-            println!("$env:SCUV_ACTIVE = '{}'", name_escaped);
-            println!("Remove-Item Env:\\PYTHONHOME -ErrorAction SilentlyContinue");
+}}
+$env:VIRTUAL_ENV = '{venv_escaped}'
+{path}
+$env:SCUV_ACTIVE = '{name_escaped}'
+Remove-Item Env:\PYTHONHOME -ErrorAction SilentlyContinue
+"#
+            )
         }
         _ => {
+            let path = if append {
+                format!("export PATH=\"$PATH:{}\"", bin_path.display())
+            } else {
+                format!("export PATH=\"{}:$PATH\"", bin_path.display())
+            };
             // Save original PATH only on first activation
-            println!(
+            format!(
                 r#"if [ -z "$_SCUV_OLD_PATH" ]; then
     _SCUV_OLD_PATH="$PATH"
     export _SCUV_OLD_PATH
@@ -75,18 +219,36 @@ fi
 if [ -n "$PYTHONHOME" ]; then
     _SCUV_OLD_PYTHONHOME="$PYTHONHOME"
     export _SCUV_OLD_PYTHONHOME
-fi"#
-            );
-            println!("export VIRTUAL_ENV=\"{}\"", venv_path.display());
-            println!("export PATH=\"{}:$PATH\"", bin_path.display());
-            println!("export SCUV_ACTIVE=\"{}\"", name);
-            println!("unset PYTHONHOME");
+fi
+export VIRTUAL_ENV="{}"
+{path}
+export SCUV_ACTIVE="{}"
+unset PYTHONHOME
+"#,
+                venv_path.display(),
+                name
+            )
         }
     }
 }
 
+/// Print activation script for the given shell
+pub fn print_activate_script(
+    shell: ShellType,
+    venv_path: &Path,
+    bin_path: &Path,
+    name: &str,
+    position: PathPosition,
+) {
+    print!(
+        "{}",
+        activate_script(shell, venv_path, bin_path, name, position)
+    );
+}
+
 pub fn print_deactivate_script(shell: ShellType) {
     match shell {
+        ShellType::Cmd => println!("{}", cmd::deactivate_script()),
         ShellType::Fish => {
             println!(
                 r#"if set -q VIRTUAL_ENV
@@ -159,6 +321,10 @@ pub fn print_unset_scoop_version(shell: ShellType) {
             println!("set -e SCUV_VERSION");
             println!("set -e SCOOP_VERSION");
         }
+        ShellType::Cmd => {
+            println!("{}", cmd::set_var("SCUV_VERSION", None));
+            println!("{}", cmd::set_var("SCOOP_VERSION", None));
+        }
         ShellType::Powershell => {
             println!("Remove-Item Env:\\SCUV_VERSION -ErrorAction SilentlyContinue");
             println!("Remove-Item Env:\\SCOOP_VERSION -ErrorAction SilentlyContinue");
@@ -190,6 +356,10 @@ pub fn print_export_scoop_version(shell: ShellType, value: &str) {
             println!("set -gx SCUV_VERSION '{}'", escaped);
             println!("set -gx SCOOP_VERSION '{}'", escaped);
         }
+        ShellType::Cmd => {
+            println!("{}", cmd::set_var("SCUV_VERSION", Some(value)));
+            println!("{}", cmd::set_var("SCOOP_VERSION", Some(value)));
+        }
         ShellType::Powershell => {
             // PowerShell: escape single quotes by doubling them
             let escaped = value.replace('\'', "''");
@@ -202,3 +372,106 @@ pub fn print_export_scoop_version(shell: ShellType, value: &str) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn script(shell: ShellType, position: PathPosition) -> String {
+        let venv = PathBuf::from("/home/u/.scuv/virtualenvs/web");
+        activate_script(shell, &venv, &venv.join("bin"), "web", position)
+    }
+
+    #[test]
+    fn prepend_is_the_default() {
+        assert_eq!(PathPosition::default(), PathPosition::Prepend);
+    }
+
+    #[test]
+    fn posix_path_line_follows_position() {
+        for shell in [ShellType::Bash, ShellType::Zsh] {
+            let prepend = script(shell, PathPosition::Prepend);
+            assert!(prepend.contains("export PATH=\"/home/u/.scuv/virtualenvs/web/bin:$PATH\"\n"));
+            let append = script(shell, PathPosition::Append);
+            assert!(append.contains("export PATH=\"$PATH:/home/u/.scuv/virtualenvs/web/bin\"\n"));
+            assert!(append.contains("export VIRTUAL_ENV=\"/home/u/.scuv/virtualenvs/web\""));
+        }
+    }
+
+    #[test]
+    fn fish_path_line_follows_position() {
+        let prepend = script(ShellType::Fish, PathPosition::Prepend);
+        assert!(prepend.contains("set -gx PATH '/home/u/.scuv/virtualenvs/web/bin' $PATH\n"));
+        let append = script(ShellType::Fish, PathPosition::Append);
+        assert!(append.contains("set -gx PATH $PATH '/home/u/.scuv/virtualenvs/web/bin'\n"));
+    }
+
+    #[test]
+    fn powershell_path_line_follows_position() {
+        let prepend = script(ShellType::Powershell, PathPosition::Prepend);
+        assert!(prepend.contains(
+            "$env:PATH = '/home/u/.scuv/virtualenvs/web/bin' + [IO.Path]::PathSeparator + $env:PATH\n"
+        ));
+        let append = script(ShellType::Powershell, PathPosition::Append);
+        assert!(append.contains(
+            "$env:PATH = $env:PATH + [IO.Path]::PathSeparator + '/home/u/.scuv/virtualenvs/web/bin'\n"
+        ));
+    }
+
+    #[test]
+    fn cmd_activation_uses_batch_syntax() {
+        let prepend = script(ShellType::Cmd, PathPosition::Prepend);
+        assert!(prepend.contains("set \"PATH=/home/u/.scuv/virtualenvs/web/bin;%PATH%\"\n"));
+        assert!(prepend.contains("set \"SCUV_ACTIVE=web\"\n"));
+    }
+
+    fn detect(vars: &[(&str, &str)], windows: bool) -> ShellType {
+        let vars: std::collections::HashMap<_, _> = vars.iter().copied().collect();
+        detect_shell_from(|name| vars.get(name).map(|v| v.to_string()), windows)
+    }
+
+    #[test]
+    fn detects_cmd_from_comspec_without_powershell_markers() {
+        let machine_modules = r"C:\Program Files\WindowsPowerShell\Modules;C:\WINDOWS\system32\WindowsPowerShell\v1.0\Modules";
+        let cmd = [
+            ("ComSpec", r"C:\WINDOWS\system32\cmd.exe"),
+            ("USERPROFILE", r"C:\Users\u"),
+            ("PSModulePath", machine_modules),
+        ];
+        assert_eq!(detect(&cmd, true), ShellType::Cmd);
+        // Same variables off Windows: no cmd.exe there.
+        assert_eq!(detect(&cmd, false), ShellType::Powershell);
+
+        // PowerShell adds the user's module directory at startup.
+        let user_modules =
+            format!(r"C:\Users\u\Documents\WindowsPowerShell\Modules;{machine_modules}");
+        let powershell = [
+            ("ComSpec", r"C:\WINDOWS\system32\cmd.exe"),
+            ("USERPROFILE", r"C:\Users\u"),
+            ("PSModulePath", user_modules.as_str()),
+        ];
+        assert_eq!(detect(&powershell, true), ShellType::Powershell);
+
+        // Git Bash also inherits ComSpec, but exports SHELL.
+        let git_bash = [
+            ("ComSpec", r"C:\WINDOWS\system32\cmd.exe"),
+            ("SHELL", "/usr/bin/bash"),
+        ];
+        assert_eq!(detect(&git_bash, true), ShellType::Bash);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn resolve_honours_flag_then_config() {
+        crate::test_utils::with_temp_scoop_home(|_| {
+            assert_eq!(PathPosition::resolve(false), PathPosition::Prepend);
+            assert_eq!(PathPosition::resolve(true), PathPosition::Append);
+
+            let mut config = crate::config::Config::default();
+            config.activate.append_path = Some(true);
+            config.save().unwrap();
+            assert_eq!(PathPosition::resolve(false), PathPosition::Append);
+        });
+    }
+}
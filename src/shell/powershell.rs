@@ -55,6 +55,7 @@ function scuv {
             & $script:ScuvBin @Arguments
             if ($LASTEXITCODE -eq 0) {
                 $name = $Arguments | Where-Object { $_ -notmatch '^-' } | Select-Object -Skip 1 -First 1
+                $opts = @($Arguments | Where-Object { $_ -eq '--append-path' })
                 if ($name) {
                     # 'use' above already warned about any legacy config; don't warn twice.
                     # Save/restore so a user-set suppression value survives this call.
@@ -62,7 +63,7 @@ function scuv {
                     $prevSuppress = if ($hadSuppress) { $env:SCUV_SUPPRESS_DEPRECATION } else { $null }
                     $env:SCUV_SUPPRESS_DEPRECATION = '1'
                     try {
-                        Invoke-Expression (& $script:ScuvBin activate $name)
+                        Invoke-Expression (& $script:ScuvBin activate @opts $name)
                     } finally {
                         if ($hadSuppress) {
                             $env:SCUV_SUPPRESS_DEPRECATION = $prevSuppress
@@ -0,0 +1,143 @@
+//! Environment stack for `scuv shell --stack`
+//!
+//! `scuv shell --stack <name>` remembers the env that was active before the
+//! switch in `_SCUV_STACK` (a `:`-separated list, most recent last — env
+//! names can't contain `:`). `scuv deactivate` pops the most recent entry
+//! and re-activates it instead of clearing everything. With an empty stack
+//! deactivation is the usual full restore.
+
+use crate::cli::ShellType;
+
+/// Environment variable holding the stacked env names.
+pub const STACK_ENV: &str = "_SCUV_STACK";
+
+const SEPARATOR: char = ':';
+
+/// Append `name` to the current stack value.
+pub fn push(stack: Option<&str>, name: &str) -> String {
+    match stack.filter(|s| !s.is_empty()) {
+        Some(stack) => format!("{stack}{SEPARATOR}{name}"),
+        None => name.to_string(),
+    }
+}
+
+/// Split off the most recent entry.
+///
+/// Returns the env to restore and the remaining stack (`None` once empty),
+/// or `None` when there is nothing to pop.
+pub fn pop(stack: Option<&str>) -> Option<(String, Option<String>)> {
+    let stack = stack.filter(|s| !s.is_empty())?;
+    match stack.rsplit_once(SEPARATOR) {
+        Some((rest, top)) => Some((top.to_string(), Some(rest.to_string()))),
+        None => Some((stack.to_string(), None)),
+    }
+}
+
+/// Shell code that sets `_SCUV_STACK` to `value`, or unsets it for `None`.
+pub fn set_script(shell: ShellType, value: Option<&str>) -> String {
+    match (shell, value) {
+        // Escape single quotes as in `print_export_scoop_version`.
+        (ShellType::Fish, Some(v)) => {
+            format!("set -gx {STACK_ENV} '{}'", v.replace('\'', "\\'"))
+        }
+        (ShellType::Fish, None) => format!("set -e {STACK_ENV}"),
+        (ShellType::Cmd, v) => super::cmd::set_var(STACK_ENV, v),
+        (ShellType::Powershell, Some(v)) => {
+            format!("$env:{STACK_ENV} = '{}'", v.replace('\'', "''"))
+        }
+        (ShellType::Powershell, None) => {
+            format!("Remove-Item Env:\\{STACK_ENV} -ErrorAction SilentlyContinue")
+        }
+        (_, Some(v)) => format!("export {STACK_ENV}=\"{v}\""),
+        (_, None) => format!("unset {STACK_ENV}"),
+    }
+}
+
+/// Print [`set_script`] for eval.
+pub fn print_set(shell: ShellType, value: Option<&str>) {
+    println!("{}", set_script(shell, value));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_appends_most_recent_last() {
+        assert_eq!(push(None, "a"), "a");
+        assert_eq!(push(Some(""), "a"), "a");
+        assert_eq!(push(Some("a"), "b"), "a:b");
+        assert_eq!(push(Some("a:b"), "c"), "a:b:c");
+    }
+
+    #[test]
+    fn pop_returns_top_and_rest() {
+        assert_eq!(pop(None), None);
+        assert_eq!(pop(Some("")), None);
+        assert_eq!(pop(Some("a")), Some(("a".to_string(), None)));
+        assert_eq!(
+            pop(Some("a:b:c")),
+            Some(("c".to_string(), Some("a:b".to_string())))
+        );
+    }
+
+    #[test]
+    fn push_then_pop_round_trips() {
+        let stack = push(Some("a"), "b");
+        let (top, rest) = pop(Some(&stack)).unwrap();
+        assert_eq!(top, "b");
+        assert_eq!(rest.as_deref(), Some("a"));
+    }
+
+    #[test]
+    fn set_script_per_shell() {
+        assert_eq!(
+            set_script(ShellType::Bash, Some("a:b")),
+            "export _SCUV_STACK=\"a:b\""
+        );
+        assert_eq!(set_script(ShellType::Zsh, None), "unset _SCUV_STACK");
+        assert_eq!(
+            set_script(ShellType::Fish, Some("a")),
+            "set -gx _SCUV_STACK 'a'"
+        );
+        assert_eq!(set_script(ShellType::Fish, None), "set -e _SCUV_STACK");
+        assert_eq!(
+            set_script(ShellType::Powershell, Some("a")),
+            "$env:_SCUV_STACK = 'a'"
+        );
+        assert!(
+            set_script(ShellType::Powershell, None).starts_with("Remove-Item Env:\\_SCUV_STACK")
+        );
+    }
+
+    #[test]
+    fn set_script_escapes_single_quotes() {
+        assert_eq!(
+            set_script(ShellType::Fish, Some("it's:b")),
+            "set -gx _SCUV_STACK 'it\\'s:b'"
+        );
+        assert_eq!(
+            set_script(ShellType::Powershell, Some("it's:b")),
+            "$env:_SCUV_STACK = 'it''s:b'"
+        );
+    }
+
+    /// The generated POSIX code must actually set and clear the variable.
+    #[test]
+    #[cfg(unix)]
+    fn set_script_evaluates_in_sh() {
+        let script = format!(
+            "{}\nprintf '%s|' \"${STACK_ENV}\"\n{}\nprintf '%s' \"${{{STACK_ENV}-unset}}\"",
+            set_script(ShellType::Bash, Some("a:b")),
+            set_script(ShellType::Bash, None),
+        );
+        let Ok(out) = std::process::Command::new("sh")
+            .arg("-c")
+            .arg(&script)
+            .output()
+        else {
+            return; // no POSIX shell available
+        };
+        assert_eq!(String::from_utf8_lossy(&out.stdout), "a:b|unset");
+    }
+}
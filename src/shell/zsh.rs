@@ -1,6 +1,6 @@
 //! Zsh shell integration
 
-use crate::{file_resolution_check, scoop_version_check};
+use crate::{file_resolution_check, leave_hook, scoop_version_check};
 
 /// Generate zsh initialization script
 pub fn init_script() -> &'static str {
@@ -27,9 +27,11 @@ scuv() {
                         *) name="$arg"; break ;;
                     esac
                 done
+                local append=""
+                [[ " $* " == *" --append-path "* ]] && append=1
                 if [[ -n "$name" ]]; then
                     # 'use' above already warned about any legacy config; don't warn twice
-                    eval "$(SCUV_SUPPRESS_DEPRECATION=1 command scuv activate "$name")"
+                    eval "$(SCUV_SUPPRESS_DEPRECATION=1 command scuv activate ${append:+--append-path} "$name")"
                 fi
             fi
             return $ret
@@ -102,10 +104,11 @@ _scuv() {
                 use)
                     if [[ $cur == -* ]]; then
                         local opts=('--help:Show help')
-                        local has_unset=false has_global=false has_link=false has_quiet=false has_nocolor=false
+                        local has_unset=false has_global=false has_link=false has_append=false has_quiet=false has_nocolor=false
                         for w in "${words[@]}"; do
                             case "$w" in
                                 --unset) has_unset=true ;;
+                                --append-path) has_append=true ;;
                                 --global) has_global=true ;;
                                 --link|--no-link) has_link=true ;;
                                 -q|--quiet) has_quiet=true ;;
@@ -115,6 +118,7 @@ _scuv() {
                         [[ $has_unset == false ]] && opts+=('--unset:Remove version setting')
                         [[ $has_link == false ]] && opts+=('--link:Create .venv symlink' '--no-link:Do not create .venv symlink')
                         [[ $has_global == false ]] && opts+=('--global:Set as global default')
+                        [[ $has_append == false ]] && opts+=('--append-path:Put the env at the end of PATH')
                         [[ $has_quiet == false ]] && opts+=('-q:Suppress all output' '--quiet:Suppress all output')
                         [[ $has_nocolor == false ]] && opts+=('--no-color:Disable colored output')
                         _describe 'option' opts
@@ -359,15 +363,17 @@ _scuv() {
                 shell)
                     if [[ $cur == -* ]]; then
                         local opts=('--help:Show help')
-                        local has_unset=false has_quiet=false has_nocolor=false
+                        local has_unset=false has_stack=false has_quiet=false has_nocolor=false
                         for w in "${words[@]}"; do
                             case "$w" in
                                 --unset) has_unset=true ;;
+                                --stack) has_stack=true ;;
                                 -q|--quiet) has_quiet=true ;;
                                 --no-color) has_nocolor=true ;;
                             esac
                         done
                         [[ $has_unset == false ]] && opts+=('--unset:Clear shell-specific environment')
+                        [[ $has_stack == false ]] && opts+=('--stack:Return to the current environment on deactivate')
                         [[ $has_quiet == false ]] && opts+=('-q:Suppress all output' '--quiet:Suppress all output')
                         [[ $has_nocolor == false ]] && opts+=('--no-color:Disable colored output')
                         _describe 'option' opts
@@ -412,6 +418,21 @@ fi
     )
 }
 
+/// Generate the zsh auto-deactivate hook, which `scuv init` appends to
+/// [`init_script`] when `activate.auto_deactivate` is set in config.json.
+pub fn auto_deactivate_script() -> &'static str {
+    concat!(
+        leave_hook!(zsh),
+        r#"
+autoload -Uz add-zsh-hook
+add-zsh-hook chpwd _scuv_leave_hook
+
+# Remember what applies where the shell starts
+_scuv_leave_hook
+"#
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
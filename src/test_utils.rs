@@ -378,6 +378,12 @@ const MIGRATE_ENV_VARS: &[&str] = &[
     "VIRTUALENVWRAPPER_HOOK_DIR",
     "CONDA_PREFIX",
     "CONDA_EXE",
+    "POETRY_VIRTUALENVS_PATH",
+    "POETRY_CACHE_DIR",
+    "HATCH_DATA_DIR",
+    // Poetry's cache and Hatch's data dir live under these when set.
+    "XDG_CACHE_HOME",
+    "XDG_DATA_HOME",
 ];
 
 /// Global mutex for migrate environment tests.
@@ -2,10 +2,13 @@
 
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::Arc;
 
 use serde::Deserialize;
 
+use crate::core::cancel;
 use crate::error::{Result, ScoopError};
+use crate::output::Output;
 use crate::validate::PythonVersion;
 
 /// One entry from `uv pip list --format=json`.
@@ -33,6 +36,8 @@ pub struct PythonInfo {
     pub installed: bool,
     /// Implementation (cpython, pypy, etc.)
     pub implementation: String,
+    /// Whether uv manages this install (vs. a system/Homebrew/pyenv Python)
+    pub managed: bool,
 }
 
 /// One entry from `uv python list --output-format=json`.
@@ -42,6 +47,8 @@ pub struct PythonInfo {
 /// `path` marks a version that is available for download but not installed.
 #[derive(Debug, Deserialize)]
 struct UvPythonEntry {
+    #[serde(default)]
+    key: Option<String>,
     version: String,
     path: Option<PathBuf>,
     implementation: String,
@@ -50,71 +57,317 @@ struct UvPythonEntry {
 impl From<UvPythonEntry> for PythonInfo {
     fn from(entry: UvPythonEntry) -> Self {
         let installed = entry.path.is_some();
+        let managed = is_managed_path(entry.path.as_deref(), entry.key.as_deref());
         Self {
             version: entry.version,
             path: entry.path,
             installed,
             implementation: entry.implementation,
+            managed,
         }
     }
 }
 
+/// uv unpacks each managed Python into a directory named after its key
+/// (`…/uv/python/cpython-3.12.1-macos-aarch64-none/bin/python3`), while
+/// interpreters it merely discovers keep their own paths. A path component
+/// equal to the key is therefore what marks an install as uv-managed.
+fn is_managed_path(path: Option<&Path>, key: Option<&str>) -> bool {
+    match (path, key) {
+        (Some(path), Some(key)) => path.components().any(|c| c.as_os_str() == key),
+        _ => false,
+    }
+}
+
+/// Callback that receives each stderr line uv prints.
+pub type StderrEcho = Arc<dyn Fn(&str) + Send + Sync>;
+
 /// Client for interacting with the uv CLI
 pub struct UvClient {
     /// Path to the uv executable
     path: PathBuf,
+    /// Receives uv's stderr line by line as it runs (set under `--verbose`)
+    stderr_echo: Option<StderrEcho>,
+    /// uv's global `-q`/`-v` flag, if any (see [`verbosity_flag`])
+    verbosity: Option<&'static str>,
+    /// Set `PIP_DISABLE_PIP_VERSION_CHECK` for every uv invocation
+    no_pip_version_check: bool,
+    /// Passed to `uv pip install` as `--constraint`
+    constraint: Option<PathBuf>,
+}
+
+/// uv's global verbosity flag for a scuv verbosity level.
+///
+/// `--quiet` maps to `-q`. A single `-v` only echoes uv's normal output
+/// (see [`UvClient::with_stderr_echo`]), so uv's own `-v` starts at `-vv`
+/// and `-vvv` or more asks uv for `-vv`.
+pub fn verbosity_flag(quiet: bool, verbose: u8) -> Option<&'static str> {
+    match (quiet, verbose) {
+        (true, _) => Some("-q"),
+        (false, 0 | 1) => None,
+        (false, 2) => Some("-v"),
+        (false, _) => Some("-vv"),
+    }
 }
 
 impl UvClient {
     /// Create a new UvClient, finding uv in PATH
     pub fn new() -> Result<Self> {
         let path = which::which("uv").map_err(|_| ScoopError::UvNotFound)?;
-        Ok(Self { path })
+        Ok(Self::with_path(path))
+    }
+
+    /// Create a new UvClient that echoes uv's stderr as debug output when
+    /// `output` is verbose.
+    ///
+    /// uv's own verbosity follows scuv's (see [`verbosity_flag`]).
+    pub fn for_output(output: &Output) -> Result<Self> {
+        Ok(Self::new()?
+            .with_stderr_echo(output.debug_lines("uv: "))
+            .with_verbosity(output.is_quiet(), output.verbosity()))
     }
 
     /// Create a new UvClient with a specific path
     pub fn with_path(path: PathBuf) -> Self {
-        Self { path }
+        Self {
+            path,
+            stderr_echo: None,
+            verbosity: None,
+            no_pip_version_check: false,
+            constraint: None,
+        }
+    }
+
+    /// Hand every stderr line uv prints to `echo`, as it arrives.
+    ///
+    /// The lines are still collected, so a failing command reports uv's
+    /// complete stderr in [`ScoopError::UvCommandFailed`] either way.
+    pub fn with_stderr_echo(mut self, echo: Option<StderrEcho>) -> Self {
+        self.stderr_echo = echo;
+        self
+    }
+
+    /// Pass uv the global flag [`verbosity_flag`] picks for this level.
+    pub fn with_verbosity(mut self, quiet: bool, verbose: u8) -> Self {
+        self.verbosity = verbosity_flag(quiet, verbose);
+        self
+    }
+
+    /// Constrain every package `uv pip install` resolves to the pins in
+    /// `path` (`--constraint`), without installing anything it lists.
+    pub fn with_constraint(mut self, path: Option<PathBuf>) -> Self {
+        self.constraint = path;
+        self
+    }
+
+    /// Run uv with `PIP_DISABLE_PIP_VERSION_CHECK=1`, so no pip started
+    /// along the way (seeding, `--upgrade-deps`) prints a "new release of
+    /// pip is available" notice (`scuv create --no-pip-upgrade-warning`).
+    pub fn without_pip_version_check(mut self) -> Self {
+        self.no_pip_version_check = true;
+        self
+    }
+
+    /// A `uv` invocation carrying the configured verbosity flag, ready for
+    /// the subcommand's arguments.
+    fn command(&self) -> Command {
+        let mut cmd = Command::new(&self.path);
+        cmd.args(self.verbosity);
+        if self.no_pip_version_check {
+            cmd.env("PIP_DISABLE_PIP_VERSION_CHECK", "1");
+        }
+        cmd
     }
 
     /// Get the uv version
     pub fn version(&self) -> Result<String> {
+        // No verbosity flag: `-q` would be free to silence the one line we
+        // want, and `-v` adds nothing to a version probe.
         let mut cmd = Command::new(&self.path);
         cmd.arg("--version");
-        let stdout = run_uv(cmd, |message| ScoopError::UvCommandFailed {
+        let stdout = self.run(cmd, |message| ScoopError::UvCommandFailed {
             command: "uv --version".to_string(),
             message,
         })?;
         Ok(String::from_utf8_lossy(&stdout).trim().to_string())
     }
 
-    /// Create a virtual environment
-    pub fn create_venv(&self, path: &Path, python_version: &str) -> Result<()> {
-        let mut cmd = Command::new(&self.path);
-        cmd.arg("venv")
-            .arg(path)
-            .arg("--python")
-            .arg(python_version);
-        let display = format!("uv venv {} --python {}", path.display(), python_version);
-        run_uv(cmd, |message| ScoopError::UvCommandFailed {
+    /// Verify uv is at least `required` before an operation that depends on
+    /// newer uv behavior.
+    ///
+    /// Opt-in per call site: ordinary commands keep working on any uv above
+    /// [`MIN_VERSION`](crate::uv::version::MIN_VERSION), and only the code
+    /// paths that need a newer flag call this first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScoopError::UvVersionIncompatible`] if uv is older than
+    /// `required`, or [`ScoopError::UvCommandFailed`] if `uv --version` fails.
+    pub fn ensure_compatible(&self, required: (u32, u32, u32)) -> Result<()> {
+        crate::uv::version::ensure_at_least(&self.version()?, required)
+    }
+
+    /// Verify uv has `feature` (a subcommand or flag newer than
+    /// [`MIN_VERSION`](crate::uv::version::MIN_VERSION)) before using it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScoopError::UvFeatureUnavailable`] if uv is older than
+    /// `required`, or [`ScoopError::UvCommandFailed`] if `uv --version` fails.
+    pub fn ensure_feature(&self, feature: &str, required: (u32, u32, u32)) -> Result<()> {
+        crate::uv::version::ensure_feature(&self.version()?, feature, required)
+    }
+
+    /// Create a virtual environment; `seed` installs pip into it (and
+    /// setuptools/wheel on older Pythons) via `uv venv --seed`.
+    pub fn create_venv(&self, path: &Path, python_version: &str, seed: bool) -> Result<()> {
+        let (cmd, display) = self.venv_command(path, python_version, seed);
+        self.run(cmd, |message| ScoopError::UvCommandFailed {
             command: display.clone(),
             message,
         })?;
         Ok(())
     }
 
-    /// Install a Python version
-    pub fn install_python(&self, version: &str) -> Result<()> {
-        let mut cmd = Command::new(&self.path);
-        cmd.arg("python").arg("install").arg(version);
-        let display = format!("uv python install {version}");
-        run_uv(cmd, |message| ScoopError::UvCommandFailed {
+    /// Rebuild the virtual environment at `path` in place: uv empties the
+    /// directory and recreates the venv in it, so the directory itself (and
+    /// any symlink pointing at it) survives.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScoopError::UvFeatureUnavailable`] if uv predates
+    /// `uv venv --clear`, or [`ScoopError::UvCommandFailed`] if uv fails.
+    pub fn clear_venv(&self, path: &Path, python_version: &str, seed: bool) -> Result<()> {
+        self.ensure_feature("uv venv --clear", crate::uv::version::VENV_CLEAR_VERSION)?;
+        let (mut cmd, display) = self.venv_command(path, python_version, seed);
+        cmd.arg("--clear");
+        let display = format!("{display} --clear");
+        self.run(cmd, |message| ScoopError::UvCommandFailed {
             command: display.clone(),
             message,
         })?;
         Ok(())
     }
 
+    /// Build `uv venv <path> --python <version> [--seed]` and its display
+    /// form for error messages.
+    fn venv_command(&self, path: &Path, python_version: &str, seed: bool) -> (Command, String) {
+        let mut cmd = self.command();
+        cmd.arg("venv")
+            .arg(path)
+            .arg("--python")
+            .arg(python_version);
+        let mut display = format!("uv venv {} --python {}", path.display(), python_version);
+        if seed {
+            cmd.arg("--seed");
+            display.push_str(" --seed");
+        }
+        (cmd, display)
+    }
+
+    /// Upgrade the seed packages `uv venv --seed` put into the env at
+    /// `venv_path` to their latest releases (`uv pip install --upgrade`).
+    ///
+    /// Only the seed packages actually present are upgraded, so a Python
+    /// 3.12+ env (seeded with pip alone) doesn't gain setuptools and wheel.
+    /// Returns the packages upgraded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScoopError::UvCommandFailed`] if uv fails.
+    pub fn upgrade_seed_packages(&self, venv_path: &Path) -> Result<Vec<String>> {
+        let site_packages = crate::paths::virtualenv_site_packages(venv_path).ok();
+        let packages: Vec<String> = SEED_PACKAGES
+            .iter()
+            .filter(|name| {
+                site_packages
+                    .as_deref()
+                    .is_some_and(|dir| has_dist_info(dir, name))
+            })
+            .map(|name| name.to_string())
+            .collect();
+        if packages.is_empty() {
+            return Ok(packages);
+        }
+
+        let mut cmd = self.command();
+        cmd.arg("pip")
+            .arg("install")
+            .arg("--python")
+            .arg(crate::paths::virtualenv_python_exe(venv_path))
+            .arg("--upgrade")
+            .args(&packages);
+        let display = format!(
+            "uv pip install --upgrade {} (into {})",
+            packages.join(" "),
+            venv_path.display()
+        );
+        self.run(cmd, |message| ScoopError::UvCommandFailed {
+            command: display.clone(),
+            message,
+        })?;
+        Ok(packages)
+    }
+
+    /// Install a Python version, returning the concrete version uv
+    /// installed (`3.12` gives e.g. `3.12.7`).
+    ///
+    /// The version is read from uv's report (`Installed Python 3.12.7 ...`,
+    /// or the key of an existing install); when uv doesn't name one, the
+    /// newest installed Python matching `spec` is looked up instead, and
+    /// failing that `spec` itself is returned.
+    pub fn install_python(&self, spec: &str) -> Result<String> {
+        self.install_python_with_progress(spec, |_| {})
+    }
+
+    /// Install a Python version, passing each stderr line to `on_line` as
+    /// uv prints it.
+    ///
+    /// Same command and return value as [`Self::install_python`]; the
+    /// streaming lets callers surface download progress during long installs.
+    pub fn install_python_with_progress(
+        &self,
+        spec: &str,
+        mut on_line: impl FnMut(&str),
+    ) -> Result<String> {
+        let mut cmd = self.command();
+        cmd.arg("python").arg("install").arg(spec);
+        let display = format!("uv python install {spec}");
+        let mut reported = None;
+        self.run_streaming(
+            cmd,
+            |line| {
+                if reported.is_none() {
+                    reported = parse_installed_version(line);
+                }
+                on_line(line);
+            },
+            |message| ScoopError::UvCommandFailed {
+                command: display.clone(),
+                message,
+            },
+        )?;
+
+        if let Some(version) = reported {
+            return Ok(version);
+        }
+        let found = PythonVersion::parse(spec)
+            .and_then(|pattern| {
+                let installed = self.list_installed_pythons().ok()?;
+                pick_latest_python(
+                    installed
+                        .into_iter()
+                        .filter(|info| {
+                            PythonVersion::parse(&info.version)
+                                .is_some_and(|version| pattern.matches(&version))
+                        })
+                        .collect(),
+                )
+            })
+            .map(|info| info.version);
+        Ok(found.unwrap_or_else(|| spec.to_string()))
+    }
+
     /// List all Python versions known to uv (installed and downloadable).
     pub fn list_pythons(&self) -> Result<Vec<PythonInfo>> {
         self.run_python_list(false)
@@ -129,11 +382,13 @@ impl UvClient {
     ///
     /// uv stabilized the JSON output in 0.5.14 (our [`MIN_VERSION`] floor), so
     /// we rely on the structured schema instead of scraping the human-readable
-    /// table, which changes format between releases.
+    /// table, which changes format between releases. A uv that rejects the
+    /// flag anyway (older or patched builds) gets one retry without it, with
+    /// the table parsed by [`parse_python_list_text`].
     ///
     /// [`MIN_VERSION`]: crate::uv::version::MIN_VERSION
     fn run_python_list(&self, only_installed: bool) -> Result<Vec<PythonInfo>> {
-        let mut cmd = Command::new(&self.path);
+        let mut cmd = self.command();
         cmd.arg("python").arg("list").arg("--output-format=json");
         if only_installed {
             cmd.arg("--only-installed");
@@ -145,13 +400,53 @@ impl UvClient {
             "uv python list --output-format=json"
         };
 
-        let stdout = run_uv(cmd, |message| ScoopError::UvCommandFailed {
+        let stdout = match self.run(cmd, |message| ScoopError::UvCommandFailed {
             command: display.to_string(),
             message,
-        })?;
+        }) {
+            Ok(stdout) => stdout,
+            Err(ScoopError::UvCommandFailed { message, .. })
+                if message.contains("--output-format") =>
+            {
+                return self.run_python_list_text(only_installed);
+            }
+            // Same rejection, on a uv old enough to be named as such.
+            Err(ScoopError::UvFeatureUnavailable { .. }) => {
+                return self.run_python_list_text(only_installed);
+            }
+            Err(e) => return Err(e),
+        };
         parse_python_list_json(&String::from_utf8_lossy(&stdout))
     }
 
+    /// Fallback for [`Self::run_python_list`]: plain `uv python list`.
+    fn run_python_list_text(&self, only_installed: bool) -> Result<Vec<PythonInfo>> {
+        let mut cmd = self.command();
+        cmd.arg("python").arg("list");
+        if only_installed {
+            cmd.arg("--only-installed");
+        }
+        let stdout = self.run(cmd, |message| ScoopError::UvCommandFailed {
+            command: "uv python list".to_string(),
+            message,
+        })?;
+        Ok(parse_python_list_text(&String::from_utf8_lossy(&stdout)))
+    }
+
+    /// Directory uv installs managed Pythons into (`uv python dir`).
+    ///
+    /// Honors `UV_PYTHON_INSTALL_DIR`, so this is the effective location,
+    /// not the platform default.
+    pub fn python_dir(&self) -> Result<PathBuf> {
+        let mut cmd = self.command();
+        cmd.arg("python").arg("dir");
+        let stdout = self.run(cmd, |message| ScoopError::UvCommandFailed {
+            command: "uv python dir".to_string(),
+            message,
+        })?;
+        Ok(PathBuf::from(String::from_utf8_lossy(&stdout).trim()))
+    }
+
     /// Prune the uv cache.
     ///
     /// Removes unused download archives, wheels, and source artifacts from
@@ -162,9 +457,9 @@ impl UvClient {
     ///
     /// Returns [`ScoopError::UvCommandFailed`] if `uv cache prune` exits non-zero.
     pub fn cache_prune(&self) -> Result<String> {
-        let mut cmd = Command::new(&self.path);
+        let mut cmd = self.command();
         cmd.arg("cache").arg("prune");
-        let stdout = run_uv(cmd, |message| ScoopError::UvCommandFailed {
+        let stdout = self.run(cmd, |message| ScoopError::UvCommandFailed {
             command: "uv cache prune".to_string(),
             message,
         })?;
@@ -173,9 +468,9 @@ impl UvClient {
 
     /// Uninstall a Python version
     pub fn uninstall_python(&self, version: &str) -> Result<()> {
-        let mut cmd = Command::new(&self.path);
+        let mut cmd = self.command();
         cmd.arg("python").arg("uninstall").arg(version);
-        run_uv(cmd, |message| ScoopError::PythonUninstallFailed {
+        self.run(cmd, |message| ScoopError::PythonUninstallFailed {
             version: version.to_string(),
             message,
         })?;
@@ -210,28 +505,67 @@ impl UvClient {
     ///
     /// Returns [`ScoopError::UvCommandFailed`] if installation fails.
     pub fn pip_install(&self, venv_path: &Path, packages: &[String]) -> Result<()> {
+        self.pip_install_with_index(venv_path, packages, None)
+    }
+
+    /// Like [`pip_install`](Self::pip_install), resolving packages from
+    /// `index_url` (`--index-url`) instead of uv's default index.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScoopError::UvCommandFailed`] if installation fails.
+    pub fn pip_install_with_index(
+        &self,
+        venv_path: &Path,
+        packages: &[String],
+        index_url: Option<&str>,
+    ) -> Result<()> {
         if packages.is_empty() {
             return Ok(());
         }
 
-        let mut cmd = Command::new(&self.path);
-        cmd.arg("pip")
-            .arg("install")
-            .arg("--python")
-            .arg(crate::paths::virtualenv_python_exe(venv_path));
-
-        for package in packages {
-            cmd.arg(package);
-        }
-
+        let cmd = self.pip_install_command(venv_path, packages, index_url);
         let display = format!("uv pip install (into {})", venv_path.display());
-        run_uv(cmd, |message| ScoopError::UvCommandFailed {
+        self.run(cmd, |message| ScoopError::UvCommandFailed {
             command: display.clone(),
             message,
         })?;
         Ok(())
     }
 
+    /// Build the `uv pip install` invocation (split out so tests can inspect
+    /// the arguments without spawning uv).
+    fn pip_install_command(
+        &self,
+        venv_path: &Path,
+        packages: &[String],
+        index_url: Option<&str>,
+    ) -> Command {
+        let mut cmd = self.pip_install_base(venv_path);
+        if let Some(url) = index_url {
+            cmd.arg("--index-url").arg(url);
+        }
+
+        for package in packages {
+            cmd.arg(package);
+        }
+        cmd
+    }
+
+    /// `uv pip install --python <env python>`, plus `--constraint`
+    /// when one is configured.
+    fn pip_install_base(&self, venv_path: &Path) -> Command {
+        let mut cmd = self.command();
+        cmd.arg("pip")
+            .arg("install")
+            .arg("--python")
+            .arg(crate::paths::virtualenv_python_exe(venv_path));
+        if let Some(path) = &self.constraint {
+            cmd.arg("--constraint").arg(path);
+        }
+        cmd
+    }
+
     /// Install packages from a requirements file into a virtual environment.
     ///
     /// # Errors
@@ -242,15 +576,10 @@ impl UvClient {
         venv_path: &Path,
         requirements_path: &Path,
     ) -> Result<()> {
-        let mut cmd = Command::new(&self.path);
-        cmd.arg("pip")
-            .arg("install")
-            .arg("--python")
-            .arg(crate::paths::virtualenv_python_exe(venv_path))
-            .arg("-r")
-            .arg(requirements_path);
+        let mut cmd = self.pip_install_base(venv_path);
+        cmd.arg("-r").arg(requirements_path);
         let display = format!("uv pip install -r {}", requirements_path.display());
-        run_uv(cmd, |message| ScoopError::UvCommandFailed {
+        self.run(cmd, |message| ScoopError::UvCommandFailed {
             command: display.clone(),
             message,
         })?;
@@ -280,14 +609,14 @@ impl UvClient {
     /// - [`ScoopError::Json`] if uv's JSON output cannot be parsed.
     pub fn pip_list(&self, venv_path: &Path) -> Result<Vec<UvPipListEntry>> {
         let python = crate::paths::virtualenv_python_exe(venv_path);
-        let mut cmd = Command::new(&self.path);
+        let mut cmd = self.command();
         cmd.arg("pip")
             .arg("list")
             .arg("--format=json")
             .arg("--python")
             .arg(&python);
         let display = format!("uv pip list --format=json --python {}", python.display());
-        let stdout = run_uv(cmd, |message| ScoopError::UvCommandFailed {
+        let stdout = self.run(cmd, |message| ScoopError::UvCommandFailed {
             command: display.clone(),
             message,
         })?;
@@ -295,20 +624,273 @@ impl UvClient {
     }
 }
 
-/// Run a built uv `Command`, returning captured stdout on success.
+impl UvClient {
+    /// Run a built uv `Command`, returning captured stdout on success.
+    ///
+    /// Centralizes the spawn + non-zero-exit handling every uv call repeats.
+    /// stderr is read line by line so it can be echoed (see
+    /// [`Self::with_stderr_echo`]) while stdout is drained on a second
+    /// thread; reading both from one thread could deadlock once either
+    /// pipe fills. `make_err` builds the error from uv's complete stderr,
+    /// letting each caller pick its own variant (most use
+    /// [`ScoopError::UvCommandFailed`]; uninstall uses
+    /// [`ScoopError::PythonUninstallFailed`]). It is invoked at most once.
+    fn run(&self, mut cmd: Command, make_err: impl Fn(String) -> ScoopError) -> Result<Vec<u8>> {
+        use std::io::Read;
+        use std::process::Stdio;
+
+        let mut child = cmd
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| make_err(e.to_string()))?;
+
+        let mut stdout = child.stdout.take().expect("stdout is piped");
+        let stdout_reader = std::thread::spawn(move || {
+            let mut buf = Vec::new();
+            stdout.read_to_end(&mut buf).map(|_| buf)
+        });
+        let stderr = child.stderr.take().expect("stderr is piped");
+        let (collected, status) = supervise(child, cancel::is_cancelled, || {
+            read_lines(stderr, |line| self.echo(line))
+        });
+
+        let stdout = stdout_reader
+            .join()
+            .expect("stdout reader panicked")
+            .map_err(|e| make_err(e.to_string()))?;
+        cancel::check()?;
+        let status = status.map_err(|e| make_err(e.to_string()))?;
+        if !status.success() {
+            return Err(self.explain_rejection(network_failure(make_err(
+                String::from_utf8_lossy(&collected).to_string(),
+            ))));
+        }
+        Ok(stdout)
+    }
+
+    /// Like [`Self::run`], but also hands each stderr line to `on_line`.
+    /// Progress redraws end in `\r` rather than `\n`, so both count as line
+    /// breaks. stdout is discarded; on failure the collected stderr becomes
+    /// the error message.
+    fn run_streaming(
+        &self,
+        mut cmd: Command,
+        mut on_line: impl FnMut(&str),
+        make_err: impl Fn(String) -> ScoopError,
+    ) -> Result<()> {
+        use std::process::Stdio;
+
+        let mut child = cmd
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| make_err(e.to_string()))?;
+
+        let stderr = child.stderr.take().expect("stderr is piped");
+        let (collected, status) = supervise(child, cancel::is_cancelled, || {
+            read_lines(stderr, |line| {
+                self.echo(line);
+                on_line(line);
+            })
+        });
+
+        cancel::check()?;
+        let status = status.map_err(|e| make_err(e.to_string()))?;
+        if !status.success() {
+            return Err(self.explain_rejection(network_failure(make_err(
+                String::from_utf8_lossy(&collected).to_string(),
+            ))));
+        }
+        Ok(())
+    }
+
+    /// Turn uv refusing a subcommand or flag outright into
+    /// [`ScoopError::UvFeatureUnavailable`] when uv is older than
+    /// [`MIN_VERSION`](crate::uv::version::MIN_VERSION), the release every
+    /// command scuv runs is known to work with. Anything else, including a
+    /// rejection by an up-to-date uv, is returned unchanged.
+    fn explain_rejection(&self, err: ScoopError) -> ScoopError {
+        use crate::uv::version;
+
+        let ScoopError::UvCommandFailed { command, message } = &err else {
+            return err;
+        };
+        if !version::is_usage_rejection(message) {
+            return err;
+        }
+        // Raw probe rather than `self.version()`, which goes through `run`.
+        let raw = Command::new(&self.path).arg("--version").output();
+        let Some(found) = raw
+            .ok()
+            .and_then(|out| version::parse(&String::from_utf8_lossy(&out.stdout)))
+        else {
+            return err;
+        };
+        if version::meets_minimum(found) {
+            return err;
+        }
+        ScoopError::UvFeatureUnavailable {
+            feature: command.clone(),
+            min_version: version::format_version(version::MIN_VERSION),
+            found: version::format_version(found),
+        }
+    }
+
+    fn echo(&self, line: &str) {
+        if let Some(echo) = &self.stderr_echo {
+            echo(line);
+        }
+    }
+}
+
+/// Packages `uv venv --seed` may install: pip always, setuptools and wheel
+/// only for Pythons before 3.12.
+const SEED_PACKAGES: &[&str] = &["pip", "setuptools", "wheel"];
+
+/// Whether `site_packages` holds an installed distribution named `name`
+/// (a `<name>-<version>.dist-info` directory).
+fn has_dist_info(site_packages: &Path, name: &str) -> bool {
+    let prefix = format!("{name}-");
+    std::fs::read_dir(site_packages).is_ok_and(|entries| {
+        entries.flatten().any(|entry| {
+            let file_name = entry.file_name().to_string_lossy().to_lowercase();
+            file_name.starts_with(&prefix) && file_name.ends_with(".dist-info")
+        })
+    })
+}
+
+/// The seed packages installed in the env at `venv_path`, as
+/// `<name> <version>` (`pip 24.0`), read from their `.dist-info` directories.
+pub fn seeded_packages(venv_path: &Path) -> Vec<String> {
+    let Ok(site_packages) = crate::paths::virtualenv_site_packages(venv_path) else {
+        return Vec::new();
+    };
+    let Ok(entries) = std::fs::read_dir(site_packages) else {
+        return Vec::new();
+    };
+    let dist_infos: Vec<String> = entries
+        .flatten()
+        .map(|entry| entry.file_name().to_string_lossy().to_lowercase())
+        .collect();
+    SEED_PACKAGES
+        .iter()
+        .filter_map(|name| {
+            let prefix = format!("{name}-");
+            dist_infos.iter().find_map(|file_name| {
+                let version = file_name
+                    .strip_prefix(&prefix)?
+                    .strip_suffix(".dist-info")?;
+                Some(format!("{name} {version}"))
+            })
+        })
+        .collect()
+}
+
+/// uv stderr fragments that mean a request never got an answer, as opposed
+/// to the server refusing it (404, no matching version). Compared
+/// lowercased; they cover reqwest's and the OS's wording on every platform.
+const NETWORK_FAILURES: &[&str] = &[
+    "connection refused",
+    "connection reset",
+    "timed out",
+    "dns error",
+    "failed to lookup address",
+    "name or service not known",
+    "temporary failure in name resolution",
+    "nodename nor servname provided",
+    "no such host is known",
+    "network is unreachable",
+    "tcp connect error",
+    "error sending request",
+];
+
+/// Turn a uv failure whose stderr reports a network problem into
+/// [`ScoopError::NetworkError`]; anything else is returned unchanged.
+fn network_failure(err: ScoopError) -> ScoopError {
+    let context = match &err {
+        ScoopError::UvCommandFailed { command, message } => format!("{command}: {message}"),
+        ScoopError::PythonInstallFailed { version, message } => {
+            format!("uv python install {version}: {message}")
+        }
+        _ => return err,
+    };
+    let lower = context.to_lowercase();
+    if !NETWORK_FAILURES.iter().any(|needle| lower.contains(needle)) {
+        return err;
+    }
+    ScoopError::NetworkError {
+        context: context.trim_end().to_string(),
+    }
+}
+
+/// Wait for `child` while `drain` reads its output, killing the child as
+/// soon as `cancelled` returns true.
 ///
-/// Centralizes the spawn + non-zero-exit handling every uv call repeats.
-/// `make_err` builds the error from the failure message, letting each caller
-/// pick its own variant (most use [`ScoopError::UvCommandFailed`]; uninstall
-/// uses [`ScoopError::PythonUninstallFailed`]). It is invoked at most once.
-fn run_uv(mut cmd: Command, make_err: impl Fn(String) -> ScoopError) -> Result<Vec<u8>> {
-    let output = cmd.output().map_err(|e| make_err(e.to_string()))?;
-    if !output.status.success() {
-        return Err(make_err(
-            String::from_utf8_lossy(&output.stderr).to_string(),
-        ));
+/// Ctrl-C from a terminal reaches uv directly (same process group), but a
+/// SIGINT sent to scuv alone wouldn't, and scuv no longer dies from it once
+/// [`cancel::install_handler`] has run; the watcher makes sure uv stops
+/// either way. It sleeps between polls and is woken when `drain` finishes,
+/// so a normal run isn't slowed down.
+fn supervise<T>(
+    child: std::process::Child,
+    cancelled: impl Fn() -> bool + Sync,
+    drain: impl FnOnce() -> T,
+) -> (T, std::io::Result<std::process::ExitStatus>) {
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+
+    let child = Mutex::new(child);
+    let done = AtomicBool::new(false);
+    std::thread::scope(|scope| {
+        let watcher = scope.spawn(|| {
+            while !done.load(Ordering::SeqCst) {
+                if cancelled() {
+                    let _ = child.lock().expect("child lock poisoned").kill();
+                    return;
+                }
+                std::thread::park_timeout(Duration::from_millis(100));
+            }
+        });
+        let drained = drain();
+        let status = child.lock().expect("child lock poisoned").wait();
+        done.store(true, Ordering::SeqCst);
+        watcher.thread().unpark();
+        (drained, status)
+    })
+}
+
+/// Read `reader` to the end, passing each non-empty line to `on_line` and
+/// returning everything read. `\n` and `\r` both end a line.
+fn read_lines(mut reader: impl std::io::Read, mut on_line: impl FnMut(&str)) -> Vec<u8> {
+    let mut collected = Vec::new();
+    let mut line = Vec::new();
+    let mut buf = [0u8; 1024];
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(_) => break,
+        };
+        collected.extend_from_slice(&buf[..n]);
+        for &b in &buf[..n] {
+            if b == b'\n' || b == b'\r' {
+                if !line.is_empty() {
+                    on_line(&String::from_utf8_lossy(&line));
+                    line.clear();
+                }
+            } else {
+                line.push(b);
+            }
+        }
+    }
+    if !line.is_empty() {
+        on_line(&String::from_utf8_lossy(&line));
     }
-    Ok(output.stdout)
+    collected
 }
 
 /// Pick the highest-versioned entry using [`PythonVersion`]'s full `Ord`
@@ -336,6 +918,59 @@ fn parse_python_list_json(stdout: &str) -> Result<Vec<PythonInfo>> {
     Ok(entries.into_iter().map(PythonInfo::from).collect())
 }
 
+/// Parse the human-readable `uv python list` table.
+///
+/// Each line is `<key>  <path or "<download available>">`, where the key is
+/// `<implementation>-<version>-<os>-<arch>-<libc>` and a symlinked path may
+/// be followed by ` -> <target>`. Lines that don't fit are skipped rather
+/// than failing the whole listing.
+fn parse_python_list_text(stdout: &str) -> Vec<PythonInfo> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            let (key, rest) = line.split_once(char::is_whitespace)?;
+            let mut parts = key.splitn(3, '-');
+            let implementation = parts.next()?.to_string();
+            let version = parts.next()?.to_string();
+            PythonVersion::parse(&version)?;
+
+            let rest = rest.trim();
+            let path = (!rest.is_empty() && !rest.starts_with('<')).then(|| {
+                let path = rest.split_once(" -> ").map_or(rest, |(p, _)| p);
+                PathBuf::from(path)
+            });
+            Some(PythonInfo {
+                installed: path.is_some(),
+                managed: is_managed_path(path.as_deref(), Some(key)),
+                version,
+                path,
+                implementation,
+            })
+        })
+        .collect()
+}
+
+/// The concrete version a `uv python install` stderr line reports, if any.
+///
+/// uv summarizes a single install as `Installed Python 3.12.7 in 1.52s`,
+/// lists each install as ` + cpython-3.12.7-linux-x86_64-gnu`, and names an
+/// existing one as `Found existing installation for Python 3.12:
+/// cpython-3.12.7-...`. Bare `Python 3.12 is already installed` carries no
+/// patch version and yields `None`.
+fn parse_installed_version(line: &str) -> Option<String> {
+    let line = line.trim();
+    if let Some(rest) = line.strip_prefix("Installed Python ") {
+        let version = rest.split_whitespace().next()?;
+        return PythonVersion::parse(version).map(|_| version.to_string());
+    }
+    let key = line
+        .strip_prefix("+ ")
+        .or_else(|| line.rsplit_once(": ").map(|(_, key)| key))?;
+    let version = key.trim().split('-').nth(1)?;
+    PythonVersion::parse(version).map(|_| version.to_string())
+}
+
 /// Parse `uv pip list --format=json` stdout into structured entries.
 ///
 /// Extracted from [`UvClient::pip_list`] for testability — the parsing
@@ -361,6 +996,78 @@ impl Default for UvClient {
 mod tests {
     use super::*;
 
+    fn command_args(cmd: &Command) -> Vec<String> {
+        cmd.get_args()
+            .map(|a| a.to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn pip_install_command_passes_index_url() {
+        let client = UvClient::with_path(PathBuf::from("/mock/uv"));
+        let packages = vec!["requests==2.31.0".to_string()];
+
+        let cmd = client.pip_install_command(
+            Path::new("/envs/web"),
+            &packages,
+            Some("https://pypi.corp.example/simple"),
+        );
+        let args = command_args(&cmd);
+        let idx = args.iter().position(|a| a == "--index-url").unwrap();
+        assert_eq!(args[idx + 1], "https://pypi.corp.example/simple");
+        // The index flag must precede the package list.
+        assert_eq!(args.last().unwrap(), "requests==2.31.0");
+
+        let plain = client.pip_install_command(Path::new("/envs/web"), &packages, None);
+        assert!(!command_args(&plain).iter().any(|a| a == "--index-url"));
+    }
+
+    #[test]
+    fn pip_install_command_passes_constraint() {
+        let packages = vec!["lxml".to_string()];
+        let env = Path::new("/envs/web");
+
+        let plain = UvClient::with_path(PathBuf::from("/mock/uv"));
+        let args = command_args(&plain.pip_install_command(env, &packages, None));
+        assert!(!args.iter().any(|a| a == "--constraint"));
+
+        let client = plain.with_constraint(Some(PathBuf::from("/team/constraints.txt")));
+        let args = command_args(&client.pip_install_command(env, &packages, None));
+        let idx = args.iter().position(|a| a == "--constraint").unwrap();
+        assert_eq!(args[idx + 1], "/team/constraints.txt");
+        assert_eq!(args.last().unwrap(), "lxml");
+    }
+
+    #[test]
+    fn verbosity_flag_maps_each_scuv_level() {
+        assert_eq!(verbosity_flag(false, 0), None);
+        assert_eq!(verbosity_flag(false, 1), None);
+        assert_eq!(verbosity_flag(false, 2), Some("-v"));
+        assert_eq!(verbosity_flag(false, 3), Some("-vv"));
+        assert_eq!(verbosity_flag(false, u8::MAX), Some("-vv"));
+        // --quiet wins over any -v count
+        assert_eq!(verbosity_flag(true, 0), Some("-q"));
+        assert_eq!(verbosity_flag(true, 2), Some("-q"));
+    }
+
+    #[test]
+    fn verbosity_flag_precedes_subcommand() {
+        let packages = vec!["requests".to_string()];
+        let env = Path::new("/envs/web");
+
+        let quiet = UvClient::with_path(PathBuf::from("/mock/uv")).with_verbosity(true, 0);
+        let args = command_args(&quiet.pip_install_command(env, &packages, None));
+        assert_eq!(args[..3], ["-q", "pip", "install"]);
+
+        let verbose = UvClient::with_path(PathBuf::from("/mock/uv")).with_verbosity(false, 2);
+        let args = command_args(&verbose.pip_install_command(env, &packages, None));
+        assert_eq!(args[..2], ["-v", "pip"]);
+
+        let default = UvClient::with_path(PathBuf::from("/mock/uv")).with_verbosity(false, 1);
+        let args = command_args(&default.pip_install_command(env, &packages, None));
+        assert_eq!(args[0], "pip");
+    }
+
     #[test]
     fn test_uv_client_creation() {
         // This test will only pass if uv is installed
@@ -429,6 +1136,47 @@ mod tests {
         assert!(pythons[2].installed);
     }
 
+    #[test]
+    fn test_parse_python_list_marks_managed_by_key_dir() {
+        let json = r#"[
+            {"key":"cpython-3.12.1-linux-x86_64-gnu","version":"3.12.1","path":"/home/u/.local/share/uv/python/cpython-3.12.1-linux-x86_64-gnu/bin/python3.12","implementation":"cpython"},
+            {"key":"cpython-3.12.3-linux-x86_64-gnu","version":"3.12.3","path":"/usr/bin/python3.12","implementation":"cpython"},
+            {"key":"cpython-3.13.0-linux-x86_64-gnu","version":"3.13.0","path":null,"implementation":"cpython"}
+        ]"#;
+        let pythons = parse_python_list_json(json).expect("valid json");
+        assert!(pythons[0].managed);
+        assert!(!pythons[1].managed);
+        assert!(!pythons[2].managed);
+    }
+
+    #[test]
+    fn test_parse_python_list_text() {
+        let text = "\
+cpython-3.13.0-linux-x86_64-gnu    <download available>
+cpython-3.12.1-linux-x86_64-gnu    /home/u/.local/share/uv/python/cpython-3.12.1-linux-x86_64-gnu/bin/python3.12
+cpython-3.12.3-linux-x86_64-gnu    /usr/bin/python3.12 -> python3.12.3
+pypy-3.10.14-linux-x86_64-gnu      /opt/pypy/bin/pypy3
+
+warning: something unrelated
+";
+        let pythons = parse_python_list_text(text);
+        assert_eq!(pythons.len(), 4);
+
+        assert_eq!(pythons[0].version, "3.13.0");
+        assert!(!pythons[0].installed);
+        assert!(pythons[0].path.is_none());
+
+        assert_eq!(pythons[1].version, "3.12.1");
+        assert!(pythons[1].installed);
+        assert!(pythons[1].managed);
+
+        assert_eq!(pythons[2].path, Some(PathBuf::from("/usr/bin/python3.12")));
+        assert!(!pythons[2].managed);
+
+        assert_eq!(pythons[3].implementation, "pypy");
+        assert_eq!(pythons[3].version, "3.10.14");
+    }
+
     #[test]
     fn test_parse_python_list_empty() {
         let pythons = parse_python_list_json("[]").expect("valid json");
@@ -442,6 +1190,7 @@ mod tests {
             path: Some(PathBuf::from("/path/to/python")),
             installed: true,
             implementation: "cpython".to_string(),
+            managed: false,
         };
 
         let info2 = PythonInfo {
@@ -449,6 +1198,7 @@ mod tests {
             path: Some(PathBuf::from("/path/to/python")),
             installed: true,
             implementation: "cpython".to_string(),
+            managed: false,
         };
 
         assert_eq!(info1, info2);
@@ -549,6 +1299,7 @@ mod tests {
             path: None,
             installed: false,
             implementation: "cpython".to_string(),
+            managed: false,
         };
 
         assert!(info.path.is_none());
@@ -565,6 +1316,7 @@ mod tests {
             path: None,
             installed: true,
             implementation: "cpython".to_string(),
+            managed: false,
         }
     }
 
@@ -650,4 +1402,441 @@ mod tests {
         // Also reject non-array shapes — uv only ever emits arrays here.
         assert!(parse_pip_list_json(br#"{"name":"x"}"#).is_err());
     }
+
+    // ==========================================================================
+    // stderr echo
+    // ==========================================================================
+
+    /// A stand-in `uv` that runs `body` as a shell script.
+    #[cfg(unix)]
+    fn fake_uv(dir: &Path, body: &str) -> UvClient {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = dir.join("uv");
+        std::fs::write(&path, format!("#!/bin/sh\n{body}\n")).unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        UvClient::with_path(path)
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn supervise_kills_child_once_cancelled() {
+        use std::process::Stdio;
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::time::{Duration, Instant};
+
+        let mut child = Command::new("sh")
+            .args(["-c", "echo started >&2; exec sleep 30"])
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let stderr = child.stderr.take().unwrap();
+        let started = Instant::now();
+        // "Press Ctrl-C" once the child is known to be running.
+        let seen = AtomicBool::new(false);
+        let (collected, status) = supervise(
+            child,
+            || seen.load(Ordering::SeqCst),
+            || read_lines(stderr, |_| seen.store(true, Ordering::SeqCst)),
+        );
+        assert!(started.elapsed() < Duration::from_secs(10));
+        assert!(!status.unwrap().success());
+        assert!(String::from_utf8_lossy(&collected).contains("started"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn supervise_leaves_child_alone_until_cancelled() {
+        use std::process::Stdio;
+
+        let mut child = Command::new("sh")
+            .args(["-c", "echo done >&2"])
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let stderr = child.stderr.take().unwrap();
+        let (collected, status) = supervise(child, || false, || read_lines(stderr, |_| {}));
+        assert!(status.unwrap().success());
+        assert_eq!(collected, b"done\n");
+    }
+
+    #[cfg(unix)]
+    fn recorder() -> (StderrEcho, Arc<std::sync::Mutex<Vec<String>>>) {
+        let lines = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = Arc::clone(&lines);
+        let echo: StderrEcho = Arc::new(move |line: &str| {
+            sink.lock().unwrap().push(line.to_string());
+        });
+        (echo, lines)
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn failing_command_echoes_and_reports_full_stderr() {
+        let dir = tempfile::tempdir().unwrap();
+        let (echo, lines) = recorder();
+        let client = fake_uv(
+            dir.path(),
+            "echo 'error: No interpreter found for Python 3.99' >&2\n\
+             echo '  Caused by: no matching download' >&2\n\
+             echo 'hint: run `uv python list`' >&2\n\
+             exit 2",
+        )
+        .with_stderr_echo(Some(echo));
+
+        let err = client
+            .create_venv(&dir.path().join("env"), "3.99", false)
+            .unwrap_err();
+
+        assert_eq!(
+            *lines.lock().unwrap(),
+            [
+                "error: No interpreter found for Python 3.99",
+                "  Caused by: no matching download",
+                "hint: run `uv python list`",
+            ]
+        );
+        let ScoopError::UvCommandFailed { command, message } = err else {
+            panic!("expected UvCommandFailed, got {err:?}");
+        };
+        assert!(command.starts_with("uv venv "), "{command}");
+        assert_eq!(
+            message,
+            "error: No interpreter found for Python 3.99\n  Caused by: no matching download\nhint: run `uv python list`\n"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn successful_command_echoes_stderr_and_keeps_stdout() {
+        let dir = tempfile::tempdir().unwrap();
+        let (echo, lines) = recorder();
+        let client = fake_uv(
+            dir.path(),
+            "echo 'warning: cache is stale' >&2\necho 'uv 0.9.0'",
+        )
+        .with_stderr_echo(Some(echo));
+
+        assert_eq!(client.version().unwrap(), "uv 0.9.0");
+        assert_eq!(*lines.lock().unwrap(), ["warning: cache is stale"]);
+    }
+
+    /// Both pipes are drained concurrently, so output larger than a pipe
+    /// buffer on each stream doesn't deadlock.
+    #[cfg(unix)]
+    #[test]
+    fn large_output_on_both_streams_completes() {
+        let dir = tempfile::tempdir().unwrap();
+        let client = fake_uv(
+            dir.path(),
+            "i=0; while [ $i -lt 5000 ]; do echo \"line $i padded to fill the pipe\"; echo \"err $i\" >&2; i=$((i+1)); done",
+        );
+
+        let stdout = client.version().unwrap();
+        assert!(stdout.ends_with("line 4999 padded to fill the pipe"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn streaming_command_feeds_both_echo_and_progress() {
+        let dir = tempfile::tempdir().unwrap();
+        let (echo, lines) = recorder();
+        // Only `python install` prints; the follow-up `python list` (uv
+        // named no version) stays quiet.
+        let client = fake_uv(
+            dir.path(),
+            "[ \"$2\" = install ] && printf 'Downloading\\rDownloaded\\n' >&2",
+        )
+        .with_stderr_echo(Some(echo));
+
+        let mut progress = Vec::new();
+        client
+            .install_python_with_progress("3.12", |line| progress.push(line.to_string()))
+            .unwrap();
+
+        assert_eq!(progress, ["Downloading", "Downloaded"]);
+        assert_eq!(*lines.lock().unwrap(), progress);
+    }
+
+    #[test]
+    fn parse_installed_version_reads_uv_install_reports() {
+        let parse = parse_installed_version;
+        assert_eq!(
+            parse("Installed Python 3.12.7 in 1.52s").as_deref(),
+            Some("3.12.7")
+        );
+        assert_eq!(
+            parse(" + cpython-3.13.0rc2-macos-aarch64-none").as_deref(),
+            Some("3.13.0rc2")
+        );
+        assert_eq!(
+            parse("Found existing installation for Python 3.12: cpython-3.12.7-linux-x86_64-gnu")
+                .as_deref(),
+            Some("3.12.7")
+        );
+        assert_eq!(parse("Python 3.12 is already installed"), None);
+        assert_eq!(parse("Installed 2 versions in 3.10s"), None);
+        assert_eq!(parse("Downloading cpython-3.12.7 (21.4MiB)"), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn install_python_returns_the_version_uv_reports() {
+        let dir = tempfile::tempdir().unwrap();
+        let client = fake_uv(
+            dir.path(),
+            "echo 'Installed Python 3.12.7 in 1.52s' >&2
+             echo ' + cpython-3.12.7-linux-x86_64-gnu' >&2",
+        );
+        assert_eq!(client.install_python("3.12").unwrap(), "3.12.7");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn install_python_falls_back_to_the_newest_matching_install() {
+        let dir = tempfile::tempdir().unwrap();
+        let client = fake_uv(
+            dir.path(),
+            r#"if [ "$2" = list ]; then
+  echo '[{"version":"3.12.1","path":"/py/3.12.1/bin/python3","implementation":"cpython"},
+         {"version":"3.12.9","path":"/py/3.12.9/bin/python3","implementation":"cpython"},
+         {"version":"3.13.0","path":"/py/3.13.0/bin/python3","implementation":"cpython"}]'
+  exit 0
+fi
+echo 'Python 3.12 is already installed' >&2"#,
+        );
+        assert_eq!(client.install_python("3.12").unwrap(), "3.12.9");
+        // Nothing to match a non-version request against: echo it back.
+        assert_eq!(client.install_python("pypy@3.10").unwrap(), "pypy@3.10");
+    }
+
+    /// A fake uv that knows `--version` but no `python` subcommands.
+    #[cfg(unix)]
+    fn uv_without_python_dir(dir: &Path, version: &str) -> UvClient {
+        fake_uv(
+            dir,
+            &format!(
+                "if [ \"$1\" = --version ]; then echo 'uv {version}'; exit 0; fi\n\
+                 echo \"error: unrecognized subcommand '$2'\" >&2; exit 2"
+            ),
+        )
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn old_uv_rejecting_a_subcommand_is_feature_unavailable() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = uv_without_python_dir(dir.path(), "0.4.30")
+            .python_dir()
+            .unwrap_err();
+        match err {
+            ScoopError::UvFeatureUnavailable {
+                feature,
+                min_version,
+                found,
+            } => {
+                assert_eq!(feature, "uv python dir");
+                assert_eq!(min_version, "0.5.14");
+                assert_eq!(found, "0.4.30");
+            }
+            other => panic!("expected UvFeatureUnavailable, got {other:?}"),
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn current_uv_rejecting_a_subcommand_stays_command_failed() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = uv_without_python_dir(dir.path(), "0.9.0")
+            .python_dir()
+            .unwrap_err();
+        assert!(
+            matches!(err, ScoopError::UvCommandFailed { ref message, .. } if message.contains("unrecognized subcommand")),
+            "{err:?}"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn clear_venv_passes_clear_flag_to_new_uv() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = dir.path().join("args");
+        let client = fake_uv(
+            dir.path(),
+            &format!(
+                "if [ \"$1\" = --version ]; then echo 'uv 0.8.3'; exit 0; fi\necho \"$@\" > '{}'",
+                log.display()
+            ),
+        );
+        client
+            .clear_venv(Path::new("/envs/web"), "3.12", false)
+            .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&log).unwrap().trim(),
+            "venv /envs/web --python 3.12 --clear"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn seed_adds_seed_flag_and_upgrade_covers_only_seeded_packages() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = dir.path().join("args");
+        let client = fake_uv(dir.path(), &format!("echo \"$@\" >> '{}'", log.display()));
+        let venv = dir.path().join("env");
+        let site_packages = venv.join("lib/python3.12/site-packages");
+        std::fs::create_dir_all(site_packages.join("pip-24.0.dist-info")).unwrap();
+        std::fs::create_dir_all(site_packages.join("wheelhouse")).unwrap();
+
+        client.create_venv(&venv, "3.12", true).unwrap();
+        assert_eq!(client.upgrade_seed_packages(&venv).unwrap(), ["pip"]);
+        let lines: Vec<String> = std::fs::read_to_string(&log)
+            .unwrap()
+            .lines()
+            .map(String::from)
+            .collect();
+        assert_eq!(
+            lines[0],
+            format!("venv {} --python 3.12 --seed", venv.display())
+        );
+        assert!(
+            lines[1].starts_with("pip install --python "),
+            "{}",
+            lines[1]
+        );
+        assert!(lines[1].ends_with(" --upgrade pip"), "{}", lines[1]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn without_pip_version_check_disables_pip_upgrade_notice_for_uv() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = dir.path().join("env-var");
+        let client = fake_uv(
+            dir.path(),
+            &format!(
+                "echo \"${{PIP_DISABLE_PIP_VERSION_CHECK-unset}}\" >> '{}'",
+                log.display()
+            ),
+        );
+        let venv = dir.path().join("env");
+
+        client.create_venv(&venv, "3.12", true).unwrap();
+        client
+            .without_pip_version_check()
+            .create_venv(&venv, "3.12", true)
+            .unwrap();
+        assert_eq!(std::fs::read_to_string(&log).unwrap(), "unset\n1\n");
+    }
+
+    #[test]
+    fn seeded_packages_lists_seed_dist_infos_with_versions() {
+        let dir = tempfile::tempdir().unwrap();
+        let venv = dir.path();
+        let site_packages = if cfg!(windows) {
+            venv.join("Lib/site-packages")
+        } else {
+            venv.join("lib/python3.11/site-packages")
+        };
+        std::fs::create_dir_all(site_packages.join("pip-24.0.dist-info")).unwrap();
+        std::fs::create_dir_all(site_packages.join("setuptools-69.5.1.dist-info")).unwrap();
+        std::fs::create_dir_all(site_packages.join("pip_audit-2.7.3.dist-info")).unwrap();
+
+        assert_eq!(seeded_packages(venv), ["pip 24.0", "setuptools 69.5.1"]);
+        assert!(seeded_packages(&venv.join("missing")).is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn network_failures_in_stderr_become_network_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let client = fake_uv(
+            dir.path(),
+            "echo 'error: Request failed after 3 retries' >&2\n\
+             echo '  Caused by: tcp connect error: Connection refused (os error 111)' >&2\n\
+             exit 2",
+        );
+        match client.create_venv(Path::new("/envs/web"), "3.12", false) {
+            Err(ScoopError::NetworkError { context }) => {
+                assert!(context.starts_with("uv venv /envs/web"), "{context}");
+                assert!(context.contains("Connection refused"), "{context}");
+            }
+            other => panic!("expected NetworkError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn network_failure_leaves_other_errors_alone() {
+        let failed = |message: &str| ScoopError::UvCommandFailed {
+            command: "uv pip install".into(),
+            message: message.into(),
+        };
+        for stderr in [
+            "dns error: failed to lookup address information",
+            "error sending request for url (https://pypi.org/simple/numpy/)",
+            "Operation timed out",
+        ] {
+            assert!(
+                matches!(
+                    network_failure(failed(stderr)),
+                    ScoopError::NetworkError { .. }
+                ),
+                "{stderr}"
+            );
+        }
+        assert!(matches!(
+            network_failure(failed("Because there is no version of nump, ...")),
+            ScoopError::UvCommandFailed { .. }
+        ));
+        assert!(matches!(
+            network_failure(ScoopError::PythonInstallFailed {
+                version: "3.12".into(),
+                message: "Temporary failure in name resolution".into(),
+            }),
+            ScoopError::NetworkError { .. }
+        ));
+        assert!(matches!(
+            network_failure(ScoopError::UvNotFound),
+            ScoopError::UvNotFound
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn clear_venv_on_old_uv_is_feature_unavailable() {
+        let dir = tempfile::tempdir().unwrap();
+        let marker = dir.path().join("ran");
+        let client = fake_uv(
+            dir.path(),
+            &format!(
+                "if [ \"$1\" = --version ]; then echo 'uv 0.7.20'; exit 0; fi\ntouch '{}'",
+                marker.display()
+            ),
+        );
+        match client.clear_venv(Path::new("/envs/web"), "3.12", false) {
+            Err(ScoopError::UvFeatureUnavailable {
+                feature,
+                min_version,
+                found,
+            }) => {
+                assert_eq!(feature, "uv venv --clear");
+                assert_eq!(min_version, "0.8.0");
+                assert_eq!(found, "0.7.20");
+            }
+            other => panic!("expected UvFeatureUnavailable, got {other:?}"),
+        }
+        assert!(!marker.exists(), "uv venv must not run on an old uv");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn ensure_feature_checks_the_running_uv() {
+        let dir = tempfile::tempdir().unwrap();
+        let client = fake_uv(dir.path(), "echo 'uv 0.6.0'");
+        assert!(client.ensure_feature("uv venv --clear", (0, 6, 0)).is_ok());
+        assert!(matches!(
+            client.ensure_feature("uv venv --clear", (0, 8, 0)),
+            Err(ScoopError::UvFeatureUnavailable { .. })
+        ));
+    }
 }
@@ -4,6 +4,10 @@
 //! check, Docker image pin, and user-facing docs should all be aligned with
 //! [`MIN_VERSION`]; bumping the floor is a deliberate, one-line change here.
 
+use std::process::Command;
+
+use crate::error::{Result, ScoopError};
+
 /// Minimum supported uv version (major, minor, patch).
 ///
 /// `0.5.14` is the first release that stabilizes
@@ -13,6 +17,21 @@
 /// (Docker image, doctor check, docs) in agreement.
 pub const MIN_VERSION: (u32, u32, u32) = (0, 5, 14);
 
+/// First uv release with `uv venv --clear` (used by `scuv create --clear`).
+pub const VENV_CLEAR_VERSION: (u32, u32, u32) = (0, 8, 0);
+
+/// Run `uv --version` from `PATH` and return its trimmed stdout.
+///
+/// Returns `None` if uv can't be spawned or exits non-zero. Shared by the
+/// doctor uv check and `scuv version` so both report the same string.
+pub fn detect() -> Option<String> {
+    let output = Command::new("uv").arg("--version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
 /// Parse `uv --version` stdout into a `(major, minor, patch)` tuple.
 ///
 /// Accepted formats (uv prints any of these depending on install source):
@@ -37,6 +56,55 @@ pub fn meets_minimum(version: (u32, u32, u32)) -> bool {
     version >= MIN_VERSION
 }
 
+/// Check raw `uv --version` output against a per-operation `required` floor.
+///
+/// Backs [`UvClient::ensure_compatible`](crate::uv::UvClient::ensure_compatible).
+/// Unparseable output is a soft pass, matching the doctor check: a banner
+/// change in uv shouldn't block commands that would otherwise work.
+///
+/// # Errors
+///
+/// Returns [`ScoopError::UvVersionIncompatible`] if the parsed version is
+/// below `required`.
+pub fn ensure_at_least(raw: &str, required: (u32, u32, u32)) -> Result<()> {
+    match parse(raw) {
+        Some(found) if found < required => Err(ScoopError::UvVersionIncompatible {
+            found: format_version(found),
+            required: format_version(required),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Check raw `uv --version` output against the release that introduced
+/// `feature` (a subcommand or flag, e.g. `"uv python dir"`).
+///
+/// Backs [`UvClient::ensure_feature`](crate::uv::UvClient::ensure_feature).
+/// Unparseable output is a soft pass, as in [`ensure_at_least`].
+///
+/// # Errors
+///
+/// Returns [`ScoopError::UvFeatureUnavailable`] if the parsed version is
+/// below `required`.
+pub fn ensure_feature(raw: &str, feature: &str, required: (u32, u32, u32)) -> Result<()> {
+    match parse(raw) {
+        Some(found) if found < required => Err(ScoopError::UvFeatureUnavailable {
+            feature: feature.to_string(),
+            min_version: format_version(required),
+            found: format_version(found),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Whether uv's stderr says it doesn't know a subcommand or flag, as
+/// opposed to failing while running it.
+///
+/// Matches clap's usage errors, which every uv release prints the same way.
+pub fn is_usage_rejection(stderr: &str) -> bool {
+    stderr.contains("unrecognized subcommand") || stderr.contains("unexpected argument")
+}
+
 /// Format a `(major, minor, patch)` tuple as `MAJOR.MINOR.PATCH`.
 pub fn format_version(version: (u32, u32, u32)) -> String {
     let (major, minor, patch) = version;
@@ -83,6 +151,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn ensure_at_least_rejects_older() {
+        let err = ensure_at_least("uv 0.5.14", (0, 6, 0)).unwrap_err();
+        match err {
+            ScoopError::UvVersionIncompatible { found, required } => {
+                assert_eq!(found, "0.5.14");
+                assert_eq!(required, "0.6.0");
+            }
+            other => panic!("expected UvVersionIncompatible, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn ensure_at_least_accepts_equal_and_newer() {
+        assert!(ensure_at_least("uv 0.6.0", (0, 6, 0)).is_ok());
+        assert!(ensure_at_least("uv 0.11.16 (Homebrew)", (0, 6, 0)).is_ok());
+    }
+
+    #[test]
+    fn ensure_at_least_soft_passes_unparseable() {
+        assert!(ensure_at_least("uv dev-build", (9, 9, 9)).is_ok());
+    }
+
+    #[test]
+    fn ensure_feature_names_the_feature_and_floor() {
+        let err = ensure_feature("uv 0.4.30", "uv python dir", (0, 5, 14)).unwrap_err();
+        match err {
+            ScoopError::UvFeatureUnavailable {
+                feature,
+                min_version,
+                found,
+            } => {
+                assert_eq!(feature, "uv python dir");
+                assert_eq!(min_version, "0.5.14");
+                assert_eq!(found, "0.4.30");
+            }
+            other => panic!("expected UvFeatureUnavailable, got {other:?}"),
+        }
+        assert!(ensure_feature("uv 0.5.14", "uv python dir", (0, 5, 14)).is_ok());
+        assert!(ensure_feature("uv dev-build", "uv python dir", (9, 9, 9)).is_ok());
+    }
+
+    #[test]
+    fn usage_rejection_matches_clap_errors_only() {
+        assert!(is_usage_rejection(
+            "error: unrecognized subcommand 'dir'\n\nUsage: uv python <COMMAND>"
+        ));
+        assert!(is_usage_rejection(
+            "error: unexpected argument '--output-format' found"
+        ));
+        assert!(!is_usage_rejection("error: No download found for request"));
+    }
+
     #[test]
     fn format_version_renders_dotted() {
         assert_eq!(format_version((0, 5, 14)), "0.5.14");
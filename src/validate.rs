@@ -54,7 +54,7 @@ const RESERVED_NAMES: &[&str] = &[
 ];
 
 /// Maximum length for environment names
-const MAX_ENV_NAME_LENGTH: usize = 64;
+pub const MAX_ENV_NAME_LENGTH: usize = 64;
 
 /// Check if a string is a valid environment name.
 ///
@@ -150,6 +150,46 @@ pub fn validate_env_name(name: &str) -> Result<()> {
     Ok(())
 }
 
+/// Turn an arbitrary string (such as a directory name) into an environment
+/// name candidate.
+///
+/// Lowercases, replaces every character outside `a-z0-9_-` with `-` (runs
+/// collapse to one), trims separators from both ends, prefixes `env-` when
+/// the result would start with a digit, and cuts it to the maximum length.
+/// The result still has to pass [`validate_env_name`]: it can be empty or
+/// reserved.
+///
+/// # Examples
+///
+/// ```
+/// use scoop_uv::validate::sanitize_env_name;
+///
+/// assert_eq!(sanitize_env_name("My Project"), "my-project");
+/// assert_eq!(sanitize_env_name("api.v2 (old)"), "api-v2-old");
+/// assert_eq!(sanitize_env_name("2024-report"), "env-2024-report");
+/// ```
+pub fn sanitize_env_name(raw: &str) -> String {
+    let mut name = String::with_capacity(raw.len());
+    for c in raw.chars().flat_map(char::to_lowercase) {
+        let c = if c.is_ascii_alphanumeric() || c == '_' {
+            c
+        } else {
+            '-'
+        };
+        if !(c == '-' && name.ends_with('-')) {
+            name.push(c);
+        }
+    }
+    let separators: &[char] = &['-', '_'];
+    let mut name = name.trim_matches(separators).to_string();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert_str(0, "env-");
+    }
+    // Only ASCII is left, so byte truncation is safe.
+    name.truncate(MAX_ENV_NAME_LENGTH);
+    name.trim_end_matches(separators).to_string()
+}
+
 /// Check if a string is a valid Python version.
 ///
 /// Accepts formats like: 3, 3.12, 3.12.0, 3.12.0a1, 3.12.0rc1
@@ -207,6 +247,178 @@ pub fn validate_python_version(version: &str) -> Result<()> {
     Ok(())
 }
 
+/// Python implementations uv understands in `<impl>@<version>` requests.
+const PYTHON_IMPLEMENTATIONS: &[&str] = &["cpython", "pypy", "graalpy"];
+
+/// Regex for one clause of a version range: an operator and a version,
+/// e.g. `>=3.11`, `<3.13`, `==3.12.*`, `~=3.10` (wildcards only with `==`/`!=`)
+static RANGE_CLAUSE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^((==|!=)\s*\d+(\.\d+)*\.\*|(>=|<=|==|!=|~=|>|<)\s*\d+(\.\d+)*((a|b|rc)\d+)?)$")
+        .expect("RANGE_CLAUSE_REGEX is a valid pattern")
+});
+
+/// A version with an optional build variant: `3.13t` and
+/// `3.13+freethreaded` (free-threaded), `3.12+debug`, `3.13+gil`.
+const VERSION_VARIANT_PATTERN: &str = r"\d+(\.\d+)*((a|b|rc)\d+)?(t|\+freethreaded|\+debug|\+gil)?";
+
+/// Regex for a version with an optional build variant; see
+/// [`VERSION_VARIANT_PATTERN`].
+static VERSION_VARIANT_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!("^{VERSION_VARIANT_PATTERN}$"))
+        .expect("VERSION_VARIANT_REGEX is a valid pattern")
+});
+
+/// Regex for an implementation joined to its version, `pypy3.10`, or a uv
+/// download key, `cpython-3.12.3-macos-aarch64-none`. Matched lowercased.
+static IMPLEMENTATION_VERSION_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        "^({})({VERSION_VARIANT_PATTERN}|-{VERSION_VARIANT_PATTERN}(-[a-z0-9_]+)*)$",
+        PYTHON_IMPLEMENTATIONS.join("|")
+    ))
+    .expect("IMPLEMENTATION_VERSION_REGEX is a valid pattern")
+});
+
+/// Validate a Python request as uv accepts it for `--python`.
+///
+/// On top of what [`validate_python_version`] allows, this accepts:
+/// - version ranges: `>=3.11,<3.13`, `~=3.12`, `==3.11.*`
+/// - build variants: `3.13t`, `3.13+freethreaded`, `3.12+debug`
+/// - an implementation, alone or pinned: `pypy`, `cpython@3.12`,
+///   `pypy@3.10`, `graalpy@>=24`, `pypy3.10`
+/// - uv download keys: `cpython-3.12`, `cpython-3.12.3-macos-aarch64-none`
+///
+/// Anything else is rejected here rather than left for uv to puzzle over.
+///
+/// # Examples
+///
+/// ```
+/// use scoop_uv::validate::validate_python_spec;
+///
+/// assert!(validate_python_spec("3.12").is_ok());
+/// assert!(validate_python_spec(">=3.11,<3.13").is_ok());
+/// assert!(validate_python_spec("cpython@3.12").is_ok());
+/// assert!(validate_python_spec("pypy@3.10").is_ok());
+/// assert!(validate_python_spec("3.13t").is_ok());
+/// assert!(validate_python_spec("cpython-3.12").is_ok());
+///
+/// assert!(validate_python_spec("jython@2.7").is_err());
+/// assert!(validate_python_spec(">=3.11,").is_err());
+/// assert!(validate_python_spec("python3.12!").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ScoopError::InvalidPythonVersion`] if the request is invalid.
+pub fn validate_python_spec(spec: &str) -> Result<()> {
+    if is_valid_python_spec(spec.trim()) {
+        Ok(())
+    } else {
+        Err(ScoopError::InvalidPythonVersion {
+            version: spec.to_string(),
+        })
+    }
+}
+
+/// Check a trimmed request; see [`validate_python_spec`].
+fn is_valid_python_spec(spec: &str) -> bool {
+    let (implementation, version) = match spec.split_once('@') {
+        Some((implementation, version)) => (Some(implementation), version),
+        None => (None, spec),
+    };
+    if let Some(implementation) = implementation {
+        if !PYTHON_IMPLEMENTATIONS.contains(&implementation.to_lowercase().as_str()) {
+            return false;
+        }
+    } else if PYTHON_IMPLEMENTATIONS.contains(&spec.to_lowercase().as_str()) {
+        // A bare implementation: any version of it.
+        return true;
+    } else if IMPLEMENTATION_VERSION_REGEX.is_match(&spec.to_lowercase()) {
+        return true;
+    }
+
+    if is_version_alias(version) {
+        // `latest` / `stable` are scuv aliases for CPython only.
+        return implementation.is_none();
+    }
+    if is_valid_python_version(version) || VERSION_VARIANT_REGEX.is_match(version) {
+        return true;
+    }
+    !version.is_empty()
+        && version
+            .split(',')
+            .all(|clause| RANGE_CLAUSE_REGEX.is_match(clause.trim()))
+}
+
+/// Maximum length for environment tags
+pub const MAX_TAG_LENGTH: usize = 32;
+
+/// Validate an environment tag, returning an error if invalid.
+///
+/// Tags are free-form labels, but must be non-empty, at most 32 characters,
+/// and free of whitespace and control characters so they survive
+/// `scuv list` columns and shell word splitting intact.
+///
+/// # Examples
+///
+/// ```
+/// use scoop_uv::validate::validate_tag;
+///
+/// assert!(validate_tag("web").is_ok());
+/// assert!(validate_tag("team:data").is_ok());
+/// assert!(validate_tag("two words").is_err());
+/// assert!(validate_tag("").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ScoopError::InvalidArgument`] if the tag is invalid.
+pub fn validate_tag(tag: &str) -> Result<()> {
+    let reason = if tag.is_empty() {
+        "tag cannot be empty".to_string()
+    } else if tag.chars().count() > MAX_TAG_LENGTH {
+        format!("tag exceeds maximum length of {MAX_TAG_LENGTH} characters")
+    } else if tag.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        "tag must not contain whitespace".to_string()
+    } else {
+        return Ok(());
+    };
+
+    Err(ScoopError::InvalidArgument {
+        message: format!("Invalid tag '{tag}': {reason}"),
+    })
+}
+
+/// Validate a template name (the key under `templates` in config.json).
+///
+/// Template names follow the environment-name rules (a letter, then
+/// letters, digits, `-` or `_`), so they are safe to type unquoted in any
+/// shell.
+///
+/// # Examples
+///
+/// ```
+/// use scoop_uv::validate::validate_template_name;
+///
+/// assert!(validate_template_name("data-science").is_ok());
+/// assert!(validate_template_name("ml_gpu2").is_ok());
+/// assert!(validate_template_name("2fast").is_err());
+/// assert!(validate_template_name("web app").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ScoopError::InvalidArgument`] if the name is invalid.
+pub fn validate_template_name(name: &str) -> Result<()> {
+    if ENV_NAME_REGEX.is_match(name) {
+        return Ok(());
+    }
+    Err(ScoopError::InvalidArgument {
+        message: format!(
+            "Invalid template name '{name}': must start with a letter and contain only letters, digits, '-' or '_'"
+        ),
+    })
+}
+
 /// Parsed Python version components.
 ///
 /// # Examples
@@ -546,6 +758,55 @@ mod tests {
         assert!(validate_python_version("").is_err());
     }
 
+    #[rstest]
+    #[case::plain("3.12")]
+    #[case::alias("stable")]
+    #[case::cpython("cpython@3.12")]
+    #[case::pypy("pypy@3.10")]
+    #[case::implementation_only("pypy")]
+    #[case::implementation_case("CPython@3.12.1")]
+    #[case::range(">=3.11,<3.13")]
+    #[case::range_spaced(">= 3.11, < 3.13")]
+    #[case::compatible("~=3.12")]
+    #[case::wildcard("==3.11.*")]
+    #[case::implementation_range("graalpy@>=24")]
+    #[case::free_threaded("3.13t")]
+    #[case::free_threaded_variant("3.13+freethreaded")]
+    #[case::debug_variant("3.12+debug")]
+    #[case::implementation_free_threaded("cpython@3.13t")]
+    #[case::implementation_joined("pypy3.10")]
+    #[case::download_key_short("cpython-3.12")]
+    #[case::download_key_full("cpython-3.12.3-macos-aarch64-none")]
+    #[case::download_key_free_threaded("cpython-3.13.1+freethreaded-linux-x86_64-gnu")]
+    fn validate_python_spec_accepts(#[case] input: &str) {
+        assert!(validate_python_spec(input).is_ok(), "{input}");
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::garbage("abc")]
+    #[case::unknown_implementation("jython@2.7")]
+    #[case::missing_version("cpython@")]
+    #[case::alias_with_implementation("pypy@latest")]
+    #[case::trailing_comma(">=3.11,")]
+    #[case::bad_operator("=>3.11")]
+    #[case::operator_only(">=")]
+    #[case::wildcard_range(">=3.*")]
+    #[case::punctuation("3.12!")]
+    #[case::shell("3.12; rm -rf /")]
+    #[case::unknown_variant("3.13+turbo")]
+    #[case::unknown_joined_implementation("jython2.7")]
+    #[case::download_key_shell("cpython-3.12-$(id)")]
+    fn validate_python_spec_rejects(#[case] input: &str) {
+        assert!(
+            matches!(
+                validate_python_spec(input),
+                Err(ScoopError::InvalidPythonVersion { .. })
+            ),
+            "{input}"
+        );
+    }
+
     /// Fuzz regression (crash-dbaf50a5, found 2026-07-14): suffix extraction
     /// took a CHAR index from `chars().position()` and sliced the string with
     /// it as a BYTE index — any multi-byte character before the alphabetic
@@ -691,6 +952,35 @@ mod tests {
         assert!(err.to_string().contains("must start with a letter"));
     }
 
+    #[rstest]
+    #[case::lowercased("MyProject", "myproject")]
+    #[case::spaces_and_dots("my project.v2", "my-project-v2")]
+    #[case::runs_collapse("a  --  b", "a-b")]
+    #[case::underscore_kept("data_tools", "data_tools")]
+    #[case::separators_trimmed("__web--", "web")]
+    #[case::digit_start("2024 report", "env-2024-report")]
+    #[case::leading_symbols_then_digit("(3) things", "env-3-things")]
+    #[case::non_ascii("café-api", "caf-api")]
+    #[case::nothing_left("???", "")]
+    fn sanitize_env_name_cases(#[case] raw: &str, #[case] expected: &str) {
+        assert_eq!(sanitize_env_name(raw), expected);
+    }
+
+    #[test]
+    fn sanitized_names_are_valid_or_rejected_for_a_reason() {
+        let long = sanitize_env_name(&format!("{}-{}", "a".repeat(63), "b"));
+        assert_eq!(
+            long.len(),
+            63,
+            "trailing separator after the cut is dropped"
+        );
+        assert!(validate_env_name(&long).is_ok());
+        assert!(validate_env_name(&sanitize_env_name("Project X")).is_ok());
+        // Reserved words survive sanitizing and still fail validation.
+        assert!(validate_env_name(&sanitize_env_name("List")).is_err());
+        assert!(validate_env_name(&sanitize_env_name("...")).is_err());
+    }
+
     #[test]
     fn test_env_name_max_length() {
         let long_name = "a".repeat(64);
@@ -958,6 +1248,39 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::simple("web", true)]
+    #[case::punctuation("team:data-1.0", true)]
+    #[case::unicode("데이터", true)]
+    #[case::max_len("a".repeat(MAX_TAG_LENGTH), true)]
+    #[case::empty("", false)]
+    #[case::space("two words", false)]
+    #[case::tab("a\tb", false)]
+    #[case::newline("a\n", false)]
+    #[case::too_long("a".repeat(MAX_TAG_LENGTH + 1), false)]
+    fn test_validate_tag(#[case] tag: impl AsRef<str>, #[case] ok: bool) {
+        let result = validate_tag(tag.as_ref());
+        assert_eq!(result.is_ok(), ok, "tag {:?}", tag.as_ref());
+        if let Err(e) = result {
+            assert!(matches!(e, ScoopError::InvalidArgument { .. }));
+        }
+    }
+
+    #[rstest]
+    #[case::simple("data-science", true)]
+    #[case::underscore_digits("ml_gpu2", true)]
+    #[case::empty("", false)]
+    #[case::leading_digit("2fast", false)]
+    #[case::space("web app", false)]
+    #[case::path("../x", false)]
+    fn test_validate_template_name(#[case] name: &str, #[case] ok: bool) {
+        let result = validate_template_name(name);
+        assert_eq!(result.is_ok(), ok, "template {name:?}");
+        if let Err(e) = result {
+            assert!(matches!(e, ScoopError::InvalidArgument { .. }));
+        }
+    }
+
     #[test]
     fn parse_python_version_output_none_on_non_python() {
         assert_eq!(parse_python_version_output("bash 5.2\n", ""), None);
@@ -103,6 +103,42 @@ fn test_list_empty() {
         .success();
 }
 
+#[test]
+fn test_list_count_with_version_filter() {
+    let fixture = TestFixture::new();
+    for (name, python) in [("api", "3.11.9"), ("etl", "3.11.2"), ("site", "3.12.1")] {
+        let env = fixture.scoop_home.join("virtualenvs").join(name);
+        std::fs::create_dir_all(&env).unwrap();
+        std::fs::write(
+            env.join(".scoop-metadata.json"),
+            format!(
+                r#"{{"name":"{name}","python_version":"{python}","created_at":"2026-01-01T00:00:00Z","created_by":"scuv test","uv_version":null}}"#
+            ),
+        )
+        .unwrap();
+    }
+
+    scoop_cmd(&fixture.scoop_home)
+        .args(["list", "--count"])
+        .assert()
+        .success()
+        .stdout("3\n");
+
+    scoop_cmd(&fixture.scoop_home)
+        .args(["list", "--count", "--python", "3.11"])
+        .assert()
+        .success()
+        .stdout("2\n");
+
+    let output = scoop_cmd(&fixture.scoop_home)
+        .args(["list", "--count", "--python", "3.12", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value["data"]["count"], 1);
+}
+
 #[test]
 fn test_list_pythons_empty() {
     let fixture = TestFixture::new();
@@ -165,6 +201,22 @@ fn test_completions_zsh() {
         .success();
 }
 
+#[test]
+fn test_completions_out_dir_writes_file() {
+    let temp = TempDir::new().unwrap();
+    let dir = temp.path().join("completions");
+    Command::cargo_bin("scuv")
+        .unwrap()
+        .args(["completions", "fish", "--out-dir"])
+        .arg(&dir)
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+
+    let content = std::fs::read_to_string(dir.join("scuv.fish")).unwrap();
+    assert!(content.contains("complete -c scuv"), "{content}");
+}
+
 #[test]
 fn test_activate_nonexistent_env() {
     let fixture = TestFixture::new();
@@ -220,6 +272,67 @@ fn test_create_reserved_name() {
         .stderr(predicate::str::contains("reserved"));
 }
 
+#[test]
+fn test_create_rejects_malformed_python_spec_before_uv() {
+    let fixture = TestFixture::new();
+
+    scoop_cmd(&fixture.scoop_home)
+        .args(["create", "web", ">=3.11,", "--dry-run"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(">=3.11,"));
+
+    for spec in [
+        "pypy@3.10",
+        "3.13t",
+        "3.13+freethreaded",
+        "pypy3.10",
+        "cpython-3.12",
+    ] {
+        scoop_cmd(&fixture.scoop_home)
+            .args(["create", "web", spec, "--dry-run"])
+            .assert()
+            .success();
+    }
+}
+
+/// `create --clear` on an existing env checks uv's version before any uv
+/// work, and an old uv leaves the env untouched.
+#[cfg(unix)]
+#[test]
+fn test_create_clear_refuses_old_uv() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let fixture = TestFixture::new();
+    let env = fixture.scoop_home.join("virtualenvs").join("web");
+    std::fs::create_dir_all(&env).unwrap();
+    std::fs::write(env.join("pyvenv.cfg"), "version = 3.12.1\n").unwrap();
+
+    let bin = fixture.temp_dir.path().join("bin");
+    std::fs::create_dir_all(&bin).unwrap();
+    let marker = fixture.temp_dir.path().join("uv-ran");
+    let uv = bin.join("uv");
+    std::fs::write(
+        &uv,
+        format!(
+            "#!/bin/sh\n[ \"$1\" = --version ] && {{ echo 'uv 0.7.20'; exit 0; }}\ntouch '{}'\n",
+            marker.display()
+        ),
+    )
+    .unwrap();
+    std::fs::set_permissions(&uv, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    scoop_cmd(&fixture.scoop_home)
+        .env("PATH", &bin)
+        .args(["create", "web", "3.12", "--clear"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("uv 0.7.20 is too old"))
+        .stderr(predicate::str::contains("uv self update"));
+    assert!(!marker.exists(), "no uv command may run on an old uv");
+    assert!(env.join("pyvenv.cfg").exists());
+}
+
 #[test]
 fn test_install_conflicting_options() {
     let fixture = TestFixture::new();
@@ -240,6 +353,19 @@ fn test_install_conflicting_latest_with_version() {
         .failure();
 }
 
+#[test]
+fn test_list_managed_and_system_only_need_pythons_and_exclude_each_other() {
+    let fixture = TestFixture::new();
+
+    for args in [
+        &["list", "--managed-only"][..],
+        &["list", "--system-only"],
+        &["list", "--pythons", "--managed-only", "--system-only"],
+    ] {
+        scoop_cmd(&fixture.scoop_home).args(args).assert().failure();
+    }
+}
+
 #[test]
 fn test_deactivate_when_not_active() {
     let fixture = TestFixture::new();
@@ -292,6 +418,54 @@ fn test_sync_warns_on_legacy_manifest() {
         .stderr(predicate::str::contains(".scoop.toml is deprecated"));
 }
 
+#[test]
+fn test_site_packages_helper_prints_path() {
+    let fixture = TestFixture::new();
+    let env = fixture.scoop_home.join("virtualenvs").join("web");
+    std::fs::create_dir_all(&env).unwrap();
+    std::fs::write(env.join("pyvenv.cfg"), "version = 3.12.1\n").unwrap();
+
+    let expected = if cfg!(windows) {
+        env.join("Lib").join("site-packages")
+    } else {
+        env.join("lib").join("python3.12").join("site-packages")
+    };
+    std::fs::create_dir_all(&expected).unwrap();
+    scoop_cmd(&fixture.scoop_home)
+        .args(["__site-packages", "web"])
+        .assert()
+        .success()
+        .stdout(format!("{}\n", expected.display()));
+}
+
+#[test]
+fn test_root_flag_overrides_scuv_home() {
+    let fixture = TestFixture::new();
+    let root = fixture.temp_dir.path().join("other-store");
+    let env = root.join("virtualenvs").join("web");
+    let site_packages = if cfg!(windows) {
+        env.join("Lib").join("site-packages")
+    } else {
+        env.join("lib").join("python3.12").join("site-packages")
+    };
+    std::fs::create_dir_all(&site_packages).unwrap();
+    std::fs::write(env.join("pyvenv.cfg"), "version = 3.12.1\n").unwrap();
+
+    scoop_cmd(&fixture.scoop_home)
+        .args(["__site-packages", "web", "--root"])
+        .arg(&root)
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(env.display().to_string()));
+
+    // Without --root the same lookup goes to SCUV_HOME, which is empty.
+    scoop_cmd(&fixture.scoop_home)
+        .args(["__site-packages", "web"])
+        .assert()
+        .failure();
+    assert!(!fixture.scoop_home.join("virtualenvs").exists());
+}
+
 #[test]
 fn test_unknown_subcommand() {
     Command::cargo_bin("scuv")
@@ -407,6 +581,28 @@ mod error_cases {
         scoop_cmd(&fixture.scoop_home).arg("use").assert().failure();
     }
 
+    #[test]
+    fn test_use_create_conflicts_with_unset() {
+        let fixture = TestFixture::new();
+
+        scoop_cmd(&fixture.scoop_home)
+            .args(["use", "--unset", "--create"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("cannot be used with"));
+    }
+
+    #[test]
+    fn test_use_create_rejects_system() {
+        let fixture = TestFixture::new();
+
+        scoop_cmd(&fixture.scoop_home)
+            .args(["use", "system", "--create"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("--create"));
+    }
+
     #[test]
     fn test_activate_without_env_name() {
         let fixture = TestFixture::new();
@@ -485,6 +681,125 @@ mod error_cases {
 mod output_format {
     use super::*;
 
+    /// Errors are rendered with a colored marker; piped stderr is not a
+    /// terminal, so only `--color always` may emit ANSI escapes.
+    #[test]
+    fn test_color_flag_controls_ansi_on_piped_stderr() {
+        let fixture = TestFixture::new();
+        let stderr_for = |args: &[&str]| {
+            let output = scoop_cmd(&fixture.scoop_home)
+                .env_remove("NO_COLOR")
+                .args(args)
+                .args(["info", "ghost"])
+                .output()
+                .unwrap();
+            String::from_utf8_lossy(&output.stderr).into_owned()
+        };
+
+        assert!(
+            !stderr_for(&[]).contains('\x1b'),
+            "auto must not color a pipe"
+        );
+        assert!(stderr_for(&["--color", "always"]).contains('\x1b'));
+        assert!(!stderr_for(&["--color", "never"]).contains('\x1b'));
+        assert!(!stderr_for(&["--no-color"]).contains('\x1b'));
+    }
+
+    #[test]
+    fn test_verbose_is_global_and_create_accepts_quiet_uv() {
+        let fixture = TestFixture::new();
+        for args in [
+            &["-vv", "create", "quietenv", "3.12", "--dry-run"][..],
+            &[
+                "create",
+                "quietenv",
+                "3.12",
+                "--dry-run",
+                "--quiet-uv",
+                "-v",
+            ],
+        ] {
+            scoop_cmd(&fixture.scoop_home)
+                .args(args)
+                .assert()
+                .success()
+                .stdout(predicate::str::contains("quietenv"));
+        }
+    }
+
+    #[test]
+    fn test_create_without_version_uses_configured_default() {
+        let fixture = TestFixture::new();
+        let python_of = |fixture: &TestFixture| {
+            let output = scoop_cmd(&fixture.scoop_home)
+                .args(["create", "web", "--dry-run", "--json"])
+                .output()
+                .unwrap();
+            let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+            value["data"]["python"].as_str().unwrap().to_string()
+        };
+
+        assert_eq!(python_of(&fixture), "3");
+        std::fs::create_dir_all(&fixture.scoop_home).unwrap();
+        std::fs::write(
+            fixture.scoop_home.join("config.json"),
+            r#"{"create":{"default_python":"3.11"}}"#,
+        )
+        .unwrap();
+        assert_eq!(python_of(&fixture), "3.11");
+    }
+
+    #[test]
+    fn test_create_name_from_cwd_takes_python_as_only_positional() {
+        let fixture = TestFixture::new();
+        let project = fixture.temp_dir.path().join("Web App");
+        std::fs::create_dir_all(&project).unwrap();
+
+        let output = scoop_cmd(&fixture.scoop_home)
+            .current_dir(&project)
+            .args(["create", "--name-from-cwd", "3.12", "--dry-run", "--json"])
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+        let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(value["data"]["name"], "web-app");
+        assert_eq!(value["data"]["python"], "3.12");
+        // A dry run doesn't touch the directory.
+        assert!(!project.join(".scuv-version").exists());
+
+        scoop_cmd(&fixture.scoop_home)
+            .current_dir(&project)
+            .args(["create", "--name-from-cwd", "web", "3.12"])
+            .assert()
+            .failure();
+    }
+
+    #[test]
+    fn test_doctor_json_output_writes_only_the_file() {
+        let fixture = TestFixture::new();
+        let report = fixture.temp_dir.path().join("reports/doctor.json");
+        let output = scoop_cmd(&fixture.scoop_home)
+            .args(["doctor", "--json", "--output"])
+            .arg(&report)
+            .output()
+            .unwrap();
+
+        assert!(output.stdout.is_empty(), "stdout: {:?}", output.stdout);
+        let value: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&report).unwrap()).unwrap();
+        assert!(value["summary"]["total"].as_u64().unwrap() > 0);
+    }
+
+    #[test]
+    fn test_no_color_conflicts_with_color() {
+        Command::cargo_bin("scuv")
+            .unwrap()
+            .args(["--color", "always", "--no-color", "list"])
+            .assert()
+            .failure()
+            .stderr(predicate::str::contains("cannot be used with"));
+    }
+
     #[test]
     fn test_version_output_format() {
         let output = Command::cargo_bin("scuv")
@@ -593,6 +908,36 @@ mod output_format {
 mod requires_uv {
     use super::*;
 
+    #[test]
+    #[ignore = "requires uv to be installed"]
+    fn test_use_create_creates_missing_env_once() {
+        let fixture = TestFixture::new();
+        let project_dir = fixture.temp_dir.path().join("project");
+        std::fs::create_dir_all(&project_dir).unwrap();
+
+        scoop_cmd(&fixture.scoop_home)
+            .current_dir(&project_dir)
+            .args(["use", "fresh", "--create", "3.12", "--json"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(r#""env_created":true"#));
+        assert!(
+            fixture
+                .scoop_home
+                .join("virtualenvs")
+                .join("fresh")
+                .is_dir()
+        );
+
+        // Already there: the create step is skipped.
+        scoop_cmd(&fixture.scoop_home)
+            .current_dir(&project_dir)
+            .args(["use", "fresh", "--create", "--json"])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains(r#""env_created":false"#));
+    }
+
     #[test]
     #[ignore = "requires uv to be installed"]
     fn test_create_and_list() {
@@ -803,3 +1148,40 @@ mod shell_commands {
             .stdout(predicate::str::contains("set -gx VIRTUAL_ENV"));
     }
 }
+
+#[test]
+fn test_bare_migrate_json_emits_list_envelope() {
+    let fixture = TestFixture::new();
+    let home = fixture.temp_dir.path().join("home");
+    std::fs::create_dir_all(&home).unwrap();
+    let mut cmd = scoop_cmd(&fixture.scoop_home);
+    // Keep discovery away from the real pyenv/conda/... installs.
+    cmd.env("HOME", &home);
+    for var in [
+        "PYENV_ROOT",
+        "WORKON_HOME",
+        "CONDA_PREFIX",
+        "CONDA_EXE",
+        "POETRY_VIRTUALENVS_PATH",
+        "POETRY_CACHE_DIR",
+        "HATCH_DATA_DIR",
+        "XDG_CACHE_HOME",
+        "XDG_DATA_HOME",
+    ] {
+        cmd.env_remove(var);
+    }
+    let output = cmd.args(["migrate", "--json"]).output().unwrap();
+    assert!(output.status.success(), "{output:?}");
+    let value: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(value["status"], "success");
+    assert_eq!(value["command"], "migrate list");
+    assert_eq!(value["data"]["source"], "all");
+    assert!(value["data"]["environments"].is_array());
+    assert_eq!(value["data"]["summary"]["total"], 0);
+
+    // The subcommands keep their own --json.
+    scoop_cmd(&fixture.scoop_home)
+        .args(["migrate", "--json", "list"])
+        .assert()
+        .failure();
+}